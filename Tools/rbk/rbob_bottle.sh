@@ -677,6 +677,19 @@ rbob_bark() {
   exec ${ZRBOB_RUNTIME} exec "${ZRBOB_BOTTLE}" "$@"
 }
 
+# Smite sentry — one container-runtime verb against the sentry container
+# (kill, start, restart, inspect). The container is appended, never taken from
+# the caller, so no verb reaches another container.
+rbob_smite() {
+  zrbob_sentinel
+  case "${1:-}" in
+    kill|start|restart|inspect) ;;
+    *) buc_die "Smite refuses verb '${1:-}' — only kill, start, restart, inspect" ;;
+  esac
+  buc_step "Smite Sentry: ${ZRBOB_SENTRY} (${1:-})"
+  exec ${ZRBOB_RUNTIME} "$@" "${ZRBOB_SENTRY}"
+}
+
 # Run ifrit sortie adjutant inside bottle (security test dispatch)
 rbob_ifrit_sortie() {
   zrbob_sentinel
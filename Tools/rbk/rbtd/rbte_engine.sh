@@ -135,7 +135,8 @@ rbte_run() {
   zrbte_build_binary
 
   # Extra CLI args (e.g. --keep-going) pass straight through to the binary,
-  # which owns flag parsing and the disposition policy gate.
+  # which owns flag parsing and the disposition policy gate. --plan's observed
+  # ETA reads the dispatch-resolved BURD_LOG_DIR from the environment.
   buc_step "Running theurge fixture '${z_fixture}'"
   "${ZRBTE_BINARY}" "${z_fixture}" "$@"
 }
@@ -150,7 +151,7 @@ rbte_suite() {
 
   # Composition is owned by theurge: pass the suite imprint straight through to
   # the binary's `suite` mode, which resolves and runs its fixtures. Extra CLI
-  # args (e.g. --keep-going) pass through with it.
+  # args (e.g. --keep-going, --plan) pass through with it.
   buc_step "Running theurge suite '${z_suite}'"
   "${ZRBTE_BINARY}" suite "${z_suite}" "$@"
 }
@@ -170,16 +171,13 @@ rbte_dowse() {
 
   zrbte_build_binary
 
-  # Read-only census over the station's self-logs. The log dir is a station
-  # regime value (BURS_LOG_DIR); dispatch does not export it to children, so
-  # reach it by sourcing the launcher-exported station file — the burs_cli
-  # pattern.
-  test -n "${BURD_STATION_FILE:-}" || buc_die "BURD_STATION_FILE not set - launch via tabtarget"
-  source "${BURD_STATION_FILE}" || buc_die "Failed to source station file: ${BURD_STATION_FILE}"
-  test -n "${BURS_LOG_DIR:-}" || buc_die "BURS_LOG_DIR not set in ${BURD_STATION_FILE}"
+  # Read-only census over the station's self-logs, at the log dir dispatch
+  # resolved (BURV_LOG_DIR override included).
+  test -n "${BURD_LOG_DIR:-}" || buc_die "BURD_LOG_DIR empty - dowse needs a logging dispatch"
 
+  # Extra CLI args (e.g. --propose) pass straight through to the binary.
   buc_step "Dowsing observed tariff history"
-  "${ZRBTE_BINARY}" dowse "${BURS_LOG_DIR}"
+  "${ZRBTE_BINARY}" dowse "${BURD_LOG_DIR}" "$@"
 }
 
 rbte_nihil() {
@@ -39,8 +39,14 @@ macro_rules! rbtd_vessels_dir {
 
 pub mod rbtdgc_consts;
 pub mod rbtdra_almanac;
+pub mod rbtdrb_checkpoint;
 pub mod rbtdrb_probe;
+pub mod rbtdrc_capture;
+pub mod rbtdrc_chaos;
 pub mod rbtdrc_crucible;
+pub mod rbtdrc_golden;
+pub mod rbtdrc_iptables;
+pub mod rbtdrc_policy;
 pub mod rbtdrd_dogfight;
 pub mod rbtdre_engine;
 pub mod rbtdrf_fast;
@@ -57,23 +63,42 @@ pub mod rbtdrn_conformance;
 pub mod rbtdro_onboarding;
 pub mod rbtdrp_attest;
 pub mod rbtdrp_lifecycle;
+pub mod rbtdrp_plan;
 pub mod rbtdrq_damnatio;
 pub mod rbtdrq_pyx;
+pub mod rbtdrr_report;
 pub mod rbtdrs_poison;
+pub mod rbtdrt_interrupt;
 pub mod rbtdru_bash;
 pub mod rbtdru_cupel;
 pub mod rbtdru_python;
 pub mod rbtdrv_patrol;
 pub mod rbtdrw_dowse;
+pub mod rbtdrw_worktree;
+pub mod rbtdrx_fingerprint;
 pub mod rbtdrx_platform;
+pub mod rbtdry_cassette;
+pub mod rbtdrz_facts;
 
 #[cfg(test)]
 mod rbtdth_helpers;
 #[cfg(test)]
+mod rbtdtb_checkpoint;
+#[cfg(test)]
 mod rbtdtb_probe;
 #[cfg(test)]
+mod rbtdtc_capture;
+#[cfg(test)]
+mod rbtdtc_chaos;
+#[cfg(test)]
 mod rbtdtc_crucible;
 #[cfg(test)]
+mod rbtdtc_golden;
+#[cfg(test)]
+mod rbtdtc_iptables;
+#[cfg(test)]
+mod rbtdtc_policy;
+#[cfg(test)]
 mod rbtdte_engine;
 #[cfg(test)]
 mod rbtdti_invocation;
@@ -86,8 +111,20 @@ mod rbtdtm_manifest;
 #[cfg(test)]
 mod rbtdto_onboarding;
 #[cfg(test)]
+mod rbtdtp_plan;
+#[cfg(test)]
+mod rbtdtr_report;
+#[cfg(test)]
 mod rbtdtu_cupel;
 #[cfg(test)]
 mod rbtdtw_dowse;
 #[cfg(test)]
+mod rbtdtw_worktree;
+#[cfg(test)]
+mod rbtdtx_fingerprint;
+#[cfg(test)]
 mod rbtdtx_platform;
+#[cfg(test)]
+mod rbtdty_cassette;
+#[cfg(test)]
+mod rbtdtz_facts;
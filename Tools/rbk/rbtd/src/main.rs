@@ -17,20 +17,67 @@
 // RBTD Theurge — test orchestrator entry point
 //
 // Subcommands:
-//   rbtd <fixture> [--keep-going]
+//   rbtd <fixture> [--keep-going] [--only <glob>] [--skip <glob>] [--tag <tag>] [--plan]
+//                  [--verbose] [--hermetic [--hermetic-keep <var>]] [--netns]
+//                  [--record <dir> | --replay <dir>] [--bless]
 //     Single-fixture runner — charge, run all cases, quench. --keep-going
 //     requests keep-going mode, resolved against the fixture's disposition
-//     (refused for StateProgressing) by rbtdre_resolve_fail_fast.
-//   rbtd suite <suite> [--keep-going]
+//     (refused for StateProgressing) by rbtdre_resolve_fail_fast. The selection
+//     flags (repeatable) narrow the cases run between one charge and quench;
+//     see rbtdre_Selection.
+//   rbtd suite <suite> [--keep-going] [--resume] [--plan] [--verbose]
+//                      [--hermetic [--hermetic-keep <var>]] [--netns] [--worktree]
 //     Suite runner — resolve the suite's fixtures (composition owned here, not
 //     in bash) and run each in sequence, fail-fast, with one aggregate summary.
 //     --keep-going applies per fixture; the cross-fixture break-on-failure is
-//     unchanged.
-//   rbtd single <fixture> [case]
+//     unchanged. --resume skips the green prefix recorded in the prior run's
+//     checkpoint (rbtdrb_checkpoint), refusing if HEAD has moved since.
+//     --worktree runs the suite in a disposable git worktree at HEAD beside the
+//     checkout, which is never touched and may stay dirty; the run's commits
+//     are offered as a branch rbtd/<suite>/<stamp> — see rbtdrw_worktree.
+//   --plan (either runner) prints the execution plan and runs nothing — no
+//     tree guard, no context. Text to the console, JSON into the trace root;
+//     see rbtdrp_plan.
+//   --verbose (either runner) echoes each tabtarget's output lines to the
+//     console as they arrive.
+//   --hermetic (either runner) launches every tabtarget with an allowlisted
+//     environment — PATH, HOME, TERM, the BURV_/BURE_ keys and each
+//     --hermetic-keep <var> (repeatable) — instead of theurge's inherited one,
+//     and reports the dropped variable names; see rbtdri_Hermetic.
+//   --netns (either runner) runs each credless (reveille) fixture's tabtargets
+//     in a fresh loopback-only network namespace; a case whose launches reach
+//     for the network fails as a hermeticity violation. Refused on a host that
+//     cannot build the namespace, and by the fixture runner for a fixture that
+//     is not credless.
+//   --record <dir> (fixture runner) tapes every tabtarget launch into a
+//     cassette; --replay <dir> re-runs the fixture against one without spawning
+//     bash, unguarded by the clean-tree check and held to no tariff — see
+//     rbtdry_cassette.
+//   --bless (fixture and single runners) rewrites the committed sentry config
+//     golden from the live sentry instead of failing on drift — see
+//     rbtdrc_golden. Commit the rewritten golden with the change that moved it.
+//   rbtd single <fixture> [case] [--bless]
 //     Single-case runner — no charge/quench. List cases or run one.
-//   rbtd dowse <log-dir>
+//   SIGINT/SIGTERM (any runner): the first kills the case in flight, launches
+//   nothing more, and lets the fixture's teardown run; the second exits at
+//   once. Exit status is 128 + signal — see rbtdrt_interrupt.
+//   Fixture and suite runs also write a machine-readable run report (JUnit XML
+//   + JSON Lines) into the trace root — see rbtdrr_report. Every runner records
+//   each tabtarget launch into <trace-root>/<fixture>/invocations.jsonl, with
+//   its stdout/stderr streamed into trace files beside it — see
+//   rbtdri_invocation.
+//   rbtd dowse <log-dir> [--propose [--floor-pct <p>] [--ceiling-pct <p>]
+//                                    [--margin-pct <pct>] [--min-runs <n>]]
 //     Observed-tariff census — read-only report over the station's logs-buk
-//     history; no tree guard, no roots, no context.
+//     history; no tree guard, no roots, no context. --propose prints seeded
+//     tariff literals and a declared-vs-observed drift report instead.
+//   rbtd dowse <log-dir> --verdicts [--since <YYYYMMDD[-HHMMSS]>]
+//     Verdict history over the same logs — per-case pass rate, failure stamps
+//     and flaky/regressed classification, per-suite green streaks.
+//   rbtd envdiff <fingerprint-a> <fingerprint-b>
+//     Compare two host fingerprints — the environment.json every fixture and
+//     suite run writes into its trace root (rbtdrx_fingerprint), or trace roots
+//     holding one. Read-only; no tree guard, no roots, no BUK dispatch needed.
 
 // RCG output discipline: all emission via rbtdrg_*! — no direct println!/eprintln!
 
@@ -38,34 +85,73 @@
 #![allow(private_interfaces)]
 #![deny(warnings)]
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use rbtd::rbtdra_almanac::{
-    rbtdra_lookup_fixture, rbtdra_lookup_suite, RBTDRA_FIXTURES, RBTDRA_SUITES,
+    rbtdra_fixture_suite_timeout, rbtdra_lookup_fixture, rbtdra_lookup_suite, RBTDRA_FIXTURES,
+    RBTDRA_SUITES,
+};
+use rbtd::rbtdrb_checkpoint::{rbtdrb_find_latest, rbtdrb_resume_index, rbtdrb_Checkpoint};
+use rbtd::rbtdrc_crucible::{
+    rbtdrc_set_bless, rbtdrc_set_context, rbtdrc_take_context, RBTDRC_CASES_POLICY,
 };
-use rbtd::rbtdrc_crucible::{rbtdrc_set_context, rbtdrc_take_context};
 use rbtd::rbtdre_engine::{
+    RBTDRE_FIXTURE_FLAGS,
+    RBTDRE_FLAG_BLESS,
+    RBTDRE_FLAG_HERMETIC,
+    RBTDRE_FLAG_HERMETIC_KEEP,
     RBTDRE_FLAG_KEEP_GOING,
+    RBTDRE_FLAG_NETNS,
+    RBTDRE_FLAG_ONLY,
+    RBTDRE_FLAG_PLAN,
+    RBTDRE_FLAG_RECORD,
+    RBTDRE_FLAG_REPLAY,
+    RBTDRE_FLAG_RESUME,
+    RBTDRE_FLAG_SKIP,
+    RBTDRE_FLAG_TAG,
+    RBTDRE_FLAG_VERBOSE,
+    RBTDRE_FLAG_WORKTREE,
+    RBTDRE_SUITE_FLAGS,
+    rbtdre_Fixture,
+    rbtdre_RunOptions,
+    rbtdre_Selection,
     rbtdre_TariffRow,
     rbtdre_detect_colors,
     rbtdre_find_case,
     rbtdre_list_cases,
-    rbtdre_parse_keep_going,
+    rbtdre_parse_runner_args,
     rbtdre_print_summary,
     rbtdre_print_tariff_table,
     rbtdre_run_fixture,
     rbtdre_run_single_case,
     rbtdre_tariff_declared,
     rbtdre_tree_clean,
+    rbtdre_tree_commit,
 };
 use rbtd::rbtdri_invocation::{
-    rbtdri_Context, rbtdri_invoke_global,
-    RBTDRI_BURD_TEMP_DIR_KEY,
+    rbtdri_Context, rbtdri_Hermetic, rbtdri_arm_cassette, rbtdri_inherited_env,
+    rbtdri_invoke_global, rbtdri_netns_probe, rbtdri_set_echo, rbtdri_set_hermetic,
+    rbtdri_set_netns, RBTDRI_BURD_TEMP_DIR_KEY,
+    RBTDRI_HERMETIC_REPORT_FILE,
+};
+use rbtd::rbtdrm_manifest::rbtdrm_replayable;
+use rbtd::rbtdrp_plan::{rbtdrp_build, RBTDRP_LOG_DIR_KEY};
+use rbtd::rbtdrr_report::rbtdrr_Report;
+use rbtd::rbtdrt_interrupt::{rbtdrt_current, rbtdrt_install_handlers, RBTDRT_EXIT_BASE};
+use rbtd::rbtdrw_dowse::{
+    rbtdrw_dowse, rbtdrw_dowse_propose, rbtdrw_dowse_verdicts, rbtdrw_gather,
+    rbtdrw_parse_dowse_args, RBTDRW_FLAG_CEILING_PCT, RBTDRW_FLAG_FLOOR_PCT,
+    RBTDRW_FLAG_MARGIN_PCT, RBTDRW_FLAG_MIN_RUNS, RBTDRW_FLAG_PROPOSE, RBTDRW_FLAG_SINCE,
+    RBTDRW_FLAG_VERDICTS,
 };
-use rbtd::rbtdrw_dowse::rbtdrw_dowse;
+use rbtd::rbtdrw_worktree::{rbtdrw_Worktree, RBTDRW_BURD_NOW_STAMP_KEY};
 use rbtd::rbtdgc_consts::RBTDGC_CRUCIBLE_ACTIVE;
+use rbtd::rbtdrx_fingerprint::{
+    rbtdrx_fingerprint_diff, rbtdrx_Fingerprint, RBTDRX_FINGERPRINT_FILE,
+};
 use rbtd::rbtdrx_platform::rbtdrx_path_from_env;
+use rbtd::rbtdry_cassette::rbtdry_Cassette;
 
 fn main() -> ExitCode {
     let args: Vec<String> = std::env::args().collect();
@@ -74,26 +160,81 @@ fn main() -> ExitCode {
         Some("single") => rbtd_run_single(&args[2..]),
         Some("suite") => rbtd_run_suite(&args[2..]),
         Some("dowse") => rbtd_run_dowse(&args[2..]),
+        Some("envdiff") => rbtd_run_envdiff(&args[2..]),
         _ => rbtd_run_fixture(&args[1..]),
     }
 }
 
-// ── Dowse (observed-tariff census) ───────────────────────────
+// ── Dowse (observed-tariff census, verdict history) ──────────
 
 fn rbtd_run_dowse(args: &[String]) -> ExitCode {
-    let log_dir = match args.first() {
-        Some(d) => PathBuf::from(d),
-        None => rbtd::rbtdrg_fatal_now!(
-            "rbtd dowse: usage: rbtd dowse <log-dir>\n\
-             launch via tabtarget: tt/rbw-td.TariffDowse.sh"
+    let parsed = match rbtdrw_parse_dowse_args(args) {
+        Ok(p) => p,
+        Err(msg) => rbtd::rbtdrg_fatal_now!(
+            "rbtd dowse: {}\n\
+             usage: rbtd dowse <log-dir> [{} [{} <p>] [{} <p>] [{} <pct>] [{} <n>]]\n\
+             \x20      rbtd dowse <log-dir> {} [{} <YYYYMMDD[-HHMMSS]>]\n\
+             launch via tabtarget: tt/rbw-td.TariffDowse.sh",
+            msg,
+            RBTDRW_FLAG_PROPOSE,
+            RBTDRW_FLAG_FLOOR_PCT,
+            RBTDRW_FLAG_CEILING_PCT,
+            RBTDRW_FLAG_MARGIN_PCT,
+            RBTDRW_FLAG_MIN_RUNS,
+            RBTDRW_FLAG_VERDICTS,
+            RBTDRW_FLAG_SINCE
         ),
     };
-    match rbtdrw_dowse(&log_dir) {
+    let log_dir = PathBuf::from(&parsed.log_dir);
+    let outcome = match &parsed.propose {
+        Some(params) => rbtdrw_dowse_propose(&log_dir, params),
+        None if parsed.verdicts => rbtdrw_dowse_verdicts(&log_dir, parsed.since.as_deref()),
+        None => rbtdrw_dowse(&log_dir),
+    };
+    match outcome {
         Ok(()) => ExitCode::SUCCESS,
         Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd: {}", msg),
     }
 }
 
+// ── Envdiff (host fingerprint comparison) ────────────────────
+
+/// A fingerprint named on the command line: the file itself, or a trace root
+/// holding one.
+fn rbtd_read_fingerprint(arg: &str) -> Result<rbtdrx_Fingerprint, String> {
+    let path = Path::new(arg);
+    if path.is_dir() {
+        rbtdrx_Fingerprint::read(&path.join(RBTDRX_FINGERPRINT_FILE))
+    } else {
+        rbtdrx_Fingerprint::read(path)
+    }
+}
+
+fn rbtd_run_envdiff(args: &[String]) -> ExitCode {
+    let (a, b) = match args {
+        [a, b] => (a, b),
+        _ => rbtd::rbtdrg_fatal_now!(
+            "rbtd envdiff: usage: rbtd envdiff <fingerprint-a> <fingerprint-b>\n\
+             each is an {} or a trace root holding one",
+            RBTDRX_FINGERPRINT_FILE
+        ),
+    };
+    let (left, right) = match (rbtd_read_fingerprint(a), rbtd_read_fingerprint(b)) {
+        (Ok(l), Ok(r)) => (l, r),
+        (Err(msg), _) | (_, Err(msg)) => rbtd::rbtdrg_fatal_now!("{}", msg),
+    };
+    let diff = rbtdrx_fingerprint_diff(&left, &right);
+    if diff.is_empty() {
+        rbtd::rbtdrg_info_now!("rbtd envdiff: the two hosts fingerprint alike");
+    } else {
+        rbtd::rbtdrg_info_now!("rbtd envdiff: {} difference(s), {} → {}", diff.len(), a, b);
+        for line in &diff {
+            rbtd::rbtdrg_info_now!("  {}", line);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
 struct rbtd_Roots {
     trace_root: PathBuf,
     burv_temp_root: PathBuf,
@@ -124,25 +265,121 @@ fn rbtd_allocate_roots() -> Result<rbtd_Roots, String> {
     Ok(rbtd_Roots { trace_root, burv_temp_root, burv_output_root })
 }
 
+/// Collect the host fingerprint into the trace root and summarize it in the run
+/// report. Best-effort like the report itself: a fingerprint that cannot be
+/// written is an error line, never a changed verdict.
+fn rbtd_record_fingerprint(project_root: &Path, trace_root: &Path, report: &mut rbtdrr_Report) {
+    let fingerprint = rbtdrx_Fingerprint::collect(project_root);
+    match fingerprint.write(trace_root) {
+        Ok(path) => {
+            report.record_environment(&fingerprint, &path);
+            rbtd::rbtdrg_info_now!("rbtd: host fingerprint: {}", path.display());
+        }
+        Err(msg) => rbtd::rbtdrg_error_now!("{}", msg),
+    }
+}
+
+/// Arm hermetic launches when the run asked for them: the dropped and absent
+/// lines of the report go to the console, the whole report into the trace root.
+fn rbtd_arm_hermetic(hermetic: bool, keep: &[String], trace_root: &Path) -> Result<(), String> {
+    if !hermetic {
+        if !keep.is_empty() {
+            return Err(format!(
+                "rbtd: {} extends the hermetic allowlist — it needs {}",
+                RBTDRE_FLAG_HERMETIC_KEEP, RBTDRE_FLAG_HERMETIC
+            ));
+        }
+        return Ok(());
+    }
+    let hermetic = rbtdri_Hermetic::new(keep);
+    let lines = hermetic.report(rbtdri_inherited_env());
+    let path = trace_root.join(RBTDRI_HERMETIC_REPORT_FILE);
+    std::fs::write(&path, format!("{}\n", lines.join("\n")))
+        .map_err(|e| format!("rbtd: cannot write hermetic report '{}': {}", path.display(), e))?;
+    for line in lines.iter().filter(|l| !l.trim_start().starts_with("kept")) {
+        rbtd::rbtdrg_info_now!("rbtd: {}", line.trim_start());
+    }
+    rbtd::rbtdrg_info_now!("rbtd: hermetic report: {}", path.display());
+    rbtdri_set_hermetic(Some(hermetic));
+    Ok(())
+}
+
+/// Arm network isolation for the run's credless fixtures, after proving this
+/// host can build the namespace — `--netns` never degrades to an unisolated run.
+fn rbtd_arm_netns(fixtures: &[&rbtdre_Fixture]) -> Result<(), String> {
+    rbtdri_netns_probe().map_err(|e| format!("rbtd: {} refused — {}", RBTDRE_FLAG_NETNS, e))?;
+    for fixture in fixtures.iter().filter(|f| !f.credless) {
+        rbtd::rbtdrg_info_now!(
+            "rbtd: fixture '{}' is not credless — its launches keep the network",
+            fixture.name
+        );
+    }
+    rbtd::rbtdrg_info_now!("rbtd: credless fixtures run in a loopback-only network namespace");
+    rbtdri_set_netns(true);
+    Ok(())
+}
+
 // ── Single-fixture runner ────────────────────────────────────
 
 fn rbtd_run_fixture(args: &[String]) -> ExitCode {
-    let (positionals, keep_going) = match rbtdre_parse_keep_going(args) {
+    let parsed = match rbtdre_parse_runner_args(args, RBTDRE_FIXTURE_FLAGS) {
         Ok(v) => v,
         Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd: {}", msg),
     };
+    let positionals = parsed.positionals;
+    let usage = format!(
+        "rbtd <fixture> [{}] [{} <glob>] [{} <glob>] [{} <tag>] [{}] [{}] \
+         [{} [{} <var>]] [{}] [{} <dir> | {} <dir>] [{}]",
+        RBTDRE_FLAG_KEEP_GOING, RBTDRE_FLAG_ONLY, RBTDRE_FLAG_SKIP, RBTDRE_FLAG_TAG,
+        RBTDRE_FLAG_PLAN, RBTDRE_FLAG_VERBOSE, RBTDRE_FLAG_HERMETIC, RBTDRE_FLAG_HERMETIC_KEEP,
+        RBTDRE_FLAG_NETNS, RBTDRE_FLAG_RECORD, RBTDRE_FLAG_REPLAY, RBTDRE_FLAG_BLESS
+    );
     let fixture = match positionals.first() {
         Some(n) => n,
         None => rbtd::rbtdrg_fatal_now!(
-            "rbtd: usage: rbtd <fixture> [{}]\n\
+            "rbtd: usage: {}\n\
              theurge must be launched via tabtarget (e.g. tt/rbw-tf.FixtureRun.sh tadmor)",
-            RBTDRE_FLAG_KEEP_GOING
+            usage
         ),
     };
     if positionals.len() > 1 {
         rbtd::rbtdrg_fatal_now!(
-            "rbtd: unexpected argument '{}' — usage: rbtd <fixture> [{}]",
-            positionals[1], RBTDRE_FLAG_KEEP_GOING
+            "rbtd: unexpected argument '{}' — usage: {}",
+            positionals[1], usage
+        );
+    }
+
+    if parsed.plan {
+        let fixture_def = match rbtdra_lookup_fixture(fixture) {
+            Some(f) => f,
+            None => rbtd::rbtdrg_fatal_now!(
+                "rbtd: unknown fixture '{}' — usage: {}",
+                fixture, usage
+            ),
+        };
+        let timeout = rbtdra_fixture_suite_timeout(fixture_def);
+        return rbtd_print_plan("fixture", fixture, &[fixture_def], timeout, &parsed.selection);
+    }
+
+    // Cassette, opened before any other work so a bad directory fails first.
+    let cassette = match (&parsed.record, &parsed.replay) {
+        (None, None) => None,
+        (Some(dir), None) => Some(rbtdry_Cassette::record(Path::new(dir))),
+        (None, Some(dir)) => Some(rbtdry_Cassette::replay(Path::new(dir))),
+        (Some(_), Some(_)) => rbtd::rbtdrg_fatal_now!(
+            "rbtd: {} and {} are exclusive — usage: {}",
+            RBTDRE_FLAG_RECORD, RBTDRE_FLAG_REPLAY, usage
+        ),
+    };
+    let cassette = match cassette.transpose() {
+        Ok(c) => c,
+        Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd: {}", msg),
+    };
+    let replaying = cassette.as_ref().is_some_and(|c| c.replaying());
+    if replaying && !rbtdrm_replayable(fixture) {
+        rbtd::rbtdrg_fatal_now!(
+            "rbtd: '{}' cannot run under {} — its cases act on a live sentry",
+            fixture, RBTDRE_FLAG_REPLAY
         );
     }
 
@@ -154,8 +391,15 @@ fn rbtd_run_fixture(args: &[String]) -> ExitCode {
     // Run-start hygiene guard (suite only). A suite run commits a sequence of
     // hallmark/yoke changes; starting on a dirty tree would interleave the
     // operator's uncommitted edits with those commits. Single-case mode is the
-    // crucible-debug loop and is intentionally left unguarded.
-    if let Err(msg) = rbtdre_tree_clean(&project_root) {
+    // crucible-debug loop and is intentionally left unguarded, and so is a
+    // replay: its commit verbs stand down (rbtdre_commit_paths), and its point
+    // is to exercise the operator's uncommitted edits to case logic.
+    if replaying {
+        rbtd::rbtdrg_info_now!(
+            "rbtd: replaying fixture '{}' from cassette — no tabtarget spawns",
+            fixture
+        );
+    } else if let Err(msg) = rbtdre_tree_clean(&project_root) {
         rbtd::rbtdrg_fatal_now!(
             "rbtd: refusing to start a suite run on a dirty working tree — \
              commit or stash first.\n{}",
@@ -168,12 +412,13 @@ fn rbtd_run_fixture(args: &[String]) -> ExitCode {
         Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
     };
 
-    let ctx = rbtdri_Context::new(
+    let mut ctx = rbtdri_Context::new(
         &project_root,
         fixture,
         &roots.burv_temp_root,
         &roots.burv_output_root,
     );
+    ctx.set_transcript_dir(&roots.trace_root.join(fixture));
 
     let fixture_def = match rbtdra_lookup_fixture(fixture) {
         Some(f) => f,
@@ -186,34 +431,77 @@ fn rbtd_run_fixture(args: &[String]) -> ExitCode {
     };
 
     rbtdrc_set_context(ctx);
+    rbtdrt_install_handlers();
+    rbtdri_set_echo(parsed.verbose);
+    rbtdrc_set_bless(parsed.bless);
+    if let Err(msg) = rbtd_arm_hermetic(parsed.hermetic, &parsed.hermetic_keep, &roots.trace_root) {
+        rbtd::rbtdrg_fatal_now!("{}", msg);
+    }
+    if parsed.netns {
+        if !fixture_def.credless {
+            rbtd::rbtdrg_fatal_now!(
+                "rbtd: {} isolates credless (reveille) fixtures; '{}' is not one",
+                RBTDRE_FLAG_NETNS, fixture
+            );
+        }
+        if let Err(msg) = rbtd_arm_netns(&[fixture_def]) {
+            rbtd::rbtdrg_fatal_now!("{}", msg);
+        }
+    }
+    rbtdri_arm_cassette(cassette);
+
+    let mut report = rbtdrr_Report::new(&roots.trace_root, "fixture", fixture_def.name);
+    rbtd_record_fingerprint(&project_root, &roots.trace_root, &mut report);
 
     let colors = rbtdre_detect_colors();
-    let run_result = rbtdre_run_fixture(fixture_def, &colors, &roots.trace_root, keep_going);
+    let options = rbtdre_RunOptions {
+        keep_going: parsed.keep_going,
+        case_timeout_secs: rbtdra_fixture_suite_timeout(fixture_def),
+        selection: parsed.selection,
+        tree_root: Some(project_root),
+    };
+    let run_result = rbtdre_run_fixture(fixture_def, &colors, &roots.trace_root, &options);
 
     let _ctx = rbtdrc_take_context();
+    if let Some(cassette) = rbtdri_arm_cassette(None) {
+        let (served, total) = cassette.served();
+        if replaying && served < total {
+            rbtd::rbtdrg_info_now!(
+                "rbtd: replay served {} of {} cassette entries — the run diverged from the tape",
+                served, total
+            );
+        }
+    }
 
     let result = match run_result {
         Ok(r) => r,
-        Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd: {}", msg),
+        Err(msg) => {
+            report.record_fixture_error(fixture_def, &msg);
+            report.finish(0, 1, 0);
+            rbtd::rbtdrg_fatal_now!("rbtd: {}", msg)
+        }
     };
 
+    report.record_fixture(fixture_def, &result);
+    report.finish(result.passed, result.failed, result.skipped);
+
     rbtdre_print_summary(&result, &colors);
 
     if result.failed > 0 {
         rbtd::rbtdrg_error_now!("rbtd: {} case(s) failed", result.failed);
-        ExitCode::FAILURE
-    } else {
-        ExitCode::SUCCESS
     }
+    rbtd_exit_code(result.failed > 0)
 }
 
 // ── Suite runner ─────────────────────────────────────────────
 
 fn rbtd_run_suite(args: &[String]) -> ExitCode {
-    let (positionals, keep_going) = match rbtdre_parse_keep_going(args) {
+    let parsed = match rbtdre_parse_runner_args(args, RBTDRE_SUITE_FLAGS) {
         Ok(v) => v,
         Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd suite: {}", msg),
     };
+    let positionals = parsed.positionals;
+    let keep_going = parsed.keep_going;
     let suite = match positionals.first() {
         Some(name) => match rbtdra_lookup_suite(name) {
             Some(s) => s,
@@ -231,24 +519,53 @@ fn rbtd_run_suite(args: &[String]) -> ExitCode {
     };
     if positionals.len() > 1 {
         rbtd::rbtdrg_fatal_now!(
-            "rbtd suite: unexpected argument '{}' — usage: rbtd suite <suite> [{}]",
-            positionals[1], RBTDRE_FLAG_KEEP_GOING
+            "rbtd suite: unexpected argument '{}' — usage: rbtd suite <suite> \
+             [{}] [{}] [{}] [{}] [{} [{} <var>]] [{}] [{}]",
+            positionals[1], RBTDRE_FLAG_KEEP_GOING, RBTDRE_FLAG_RESUME, RBTDRE_FLAG_PLAN,
+            RBTDRE_FLAG_VERBOSE, RBTDRE_FLAG_HERMETIC, RBTDRE_FLAG_HERMETIC_KEEP, RBTDRE_FLAG_NETNS,
+            RBTDRE_FLAG_WORKTREE
         );
     }
 
-    let project_root = match std::env::current_dir() {
+    if parsed.plan {
+        if parsed.resume {
+            rbtd::rbtdrg_fatal_now!(
+                "rbtd suite: {} previews the whole suite — drop {}",
+                RBTDRE_FLAG_PLAN, RBTDRE_FLAG_RESUME
+            );
+        }
+        return rbtd_print_plan(
+            "suite", suite.name, suite.fixtures, suite.case_timeout_secs, &rbtdre_Selection::ALL,
+        );
+    }
+
+    if parsed.worktree && parsed.resume {
+        rbtd::rbtdrg_fatal_now!(
+            "rbtd suite: {} starts afresh at HEAD in a new worktree — drop {}",
+            RBTDRE_FLAG_WORKTREE, RBTDRE_FLAG_RESUME
+        );
+    }
+
+    let checkout = match std::env::current_dir() {
         Ok(p) => p,
         Err(e) => rbtd::rbtdrg_fatal_now!("rbtd: cannot determine working directory: {}", e),
     };
 
     // Run-start hygiene guard, once per suite (under the bash loop it ran once
     // per fixture). A suite commits a sequence of hallmark/yoke changes; a dirty
-    // tree at the start would interleave the operator's uncommitted edits.
-    if let Err(msg) = rbtdre_tree_clean(&project_root) {
-        rbtd::rbtdrg_fatal_now!(
-            "rbtd: refusing to start a suite run on a dirty working tree — \
-             commit or stash first.\n{}",
-            msg
+    // tree at the start would interleave the operator's uncommitted edits. A
+    // worktree run commits elsewhere and starts from HEAD, so the checkout may
+    // stay dirty — its edits just sit the run out.
+    if let Err(msg) = rbtdre_tree_clean(&checkout) {
+        if !parsed.worktree {
+            rbtd::rbtdrg_fatal_now!(
+                "rbtd: refusing to start a suite run on a dirty working tree — \
+                 commit or stash first.\n{}",
+                msg
+            );
+        }
+        rbtd::rbtdrg_info_now!(
+            "rbtd: checkout has uncommitted edits — the worktree runs HEAD without them"
         );
     }
 
@@ -260,7 +577,76 @@ fn rbtd_run_suite(args: &[String]) -> ExitCode {
         Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
     };
 
+    // Resume point. The clean-tree guard above has already run, so a resume
+    // additionally needs only HEAD to match the checkpoint's commit: together
+    // they pin the tree the checkpoint's green prefix was proven against.
+    let head = match rbtdre_tree_commit(&checkout) {
+        Ok(c) => c,
+        Err(msg) => rbtd::rbtdrg_fatal_now!(
+            "rbtd: cannot read HEAD for the suite checkpoint: {}",
+            msg
+        ),
+    };
+    let (start, mut next_invoke_count, mut green) = if parsed.resume {
+        let (path, checkpoint) = match rbtdrb_find_latest(&roots.trace_root, suite.name) {
+            Ok(v) => v,
+            Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
+        };
+        if checkpoint.commit != head {
+            rbtd::rbtdrg_fatal_now!(
+                "rbtd: refusing to resume suite '{}' — HEAD moved since the checkpoint.\n\
+                 checkpoint: {} ({})\n\
+                 HEAD:       {}\n\
+                 Run the suite afresh.",
+                suite.name, checkpoint.commit, path.display(), head
+            );
+        }
+        let start = match rbtdrb_resume_index(&checkpoint, suite) {
+            Ok(i) => i,
+            Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
+        };
+        if start == suite.fixtures.len() {
+            rbtd::rbtdrg_info_now!(
+                "Suite '{}' is already green in {} — nothing to resume",
+                suite.name, path.display()
+            );
+            return ExitCode::SUCCESS;
+        }
+        rbtd::rbtdrg_info_now!(
+            "Resuming suite '{}' at fixture '{}' — {} green fixture(s) carried from {}",
+            suite.name, suite.fixtures[start].name, start, path.display()
+        );
+        (start, checkpoint.invoke_count, checkpoint.passed)
+    } else {
+        (0, 0u32, Vec::new())
+    };
+
+    rbtdrt_install_handlers();
+    rbtdri_set_echo(parsed.verbose);
+    if let Err(msg) = rbtd_arm_hermetic(parsed.hermetic, &parsed.hermetic_keep, &roots.trace_root) {
+        rbtd::rbtdrg_fatal_now!("{}", msg);
+    }
+    if parsed.netns {
+        if let Err(msg) = rbtd_arm_netns(&suite.fixtures[start..]) {
+            rbtd::rbtdrg_fatal_now!("{}", msg);
+        }
+    }
+
+    // The worktree is created last, after every refusal above, so a refused run
+    // leaves none behind. From here on it is the run's root and working dir.
+    let worktree = if parsed.worktree {
+        match rbtd_open_worktree(&checkout, suite.name) {
+            Ok(w) => Some(w),
+            Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
+        }
+    } else {
+        None
+    };
+    let project_root = worktree.as_ref().map_or_else(|| checkout.clone(), |w| w.path.clone());
+
     let colors = rbtdre_detect_colors();
+    let mut report = rbtdrr_Report::new(&roots.trace_root, "suite", suite.name);
+    rbtd_record_fingerprint(&project_root, &roots.trace_root, &mut report);
 
     let mut total_passed = 0usize;
     let mut total_failed = 0usize;
@@ -273,13 +659,24 @@ fn rbtd_run_suite(args: &[String]) -> ExitCode {
     // `set -e`. A fixture whose cases fail (or whose setup errors) stops the
     // suite; fixtures already run have charged-and-quenched cleanly.
     //
-    // Note: a case that *panics* (rather than returning a Fail verdict) unwinds
-    // past its fixture's quench and aborts the whole suite process with that
-    // fixture's crucible left charged — the same leak the per-process bash loop
-    // had. Routine failure is always a Fail verdict, which quenches normally
-    // (teardown is finally-shaped in rbtdre_run_fixture).
-    let mut next_invoke_count = 0u32;
-    for fixture in suite.fixtures {
+    // A case or hook that *panics* is caught at the engine's panic boundary
+    // (rbtdre_catch_panic) and becomes a Fail verdict or setup failure, so its
+    // fixture still quenches and the suite stops here through the same
+    // break-on-failure as any other red.
+    //
+    // After each fixture the checkpoint is rewritten (green or red), so a
+    // `--resume` after a red picks up at that fixture with HEAD as it was left.
+    //
+    // An interrupt (rbtdrt_interrupt) stops the suite the same way once the
+    // current fixture has torn down; the interrupted fixture is never green,
+    // even when no case failed before the interrupt landed.
+    let options = rbtdre_RunOptions {
+        keep_going,
+        case_timeout_secs: suite.case_timeout_secs,
+        selection: rbtdre_Selection::ALL,
+        tree_root: Some(project_root.clone()),
+    };
+    for fixture in &suite.fixtures[start..] {
         let mut ctx = rbtdri_Context::new(
             &project_root,
             fixture.name,
@@ -293,14 +690,16 @@ fn rbtd_run_suite(args: &[String]) -> ExitCode {
         // fixture's chaining facts into a non-chained invoke's previous/.
         // Suite-monotonic numbering gives each invoke its own dir, closing that.
         ctx.set_invoke_count(next_invoke_count);
+        ctx.set_transcript_dir(&roots.trace_root.join(fixture.name));
         rbtdrc_set_context(ctx);
 
-        let run_result = rbtdre_run_fixture(fixture, &colors, &roots.trace_root, keep_going);
+        let run_result = rbtdre_run_fixture(fixture, &colors, &roots.trace_root, &options);
 
         next_invoke_count = rbtdrc_take_context().invoke_count();
 
-        match run_result {
+        let stop = match run_result {
             Ok(result) => {
+                report.record_fixture(fixture, &result);
                 rbtdre_print_summary(&result, &colors);
                 tariff_rows.push(rbtdre_TariffRow {
                     name: fixture.name.to_string(),
@@ -312,30 +711,174 @@ fn rbtd_run_suite(args: &[String]) -> ExitCode {
                 total_failed += result.failed;
                 total_skipped += result.skipped;
                 ran += 1;
-                if result.failed > 0 {
-                    break;
+                if result.failed == 0 && !result.interrupted {
+                    green.push(fixture.name.to_string());
                 }
+                result.failed > 0 || result.interrupted
             }
             Err(msg) => {
                 rbtd::rbtdrg_error_now!("rbtd: fixture '{}': {}", fixture.name, msg);
+                report.record_fixture_error(fixture, &msg);
                 total_failed += 1;
                 ran += 1;
-                break;
+                true
             }
+        };
+
+        rbtd_write_checkpoint(
+            &roots.trace_root, &project_root, suite.name, next_invoke_count, &green,
+        );
+        if stop {
+            break;
         }
     }
 
     rbtdre_print_tariff_table(&tariff_rows);
+    report.finish(total_passed, total_failed, total_skipped);
 
+    // Shape parsed back by `rbtd dowse --verdicts` (rbtdrw_parse_suite_line).
+    // An interrupted suite prints no summary: its zero-failed tally covers only
+    // the fixtures reached, and dowse would read it as green. Without the
+    // summary dowse records the run as incomplete.
+    // A worktree run cannot resume (refused above), so it is pointed at a rerun
+    // instead; its commits, if any, are offered on a branch as it closes.
+    if rbtdrt_current().raised() && worktree.is_some() {
+        rbtd::rbtdrg_info_now!(
+            "Suite '{}' interrupted by {} — {} cannot {}: rerun the suite; \
+             any commits this run made are offered on the branch below",
+            suite.name, rbtdrt_current().signal_name(), RBTDRE_FLAG_WORKTREE, RBTDRE_FLAG_RESUME
+        );
+    } else if rbtdrt_current().raised() {
+        rbtd::rbtdrg_info_now!(
+            "Suite '{}' interrupted by {} — continue with {}",
+            suite.name, rbtdrt_current().signal_name(), RBTDRE_FLAG_RESUME
+        );
+    } else {
+        rbtd::rbtdrg_info_now!(
+            "Suite '{}': {} fixture(s) run, {} passed, {} failed, {} skipped",
+            suite.name, ran, total_passed, total_failed, total_skipped
+        );
+    }
+
+    let worktree_failed = match worktree {
+        Some(w) => rbtd_close_worktree(w, &checkout),
+        None => false,
+    };
+
+    rbtd_exit_code(total_failed > 0 || worktree_failed)
+}
+
+/// Create the suite's worktree and move theurge into it — fixtures read the
+/// project root from `current_dir()`, and tabtargets inherit it.
+fn rbtd_open_worktree(checkout: &Path, suite: &str) -> Result<rbtdrw_Worktree, String> {
+    let stamp = std::env::var(RBTDRW_BURD_NOW_STAMP_KEY).map_err(|_| {
+        format!(
+            "rbtd: {} needs {} — theurge must be launched via BUK dispatch",
+            RBTDRE_FLAG_WORKTREE, RBTDRW_BURD_NOW_STAMP_KEY
+        )
+    })?;
+    let worktree = rbtdrw_Worktree::create(checkout, suite, &stamp)
+        .map_err(|e| format!("rbtd: cannot create suite worktree: {}", e))?;
+    if let Err(e) = std::env::set_current_dir(&worktree.path) {
+        let msg = format!("rbtd: cannot enter worktree '{}': {}", worktree.path.display(), e);
+        if let Err(cleanup) = worktree.finish() {
+            rbtd::rbtdrg_error_now!("rbtd: {}", cleanup);
+        }
+        return Err(msg);
+    }
     rbtd::rbtdrg_info_now!(
-        "Suite '{}': {} fixture(s) run, {} passed, {} failed, {} skipped",
-        suite.name, ran, total_passed, total_failed, total_skipped
+        "rbtd: suite runs in worktree {} at {}",
+        worktree.path.display(), worktree.base
     );
+    Ok(worktree)
+}
 
-    if total_failed > 0 {
-        ExitCode::FAILURE
-    } else {
-        ExitCode::SUCCESS
+/// Leave and remove the suite's worktree, offering its commits for review.
+/// Returns true when the worktree could not be closed cleanly.
+fn rbtd_close_worktree(worktree: rbtdrw_Worktree, checkout: &Path) -> bool {
+    if let Err(e) = std::env::set_current_dir(checkout) {
+        rbtd::rbtdrg_error_now!("rbtd: cannot return to checkout '{}': {}", checkout.display(), e);
+    }
+    let base = worktree.base.clone();
+    let outcome = match worktree.finish() {
+        Ok(o) => o,
+        Err(msg) => {
+            rbtd::rbtdrg_error_now!("rbtd: {}", msg);
+            return true;
+        }
+    };
+    for line in &outcome.leftovers {
+        rbtd::rbtdrg_info_now!("rbtd: worktree discarded uncommitted: {}", line);
+    }
+    match &outcome.branch {
+        Some(branch) => {
+            rbtd::rbtdrg_info_now!(
+                "rbtd: {} commit(s) offered for review on branch '{}':",
+                outcome.commits.len(), branch
+            );
+            for commit in &outcome.commits {
+                rbtd::rbtdrg_info_now!("rbtd:   {}", commit);
+            }
+            rbtd::rbtdrg_info_now!("rbtd: review with: git log -p {}..{}", base, branch);
+        }
+        None => rbtd::rbtdrg_info_now!("rbtd: worktree made no commits — discarded"),
+    }
+    false
+}
+
+/// Rewrite the suite checkpoint after a fixture concludes. HEAD is re-read each
+/// time because fixtures commit config evolution as they run. Best-effort: a
+/// checkpoint that cannot be written costs only the ability to resume, never
+/// the run itself.
+fn rbtd_write_checkpoint(
+    trace_root: &std::path::Path,
+    project_root: &std::path::Path,
+    suite: &str,
+    invoke_count: u32,
+    green: &[String],
+) {
+    let written = rbtdre_tree_commit(project_root).and_then(|commit| {
+        rbtdrb_Checkpoint {
+            suite: suite.to_string(),
+            commit,
+            invoke_count,
+            passed: green.to_vec(),
+        }
+        .write(trace_root)
+    });
+    if let Err(msg) = written {
+        rbtd::rbtdrg_error_now!("rbtd: suite checkpoint not written (resume unavailable): {}", msg);
+    }
+}
+
+/// Resolve and print a run's plan, then write its JSON into the trace root.
+/// Observed history comes from the station log dir when the launcher forwards
+/// it; without one the plan still renders, its observed ETA marked unavailable.
+fn rbtd_print_plan(
+    mode: &'static str,
+    name: &str,
+    fixtures: &[&'static rbtdre_Fixture],
+    suite_timeout_secs: Option<u64>,
+    selection: &rbtdre_Selection,
+) -> ExitCode {
+    let history = rbtdrx_path_from_env(RBTDRP_LOG_DIR_KEY).and_then(|dir| rbtdrw_gather(&dir));
+    let plan = match rbtdrp_build(mode, name, fixtures, suite_timeout_secs, selection, history) {
+        Ok(p) => p,
+        Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
+    };
+    for line in plan.render_text() {
+        rbtd::rbtdrg_info_now!("{}", line);
+    }
+    let roots = match rbtd_allocate_roots() {
+        Ok(r) => r,
+        Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
+    };
+    match plan.write_json(&roots.trace_root) {
+        Ok(path) => {
+            rbtd::rbtdrg_info_now!("plan JSON: {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(msg) => rbtd::rbtdrg_fatal_now!("{}", msg),
     }
 }
 
@@ -349,12 +892,18 @@ fn rbtd_list_suites() {
 // ── Single-case runner ───────────────────────────────────────
 
 fn rbtd_run_single(args: &[String]) -> ExitCode {
+    let parsed = match rbtdre_parse_runner_args(args, &[RBTDRE_FLAG_BLESS]) {
+        Ok(v) => v,
+        Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd single: {}", msg),
+    };
+    let args = &parsed.positionals;
     let fixture = match args.first() {
         Some(f) => f,
         None => {
             rbtd::rbtdrg_error_now!(
-                "rbtd single: usage: rbtd single <fixture> [case]\n\
-                 omit case to list all cases for the fixture"
+                "rbtd single: usage: rbtd single <fixture> [case] [{}]\n\
+                 omit case to list all cases for the fixture",
+                RBTDRE_FLAG_BLESS
             );
             rbtd_list_fixtures();
             return ExitCode::FAILURE;
@@ -400,24 +949,45 @@ fn rbtd_run_single(args: &[String]) -> ExitCode {
     };
 
     if fixture_def.setup.is_some() {
+        let nameplate = ctx.imprint().to_string();
         match rbtdri_invoke_global(
             &mut ctx,
             RBTDGC_CRUCIBLE_ACTIVE,
-            &[fixture],
+            &[&nameplate],
             &[],
         ) {
             Ok(r) if r.exit_code == 0 => {}
             _ => rbtd::rbtdrg_fatal_now!(
                 "rbtd single: crucible not charged for '{}'\n\
                  charge first: tt/rbw-cC.Charge.{}.sh",
-                fixture, fixture
+                nameplate, nameplate
             ),
         }
     }
 
+    // The transcript starts after the charge probe, for the same reason.
+    ctx.set_transcript_dir(&roots.trace_root.join(fixture));
     rbtdrc_set_context(ctx);
+    rbtdrc_set_bless(parsed.bless);
 
     let cases = fixture_def.cases;
+    // The policy-derived cases are generic over the nameplate: every crucible
+    // fixture can run them by name, whether or not its own set lists them.
+    let policy_missing = RBTDRC_CASES_POLICY
+        .iter()
+        .any(|p| rbtdre_find_case(cases, p.name).is_none());
+    let policy_cases = if fixture_def.setup.is_some() && policy_missing {
+        RBTDRC_CASES_POLICY
+    } else {
+        &[]
+    };
+    let list_cases = || {
+        rbtdre_list_cases(cases);
+        if !policy_cases.is_empty() {
+            rbtd::rbtdrg_info_now!("policy cases (any crucible):");
+            rbtdre_list_cases(policy_cases);
+        }
+    };
 
     // No case argument — list all cases
     let case_name = match args.get(1) {
@@ -426,34 +996,47 @@ fn rbtd_run_single(args: &[String]) -> ExitCode {
                 "fixture '{}' declared tariff: [{}]",
                 fixture, rbtdre_tariff_declared(&fixture_def.tariff)
             );
-            rbtdre_list_cases(cases);
+            list_cases();
             return ExitCode::SUCCESS;
         }
         Some(n) => n,
     };
 
     // Find the case
-    let case = match rbtdre_find_case(cases, case_name) {
+    let found = rbtdre_find_case(cases, case_name)
+        .or_else(|| rbtdre_find_case(policy_cases, case_name));
+    let case = match found {
         Some(c) => c,
         None => {
             rbtd::rbtdrg_error_now!(
                 "rbtd single: case '{}' not found in fixture '{}'",
                 case_name, fixture
             );
-            rbtdre_list_cases(cases);
+            list_cases();
             return ExitCode::FAILURE;
         }
     };
 
+    rbtdrt_install_handlers();
     let colors = rbtdre_detect_colors();
-    let result = match rbtdre_run_single_case(case, &colors, &roots.trace_root) {
+    let timeout = fixture_def.case_timeout_secs.or(rbtdra_fixture_suite_timeout(fixture_def));
+    let result = match rbtdre_run_single_case(case, &colors, &roots.trace_root, timeout) {
         Ok(r) => r,
         Err(msg) => rbtd::rbtdrg_fatal_now!("rbtd: case execution error: {}", msg),
     };
 
     rbtdre_print_summary(&result, &colors);
 
-    if result.failed > 0 {
+    rbtd_exit_code(result.failed > 0)
+}
+
+/// Runner exit status: 128 + signal when an interrupt stopped the run (shell
+/// convention), otherwise failure or success.
+fn rbtd_exit_code(failed: bool) -> ExitCode {
+    let interrupt = rbtdrt_current();
+    if interrupt.raised() {
+        ExitCode::from((RBTDRT_EXIT_BASE + interrupt.signal()) as u8)
+    } else if failed {
         ExitCode::FAILURE
     } else {
         ExitCode::SUCCESS
@@ -15,6 +15,7 @@ pub const RBTDGC_CRUCIBLE_SCRY: &str = "rbw-cs";
 pub const RBTDGC_CRUCIBLE_WRIT: &str = "rbw-cw";
 pub const RBTDGC_CRUCIBLE_FIAT: &str = "rbw-cf";
 pub const RBTDGC_CRUCIBLE_BARK: &str = "rbw-cb";
+pub const RBTDGC_CRUCIBLE_SMITE: &str = "rbw-cm";
 pub const RBTDGC_CRUCIBLE_ACTIVE: &str = "rbw-cic";
 pub const RBTDGC_CRUCIBLE_KLUDGE_BOTTLE: &str = "rbw-cKB";
 pub const RBTDGC_CRUCIBLE_KLUDGE_SENTRY: &str = "rbw-cKS";
@@ -32,6 +32,7 @@ pub static RBTDRA_FIXTURES: &[&'static rbtdre_Fixture] = &[
     &crate::rbtdrc_crucible::RBTDRC_FIXTURE_MORIAH,
     &crate::rbtdrc_crucible::RBTDRC_FIXTURE_SRJCL,
     &crate::rbtdrc_crucible::RBTDRC_FIXTURE_PLUML,
+    &crate::rbtdrc_crucible::RBTDRC_FIXTURE_CHAOS_TADMOR,
     &crate::rbtdrv_patrol::RBTDRV_FIXTURE_HALLMARK_LIFECYCLE,
     &crate::rbtdrv_patrol::RBTDRV_FIXTURE_LODE_LIFECYCLE,
     &crate::rbtdrv_patrol::RBTDRV_FIXTURE_RELIQUARY_LIFECYCLE,
@@ -113,6 +114,11 @@ pub fn rbtdra_lookup_fixture(fixture: &str) -> Option<&'static rbtdre_Fixture> {
 /// the independent `RBTDRA_REVEILLE_BASE` set-equality/superset guard below pins
 /// that base against silent per-ladder drift (audit 260623, finding X-d: the
 /// chaining-fact-band member had been quietly dropped from gauntlet and skirmish).
+///
+/// Each suite's `case_timeout_secs` is the per-case budget for members that do
+/// not declare their own: minutes for the credless tiers, an hour where a case
+/// may wait out cloud builds. The budget bounds a hang, not a healthy run — the
+/// tariff is what checks a healthy run's cost.
 pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
     // Reveille — no external dependencies.
     rbtdre_Suite {
@@ -132,6 +138,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrh_chain::RBTDRH_FIXTURE_CHAINING_FACT_BAND,
             &crate::rbtdrj_touchstone::RBTDRJ_FIXTURE_TOUCHSTONE,
         ],
+        case_timeout_secs: Some(300),
     },
     // Picket — reveille + GCP-credentialed bare fixtures.
     rbtdre_Suite {
@@ -162,6 +169,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrh_chain::RBTDRH_FIXTURE_CHAINING_FACT_BAND,
             &crate::rbtdrj_touchstone::RBTDRJ_FIXTURE_TOUCHSTONE,
         ],
+        case_timeout_secs: Some(900),
     },
     // Bivouac — reveille + container-runtime crucible fixtures.
     rbtdre_Suite {
@@ -185,6 +193,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrh_chain::RBTDRH_FIXTURE_CHAINING_FACT_BAND,
             &crate::rbtdrj_touchstone::RBTDRJ_FIXTURE_TOUCHSTONE,
         ],
+        case_timeout_secs: Some(1800),
     },
     // Echelon — reveille + every dependency-tiered fixture (picket ∪ bivouac).
     rbtdre_Suite {
@@ -218,6 +227,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrh_chain::RBTDRH_FIXTURE_CHAINING_FACT_BAND,
             &crate::rbtdrj_touchstone::RBTDRJ_FIXTURE_TOUCHSTONE,
         ],
+        case_timeout_secs: Some(1800),
     },
     // Gauntlet — release-qualification ladder. Walks marshal-zero state through
     // freehold-credentialed state to crucible verification. Depot-lifecycle
@@ -261,6 +271,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrc_crucible::RBTDRC_FIXTURE_SRJCL,
             &crate::rbtdrc_crucible::RBTDRC_FIXTURE_PLUML,
         ],
+        case_timeout_secs: Some(3600),
     },
     // Skirmish — the "mini gauntlet": the depot→build→crucible chain WITHOUT
     // project-ID churn, against a standing operator-levied depot (no levy, no
@@ -301,6 +312,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrc_crucible::RBTDRC_FIXTURE_SRJCL,
             &crate::rbtdrc_crucible::RBTDRC_FIXTURE_PLUML,
         ],
+        case_timeout_secs: Some(3600),
     },
     // Dogfight — standing-depot cloud-build viability probe. Sibling to skirmish
     // in the operator-precondition family (reuses a hand-levied depot, no levy,
@@ -316,21 +328,27 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrv_patrol::RBTDRV_FIXTURE_CREDENTIAL_READINESS,
             &crate::rbtdrd_dogfight::RBTDRD_FIXTURE_DOGFIGHT,
         ],
+        case_timeout_secs: Some(3600),
     },
-    // Tadmor self-contained — fully local, no GCP/depot/project. Two fixtures in
+    // Tadmor self-contained — fully local, no GCP/depot/project. Three fixtures in
     // sequence: kludge-tadmor builds BOTH vessels (sentry + bottle) locally and
     // commits each hallmark (the fixture owns the notch — same precedent as
     // onboarding's rbtdro_kludge_nameplate); then the tadmor crucible fixture
-    // charges against the now-clean nameplate, runs the security cases, quenches.
-    // The build is a separate fixture (nameplate passed explicitly) rather than
-    // a self-charging tadmor fixture, because the crucible security cases resolve
-    // their nameplate from the fixture name and would collide on "tadmor".
+    // charges against the now-clean nameplate, runs the security cases, quenches;
+    // then chaos-tadmor charges the same nameplate afresh and proves the sentry
+    // fails closed while degraded. The build is a separate fixture (nameplate
+    // passed explicitly) rather than a self-charging tadmor fixture, because the
+    // crucible security cases resolve their nameplate from the fixture name and
+    // would collide on "tadmor"; chaos-tadmor reaches tadmor through its
+    // manifest imprint instead.
     rbtdre_Suite {
         name: "siege",
         fixtures: &[
             &crate::rbtdro_onboarding::RBTDRO_FIXTURE_KLUDGE_TADMOR,
             &crate::rbtdrc_crucible::RBTDRC_FIXTURE_TADMOR,
+            &crate::rbtdrc_crucible::RBTDRC_FIXTURE_CHAOS_TADMOR,
         ],
+        case_timeout_secs: Some(1800),
     },
     // Blockade - moriah airgap crucible. Sibling to siege on the network-posture
     // axis (siege = tether bottle, blockade = airgap bottle), but unlike siege it
@@ -349,6 +367,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrv_patrol::RBTDRV_FIXTURE_CREDENTIAL_READINESS,
             &crate::rbtdrc_crucible::RBTDRC_FIXTURE_MORIAH,
         ],
+        case_timeout_secs: Some(1800),
     },
     // Parley — positive federation-admission probe. Base-free (like dogfight/siege/
     // blockade), a single-fixture REUSE probe: it drives the real polity verbs
@@ -363,6 +382,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
         fixtures: &[
             &crate::rbtdrv_patrol::RBTDRV_FIXTURE_PARLEY,
         ],
+        case_timeout_secs: Some(900),
     },
     // Calibrant — the deliberately-failing two-member suite the touchstone
     // surface fixture drives as its suite-abort subject: calibrant-fail-fast
@@ -375,6 +395,7 @@ pub static RBTDRA_SUITES: &[rbtdre_Suite] = &[
             &crate::rbtdrl_calibrant::RBTDRL_FIXTURE_FAIL_FAST,
             &crate::rbtdrl_calibrant::RBTDRL_FIXTURE_SENTINEL,
         ],
+        case_timeout_secs: Some(300),
     },
 ];
 
@@ -390,6 +411,18 @@ pub fn rbtdra_lookup_suite(suite: &str) -> Option<&'static rbtdre_Suite> {
     RBTDRA_SUITES.iter().find(|s| s.name == suite)
 }
 
+/// Suite-derived case budget for a fixture run outside any suite (the fixture
+/// and single runners): the tightest `case_timeout_secs` among the suites that
+/// carry it. The fixture's own budget still wins where it declares one; a
+/// fixture no suite carries gets None.
+pub fn rbtdra_fixture_suite_timeout(fixture: &rbtdre_Fixture) -> Option<u64> {
+    RBTDRA_SUITES
+        .iter()
+        .filter(|s| s.fixtures.iter().any(|f| f.name == fixture.name))
+        .filter_map(|s| s.case_timeout_secs)
+        .min()
+}
+
 /// Canonical reveille base — the substrate-independent fixture set every
 /// dependency-tiered suite and release ladder is contractually required to carry.
 /// Declared INDEPENDENTLY of any suite (never spliced into one — see the
@@ -0,0 +1,202 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRB — suite checkpoint for `rbtd suite <suite> --resume`
+//
+// The suite runner is fail-fast across fixtures, so a ladder that dies on
+// fixture 9 of 14 would otherwise restart from fixture 1 and re-spend every
+// cloud build before it. After each fixture concludes the runner rewrites a
+// checkpoint into its trace root: the suite name, the green fixtures so far
+// (always a prefix of the suite order — the runner stops at the first red),
+// the suite-monotonic BURV invoke counter, and the HEAD commit.
+//
+// Every dispatch gets a fresh BURD_TEMP_DIR, so a resumed run finds the failed
+// run's checkpoint among its sibling temp dirs: the newest one recording the
+// same suite. It resumes only if HEAD still equals the recorded commit —
+// fixtures commit config evolution as they go, so a moved HEAD means the tree
+// is no longer the one the green prefix was proven against. The recorded
+// commit is HEAD as of the end of the failed run (the checkpoint is rewritten
+// after the failing fixture too), so the failing fixture's own commits do not
+// block the resume; an operator commit in between does.
+//
+// Format: one `key=value` per line, `passed=` repeated in suite order. Hand-
+// rolled and line-oriented like the BURV fact files — no serde in theurge.
+
+use std::path::{Path, PathBuf};
+
+use crate::rbtdre_engine::rbtdre_Suite;
+
+/// Checkpoint filename, written into the run's trace root.
+pub const RBTDRB_CHECKPOINT_FILE: &str = "suite-checkpoint.txt";
+
+const RBTDRB_KEY_SUITE: &str = "suite";
+const RBTDRB_KEY_COMMIT: &str = "commit";
+const RBTDRB_KEY_INVOKE_COUNT: &str = "invoke_count";
+const RBTDRB_KEY_PASSED: &str = "passed";
+
+/// A suite run's resumable state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrb_Checkpoint {
+    pub suite: String,
+    /// HEAD at the moment the checkpoint was written.
+    pub commit: String,
+    /// Suite-monotonic BURV invoke counter after the last concluded fixture.
+    pub invoke_count: u32,
+    /// Green fixtures, in suite order.
+    pub passed: Vec<String>,
+}
+
+impl rbtdrb_Checkpoint {
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{}={}\n{}={}\n{}={}\n",
+            RBTDRB_KEY_SUITE, self.suite,
+            RBTDRB_KEY_COMMIT, self.commit,
+            RBTDRB_KEY_INVOKE_COUNT, self.invoke_count,
+        );
+        for name in &self.passed {
+            out.push_str(&format!("{}={}\n", RBTDRB_KEY_PASSED, name));
+        }
+        out
+    }
+
+    pub fn parse(text: &str) -> Result<rbtdrb_Checkpoint, String> {
+        let mut suite = None;
+        let mut commit = None;
+        let mut invoke_count = None;
+        let mut passed = Vec::new();
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("checkpoint line without '=': '{}'", line))?;
+            match key {
+                RBTDRB_KEY_SUITE => suite = Some(value.to_string()),
+                RBTDRB_KEY_COMMIT => commit = Some(value.to_string()),
+                RBTDRB_KEY_INVOKE_COUNT => {
+                    invoke_count = Some(value.parse::<u32>().map_err(|e| {
+                        format!("checkpoint {} '{}': {}", RBTDRB_KEY_INVOKE_COUNT, value, e)
+                    })?)
+                }
+                RBTDRB_KEY_PASSED => passed.push(value.to_string()),
+                other => return Err(format!("checkpoint has unknown key '{}'", other)),
+            }
+        }
+        let missing = |k: &str| format!("checkpoint is missing '{}'", k);
+        Ok(rbtdrb_Checkpoint {
+            suite: suite.ok_or_else(|| missing(RBTDRB_KEY_SUITE))?,
+            commit: commit.ok_or_else(|| missing(RBTDRB_KEY_COMMIT))?,
+            invoke_count: invoke_count.ok_or_else(|| missing(RBTDRB_KEY_INVOKE_COUNT))?,
+            passed,
+        })
+    }
+
+    /// Rewrite the checkpoint in `trace_root`. Written to a sibling and renamed
+    /// so an interrupted write never leaves a truncated checkpoint behind.
+    pub fn write(&self, trace_root: &Path) -> Result<(), String> {
+        let path = trace_root.join(RBTDRB_CHECKPOINT_FILE);
+        let staging = trace_root.join(format!("{}.partial", RBTDRB_CHECKPOINT_FILE));
+        std::fs::write(&staging, self.render())
+            .map_err(|e| format!("rbtd: failed to write checkpoint '{}': {}", staging.display(), e))?;
+        std::fs::rename(&staging, &path)
+            .map_err(|e| format!("rbtd: failed to write checkpoint '{}': {}", path.display(), e))
+    }
+
+    pub fn read(path: &Path) -> Result<rbtdrb_Checkpoint, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("rbtd: cannot read checkpoint '{}': {}", path.display(), e))?;
+        rbtdrb_Checkpoint::parse(&text).map_err(|e| format!("rbtd: '{}': {}", path.display(), e))
+    }
+}
+
+/// Find the newest prior checkpoint for `suite` among the sibling dispatch
+/// temp dirs of this run's BURD_TEMP_DIR. `trace_root` is this run's
+/// `BURD_TEMP_DIR/rbtd`; the current run's own dir is skipped. Temp dir names
+/// lead with a `YYYYMMDD-HHMMSS` stamp, so the lexically greatest is the newest.
+/// A checkpoint that cannot be read or parsed is reported and passed over.
+pub fn rbtdrb_find_latest(
+    trace_root: &Path,
+    suite: &str,
+) -> Result<(PathBuf, rbtdrb_Checkpoint), String> {
+    let burd_temp = trace_root
+        .parent()
+        .ok_or_else(|| format!("rbtd: trace root '{}' has no parent", trace_root.display()))?;
+    let temp_root = burd_temp
+        .parent()
+        .ok_or_else(|| format!("rbtd: temp dir '{}' has no parent", burd_temp.display()))?;
+    let trace_leaf = trace_root.file_name().unwrap_or_default();
+
+    let entries = std::fs::read_dir(temp_root)
+        .map_err(|e| format!("rbtd: cannot read temp root '{}': {}", temp_root.display(), e))?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir() && p.as_path() != burd_temp)
+        .collect();
+    dirs.sort();
+
+    for dir in dirs.iter().rev() {
+        let path = dir.join(trace_leaf).join(RBTDRB_CHECKPOINT_FILE);
+        if !path.is_file() {
+            continue;
+        }
+        // One torn or hand-mangled checkpoint must not strand the older valid
+        // ones behind it: warn and keep looking.
+        let checkpoint = match rbtdrb_Checkpoint::read(&path) {
+            Ok(c) => c,
+            Err(e) => {
+                crate::rbtdrg_error_now!("{} — skipped", e);
+                continue;
+            }
+        };
+        if checkpoint.suite == suite {
+            return Ok((path, checkpoint));
+        }
+    }
+    Err(format!(
+        "rbtd: no checkpoint for suite '{}' under '{}' — nothing to resume",
+        suite,
+        temp_root.display()
+    ))
+}
+
+/// Index of the first fixture to run when resuming `suite` from `checkpoint`.
+/// Refuses a checkpoint whose green list is not a prefix of the suite's
+/// current composition — the suite was edited since, so which fixtures the
+/// green prefix covers is no longer knowable.
+pub fn rbtdrb_resume_index(
+    checkpoint: &rbtdrb_Checkpoint,
+    suite: &rbtdre_Suite,
+) -> Result<usize, String> {
+    if checkpoint.suite != suite.name {
+        return Err(format!(
+            "rbtd: checkpoint is for suite '{}', not '{}'",
+            checkpoint.suite, suite.name
+        ));
+    }
+    for (i, name) in checkpoint.passed.iter().enumerate() {
+        match suite.fixtures.get(i) {
+            Some(f) if f.name == name => {}
+            _ => {
+                return Err(format!(
+                    "rbtd: checkpoint green fixture #{} '{}' does not match suite '{}' \
+                     composition — the suite changed since the checkpoint; run it afresh",
+                    i + 1, name, suite.name
+                ))
+            }
+        }
+    }
+    Ok(checkpoint.passed.len())
+}
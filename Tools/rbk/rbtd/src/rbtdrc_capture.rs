@@ -0,0 +1,405 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRC — sentry packet capture: the pcap reader and the pure halves of the
+// capture lifecycle
+//
+// rbtdrc_crucible wraps a case in a bounded tcpdump on the sentry's enclave and
+// uplink legs (the same two legs `rbw-cs` scry watches), copies each pcap into
+// the case dir, and hands the parsed captures to the case. This module holds
+// everything that needs no crucible: the classic-pcap reader (both byte orders,
+// micro- and nanosecond stamps; Ethernet, Linux cooked v1/v2 and raw IP link
+// types), the IPv4/IPv6 header decode down to TCP/UDP ports, each frame's
+// direction (an Ethernet frame's source MAC, a cooked frame's packet type),
+// the sentry leg discovery from `ip -o -4 addr` and `ip -o link` output, the
+// base64 decode the copy rides on (writ stdout is text), and the egress
+// assertion: every packet sent out of the sentry's uplink, whatever its source
+// address, went to an allowed CIDR or to the upstream resolver on port 53, and
+// none was IPv6. pcapng is refused — tcpdump -w writes classic pcap.
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+use crate::rbtdrc_policy::rbtdrc_Cidr;
+
+const ZRBTDRC_PCAP_MAGIC_MICROS: u32 = 0xa1b2_c3d4;
+const ZRBTDRC_PCAP_MAGIC_NANOS: u32 = 0xa1b2_3c4d;
+const ZRBTDRC_PCAP_HEADER_LEN: usize = 24;
+const ZRBTDRC_PCAP_RECORD_LEN: usize = 16;
+
+/// DLT_EN10MB — Ethernet.
+pub const RBTDRC_LINKTYPE_ETHERNET: u32 = 1;
+/// DLT_RAW as tcpdump writes it on Linux.
+pub const RBTDRC_LINKTYPE_RAW: u32 = 101;
+/// DLT_LINUX_SLL — `-i any` cooked capture.
+pub const RBTDRC_LINKTYPE_LINUX_SLL: u32 = 113;
+/// DLT_LINUX_SLL2 — newer libpcap's `-i any`.
+pub const RBTDRC_LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ZRBTDRC_ETHERTYPE_IPV4: u16 = 0x0800;
+const ZRBTDRC_ETHERTYPE_IPV6: u16 = 0x86dd;
+const ZRBTDRC_ETHERTYPE_VLAN: u16 = 0x8100;
+const ZRBTDRC_ETHERTYPE_QINQ: u16 = 0x88a8;
+
+/// Cooked-capture packet type of a frame the host sent (PACKET_OUTGOING).
+const ZRBTDRC_SLL_OUTGOING: u8 = 4;
+
+pub const RBTDRC_PROTO_TCP: u8 = 6;
+pub const RBTDRC_PROTO_UDP: u8 = 17;
+const ZRBTDRC_DNS_PORT: u16 = 53;
+
+// ── pcap reader ──────────────────────────────────────────────
+
+/// One captured frame, as recorded (possibly cut at the snaplen).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_PcapRecord {
+    pub ts_secs: u32,
+    /// Sub-second part, in the file's unit (see `rbtdrc_Pcap::nanos`).
+    pub ts_frac: u32,
+    /// Length of the frame on the wire.
+    pub orig_len: u32,
+    pub data: Vec<u8>,
+}
+
+/// A parsed classic pcap file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Pcap {
+    pub linktype: u32,
+    pub snaplen: u32,
+    pub nanos: bool,
+    pub records: Vec<rbtdrc_PcapRecord>,
+}
+
+fn zrbtdrc_u16(bytes: &[u8], at: usize) -> Option<u16> {
+    bytes.get(at..at + 2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn zrbtdrc_u32(bytes: &[u8], at: usize, big: bool) -> Option<u32> {
+    let b: [u8; 4] = bytes.get(at..at + 4)?.try_into().ok()?;
+    Some(if big { u32::from_be_bytes(b) } else { u32::from_le_bytes(b) })
+}
+
+impl rbtdrc_Pcap {
+    /// Parse a classic pcap body. A record cut short by a capture that was
+    /// killed mid-write ends the file rather than failing it — tcpdump's last
+    /// write on SIGINT may be partial, and every whole record before it counts.
+    pub fn parse(bytes: &[u8]) -> Result<rbtdrc_Pcap, String> {
+        if bytes.len() < ZRBTDRC_PCAP_HEADER_LEN {
+            return Err(format!("pcap too short for a header ({} bytes)", bytes.len()));
+        }
+        let magic_le = zrbtdrc_u32(bytes, 0, false).unwrap_or(0);
+        let magic_be = zrbtdrc_u32(bytes, 0, true).unwrap_or(0);
+        let (big, nanos) = match (magic_le, magic_be) {
+            (ZRBTDRC_PCAP_MAGIC_MICROS, _) => (false, false),
+            (ZRBTDRC_PCAP_MAGIC_NANOS, _) => (false, true),
+            (_, ZRBTDRC_PCAP_MAGIC_MICROS) => (true, false),
+            (_, ZRBTDRC_PCAP_MAGIC_NANOS) => (true, true),
+            _ => return Err(format!("not a classic pcap (magic {:08x})", magic_be)),
+        };
+        let snaplen = zrbtdrc_u32(bytes, 16, big).unwrap_or(0);
+        let linktype = zrbtdrc_u32(bytes, 20, big).unwrap_or(0) & 0x0fff_ffff;
+        let mut records = Vec::new();
+        let mut at = ZRBTDRC_PCAP_HEADER_LEN;
+        while at + ZRBTDRC_PCAP_RECORD_LEN <= bytes.len() {
+            let field = |n: usize| zrbtdrc_u32(bytes, at + 4 * n, big).unwrap_or(0);
+            let (ts_secs, ts_frac, incl_len, orig_len) = (field(0), field(1), field(2), field(3));
+            let start = at + ZRBTDRC_PCAP_RECORD_LEN;
+            let Some(data) = bytes.get(start..start + incl_len as usize) else {
+                break;
+            };
+            records.push(rbtdrc_PcapRecord { ts_secs, ts_frac, orig_len, data: data.to_vec() });
+            at = start + incl_len as usize;
+        }
+        Ok(rbtdrc_Pcap { linktype, snaplen, nanos, records })
+    }
+
+    pub fn read(path: &Path) -> Result<rbtdrc_Pcap, String> {
+        let bytes =
+            std::fs::read(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        rbtdrc_Pcap::parse(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// The IP packets in the capture, in order; non-IP frames are passed over.
+    pub fn packets(&self) -> Vec<rbtdrc_IpPacket> {
+        self.records
+            .iter()
+            .filter_map(|r| rbtdrc_ip_packet(self.linktype, &r.data))
+            .collect()
+    }
+
+    /// The IP packets the interface whose MAC is `mac` sent, in order. Refused
+    /// for a link type that records no direction — raw IP cannot tell out from in.
+    pub fn outbound_packets(&self, mac: &[u8; 6]) -> Result<Vec<rbtdrc_IpPacket>, String> {
+        if !matches!(
+            self.linktype,
+            RBTDRC_LINKTYPE_ETHERNET | RBTDRC_LINKTYPE_LINUX_SLL | RBTDRC_LINKTYPE_LINUX_SLL2
+        ) {
+            return Err(format!("link type {} records no direction", self.linktype));
+        }
+        Ok(self
+            .records
+            .iter()
+            .filter(|r| rbtdrc_frame_outbound(self.linktype, &r.data, mac) == Some(true))
+            .filter_map(|r| rbtdrc_ip_packet(self.linktype, &r.data))
+            .collect())
+    }
+}
+
+/// Whether a frame was sent by the capturing interface rather than received on
+/// it: an Ethernet frame by its source MAC (`mac` is the interface's own), a
+/// cooked frame by its packet type. None where the link type records no
+/// direction, or the frame is cut too short to show it.
+pub fn rbtdrc_frame_outbound(linktype: u32, frame: &[u8], mac: &[u8; 6]) -> Option<bool> {
+    match linktype {
+        RBTDRC_LINKTYPE_ETHERNET => Some(frame.get(6..12)? == mac),
+        RBTDRC_LINKTYPE_LINUX_SLL => {
+            Some(zrbtdrc_u16(frame, 0)? == u16::from(ZRBTDRC_SLL_OUTGOING))
+        }
+        RBTDRC_LINKTYPE_LINUX_SLL2 => Some(*frame.get(10)? == ZRBTDRC_SLL_OUTGOING),
+        _ => None,
+    }
+}
+
+// ── IP decode ────────────────────────────────────────────────
+
+/// The addressing of one captured IP packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct rbtdrc_IpPacket {
+    pub src: IpAddr,
+    pub dst: IpAddr,
+    pub protocol: u8,
+    pub src_port: Option<u16>,
+    pub dst_port: Option<u16>,
+}
+
+impl fmt::Display for rbtdrc_IpPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let proto = match self.protocol {
+            RBTDRC_PROTO_TCP => "tcp".to_string(),
+            RBTDRC_PROTO_UDP => "udp".to_string(),
+            1 => "icmp".to_string(),
+            58 => "icmp6".to_string(),
+            n => format!("proto {}", n),
+        };
+        let port = |p: Option<u16>| p.map(|p| format!(":{}", p)).unwrap_or_default();
+        write!(
+            f,
+            "{} {}{} → {}{}",
+            proto,
+            self.src,
+            port(self.src_port),
+            self.dst,
+            port(self.dst_port)
+        )
+    }
+}
+
+/// Decode one frame of the given link type down to its IP addressing and, for
+/// an unfragmented-first TCP/UDP packet, its ports. None for non-IP frames and
+/// frames cut too short to hold the headers.
+pub fn rbtdrc_ip_packet(linktype: u32, frame: &[u8]) -> Option<rbtdrc_IpPacket> {
+    let (ethertype, offset) = match linktype {
+        RBTDRC_LINKTYPE_ETHERNET => {
+            let mut offset = 12;
+            let mut ethertype = zrbtdrc_u16(frame, offset)?;
+            while ethertype == ZRBTDRC_ETHERTYPE_VLAN || ethertype == ZRBTDRC_ETHERTYPE_QINQ {
+                offset += 4;
+                ethertype = zrbtdrc_u16(frame, offset)?;
+            }
+            (ethertype, offset + 2)
+        }
+        RBTDRC_LINKTYPE_LINUX_SLL => (zrbtdrc_u16(frame, 14)?, 16),
+        RBTDRC_LINKTYPE_LINUX_SLL2 => (zrbtdrc_u16(frame, 0)?, 20),
+        RBTDRC_LINKTYPE_RAW => match frame.first()? >> 4 {
+            4 => (ZRBTDRC_ETHERTYPE_IPV4, 0),
+            6 => (ZRBTDRC_ETHERTYPE_IPV6, 0),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let ip = frame.get(offset..)?;
+    match ethertype {
+        ZRBTDRC_ETHERTYPE_IPV4 => {
+            let header_len = usize::from(ip.first()? & 0x0f) * 4;
+            let addr = |at: usize| -> Option<Ipv4Addr> {
+                let b: [u8; 4] = ip.get(at..at + 4)?.try_into().ok()?;
+                Some(Ipv4Addr::from(b))
+            };
+            let (src, dst) = (addr(12)?, addr(16)?);
+            let protocol = *ip.get(9)?;
+            let fragment_offset = zrbtdrc_u16(ip, 6)? & 0x1fff;
+            let transport = if fragment_offset == 0 { ip.get(header_len..) } else { None };
+            let (src_port, dst_port) = zrbtdrc_ports(protocol, transport);
+            Some(rbtdrc_IpPacket { src: src.into(), dst: dst.into(), protocol, src_port, dst_port })
+        }
+        ZRBTDRC_ETHERTYPE_IPV6 => {
+            let addr = |at: usize| -> Option<Ipv6Addr> {
+                let b: [u8; 16] = ip.get(at..at + 16)?.try_into().ok()?;
+                Some(Ipv6Addr::from(b))
+            };
+            let (src, dst) = (addr(8)?, addr(24)?);
+            let protocol = *ip.get(6)?;
+            let (src_port, dst_port) = zrbtdrc_ports(protocol, ip.get(40..));
+            Some(rbtdrc_IpPacket { src: src.into(), dst: dst.into(), protocol, src_port, dst_port })
+        }
+        _ => None,
+    }
+}
+
+fn zrbtdrc_ports(protocol: u8, transport: Option<&[u8]>) -> (Option<u16>, Option<u16>) {
+    match (protocol, transport) {
+        (RBTDRC_PROTO_TCP | RBTDRC_PROTO_UDP, Some(t)) => (zrbtdrc_u16(t, 0), zrbtdrc_u16(t, 2)),
+        _ => (None, None),
+    }
+}
+
+// ── Egress assertion ─────────────────────────────────────────
+
+/// Every outbound packet that went neither to an allowed CIDR nor to
+/// `resolver` on port 53, rendered for the case verdict. `outbound` is what the
+/// uplink sent (`rbtdrc_Pcap::outbound_packets`), whatever its source address —
+/// a bottle packet that left un-NATed breaches as surely as the sentry's own.
+/// The allowlist is IPv4 only, so every outbound IPv6 packet is a violation.
+pub fn rbtdrc_egress_violations(
+    outbound: &[rbtdrc_IpPacket],
+    allowed: &[rbtdrc_Cidr],
+    resolver: Option<Ipv4Addr>,
+) -> Vec<String> {
+    outbound
+        .iter()
+        .filter(|p| {
+            let IpAddr::V4(dst) = p.dst else {
+                return true;
+            };
+            let to_resolver = Some(dst) == resolver && p.dst_port == Some(ZRBTDRC_DNS_PORT);
+            !to_resolver && !allowed.iter().any(|c| c.contains(dst))
+        })
+        .map(|p| p.to_string())
+        .collect()
+}
+
+// ── Capture lifecycle helpers ────────────────────────────────
+
+/// One sentry interface a capture runs on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_CaptureLeg {
+    /// "enclave" or "uplink" — names the pcap copied into the case dir.
+    pub role: &'static str,
+    pub iface: String,
+    pub address: Ipv4Addr,
+    /// The interface's own MAC — what marks a captured frame as sent by it.
+    pub mac: [u8; 6],
+}
+
+pub const RBTDRC_LEG_ENCLAVE: &str = "enclave";
+pub const RBTDRC_LEG_UPLINK: &str = "uplink";
+
+/// Resolve the sentry's two legs from `ip -o -4 addr show scope global`
+/// output by role, the way rbjs_sentry.sh and scry do: the enclave leg holds
+/// the nameplate's sentry enclave IP, the uplink is the first other global
+/// interface. Never by name — Docker does not guarantee eth0/eth1 ordering.
+/// Each leg's MAC comes from the matching `ip -o link` line.
+pub fn rbtdrc_capture_legs(
+    ip_addr_output: &str,
+    ip_link_output: &str,
+    enclave_ip: Ipv4Addr,
+) -> Result<Vec<rbtdrc_CaptureLeg>, String> {
+    let interfaces: Vec<(String, Ipv4Addr)> = ip_addr_output
+        .lines()
+        .filter(|line| line.contains(" scope global "))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let at = fields.iter().position(|f| *f == "inet")?;
+            let addr = fields.get(at + 1)?.split('/').next()?.parse().ok()?;
+            Some((fields.get(1)?.to_string(), addr))
+        })
+        .collect();
+    let enclave = interfaces
+        .iter()
+        .find(|(_, a)| *a == enclave_ip)
+        .ok_or_else(|| format!("no sentry interface holds enclave IP {}", enclave_ip))?;
+    let uplink = interfaces
+        .iter()
+        .find(|(i, _)| *i != enclave.0)
+        .ok_or_else(|| format!("no sentry uplink interface (enclave={})", enclave.0))?;
+    let leg = |role, (iface, address): &(String, Ipv4Addr)| -> Result<_, String> {
+        Ok(rbtdrc_CaptureLeg {
+            role,
+            iface: iface.clone(),
+            address: *address,
+            mac: rbtdrc_link_mac(ip_link_output, iface)?,
+        })
+    };
+    Ok(vec![leg(RBTDRC_LEG_ENCLAVE, enclave)?, leg(RBTDRC_LEG_UPLINK, uplink)?])
+}
+
+/// The `link/ether` address of `iface` in `ip -o link` output, whose name
+/// field may carry a peer suffix (`eth1@if44:`).
+pub fn rbtdrc_link_mac(ip_link_output: &str, iface: &str) -> Result<[u8; 6], String> {
+    let line = ip_link_output
+        .lines()
+        .find(|line| {
+            line.split_whitespace().nth(1).is_some_and(|name| {
+                name.trim_end_matches(':').split('@').next() == Some(iface)
+            })
+        })
+        .ok_or_else(|| format!("no link line for sentry interface {}", iface))?;
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let text = fields
+        .iter()
+        .position(|f| *f == "link/ether")
+        .and_then(|at| fields.get(at + 1))
+        .ok_or_else(|| format!("sentry interface {} has no link/ether address", iface))?;
+    let octets: Vec<u8> =
+        text.split(':').filter_map(|o| u8::from_str_radix(o, 16).ok()).collect();
+    octets
+        .try_into()
+        .map_err(|_| format!("sentry interface {}: malformed MAC {}", iface, text))
+}
+
+/// Decode standard base64, ignoring whitespace. The pcap copy rides writ
+/// stdout as `base64 -w0` text.
+pub fn rbtdrc_base64_decode(text: &str) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(text.len() / 4 * 3);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut padding = 0;
+    for c in text.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => {
+                padding += 1;
+                continue;
+            }
+            _ => return Err(format!("invalid base64 byte 0x{:02x}", c)),
+        };
+        if padding > 0 {
+            return Err("base64 data after padding".to_string());
+        }
+        acc = (acc << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
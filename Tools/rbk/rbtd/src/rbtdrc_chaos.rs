@@ -0,0 +1,266 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRC — chaos catalog: the sentry degradations the chaos crucible inflicts,
+// how each is undone, and what egress each must cost the bottle
+//
+// A healthy sentry filtering correctly is half the containment claim; the
+// other half is that a degraded sentry fails closed. Each rbtdrc_Chaos is one
+// degradation a live sentry can suffer — its DNS forwarder dies, its ruleset
+// is flushed, it loses its uplink route, its container is killed or restarted
+// under the bottle — spelled as the commands that inflict it, the check that
+// it took, and the commands that undo it. While it holds, the bottle must
+// still be denied everything the egress battery tries, and must also lose the
+// allowed egress the degradation cut (its dark probes): an allowed path that
+// still works through a broken sentry is a path around it.
+//
+// The chaos crucible in rbtdrc_crucible runs the catalog; this module does no
+// I/O, so the catalog is checked without a crucible.
+
+use std::fmt;
+
+/// Ifrit attacks the bottle must lose to whether the sentry is healthy or
+/// degraded: each passes only when the egress it tries is denied.
+pub const RBTDRC_CHAOS_BATTERY: &[&str] = &[
+    "dns-blocked-google",
+    "apt-get-blocked",
+    "dns-block-direct",
+    "dns-block-altport",
+    "dns-block-cloudflare",
+    "dns-block-quad9",
+    "dns-block-tunneling",
+    "udp-non-dns-blocked",
+    "meta-cloud-endpoint",
+    "net-forbidden-cidr",
+];
+
+/// Container-runtime verbs smite may carry, first argument only. The smite
+/// tabtarget refuses any other verb too; this keeps the refusal in Rust as
+/// well, before a launch is spent.
+pub const RBTDRC_CHAOS_SMITE_VERBS: &[&str] = &["kill", "start", "restart", "inspect"];
+
+/// Where a chaos command runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_ChaosSite {
+    /// Inside sentry, through writ.
+    Sentry,
+    /// On the host, through smite — a container-runtime verb that the
+    /// tabtarget aims at the sentry container itself.
+    Runtime,
+}
+
+/// One command of a chaos step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_ChaosCommand {
+    pub site: rbtdrc_ChaosSite,
+    pub args: Vec<String>,
+}
+
+impl rbtdrc_ChaosCommand {
+    fn sentry(args: &[&str]) -> Self {
+        rbtdrc_ChaosCommand {
+            site: rbtdrc_ChaosSite::Sentry,
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    fn runtime(args: &[&str]) -> Self {
+        rbtdrc_ChaosCommand {
+            site: rbtdrc_ChaosSite::Runtime,
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+impl fmt::Display for rbtdrc_ChaosCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let site = match self.site {
+            rbtdrc_ChaosSite::Sentry => "writ",
+            rbtdrc_ChaosSite::Runtime => "smite",
+        };
+        write!(f, "{}: {}", site, self.args.join(" "))
+    }
+}
+
+/// Egress a healthy sentry grants the bottle, which a degradation may cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_Dark {
+    /// Resolving an allowlisted domain through the sentry's dnsmasq.
+    AllowedDomain,
+    /// TCP 443 to an allowlisted address, forwarded and masqueraded by sentry.
+    AllowedAddress,
+}
+
+impl rbtdrc_Dark {
+    pub const ALL: [rbtdrc_Dark; 2] = [rbtdrc_Dark::AllowedDomain, rbtdrc_Dark::AllowedAddress];
+
+    /// The ifrit attack that passes while this egress works.
+    pub fn lit_attack(self) -> &'static str {
+        match self {
+            rbtdrc_Dark::AllowedDomain => "dns-resolve",
+            rbtdrc_Dark::AllowedAddress => "tcp443-connect",
+        }
+    }
+
+    /// The ifrit attack that passes while this egress is cut.
+    pub fn dark_attack(self) -> &'static str {
+        match self {
+            rbtdrc_Dark::AllowedDomain => "dns-resolve-block",
+            rbtdrc_Dark::AllowedAddress => "tcp443-block",
+        }
+    }
+}
+
+/// One sentry degradation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_Chaos {
+    /// dnsmasq killed; the rest of the sentry untouched.
+    DnsmasqKilled,
+    /// Every filter and nat rule and user chain flushed; chain policies stand.
+    IptablesFlushed,
+    /// The default route via the uplink deleted.
+    UplinkRouteRemoved,
+    /// The sentry container killed outright, then started again.
+    SentryKilled,
+    /// The sentry container restarted, with the battery racing its startup.
+    SentryRestarted,
+}
+
+impl rbtdrc_Chaos {
+    /// The whole catalog, in the order the chaos crucible runs it.
+    pub const ALL: [rbtdrc_Chaos; 5] = [
+        rbtdrc_Chaos::DnsmasqKilled,
+        rbtdrc_Chaos::IptablesFlushed,
+        rbtdrc_Chaos::UplinkRouteRemoved,
+        rbtdrc_Chaos::SentryKilled,
+        rbtdrc_Chaos::SentryRestarted,
+    ];
+
+    /// Writ command whose output the revert needs, read before inflicting.
+    pub fn capture(self) -> Option<&'static [&'static str]> {
+        match self {
+            rbtdrc_Chaos::IptablesFlushed => Some(&["iptables-save"]),
+            rbtdrc_Chaos::UplinkRouteRemoved => Some(&["ip", "-4", "route", "show", "default"]),
+            _ => None,
+        }
+    }
+
+    /// The commands that inflict it.
+    pub fn inflict(self) -> Vec<rbtdrc_ChaosCommand> {
+        match self {
+            rbtdrc_Chaos::DnsmasqKilled => {
+                vec![rbtdrc_ChaosCommand::sentry(&["pkill", "-KILL", "-x", "dnsmasq"])]
+            }
+            rbtdrc_Chaos::IptablesFlushed => vec![rbtdrc_ChaosCommand::sentry(&[
+                "sh",
+                "-c",
+                "iptables -F && iptables -X && iptables -t nat -F && iptables -t nat -X",
+            ])],
+            rbtdrc_Chaos::UplinkRouteRemoved => {
+                vec![rbtdrc_ChaosCommand::sentry(&["ip", "-4", "route", "del", "default"])]
+            }
+            rbtdrc_Chaos::SentryKilled => vec![rbtdrc_ChaosCommand::runtime(&["kill"])],
+            rbtdrc_Chaos::SentryRestarted => {
+                vec![rbtdrc_ChaosCommand::runtime(&["restart", "-t", "0"])]
+            }
+        }
+    }
+
+    /// A command whose trimmed output is the paired text exactly while the
+    /// degradation holds. None for a restart: the startup it races is the point.
+    pub fn holds(self) -> Option<(rbtdrc_ChaosCommand, &'static str)> {
+        match self {
+            rbtdrc_Chaos::DnsmasqKilled => Some((
+                rbtdrc_ChaosCommand::sentry(&["sh", "-c", "pidof dnsmasq || echo none"]),
+                "none",
+            )),
+            rbtdrc_Chaos::IptablesFlushed => Some((
+                rbtdrc_ChaosCommand::sentry(&["sh", "-c", "iptables-save | grep -c '^-A' || true"]),
+                "0",
+            )),
+            rbtdrc_Chaos::UplinkRouteRemoved => Some((
+                rbtdrc_ChaosCommand::sentry(&["sh", "-c", "ip -4 route show default | wc -l"]),
+                "0",
+            )),
+            rbtdrc_Chaos::SentryKilled => Some((
+                rbtdrc_ChaosCommand::runtime(&["inspect", "-f", "{{.State.Running}}"]),
+                "false",
+            )),
+            rbtdrc_Chaos::SentryRestarted => None,
+        }
+    }
+
+    /// Allowed egress the bottle must have lost while it holds.
+    pub fn dark(self) -> &'static [rbtdrc_Dark] {
+        match self {
+            rbtdrc_Chaos::DnsmasqKilled => &[rbtdrc_Dark::AllowedDomain],
+            rbtdrc_Chaos::IptablesFlushed => &rbtdrc_Dark::ALL,
+            rbtdrc_Chaos::UplinkRouteRemoved => &[rbtdrc_Dark::AllowedAddress],
+            rbtdrc_Chaos::SentryKilled => &rbtdrc_Dark::ALL,
+            rbtdrc_Chaos::SentryRestarted => &[],
+        }
+    }
+
+    /// The commands that undo it, from what `capture` read beforehand.
+    pub fn revert(self, captured: &str) -> Result<Vec<rbtdrc_ChaosCommand>, String> {
+        match self {
+            rbtdrc_Chaos::DnsmasqKilled => Ok(vec![rbtdrc_ChaosCommand::sentry(&["dnsmasq"])]),
+            rbtdrc_Chaos::IptablesFlushed => {
+                if !captured.lines().any(|l| l.starts_with("-A ")) {
+                    return Err("captured ruleset has no rules to restore".to_string());
+                }
+                Ok(vec![rbtdrc_ChaosCommand::sentry(&[
+                    "sh",
+                    "-c",
+                    "printf '%s\\n' \"$1\" | iptables-restore",
+                    "sh",
+                    captured.trim_end(),
+                ])])
+            }
+            rbtdrc_Chaos::UplinkRouteRemoved => {
+                let route: Vec<&str> = captured
+                    .lines()
+                    .find(|l| l.starts_with("default "))
+                    .ok_or_else(|| "captured routes have no default route".to_string())?
+                    .split_whitespace()
+                    .collect();
+                let mut args = vec!["ip", "-4", "route", "add"];
+                args.extend(route);
+                Ok(vec![rbtdrc_ChaosCommand::sentry(&args)])
+            }
+            rbtdrc_Chaos::SentryKilled => Ok(vec![rbtdrc_ChaosCommand::runtime(&["start"])]),
+            rbtdrc_Chaos::SentryRestarted => Ok(Vec::new()),
+        }
+    }
+
+    /// Whether the sentry comes back through its own startup script, which
+    /// the crucible must wait out before anything reads the sentry again.
+    pub fn recycles(self) -> bool {
+        matches!(self, rbtdrc_Chaos::SentryKilled | rbtdrc_Chaos::SentryRestarted)
+    }
+}
+
+impl fmt::Display for rbtdrc_Chaos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            rbtdrc_Chaos::DnsmasqKilled => "dnsmasq killed",
+            rbtdrc_Chaos::IptablesFlushed => "iptables flushed",
+            rbtdrc_Chaos::UplinkRouteRemoved => "uplink default route removed",
+            rbtdrc_Chaos::SentryKilled => "sentry container killed",
+            rbtdrc_Chaos::SentryRestarted => "sentry container restarted",
+        })
+    }
+}
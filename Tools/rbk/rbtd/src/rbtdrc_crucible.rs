@@ -22,24 +22,46 @@
 
 // RCG output discipline: all emission via rbtdrg_*! — no direct println!/eprintln!
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
 use crate::case;
 use crate::rbtdra_almanac::rbtdra_lookup_fixture;
+use crate::rbtdrc_capture::{
+    rbtdrc_CaptureLeg, rbtdrc_Pcap, rbtdrc_base64_decode, rbtdrc_capture_legs,
+    rbtdrc_egress_violations, RBTDRC_LEG_ENCLAVE, RBTDRC_LEG_UPLINK,
+};
+use crate::rbtdrc_chaos::{
+    rbtdrc_Chaos, rbtdrc_ChaosCommand, rbtdrc_ChaosSite, rbtdrc_Dark, RBTDRC_CHAOS_BATTERY,
+    RBTDRC_CHAOS_SMITE_VERBS,
+};
+use crate::rbtdrc_golden::{
+    rbtdrc_SentryRaw, rbtdrc_golden_diff, rbtdrc_golden_path, rbtdrc_sentry_snapshot,
+    RBTDRC_GOLDEN_SYSCTLS,
+};
+use crate::rbtdrc_iptables::{
+    rbtdrc_RuleGroup, rbtdrc_Ruleset, rbtdrc_SentryFacts, rbtdrc_policy_model,
+    rbtdrc_ruleset_diff,
+};
 use crate::rbtdre_engine::{
     rbtdre_Tariff,
     rbtdre_Case, rbtdre_Disposition, rbtdre_Fixture, rbtdre_Verdict,
 };
+use crate::rbtdrc_policy::{
+    rbtdrc_Cidr, rbtdrc_Expect, rbtdrc_Policy, rbtdrc_PolicyFamily, rbtdrc_Probe,
+    rbtdrc_Target, rbtdrc_listening_ports, rbtdrc_policy_probes, rbtdrc_policy_report_line,
+    RBTDRC_KEY_ENCLAVE_SENTRY_IP, RBTDRC_POLICY_FILE,
+};
 use crate::rbtdri_invocation::{
     rbtdri_Context, rbtdri_invoke, rbtdri_invoke_env, rbtdri_invoke_global,
     rbtdri_parse_ifrit_verdict,
 };
 use crate::rbtdgc_consts::{
     RBTDGC_CRUCIBLE_ACTIVE, RBTDGC_CRUCIBLE_BARK,
-    RBTDGC_CRUCIBLE_CHARGE, RBTDGC_CRUCIBLE_FIAT, RBTDGC_CRUCIBLE_QUENCH, RBTDGC_CRUCIBLE_WRIT,
+    RBTDGC_CRUCIBLE_CHARGE, RBTDGC_CRUCIBLE_FIAT, RBTDGC_CRUCIBLE_QUENCH, RBTDGC_CRUCIBLE_SMITE,
+    RBTDGC_CRUCIBLE_WRIT,
 };
 
 // ── Thread-local invocation context ──────────────────────────
@@ -88,9 +110,20 @@ pub fn rbtdrc_take_context() -> rbtdri_Context {
     })
 }
 
-pub(crate) fn rbtdrc_with_ctx<F>(f: F) -> rbtdre_Verdict
+/// Drop the installed context's chain marks between cases (see
+/// `rbtdri_Context::chain_reset`). A no-op without a context: the engine's
+/// own runners run cases with none installed.
+pub fn rbtdrc_reset_chains() {
+    RBTDRC_CTX.with(|c| {
+        if let Some(ctx) = c.borrow_mut().as_mut() {
+            ctx.chain_reset();
+        }
+    })
+}
+
+pub(crate) fn rbtdrc_with_ctx<F, R>(f: F) -> R
 where
-    F: FnOnce(&mut rbtdri_Context) -> rbtdre_Verdict,
+    F: FnOnce(&mut rbtdri_Context) -> R,
 {
     RBTDRC_CTX.with(|c| {
         let mut opt = c.borrow_mut();
@@ -134,7 +167,7 @@ pub fn rbtdrc_quench_crucible() {
 }
 
 fn zrbtdrc_charge_impl(ctx: &mut rbtdri_Context) -> Result<(), String> {
-    let fixture = ctx.fixture().to_string();
+    let fixture = ctx.imprint().to_string();
     crate::rbtdrg_info_now!("Charging crucible for nameplate '{}'...", fixture);
     match rbtdri_invoke(ctx, RBTDGC_CRUCIBLE_CHARGE, &[]) {
         Ok(r) if r.exit_code == 0 => {
@@ -171,7 +204,7 @@ fn zrbtdrc_charge_impl(ctx: &mut rbtdri_Context) -> Result<(), String> {
 }
 
 fn zrbtdrc_quench_impl(ctx: &mut rbtdri_Context) {
-    let fixture = ctx.fixture().to_string();
+    let fixture = ctx.imprint().to_string();
     crate::rbtdrg_info_now!("Quenching crucible...");
     match rbtdri_invoke(ctx, RBTDGC_CRUCIBLE_QUENCH, &[]) {
         Ok(r) if r.exit_code == 0 => crate::rbtdrg_info_now!("Crucible quenched"),
@@ -308,19 +341,6 @@ fn rbtdrc_looks_like_ip(s: &str) -> bool {
     !s.is_empty() && s.chars().all(|c| c.is_ascii_digit() || c == '.')
 }
 
-/// Extract iptables rule lines from writ output, filtering BUK log headers.
-/// Rules start with -P (policy), -N (new chain), or -A (append).
-fn rbtdrc_extract_iptables_rules(output: &str) -> String {
-    output
-        .lines()
-        .filter(|l| {
-            let t = l.trim();
-            t.starts_with("-P ") || t.starts_with("-N ") || t.starts_with("-A ")
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
 /// Read a named environment variable from sentry's process environment via writ.
 /// writ runs under BURD_NO_LOG, so its stdout is the clean command output —
 /// the first non-empty line is the value, with no header/ANSI stripping needed.
@@ -447,34 +467,218 @@ fn rbtdrc_ifrit_dns_block_tunneling(dir: &Path) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| rbtdrc_invoke_ifrit(ctx, "dns-block-tunneling", dir))
 }
 
-// ── Observation cases (writ + bark, inside/outside) ──────────
+// ── Sentry ruleset model (rbtdrc_iptables) ───────────────────
+//
+// Each iptables case owns one rbtdrc_RuleGroup — chain policies, INPUT,
+// FORWARD, OUTPUT, nat PREROUTING, nat POSTROUTING — and leaves the save and
+// every claim's outcome in its case dir. Sentry integrity reads its before and
+// after saves through the same parser, so its diff lists only changed rules.
+
+/// The sentry's live ruleset, as `iptables-save` prints it.
+const RBTDRC_IPTABLES_SAVE_FILE: &str = "iptables-save.txt";
+
+/// One PASS/FAIL row per policy-model claim a case checked.
+const RBTDRC_POLICY_MODEL_FILE: &str = "policy-model.txt";
+
+/// The sentry's enclave and uplink legs, told apart by the nameplate's
+/// enclave sentry address.
+fn zrbtdrc_sentry_legs(ctx: &mut rbtdri_Context) -> Result<Vec<rbtdrc_CaptureLeg>, String> {
+    let policy = zrbtdrc_nameplate_policy(ctx)?;
+    let line = policy.require(RBTDRC_KEY_ENCLAVE_SENTRY_IP)?;
+    let enclave_ip = line
+        .value
+        .parse()
+        .map_err(|e| format!("{}: {} ({})", RBTDRC_KEY_ENCLAVE_SENTRY_IP, e, line))?;
+    let addrs = rbtdrc_writ(ctx, &["ip", "-o", "-4", "addr", "show", "scope", "global"])?;
+    let links = rbtdrc_writ(ctx, &["ip", "-o", "link", "show"])?;
+    rbtdrc_capture_legs(&addrs, &links, enclave_ip)
+}
+
+/// Read the sentry's ruleset through iptables-save into `dir/file`, parsed.
+fn zrbtdrc_sentry_ruleset(
+    ctx: &mut rbtdri_Context,
+    dir: &Path,
+    file: &str,
+) -> Result<rbtdrc_Ruleset, String> {
+    let output = rbtdrc_writ(ctx, &["iptables-save"])?;
+    let _ = std::fs::write(dir.join(file), &output);
+    rbtdrc_Ruleset::parse(&output)
+}
+
+/// What the policy model needs from the running sentry beyond its nameplate.
+fn zrbtdrc_sentry_facts(ctx: &mut rbtdri_Context) -> Result<rbtdrc_SentryFacts, String> {
+    let legs = zrbtdrc_sentry_legs(ctx)?;
+    let leg = |role: &str| {
+        legs.iter()
+            .find(|l| l.role == role)
+            .ok_or_else(|| format!("sentry has no {} leg", role))
+    };
+    let (enclave, uplink) = (leg(RBTDRC_LEG_ENCLAVE)?, leg(RBTDRC_LEG_UPLINK)?);
+    let dns = rbtdrc_read_sentry_env(ctx, "RBRR_DNS_SERVER")?;
+    Ok(rbtdrc_SentryFacts {
+        enclave_if: enclave.iface.clone(),
+        uplink_if: uplink.iface.clone(),
+        uplink_ip: uplink.address,
+        dns_server: dns.parse().map_err(|e| format!("RBRR_DNS_SERVER '{}': {}", dns, e))?,
+    })
+}
 
-fn rbtdrc_sentry_iptables_loaded(dir: &Path) -> rbtdre_Verdict {
+/// Hold the sentry's live ruleset to one rule group of the nameplate's
+/// policy model. Each claim's outcome lands in policy-model.txt; the verdict
+/// lists what the ruleset lacks, packet and deciding rule included.
+fn zrbtdrc_sentry_ruleset_check(dir: &Path, group: rbtdrc_RuleGroup) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| {
-        let output = match rbtdrc_writ(ctx, &["iptables", "-S"]) {
-            Ok(o) => o,
-            Err(e) => return rbtdre_Verdict::Fail(format!("writ error: {}", e)),
+        let ruleset = match zrbtdrc_sentry_ruleset(ctx, dir, RBTDRC_IPTABLES_SAVE_FILE) {
+            Ok(r) => r,
+            Err(e) => return rbtdre_Verdict::Fail(format!("sentry ruleset: {}", e)),
+        };
+        let model = zrbtdrc_nameplate_policy(ctx).and_then(|policy| {
+            zrbtdrc_sentry_facts(ctx).and_then(|facts| rbtdrc_policy_model(&policy, &facts))
+        });
+        let model = match model {
+            Ok(m) => m,
+            Err(e) => return rbtdre_Verdict::Fail(format!("policy model: {}", e)),
         };
-        let _ = std::fs::write(dir.join("iptables-rules.txt"), &output);
 
-        if output.trim().is_empty() {
-            return rbtdre_Verdict::Fail("iptables -S returned empty output".to_string());
+        let claims: Vec<_> = model.iter().filter(|e| e.group == group).collect();
+        let mut rows = Vec::new();
+        let mut failures = Vec::new();
+        for claim in &claims {
+            match claim.check(&ruleset) {
+                Ok(()) => rows.push(format!("PASS {}", claim)),
+                Err(e) => {
+                    rows.push(format!("FAIL {}", e));
+                    failures.push(e);
+                }
+            }
         }
-        // Expect at least one policy line (-P) and one append rule (-A)
-        if !output.contains("-P") || !output.contains("-A") {
-            return rbtdre_Verdict::Fail(format!(
-                "iptables rules incomplete (missing -P or -A):\n{}",
-                output
-            ));
+        let _ = std::fs::write(dir.join(RBTDRC_POLICY_MODEL_FILE), rows.join("\n") + "\n");
+
+        if failures.is_empty() {
+            rbtdre_Verdict::Pass
+        } else {
+            rbtdre_Verdict::Fail(format!(
+                "{} of {} {} claims failed:\n{}",
+                failures.len(),
+                claims.len(),
+                group,
+                failures.join("\n")
+            ))
         }
-        rbtdre_Verdict::Pass
     })
 }
 
-// Structural-presence backstops for the load-bearing iptables clauses and
-// kernel sysctl set by rbjs_sentry.sh. Each case is a fast read-and-check
-// against sentry's runtime state — fail loudly if a future change strips a
-// defensive clause the architecture depends on.
+// ── Sentry config golden (rbtdrc_golden) ─────────────────────
+
+/// This run's normalized sentry configuration, as the golden would hold it.
+const RBTDRC_GOLDEN_SNAPSHOT_FILE: &str = "sentry-config.txt";
+
+/// The golden-vs-snapshot diff, written only when they differ.
+const RBTDRC_GOLDEN_DIFF_FILE: &str = "sentry-config.diff";
+
+thread_local! {
+    /// Whether the golden case rewrites the committed golden instead of
+    /// comparing against it. Set by the runners from `--bless`.
+    static RBTDRC_BLESS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Arm or disarm golden rewriting for this thread's cases.
+pub fn rbtdrc_set_bless(bless: bool) {
+    RBTDRC_BLESS.with(|b| b.set(bless));
+}
+
+/// Read the sentry's effective network configuration over writ, unnormalized.
+fn zrbtdrc_sentry_raw(ctx: &mut rbtdri_Context) -> Result<rbtdrc_SentryRaw, String> {
+    let sysctl_script = format!(
+        "cd /proc/sys && for f in {}; do if [ -r \"$f\" ]; then \
+         echo \"$f = $(cat \"$f\")\"; fi; done",
+        RBTDRC_GOLDEN_SYSCTLS.join(" ")
+    );
+    Ok(rbtdrc_SentryRaw {
+        iptables_save: rbtdrc_writ(ctx, &["iptables-save"])?,
+        dnsmasq_conf: rbtdrc_writ(ctx, &["sh", "-c", "cat /etc/dnsmasq.conf 2>/dev/null || true"])?,
+        sysctl: rbtdrc_writ(ctx, &["sh", "-c", &sysctl_script])?,
+        routes: rbtdrc_writ(ctx, &["ip", "-4", "route", "show"])?,
+    })
+}
+
+/// The sentry's effective network configuration, normalized as the golden
+/// holds it.
+fn zrbtdrc_sentry_config(ctx: &mut rbtdri_Context) -> Result<String, String> {
+    let raw = zrbtdrc_sentry_raw(ctx)?;
+    let facts = zrbtdrc_sentry_facts(ctx)?;
+    rbtdrc_sentry_snapshot(ctx.imprint(), &raw, &facts)
+}
+
+/// Hold the sentry's whole effective network configuration to the golden
+/// committed for this nameplate. Drift fails with the section-tagged diff;
+/// under `--bless` the snapshot replaces the golden and the case passes, so
+/// the policy change reaches review as a golden update.
+fn rbtdrc_sentry_config_golden(dir: &Path) -> rbtdre_Verdict {
+    rbtdrc_with_ctx(|ctx| {
+        let snapshot = match zrbtdrc_sentry_config(ctx) {
+            Ok(s) => s,
+            Err(e) => return rbtdre_Verdict::Fail(format!("sentry snapshot: {}", e)),
+        };
+        let _ = std::fs::write(dir.join(RBTDRC_GOLDEN_SNAPSHOT_FILE), &snapshot);
+
+        let golden_path = rbtdrc_golden_path(ctx.project_root(), ctx.imprint());
+        let golden = std::fs::read_to_string(&golden_path).ok();
+        let diff = golden.as_deref().map(|g| rbtdrc_golden_diff(g, &snapshot));
+        if diff.as_ref().is_some_and(|d| d.is_empty()) {
+            return rbtdre_Verdict::Pass;
+        }
+        if let Some(d) = &diff {
+            let _ = std::fs::write(dir.join(RBTDRC_GOLDEN_DIFF_FILE), d.join("\n") + "\n");
+        }
+
+        if RBTDRC_BLESS.with(|b| b.get()) {
+            let written = golden_path
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|()| std::fs::write(&golden_path, &snapshot));
+            return match written {
+                Ok(()) => {
+                    crate::rbtdrg_info_now!(
+                        "Blessed {} ({} changed lines) — commit it with the change that moved it",
+                        golden_path.display(),
+                        diff.map_or(snapshot.lines().count(), |d| d.len())
+                    );
+                    rbtdre_Verdict::Pass
+                }
+                Err(e) => {
+                    rbtdre_Verdict::Fail(format!("write {}: {}", golden_path.display(), e))
+                }
+            };
+        }
+
+        match diff {
+            None => rbtdre_Verdict::Fail(format!(
+                "no sentry config golden at {} — rerun with --bless to record one",
+                golden_path.display()
+            )),
+            Some(d) => rbtdre_Verdict::Fail(format!(
+                "sentry config drifted from {} ({} lines; rerun with --bless if intended):\n{}",
+                golden_path.display(),
+                d.len(),
+                d.join("\n")
+            )),
+        }
+    })
+}
+
+// ── Observation cases (writ + bark, inside/outside) ──────────
+
+fn rbtdrc_sentry_iptables_loaded(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_sentry_ruleset_check(dir, rbtdrc_RuleGroup::Policies)
+}
+
+// Backstops for the load-bearing iptables clauses and kernel sysctl set by
+// rbjs_sentry.sh. Each case is a fast read-and-check against sentry's runtime
+// state — fail loudly if a future change strips a defensive clause the
+// architecture depends on. The iptables cases each hold one rule group of the
+// live ruleset to the nameplate's policy model (rbtdrc_iptables), so they
+// judge what the rules do, not how they are spelled.
 
 fn rbtdrc_sentry_config_rp_filter(dir: &Path) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| {
@@ -514,132 +718,23 @@ fn rbtdrc_sentry_config_rp_filter(dir: &Path) -> rbtdre_Verdict {
 }
 
 fn rbtdrc_sentry_config_prerouting_dnat(dir: &Path) -> rbtdre_Verdict {
-    rbtdrc_with_ctx(|ctx| {
-        let entry_mode = match rbtdrc_read_sentry_env(ctx, "RBRN_ENTRY_MODE") {
-            Ok(m) => m,
-            Err(e) => return rbtdre_Verdict::Fail(format!("read RBRN_ENTRY_MODE: {}", e)),
-        };
-        if entry_mode != "rbnne_enabled" {
-            return rbtdre_Verdict::Skip(format!("entry-mode={}", entry_mode));
-        }
-        let output = match rbtdrc_writ(ctx, &["iptables", "-t", "nat", "-S", "PREROUTING"]) {
-            Ok(o) => o,
-            Err(e) => return rbtdre_Verdict::Fail(format!("writ error: {}", e)),
-        };
-        let _ = std::fs::write(dir.join("prerouting.txt"), &output);
-
-        let append_rules: Vec<&str> = output
-            .lines()
-            .filter(|l| l.trim().starts_with("-A "))
-            .collect();
-
-        let return_positions: Vec<usize> = append_rules
-            .iter()
-            .enumerate()
-            .filter(|(_, r)| r.contains("-j RETURN"))
-            .map(|(i, _)| i)
-            .collect();
-        let dnat_positions: Vec<usize> = append_rules
-            .iter()
-            .enumerate()
-            .filter(|(_, r)| r.contains("-j DNAT"))
-            .map(|(i, _)| i)
-            .collect();
-
-        if return_positions.len() != 2 {
-            return rbtdre_Verdict::Fail(format!(
-                "PREROUTING RETURN rule count = {} (expected 2)",
-                return_positions.len()
-            ));
-        }
-        if dnat_positions.is_empty() {
-            return rbtdre_Verdict::Fail("PREROUTING DNAT rule absent".to_string());
-        }
-        let max_return = *return_positions.iter().max().unwrap();
-        let min_dnat = *dnat_positions.iter().min().unwrap();
-        if max_return >= min_dnat {
-            return rbtdre_Verdict::Fail(format!(
-                "PREROUTING RETURN at position {} not before DNAT at position {}",
-                max_return, min_dnat
-            ));
-        }
-        rbtdre_Verdict::Pass
-    })
+    zrbtdrc_sentry_ruleset_check(dir, rbtdrc_RuleGroup::Prerouting)
 }
 
 fn rbtdrc_sentry_config_postrouting_masquerade(dir: &Path) -> rbtdre_Verdict {
-    rbtdrc_with_ctx(|ctx| {
-        let entry_mode = match rbtdrc_read_sentry_env(ctx, "RBRN_ENTRY_MODE") {
-            Ok(m) => m,
-            Err(e) => return rbtdre_Verdict::Fail(format!("read RBRN_ENTRY_MODE: {}", e)),
-        };
-        if entry_mode != "rbnne_enabled" {
-            return rbtdre_Verdict::Skip(format!("entry-mode={}", entry_mode));
-        }
-        let output = match rbtdrc_writ(ctx, &["iptables", "-t", "nat", "-S", "POSTROUTING"]) {
-            Ok(o) => o,
-            Err(e) => return rbtdre_Verdict::Fail(format!("writ error: {}", e)),
-        };
-        let _ = std::fs::write(dir.join("postrouting.txt"), &output);
-
-        // Entry-port MASQUERADE has -p tcp and --dport; the egress MASQUERADE
-        // (uplink path, set when uplink access-mode != disabled) has neither.
-        let entry_masq = output
-            .lines()
-            .any(|l| l.contains("-j MASQUERADE") && l.contains("-p tcp") && l.contains("--dport"));
-
-        if !entry_masq {
-            return rbtdre_Verdict::Fail(
-                "POSTROUTING entry-port MASQUERADE rule absent".to_string(),
-            );
-        }
-        rbtdre_Verdict::Pass
-    })
+    zrbtdrc_sentry_ruleset_check(dir, rbtdrc_RuleGroup::Postrouting)
 }
 
 fn rbtdrc_sentry_config_forward_estab_related(dir: &Path) -> rbtdre_Verdict {
-    rbtdrc_with_ctx(|ctx| {
-        let output = match rbtdrc_writ(ctx, &["iptables", "-S", "FORWARD"]) {
-            Ok(o) => o,
-            Err(e) => return rbtdre_Verdict::Fail(format!("writ error: {}", e)),
-        };
-        let _ = std::fs::write(dir.join("forward.txt"), &output);
-
-        let append_rules: Vec<&str> = output
-            .lines()
-            .filter(|l| l.trim().starts_with("-A "))
-            .collect();
-
-        let estab_pos = append_rules
-            .iter()
-            .position(|r| r.contains("RELATED") && r.contains("ESTABLISHED") && r.contains("-j ACCEPT"));
-        let jump_pos = append_rules
-            .iter()
-            .position(|r| r.contains("-j RBM-FORWARD"));
+    zrbtdrc_sentry_ruleset_check(dir, rbtdrc_RuleGroup::Forward)
+}
 
-        let estab_pos = match estab_pos {
-            Some(p) => p,
-            None => {
-                return rbtdre_Verdict::Fail(
-                    "FORWARD ESTABLISHED,RELATED ACCEPT rule absent".to_string(),
-                );
-            }
-        };
-        let jump_pos = match jump_pos {
-            Some(p) => p,
-            None => {
-                return rbtdre_Verdict::Fail("FORWARD -j RBM-FORWARD jump absent".to_string());
-            }
-        };
+fn rbtdrc_sentry_config_input_chain(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_sentry_ruleset_check(dir, rbtdrc_RuleGroup::Input)
+}
 
-        if estab_pos >= jump_pos {
-            return rbtdre_Verdict::Fail(format!(
-                "FORWARD ESTABLISHED,RELATED at position {} not before RBM-FORWARD jump at position {}",
-                estab_pos, jump_pos
-            ));
-        }
-        rbtdre_Verdict::Pass
-    })
+fn rbtdrc_sentry_config_output_chain(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_sentry_ruleset_check(dir, rbtdrc_RuleGroup::Output)
 }
 
 fn rbtdrc_dns_blocked_with_observation(dir: &Path) -> rbtdre_Verdict {
@@ -828,11 +923,415 @@ fn rbtdrc_sortie_sentry_udp_non_dns(dir: &Path) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| rbtdrc_invoke_ifrit(ctx, "sentry-udp-non-dns", dir))
 }
 
+// ── Policy-derived containment cases (rbtdrc_policy) ─────────
+//
+// Generic over the nameplate: each case reads its nameplate's rbrn.env,
+// synthesizes its family's probes, runs every one against the charged
+// crucible, and writes policy-verdicts.txt — one row per probe, naming the
+// nameplate line it tests. The cases are their own set (RBTDRC_CASES_POLICY):
+// the security fixtures run it in full, and `rbtd single <nameplate> <case>`
+// runs it against any crucible fixture already charged by hand. Entry-port
+// near-misses are host loopback ports, shared with every other charged
+// crucible (moriah publishes 8891 beside tadmor's 8890), so one that accepts
+// fails only when the sentry itself listens on it; otherwise it is skipped.
+
+/// Host-side connect budget for an entry-port probe.
+const RBTDRC_ENTRY_PROBE_TIMEOUT_SECS: u64 = 3;
+
+/// Report file each policy case writes into its case dir.
+const RBTDRC_POLICY_VERDICTS_FILE: &str = "policy-verdicts.txt";
+
+fn rbtdrc_policy_uplink_domains(dir: &Path) -> rbtdre_Verdict {
+    rbtdrc_with_ctx(|ctx| zrbtdrc_run_policy_family(ctx, rbtdrc_PolicyFamily::Domains, dir))
+}
+
+fn rbtdrc_policy_uplink_cidrs(dir: &Path) -> rbtdre_Verdict {
+    rbtdrc_with_ctx(|ctx| zrbtdrc_run_policy_family(ctx, rbtdrc_PolicyFamily::Cidrs, dir))
+}
+
+fn rbtdrc_policy_entry_ports(dir: &Path) -> rbtdre_Verdict {
+    rbtdrc_with_ctx(|ctx| zrbtdrc_run_policy_family(ctx, rbtdrc_PolicyFamily::Entry, dir))
+}
+
+fn zrbtdrc_run_policy_family(
+    ctx: &mut rbtdri_Context,
+    family: rbtdrc_PolicyFamily,
+    dir: &Path,
+) -> rbtdre_Verdict {
+    let policy = match zrbtdrc_nameplate_policy(ctx) {
+        Ok(p) => p,
+        Err(e) => return rbtdre_Verdict::Fail(e),
+    };
+    let probes: Vec<rbtdrc_Probe> = match rbtdrc_policy_probes(&policy) {
+        Ok(all) => all.into_iter().filter(|p| p.family() == family).collect(),
+        Err(e) => return rbtdre_Verdict::Fail(format!("policy probes: {}", e)),
+    };
+    if probes.is_empty() {
+        return rbtdre_Verdict::Skip(format!("nameplate grants no {:?} policy to probe", family));
+    }
+
+    let allowed_domains = policy.allowed_domains();
+    let mut rows: Vec<String> = Vec::new();
+    let mut failures: Vec<String> = Vec::new();
+    for (index, probe) in probes.iter().enumerate() {
+        let probe_dir = dir.join(format!("probe-{:02}", index + 1));
+        if let Err(e) = std::fs::create_dir_all(&probe_dir) {
+            return rbtdre_Verdict::Fail(format!("create {}: {}", probe_dir.display(), e));
+        }
+        let verdict = zrbtdrc_run_probe(ctx, probe, &allowed_domains, &probe_dir);
+        let row = rbtdrc_policy_report_line(probe, &verdict);
+        crate::rbtdrg_info_now!("  {}", row);
+        if matches!(verdict, rbtdre_Verdict::Fail(_)) {
+            failures.push(row.clone());
+        }
+        rows.push(row);
+    }
+    let _ = std::fs::write(dir.join(RBTDRC_POLICY_VERDICTS_FILE), rows.join("\n") + "\n");
+
+    if failures.is_empty() {
+        rbtdre_Verdict::Pass
+    } else {
+        rbtdre_Verdict::Fail(format!(
+            "{} of {} policy probes failed:\n{}",
+            failures.len(),
+            probes.len(),
+            failures.join("\n")
+        ))
+    }
+}
+
+/// The fixture's nameplate rbrn.env (its imprint), parsed.
+fn zrbtdrc_nameplate_policy(ctx: &rbtdri_Context) -> Result<rbtdrc_Policy, String> {
+    let env_path = ctx
+        .project_root()
+        .join(crate::rbtdgc_consts::RBTDGC_MOORINGS_DIR)
+        .join(ctx.imprint())
+        .join(RBTDRC_POLICY_FILE);
+    std::fs::read_to_string(&env_path)
+        .map_err(|e| format!("cannot read {}: {}", env_path.display(), e))
+        .and_then(|content| rbtdrc_Policy::parse(&content))
+}
+
+fn zrbtdrc_run_probe(
+    ctx: &mut rbtdri_Context,
+    probe: &rbtdrc_Probe,
+    allowed_domains: &[String],
+    dir: &Path,
+) -> rbtdre_Verdict {
+    let allow = probe.expect == rbtdrc_Expect::Allow;
+    match &probe.target {
+        rbtdrc_Target::Domain(domain) => {
+            let attack = if allow { "dns-resolve" } else { "dns-resolve-block" };
+            rbtdrc_invoke_ifrit_with_args(ctx, attack, &[domain], dir)
+        }
+        rbtdrc_Target::Address(addr) => {
+            let attack = if allow { "tcp443-connect" } else { "tcp443-block" };
+            rbtdrc_invoke_ifrit_with_args(ctx, attack, &[&addr.to_string()], dir)
+        }
+        rbtdrc_Target::HostWithin(cidr) => {
+            let Some(ip) = zrbtdrc_known_host_within(ctx, cidr, allowed_domains) else {
+                return rbtdre_Verdict::Skip(format!(
+                    "no allowed domain resolves inside {} — no known host to reach",
+                    cidr
+                ));
+            };
+            let _ = std::fs::write(dir.join("resolved-ip.txt"), &ip);
+            let attack = if allow { "tcp443-connect" } else { "tcp443-block" };
+            rbtdrc_invoke_ifrit_with_args(ctx, attack, &[&ip], dir)
+        }
+        rbtdrc_Target::EntryPort(port) => {
+            let addr = std::net::SocketAddr::from(([127, 0, 0, 1], *port));
+            let timeout = std::time::Duration::from_secs(RBTDRC_ENTRY_PROBE_TIMEOUT_SECS);
+            match (std::net::TcpStream::connect_timeout(&addr, timeout), allow) {
+                (Ok(_), true) | (Err(_), false) => rbtdre_Verdict::Pass,
+                (Ok(_), false) => match zrbtdrc_sentry_listens(ctx, *port) {
+                    Ok(true) => rbtdre_Verdict::Fail(format!(
+                        "{} accepted a connection, and the sentry listens on {}",
+                        addr, port
+                    )),
+                    Ok(false) => rbtdre_Verdict::Skip(format!(
+                        "{} accepted, but the sentry does not listen on {} — another \
+                         crucible's published port, not this one's",
+                        addr, port
+                    )),
+                    Err(e) => rbtdre_Verdict::Fail(format!(
+                        "{} accepted a connection; sentry listeners unreadable: {}",
+                        addr, e
+                    )),
+                },
+                (Err(e), true) => rbtdre_Verdict::Fail(format!("{} did not accept: {}", addr, e)),
+            }
+        }
+    }
+}
+
+/// Whether the sentry itself listens on `port`. Host loopback is shared by
+/// every charged crucible, so a near-miss port that accepts is this crucible's
+/// breach only when the listener behind it is the sentry's.
+fn zrbtdrc_sentry_listens(ctx: &mut rbtdri_Context, port: u16) -> Result<bool, String> {
+    let tables = rbtdrc_writ(
+        ctx,
+        &["sh", "-c", "cat /proc/net/tcp; cat /proc/net/tcp6 2>/dev/null || true"],
+    )?;
+    Ok(rbtdrc_listening_ports(&tables).contains(&port))
+}
+
+/// Resolve the allowed domains on the sentry, in nameplate order, and return
+/// the first address inside `cidr`. A domain that fails to resolve is passed
+/// over — the domain probes are what hold resolution to account.
+fn zrbtdrc_known_host_within(
+    ctx: &mut rbtdri_Context,
+    cidr: &rbtdrc_Cidr,
+    allowed_domains: &[String],
+) -> Option<String> {
+    allowed_domains.iter().find_map(|domain| {
+        rbtdrc_resolve_via_writ(ctx, domain)
+            .ok()
+            .filter(|ip| ip.parse().is_ok_and(|addr| cidr.contains(addr)))
+    })
+}
+
+// ── Sentry packet capture (rbtdrc_capture) ───────────────────
+//
+// A case wrapped in zrbtdrc_with_capture runs between a tcpdump start and stop
+// on both sentry legs, found by role as scry finds them. Each leg is bounded on
+// the sentry side by time, packet count and snaplen, so a theurge that dies
+// mid-case leaves no unbounded capture behind. After the case the pcaps are
+// copied into the case dir as sentry-enclave.pcap and sentry-uplink.pcap and
+// handed back parsed. A capture failure never changes a case's own verdict: it
+// is logged and written to capture-error.txt, and only a case that asserts on
+// the packets fails for want of them.
+//
+// Egress lockdown is that case: it holds every uplink packet the sentry sent to
+// the allowlist, telling sent from received by the uplink's own MAC rather than
+// the source address, and writes what escaped to uplink-egress.txt. The TCP RST
+// hijack and DNS cache cases keep their pcaps for triage.
+
+/// Sentry-side wall-clock bound on one capture.
+const RBTDRC_CAPTURE_MAX_SECS: u32 = 300;
+
+/// Sentry-side packet-count bound on one leg.
+const RBTDRC_CAPTURE_MAX_PACKETS: u32 = 20000;
+
+/// Bytes kept per packet — headers only; payloads are not the evidence.
+const RBTDRC_CAPTURE_SNAPLEN: u32 = 256;
+
+/// 100ms polls allowed for tcpdump to report listening, and later to exit.
+const RBTDRC_CAPTURE_POLLS: u32 = 50;
+
+/// Sentry path prefix for a leg's pcap and tcpdump log.
+const RBTDRC_CAPTURE_REMOTE_PREFIX: &str = "/tmp/rbtd-capture-";
+
+/// tcpdump's own start and exit reports, kept for triage.
+const RBTDRC_CAPTURE_LOG_FILE: &str = "capture-tcpdump.txt";
+
+const RBTDRC_CAPTURE_ERROR_FILE: &str = "capture-error.txt";
+
+/// A leg's pcap, copied into the case dir and parsed.
+struct zrbtdrc_Captured {
+    leg: rbtdrc_CaptureLeg,
+    pcap: rbtdrc_Pcap,
+}
+
+fn zrbtdrc_capture_remote(leg: &rbtdrc_CaptureLeg) -> String {
+    format!("{}{}.pcap", RBTDRC_CAPTURE_REMOTE_PREFIX, leg.role)
+}
+
+fn zrbtdrc_capture_logs(legs: &[rbtdrc_CaptureLeg]) -> String {
+    let logs: Vec<String> =
+        legs.iter().map(|l| format!("{}.log", zrbtdrc_capture_remote(l))).collect();
+    logs.join(" ")
+}
+
+/// Shell fragment that polls until every leg's tcpdump log shows `pattern`,
+/// then runs `on_timeout` and exits 1 if the poll budget runs out.
+fn zrbtdrc_capture_poll(legs: &[rbtdrc_CaptureLeg], pattern: &str, on_timeout: &str) -> String {
+    let ready: Vec<String> = legs
+        .iter()
+        .map(|l| format!("grep -q '{}' {}.log", pattern, zrbtdrc_capture_remote(l)))
+        .collect();
+    format!(
+        "i=0; until {}; do i=$((i+1)); if [ $i -ge {} ]; then {}; exit 1; fi; sleep 0.1; done",
+        ready.join(" && "),
+        RBTDRC_CAPTURE_POLLS,
+        on_timeout
+    )
+}
+
+/// Start a bounded tcpdump on each sentry leg and wait until both listen.
+/// Returns the legs with their sentry pids, in leg order.
+fn zrbtdrc_capture_start(
+    ctx: &mut rbtdri_Context,
+) -> Result<Vec<(rbtdrc_CaptureLeg, String)>, String> {
+    let legs = zrbtdrc_sentry_legs(ctx)?;
+
+    let launches: Vec<String> = legs
+        .iter()
+        .map(|l| {
+            format!(
+                "rm -f {r} {r}.log; timeout {} tcpdump -i {} -U -nn -s {} -c {} -w {r} \
+                 2>{r}.log >/dev/null & pids=\"$pids $!\"",
+                RBTDRC_CAPTURE_MAX_SECS,
+                l.iface,
+                RBTDRC_CAPTURE_SNAPLEN,
+                RBTDRC_CAPTURE_MAX_PACKETS,
+                r = zrbtdrc_capture_remote(l)
+            )
+        })
+        .collect();
+    let script = format!(
+        "pids=; {}; {}; echo $pids",
+        launches.join("; "),
+        zrbtdrc_capture_poll(
+            &legs,
+            "listening on",
+            &format!("kill -INT $pids; cat {} >&2", zrbtdrc_capture_logs(&legs))
+        )
+    );
+    let output = rbtdrc_writ(ctx, &["sh", "-c", &script])
+        .map_err(|e| format!("tcpdump did not start on the sentry: {}", e))?;
+    let pids: Vec<String> = output.split_whitespace().map(str::to_string).collect();
+    if pids.len() != legs.len() {
+        return Err(format!("expected {} tcpdump pids, got: {}", legs.len(), output.trim()));
+    }
+    Ok(legs.into_iter().zip(pids).collect())
+}
+
+/// Stop each leg's tcpdump, wait for its exit report, and copy its pcap into
+/// the case dir. The sentry-side files are removed once copied.
+fn zrbtdrc_capture_finish(
+    ctx: &mut rbtdri_Context,
+    started: Vec<(rbtdrc_CaptureLeg, String)>,
+    dir: &Path,
+) -> Result<Vec<zrbtdrc_Captured>, String> {
+    let legs: Vec<rbtdrc_CaptureLeg> = started.iter().map(|(l, _)| l.clone()).collect();
+    let pids: Vec<&str> = started.iter().map(|(_, p)| p.as_str()).collect();
+    // tcpdump prints its packet counts on exit; waiting on that rather than on
+    // the pid holds even where the sentry's pid 1 leaves the exited child a zombie.
+    let script = format!(
+        "kill -INT {} 2>/dev/null; {}; cat {}",
+        pids.join(" "),
+        zrbtdrc_capture_poll(&legs, "packets captured", "true"),
+        zrbtdrc_capture_logs(&legs)
+    );
+    let stopped = rbtdrc_writ(ctx, &["sh", "-c", &script]);
+    if let Ok(report) = &stopped {
+        let _ = std::fs::write(dir.join(RBTDRC_CAPTURE_LOG_FILE), report);
+    }
+    stopped.map_err(|e| format!("tcpdump did not stop on the sentry: {}", e))?;
+
+    let mut captured = Vec::new();
+    for leg in legs {
+        let remote = zrbtdrc_capture_remote(&leg);
+        let script = format!("base64 -w0 {r} && rm -f {r} {r}.log", r = remote);
+        let encoded = rbtdrc_writ(ctx, &["sh", "-c", &script])
+            .map_err(|e| format!("cannot copy {}: {}", remote, e))?;
+        let bytes = rbtdrc_base64_decode(&encoded).map_err(|e| format!("{}: {}", remote, e))?;
+        let local = dir.join(format!("sentry-{}.pcap", leg.role));
+        std::fs::write(&local, &bytes)
+            .map_err(|e| format!("cannot write {}: {}", local.display(), e))?;
+        let pcap = rbtdrc_Pcap::parse(&bytes).map_err(|e| format!("{}: {}", local.display(), e))?;
+        captured.push(zrbtdrc_Captured { leg, pcap });
+    }
+    Ok(captured)
+}
+
+/// Run `case` inside a sentry capture. The case's verdict comes back as it
+/// was; the capture comes back beside it, or the reason there is none.
+fn zrbtdrc_with_capture(
+    dir: &Path,
+    case: fn(&Path) -> rbtdre_Verdict,
+) -> (rbtdre_Verdict, Result<Vec<zrbtdrc_Captured>, String>) {
+    let started = rbtdrc_with_ctx(zrbtdrc_capture_start);
+    let verdict = case(dir);
+    let captured =
+        rbtdrc_with_ctx(|ctx| started.and_then(|s| zrbtdrc_capture_finish(ctx, s, dir)));
+    if let Err(e) = &captured {
+        crate::rbtdrg_error_now!("  sentry capture: {}", e);
+        let _ = std::fs::write(dir.join(RBTDRC_CAPTURE_ERROR_FILE), e);
+    }
+    (verdict, captured)
+}
+
+/// Hold the uplink capture to the nameplate: every packet sent out of the
+/// uplink — told from received ones by the uplink's own MAC, not by source
+/// address — went to an allowed CIDR or to the sentry's upstream resolver on
+/// port 53, and none was IPv6. Global access has no bound to hold it to.
+fn zrbtdrc_assert_uplink_egress(
+    ctx: &mut rbtdri_Context,
+    captured: &[zrbtdrc_Captured],
+    dir: &Path,
+) -> rbtdre_Verdict {
+    let allowed = match zrbtdrc_nameplate_policy(ctx).and_then(|p| p.uplink_egress()) {
+        Ok(Some(cidrs)) => cidrs,
+        Ok(None) => {
+            let _ = std::fs::write(
+                dir.join("uplink-egress.txt"),
+                "global uplink access: no egress bound to assert\n",
+            );
+            return rbtdre_Verdict::Pass;
+        }
+        Err(e) => return rbtdre_Verdict::Fail(format!("nameplate policy: {}", e)),
+    };
+    let Some(uplink) = captured.iter().find(|c| c.leg.role == RBTDRC_LEG_UPLINK) else {
+        return rbtdre_Verdict::Fail("no uplink capture to assert on".to_string());
+    };
+    // The sentry's own resolv.conf names the upstream resolver dnsmasq forwards to.
+    let resolv_conf = rbtdrc_writ(ctx, &["cat", "/etc/resolv.conf"]);
+    let resolver: Option<std::net::Ipv4Addr> = match resolv_conf {
+        Ok(conf) => conf.lines().find_map(|l| {
+            l.trim().strip_prefix("nameserver").and_then(|ip| ip.trim().parse().ok())
+        }),
+        Err(e) => return rbtdre_Verdict::Fail(format!("cannot read sentry resolv.conf: {}", e)),
+    };
+
+    let packets = match uplink.pcap.outbound_packets(&uplink.leg.mac) {
+        Ok(p) => p,
+        Err(e) => return rbtdre_Verdict::Fail(format!("uplink capture: {}", e)),
+    };
+    let violations = rbtdrc_egress_violations(&packets, &allowed, resolver);
+    let allowed_text: Vec<String> = allowed.iter().map(|c| c.to_string()).collect();
+    let summary = format!(
+        "uplink {} ({}): {} outbound packets, {} outside [{}] + resolver {}:53",
+        uplink.leg.iface,
+        uplink.leg.address,
+        packets.len(),
+        violations.len(),
+        allowed_text.join(" "),
+        resolver.map(|r| r.to_string()).unwrap_or_else(|| "none".to_string())
+    );
+    let _ = std::fs::write(
+        dir.join("uplink-egress.txt"),
+        format!("{}\n{}", summary, violations.join("\n")),
+    );
+    if violations.is_empty() {
+        return rbtdre_Verdict::Pass;
+    }
+    let shown: Vec<&str> = violations.iter().take(10).map(String::as_str).collect();
+    rbtdre_Verdict::Fail(format!(
+        "BREACH: packets left the sentry uplink for destinations outside the allowlist — {}:\n{}",
+        summary,
+        shown.join("\n")
+    ))
+}
+
 // ── Sentry self-protection coordinated cases ─────────────────
 
 /// Coordinated: attempt outbound connections from sentry itself to non-allowed destinations.
 /// The sentry's OUTPUT DROP policy should block these — verifies sentry can't be used as a pivot.
+/// The uplink capture then proves it on the wire: nothing the attempts sent left the sentry.
 fn rbtdrc_coordinated_sentry_egress_lockdown(dir: &Path) -> rbtdre_Verdict {
+    let (verdict, captured) = zrbtdrc_with_capture(dir, zrbtdrc_sentry_egress_lockdown);
+    if !matches!(verdict, rbtdre_Verdict::Pass) {
+        return verdict;
+    }
+    match captured {
+        Ok(captured) => rbtdrc_with_ctx(|ctx| zrbtdrc_assert_uplink_egress(ctx, &captured, dir)),
+        Err(e) => rbtdre_Verdict::Fail(format!("sentry capture: {}", e)),
+    }
+}
+
+fn zrbtdrc_sentry_egress_lockdown(dir: &Path) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| {
         // Negative: attempt TCP to 1.1.1.1:443 from sentry via bash /dev/tcp
         let tcp1_result = rbtdri_invoke(
@@ -997,7 +1496,13 @@ fn rbtdrc_coordinated_dnsmasq_query_audit(dir: &Path) -> rbtdre_Verdict {
 }
 
 /// Coordinated: ifrit sends TCP RST packets at sentry DNS, theurge verifies DNS still works.
+/// Runs inside a sentry capture; the verdict does not read it — the pcaps stay in
+/// the case dir for triage of the forged segments.
 fn rbtdrc_coordinated_tcp_rst_hijack(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_with_capture(dir, zrbtdrc_tcp_rst_hijack).0
+}
+
+fn zrbtdrc_tcp_rst_hijack(dir: &Path) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| {
         let sentry_ip = match rbtdrc_discover_sentry_ip(ctx) {
             Ok(ip) => ip,
@@ -1400,12 +1905,10 @@ fn rbtdrc_coordinated_sentry_integrity(dir: &Path) -> rbtdre_Verdict {
         };
         let _ = std::fs::write(dir.join("pre-procs.txt"), &pre_procs);
 
-        let pre_iptables = match rbtdrc_writ(ctx, &["iptables", "-S"]) {
-            Ok(o) => o,
+        let pre_iptables = match zrbtdrc_sentry_ruleset(ctx, dir, "pre-iptables.txt") {
+            Ok(r) => r,
             Err(e) => return rbtdre_Verdict::Fail(format!("pre-snapshot iptables: {}", e)),
         };
-        let pre_iptables_rules = rbtdrc_extract_iptables_rules(&pre_iptables);
-        let _ = std::fs::write(dir.join("pre-iptables.txt"), &pre_iptables);
 
         let pre_links = match rbtdrc_writ(ctx, &["ip", "link", "show"]) {
             Ok(o) => o,
@@ -1451,12 +1954,10 @@ fn rbtdrc_coordinated_sentry_integrity(dir: &Path) -> rbtdre_Verdict {
         };
         let _ = std::fs::write(dir.join("post-procs.txt"), &post_procs);
 
-        let post_iptables = match rbtdrc_writ(ctx, &["iptables", "-S"]) {
-            Ok(o) => o,
+        let post_iptables = match zrbtdrc_sentry_ruleset(ctx, dir, "post-iptables.txt") {
+            Ok(r) => r,
             Err(e) => return rbtdre_Verdict::Fail(format!("post-snapshot iptables: {}", e)),
         };
-        let post_iptables_rules = rbtdrc_extract_iptables_rules(&post_iptables);
-        let _ = std::fs::write(dir.join("post-iptables.txt"), &post_iptables);
 
         let post_links = match rbtdrc_writ(ctx, &["ip", "link", "show"]) {
             Ok(o) => o,
@@ -1464,18 +1965,15 @@ fn rbtdrc_coordinated_sentry_integrity(dir: &Path) -> rbtdre_Verdict {
         };
         let _ = std::fs::write(dir.join("post-links.txt"), &post_links);
 
-        // Verify: iptables rules unchanged (compare rule lines only, not BUK headers)
-        if pre_iptables_rules != post_iptables_rules {
-            let _ = std::fs::write(
-                dir.join("iptables-diff.txt"),
-                format!(
-                    "BEFORE:\n{}\nAFTER:\n{}\n",
-                    pre_iptables_rules, post_iptables_rules
-                ),
-            );
-            return rbtdre_Verdict::Fail(
-                "BREACH: iptables rules changed after attack battery".to_string(),
-            );
+        // Verify: iptables rules unchanged (parsed, so save-time comments and
+        // counters never register as a change)
+        let iptables_diff = rbtdrc_ruleset_diff(&pre_iptables, &post_iptables);
+        if !iptables_diff.is_empty() {
+            let _ = std::fs::write(dir.join("iptables-diff.txt"), iptables_diff.join("\n") + "\n");
+            return rbtdre_Verdict::Fail(format!(
+                "BREACH: iptables rules changed after attack battery:\n{}",
+                iptables_diff.join("\n")
+            ));
         }
 
         // Verify: network interfaces unchanged
@@ -1502,7 +2000,13 @@ fn rbtdrc_coordinated_sentry_integrity(dir: &Path) -> rbtdre_Verdict {
 
 /// Coordinated integrity test: ifrit sends forged DNS responses claiming google.com → 1.2.3.4,
 /// theurge verifies frozen DNS records on sentry's dnsmasq are unchanged.
+/// Runs inside a sentry capture; the verdict does not read it — the pcaps stay in
+/// the case dir for triage of the forged responses.
 fn rbtdrc_coordinated_dns_cache_integrity(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_with_capture(dir, zrbtdrc_dns_cache_integrity).0
+}
+
+fn zrbtdrc_dns_cache_integrity(dir: &Path) -> rbtdre_Verdict {
     rbtdrc_with_ctx(|ctx| {
         // Discover sentry enclave IP to query its dnsmasq directly
         let sentry_ip = match rbtdrc_discover_sentry_ip(ctx) {
@@ -1720,16 +2224,299 @@ fn rbtdrc_coordinated_mac_flood_resilience(dir: &Path) -> rbtdre_Verdict {
     })
 }
 
+// ── Chaos crucible (rbtdrc_chaos) ────────────────────────────
+//
+// chaos-tadmor charges tadmor, proves its allowed egress works, then degrades
+// the sentry one way per case and reruns the denial battery while the damage
+// holds. Each case undoes its degradation before returning and holds the
+// restored sentry to the configuration it had beforehand, so a revert that
+// does not land fails that case — the fixture is StateProgressing and never
+// runs the next degradation on a sentry the last one left broken.
+//
+// The baseline and recovered cases aim dns-resolve at www.internic.net and
+// tcp443-connect at its address. A restarted sentry is probed while its startup script
+// races the battery; a recycled one is then waited out until PID 1 is sleep
+// again, and the restored sentry must match its pre-chaos snapshot (the golden
+// normalization) with dnsmasq running.
+
+/// Per-case record: the degradation, each command run, each probe's verdict.
+const RBTDRC_CHAOS_REPORT_FILE: &str = "chaos.txt";
+
+/// How long a recycled sentry gets to rerun its startup script.
+const RBTDRC_CHAOS_SETTLE_SECS: u64 = 60;
+
+/// The sentry startup script ends by exec'ing this as PID 1. Its health file
+/// survives a restart, so PID 1 is what says the script finished again.
+const RBTDRC_CHAOS_SETTLED_COMM: &str = "sleep";
+
+/// Run one chaos command — in the sentry through writ, or against the sentry
+/// container through smite — returning its stdout. Both are colophons, so
+/// every step is censused, transcribed and served from a cassette like any
+/// other launch.
+fn zrbtdrc_chaos_exec(
+    ctx: &mut rbtdri_Context,
+    command: &rbtdrc_ChaosCommand,
+) -> Result<String, String> {
+    let args: Vec<&str> = command.args.iter().map(String::as_str).collect();
+    match command.site {
+        rbtdrc_ChaosSite::Sentry => rbtdrc_writ(ctx, &args),
+        rbtdrc_ChaosSite::Runtime => {
+            if !args.first().is_some_and(|verb| RBTDRC_CHAOS_SMITE_VERBS.contains(verb)) {
+                return Err(format!("smite refuses '{}': not a chaos verb", args.join(" ")));
+            }
+            let result =
+                rbtdri_invoke_env(ctx, RBTDGC_CRUCIBLE_SMITE, &args, &[("BURD_NO_LOG", "1")])?;
+            if result.exit_code != 0 {
+                return Err(format!(
+                    "smite exit {}\nstdout: {}\nstderr: {}",
+                    result.exit_code, result.stdout, result.stderr
+                ));
+            }
+            Ok(result.stdout)
+        }
+    }
+}
+
+/// Wait out a recycled sentry's startup script.
+fn zrbtdrc_chaos_settle(ctx: &mut rbtdri_Context) -> Result<(), String> {
+    let deadline =
+        std::time::Instant::now() + std::time::Duration::from_secs(RBTDRC_CHAOS_SETTLE_SECS);
+    loop {
+        let comm = rbtdrc_writ(ctx, &["cat", "/proc/1/comm"]);
+        if comm.as_ref().is_ok_and(|c| c.trim() == RBTDRC_CHAOS_SETTLED_COMM) {
+            return Ok(());
+        }
+        if std::time::Instant::now() >= deadline {
+            return Err(format!(
+                "sentry not settled after {}s: PID 1 is {}",
+                RBTDRC_CHAOS_SETTLE_SECS,
+                comm.map_or_else(|e| e, |c| format!("'{}'", c.trim()))
+            ));
+        }
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+}
+
+/// What a lit or dark probe of `dark` aims at.
+fn zrbtdrc_chaos_aim(dark: rbtdrc_Dark, allowed_ip: &str) -> &str {
+    match dark {
+        rbtdrc_Dark::AllowedDomain => RBTDRC_CONNECTIVITY_DOMAIN,
+        rbtdrc_Dark::AllowedAddress => allowed_ip,
+    }
+}
+
+/// Run ifrit probes, each in its own numbered subdirectory, returning one
+/// report row per probe and the rows of those that did not pass. A probe
+/// that skips counts against the run: it proved nothing about the sentry.
+fn zrbtdrc_chaos_probes(
+    ctx: &mut rbtdri_Context,
+    probes: &[(&str, Option<&str>)],
+    dir: &Path,
+) -> (Vec<String>, Vec<String>) {
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    for (index, (attack, aim)) in probes.iter().enumerate() {
+        let probe_dir = dir.join(format!("probe-{:02}", index + 1));
+        let label = match aim {
+            Some(a) => format!("{} {}", attack, a),
+            None => attack.to_string(),
+        };
+        let verdict = match std::fs::create_dir_all(&probe_dir) {
+            Err(e) => rbtdre_Verdict::Fail(format!("create {}: {}", probe_dir.display(), e)),
+            Ok(()) => match aim {
+                Some(a) => rbtdrc_invoke_ifrit_with_args(ctx, attack, &[a], &probe_dir),
+                None => rbtdrc_invoke_ifrit(ctx, attack, &probe_dir),
+            },
+        };
+        let row = match verdict {
+            rbtdre_Verdict::Pass => format!("PASS {}", label),
+            rbtdre_Verdict::Fail(r) => {
+                format!("FAIL {} — {}", label, r.lines().next().unwrap_or(""))
+            }
+            rbtdre_Verdict::Skip(r) => format!("SKIP {} — {}", label, r),
+        };
+        crate::rbtdrg_info_now!("  {}", row);
+        if !row.starts_with("PASS") {
+            failures.push(row.clone());
+        }
+        rows.push(row);
+    }
+    (rows, failures)
+}
+
+/// Prove the bottle's allowed egress works and the battery is denied, so a
+/// dark probe under chaos means the degradation cut it, not that it was
+/// never there.
+fn zrbtdrc_chaos_lit(dir: &Path) -> rbtdre_Verdict {
+    rbtdrc_with_ctx(|ctx| {
+        let ip = match rbtdrc_resolve_via_writ(ctx, RBTDRC_CONNECTIVITY_DOMAIN) {
+            Ok(ip) => ip,
+            Err(e) => return rbtdre_Verdict::Fail(format!("writ resolve: {}", e)),
+        };
+        let mut probes: Vec<(&str, Option<&str>)> = rbtdrc_Dark::ALL
+            .iter()
+            .map(|d| (d.lit_attack(), Some(zrbtdrc_chaos_aim(*d, &ip))))
+            .collect();
+        probes.extend(RBTDRC_CHAOS_BATTERY.iter().map(|a| (*a, None)));
+        let (rows, failures) = zrbtdrc_chaos_probes(ctx, &probes, dir);
+        let _ = std::fs::write(dir.join(RBTDRC_CHAOS_REPORT_FILE), rows.join("\n") + "\n");
+        if failures.is_empty() {
+            rbtdre_Verdict::Pass
+        } else {
+            rbtdre_Verdict::Fail(format!(
+                "{} of {} probes failed on a healthy sentry:\n{}",
+                failures.len(),
+                probes.len(),
+                failures.join("\n")
+            ))
+        }
+    })
+}
+
+/// Inflict one degradation, rerun the battery and its dark probes while it
+/// holds, undo it, and hold the sentry to the configuration it had before.
+/// A probe that gets through is a BREACH; a degradation that does not take
+/// or does not come undone fails the case, the leak verdict first.
+fn zrbtdrc_chaos_run(dir: &Path, chaos: rbtdrc_Chaos) -> rbtdre_Verdict {
+    rbtdrc_with_ctx(|ctx| {
+        // Everything read from the sentry is read before it is degraded.
+        let ip = match rbtdrc_resolve_via_writ(ctx, RBTDRC_CONNECTIVITY_DOMAIN) {
+            Ok(ip) => ip,
+            Err(e) => return rbtdre_Verdict::Fail(format!("writ resolve: {}", e)),
+        };
+        let before = match zrbtdrc_sentry_config(ctx) {
+            Ok(s) => s,
+            Err(e) => return rbtdre_Verdict::Fail(format!("sentry snapshot: {}", e)),
+        };
+        let captured = match chaos.capture().map(|args| rbtdrc_writ(ctx, args)) {
+            None => String::new(),
+            Some(Ok(out)) => out,
+            Some(Err(e)) => return rbtdre_Verdict::Fail(format!("capture for revert: {}", e)),
+        };
+        let revert = match chaos.revert(&captured) {
+            Ok(r) => r,
+            Err(e) => return rbtdre_Verdict::Fail(format!("no way back from {}: {}", chaos, e)),
+        };
+
+        let mut report = vec![format!("chaos: {}", chaos)];
+        let mut broken: Vec<String> = Vec::new();
+        for command in chaos.inflict() {
+            report.push(format!("inflict {}", command));
+            if let Err(e) = zrbtdrc_chaos_exec(ctx, &command) {
+                broken.push(format!("inflict {}: {}", command, e));
+                break;
+            }
+        }
+        let inflicted = broken.is_empty();
+        if let (true, Some((check, expect))) = (inflicted, chaos.holds()) {
+            match zrbtdrc_chaos_exec(ctx, &check) {
+                Ok(out) if out.trim() == expect => report.push(format!("holds {}", check)),
+                Ok(out) => broken.push(format!(
+                    "{} did not take: {} printed '{}', not '{}'",
+                    chaos,
+                    check,
+                    out.trim(),
+                    expect
+                )),
+                Err(e) => broken.push(format!("hold check {}: {}", check, e)),
+            }
+        }
+
+        let mut leaks = Vec::new();
+        if broken.is_empty() {
+            let mut probes: Vec<(&str, Option<&str>)> =
+                RBTDRC_CHAOS_BATTERY.iter().map(|a| (*a, None)).collect();
+            probes.extend(
+                chaos.dark().iter().map(|d| (d.dark_attack(), Some(zrbtdrc_chaos_aim(*d, &ip)))),
+            );
+            let (rows, failures) = zrbtdrc_chaos_probes(ctx, &probes, dir);
+            report.extend(rows);
+            leaks = failures;
+        }
+
+        // Undo whatever was inflicted, whatever the probes found.
+        if inflicted {
+            for command in &revert {
+                report.push(format!("revert {}", command));
+                if let Err(e) = zrbtdrc_chaos_exec(ctx, command) {
+                    broken.push(format!("revert {}: {}", command, e));
+                }
+            }
+        }
+        if chaos.recycles() {
+            if let Err(e) = zrbtdrc_chaos_settle(ctx) {
+                broken.push(e);
+            }
+        }
+        match zrbtdrc_sentry_config(ctx) {
+            Ok(after) => {
+                let diff = rbtdrc_golden_diff(&before, &after);
+                if !diff.is_empty() {
+                    broken.push(format!("sentry not restored:\n{}", diff.join("\n")));
+                }
+            }
+            Err(e) => broken.push(format!("sentry snapshot after revert: {}", e)),
+        }
+        if rbtdrc_writ(ctx, &["pidof", "dnsmasq"]).is_err() {
+            broken.push("sentry not restored: dnsmasq is not running".to_string());
+        }
+        report.extend(broken.iter().map(|b| format!("BROKEN {}", b)));
+        let _ = std::fs::write(dir.join(RBTDRC_CHAOS_REPORT_FILE), report.join("\n") + "\n");
+
+        if !leaks.is_empty() {
+            return rbtdre_Verdict::Fail(format!(
+                "BREACH: {} probes got through with {}:\n{}",
+                leaks.len(),
+                chaos,
+                leaks.join("\n")
+            ));
+        }
+        if !broken.is_empty() {
+            return rbtdre_Verdict::Fail(broken.join("\n"));
+        }
+        rbtdre_Verdict::Pass
+    })
+}
+
+fn rbtdrc_chaos_baseline(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_lit(dir)
+}
+
+fn rbtdrc_chaos_dnsmasq_killed(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_run(dir, rbtdrc_Chaos::DnsmasqKilled)
+}
+
+fn rbtdrc_chaos_iptables_flushed(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_run(dir, rbtdrc_Chaos::IptablesFlushed)
+}
+
+fn rbtdrc_chaos_uplink_route_removed(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_run(dir, rbtdrc_Chaos::UplinkRouteRemoved)
+}
+
+fn rbtdrc_chaos_sentry_killed(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_run(dir, rbtdrc_Chaos::SentryKilled)
+}
+
+fn rbtdrc_chaos_sentry_restarted(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_run(dir, rbtdrc_Chaos::SentryRestarted)
+}
+
+/// After every degradation has come and gone, the allowed egress is back.
+fn rbtdrc_chaos_recovered(dir: &Path) -> rbtdre_Verdict {
+    zrbtdrc_chaos_lit(dir)
+}
+
 // ── Host-side helpers (HTTP probes, port discovery) ──────────
 
 /// Read RBRN_ENTRY_PORT_WORKSTATION from the nameplate's rbrn.env file.
-/// Caller's fixture name must equal a valid nameplate moniker — only crucible
+/// Caller's imprint must be a valid nameplate moniker — only crucible
 /// fixtures (srjcl/pluml) call this.
 fn rbtdrc_read_nameplate_port(ctx: &rbtdri_Context) -> Result<u16, String> {
     let env_path = ctx
         .project_root()
         .join(crate::rbtdgc_consts::RBTDGC_MOORINGS_DIR)
-        .join(ctx.fixture())
+        .join(ctx.imprint())
         .join("rbrn.env");
     let content = std::fs::read_to_string(&env_path)
         .map_err(|e| format!("cannot read {}: {}", env_path.display(), e))?;
@@ -2430,6 +3217,9 @@ fn rbtdrc_pluml_render_diagrams(dir: &Path) -> rbtdre_Verdict {
 // ── Case registry ────────────────────────────────────────────
 
 // ── Crucible fixtures (charge/quench lifecycle) ──────────────
+//
+// Crucible cases probe an already-charged bottle, so five minutes bounds a hung
+// case; chaos cases also wait out a degradation and its revert, hence ten.
 
 pub static RBTDRC_FIXTURE_TADMOR: rbtdre_Fixture = rbtdre_Fixture {
     name: crate::rbtdrm_manifest::RBTDRM_FIXTURE_TADMOR,
@@ -2438,7 +3228,9 @@ pub static RBTDRC_FIXTURE_TADMOR: rbtdre_Fixture = rbtdre_Fixture {
     teardown: Some(rbtdrc_quench_crucible),
     cases: RBTDRC_CASES_SECURITY,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(1200), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
 // Moriah is the airgap-bottle nameplate; runtime semantics are identical
@@ -2451,7 +3243,9 @@ pub static RBTDRC_FIXTURE_MORIAH: rbtdre_Fixture = rbtdre_Fixture {
     teardown: Some(rbtdrc_quench_crucible),
     cases: RBTDRC_CASES_SECURITY,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(1200), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
 pub static RBTDRC_FIXTURE_SRJCL: rbtdre_Fixture = rbtdre_Fixture {
@@ -2461,7 +3255,9 @@ pub static RBTDRC_FIXTURE_SRJCL: rbtdre_Fixture = rbtdre_Fixture {
     teardown: Some(rbtdrc_quench_crucible),
     cases: RBTDRC_CASES_SRJCL,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(600), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
 pub static RBTDRC_FIXTURE_PLUML: rbtdre_Fixture = rbtdre_Fixture {
@@ -2471,14 +3267,31 @@ pub static RBTDRC_FIXTURE_PLUML: rbtdre_Fixture = rbtdre_Fixture {
     teardown: Some(rbtdrc_quench_crucible),
     cases: RBTDRC_CASES_PLUML,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(600), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
+// Chaos-tadmor charges the tadmor nameplate (its imprint) and degrades its
+// sentry case by case — StateProgressing, so a degradation whose revert did
+// not land stops the fixture before the next one runs on a broken sentry.
+pub static RBTDRC_FIXTURE_CHAOS_TADMOR: rbtdre_Fixture = rbtdre_Fixture {
+    name: crate::rbtdrm_manifest::RBTDRM_FIXTURE_CHAOS_TADMOR,
+    disposition: rbtdre_Disposition::StateProgressing,
+    setup: Some(rbtdrc_charge_crucible),
+    teardown: Some(rbtdrc_quench_crucible),
+    cases: RBTDRC_CASES_CHAOS,
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(1200), invocations: None },
+    case_timeout_secs: Some(600),
+};
 
 pub static RBTDRC_CASES_SRJCL: &[rbtdre_Case] = &[
     case!(rbtdrc_srjcl_jupyter_running),
     case!(rbtdrc_srjcl_jupyter_connectivity),
     case!(rbtdrc_srjcl_websocket_kernel),
+    case!(rbtdrc_sentry_config_golden, tags: ["sentry", "golden"]),
 ];
 
 pub static RBTDRC_CASES_PLUML: &[rbtdre_Case] = &[
@@ -2488,72 +3301,114 @@ pub static RBTDRC_CASES_PLUML: &[rbtdre_Case] = &[
     case!(rbtdrc_pluml_invalid_hash),
     case!(rbtdrc_pluml_malformed_diagram),
     case!(rbtdrc_pluml_render_diagrams),
+    case!(rbtdrc_sentry_config_golden, tags: ["sentry", "golden"]),
 ];
 
 // Bottle/sentry security cases — shared by RBTDRC_FIXTURE_TADMOR and
 // RBTDRC_FIXTURE_MORIAH. The engine surfaces fixture identity in its own output.
+// Tags name the traffic or component a case exercises, for the fixture runner's
+// `--tag` filter (the family is already in the name, reachable by `--only`).
 static RBTDRC_CASES_SECURITY: &[rbtdre_Case] = &[
-    case!(rbtdrc_pentacle_dnsmasq_responds),
-    case!(rbtdrc_pentacle_ping_sentry),
-    case!(rbtdrc_ifrit_dns_allowed),
-    case!(rbtdrc_ifrit_dns_allowed_example_org),
-    case!(rbtdrc_ifrit_dns_blocked),
+    case!(rbtdrc_pentacle_dnsmasq_responds, tags: ["dns"]),
+    case!(rbtdrc_pentacle_ping_sentry, tags: ["sentry"]),
+    case!(rbtdrc_ifrit_dns_allowed, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_allowed_example_org, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_blocked, tags: ["dns"]),
     case!(rbtdrc_ifrit_apt_blocked),
-    case!(rbtdrc_ifrit_dns_nonexistent),
-    case!(rbtdrc_ifrit_dns_tcp),
-    case!(rbtdrc_ifrit_dns_udp),
-    case!(rbtdrc_ifrit_dns_block_direct),
-    case!(rbtdrc_ifrit_dns_block_altport),
-    case!(rbtdrc_ifrit_dns_block_cloudflare),
-    case!(rbtdrc_ifrit_dns_block_quad9),
-    case!(rbtdrc_ifrit_dns_block_zonetransfer),
-    case!(rbtdrc_ifrit_dns_block_ipv6),
-    case!(rbtdrc_ifrit_dns_block_multicast),
-    case!(rbtdrc_ifrit_dns_block_spoofing),
-    case!(rbtdrc_ifrit_dns_block_tunneling),
-    case!(rbtdrc_sentry_iptables_loaded),
-    case!(rbtdrc_sentry_config_rp_filter),
-    case!(rbtdrc_sentry_config_prerouting_dnat),
-    case!(rbtdrc_sentry_config_postrouting_masquerade),
-    case!(rbtdrc_sentry_config_forward_estab_related),
-    case!(rbtdrc_dns_blocked_with_observation),
+    case!(rbtdrc_ifrit_dns_nonexistent, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_tcp, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_udp, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_direct, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_altport, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_cloudflare, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_quad9, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_zonetransfer, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_ipv6, tags: ["dns", "ipv6"]),
+    case!(rbtdrc_ifrit_dns_block_multicast, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_spoofing, tags: ["dns"]),
+    case!(rbtdrc_ifrit_dns_block_tunneling, tags: ["dns"]),
+    case!(rbtdrc_sentry_iptables_loaded, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_rp_filter, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_prerouting_dnat, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_postrouting_masquerade, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_forward_estab_related, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_input_chain, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_output_chain, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_golden, tags: ["sentry", "golden"]),
+    case!(rbtdrc_dns_blocked_with_observation, tags: ["dns"]),
     case!(rbtdrc_tcp443_allow_example),
     case!(rbtdrc_tcp443_block_google),
-    case!(rbtdrc_icmp_first_hop),
-    case!(rbtdrc_icmp_second_hop_blocked),
+    case!(rbtdrc_icmp_first_hop, tags: ["icmp"]),
+    case!(rbtdrc_icmp_second_hop_blocked, tags: ["icmp"]),
     case!(rbtdrc_udp_non_dns_blocked),
     case!(rbtdrc_cidr_all_ports_allowed),
-    case!(rbtdrc_sortie_dns_exfil_subdomain),
+    case!(rbtdrc_policy_uplink_domains, tags: ["policy", "dns"]),
+    case!(rbtdrc_policy_uplink_cidrs, tags: ["policy"]),
+    case!(rbtdrc_policy_entry_ports, tags: ["policy"]),
+    case!(rbtdrc_sortie_dns_exfil_subdomain, tags: ["dns"]),
     case!(rbtdrc_sortie_meta_cloud_endpoint),
     case!(rbtdrc_sortie_net_forbidden_cidr),
-    case!(rbtdrc_sortie_direct_sentry_probe),
-    case!(rbtdrc_sortie_icmp_exfil_payload),
-    case!(rbtdrc_sortie_net_ipv6_escape),
+    case!(rbtdrc_sortie_direct_sentry_probe, tags: ["sentry"]),
+    case!(rbtdrc_sortie_icmp_exfil_payload, tags: ["icmp"]),
+    case!(rbtdrc_sortie_net_ipv6_escape, tags: ["ipv6"]),
     case!(rbtdrc_sortie_net_srcip_spoof),
     case!(rbtdrc_sortie_net_srcip_spoof_external),
     case!(rbtdrc_sortie_proto_smuggle_rawsock),
     case!(rbtdrc_sortie_net_fragment_evasion),
-    case!(rbtdrc_sortie_direct_arp_poison),
+    case!(rbtdrc_sortie_direct_arp_poison, tags: ["arp"]),
     case!(rbtdrc_sortie_ns_capability_escape),
-    case!(rbtdrc_sortie_dns_rebinding),
+    case!(rbtdrc_sortie_dns_rebinding, tags: ["dns"]),
     case!(rbtdrc_sortie_proc_sys_write),
     case!(rbtdrc_sortie_http_end_to_end),
     case!(rbtdrc_sortie_conntrack_spoofed_ack),
     case!(rbtdrc_sortie_offpath_blocked_dest),
     case!(rbtdrc_sortie_conntrack_pipeline_selfcheck),
-    case!(rbtdrc_sortie_sentry_udp_non_dns),
+    case!(rbtdrc_sortie_sentry_udp_non_dns, tags: ["sentry"]),
     case!(rbtdrc_sortie_net_route_manipulation),
     case!(rbtdrc_sortie_net_enclave_subnet_escape),
     case!(rbtdrc_sortie_net_dnat_entry_reflection),
-    case!(rbtdrc_coordinated_arp_gratuitous),
-    case!(rbtdrc_coordinated_arp_gateway_poison),
-    case!(rbtdrc_coordinated_arp_table_stability),
-    case!(rbtdrc_coordinated_sentry_integrity),
-    case!(rbtdrc_coordinated_dns_cache_integrity),
+    case!(rbtdrc_coordinated_arp_gratuitous, tags: ["arp"]),
+    case!(rbtdrc_coordinated_arp_gateway_poison, tags: ["arp"]),
+    case!(rbtdrc_coordinated_arp_table_stability, tags: ["arp"]),
+    case!(rbtdrc_coordinated_sentry_integrity, tags: ["sentry"]),
+    case!(rbtdrc_coordinated_dns_cache_integrity, tags: ["dns", "capture"]),
     case!(rbtdrc_coordinated_mac_flood_resilience),
-    case!(rbtdrc_coordinated_tcp_rst_hijack),
-    case!(rbtdrc_coordinated_sentry_egress_lockdown),
-    case!(rbtdrc_coordinated_dnsmasq_query_audit),
+    case!(rbtdrc_coordinated_tcp_rst_hijack, tags: ["capture"]),
+    case!(rbtdrc_coordinated_sentry_egress_lockdown, tags: ["sentry", "capture"]),
+    case!(rbtdrc_coordinated_dnsmasq_query_audit, tags: ["dns"]),
+];
+
+// Policy-derived cases — generic over the nameplate, so not tied to the
+// security battery that also lists them. `rbtd single` falls back to this set
+// for every crucible fixture, so any charged nameplate can run them by name.
+pub static RBTDRC_CASES_POLICY: &[rbtdre_Case] = &[
+    case!(rbtdrc_policy_uplink_domains, tags: ["policy", "dns"]),
+    case!(rbtdrc_policy_uplink_cidrs, tags: ["policy"]),
+    case!(rbtdrc_policy_entry_ports, tags: ["policy"]),
+];
+
+// Chaos cases — baseline, one degradation each, recovery, then the sentry
+// config checks and a lit probe each way, rerun on the sentry every revert
+// and restart has passed through.
+static RBTDRC_CASES_CHAOS: &[rbtdre_Case] = &[
+    case!(rbtdrc_chaos_baseline, tags: ["chaos"]),
+    case!(rbtdrc_chaos_dnsmasq_killed, tags: ["chaos", "dns"]),
+    case!(rbtdrc_chaos_iptables_flushed, tags: ["chaos", "sentry"]),
+    case!(rbtdrc_chaos_uplink_route_removed, tags: ["chaos", "sentry"]),
+    case!(rbtdrc_chaos_sentry_killed, tags: ["chaos", "sentry"]),
+    case!(rbtdrc_chaos_sentry_restarted, tags: ["chaos", "sentry"]),
+    case!(rbtdrc_chaos_recovered, tags: ["chaos"]),
+    case!(rbtdrc_sentry_iptables_loaded, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_rp_filter, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_prerouting_dnat, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_postrouting_masquerade, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_forward_estab_related, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_input_chain, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_output_chain, tags: ["sentry"]),
+    case!(rbtdrc_sentry_config_golden, tags: ["sentry", "golden"]),
+    case!(rbtdrc_ifrit_dns_allowed, tags: ["dns"]),
+    case!(rbtdrc_tcp443_allow_example),
+    case!(rbtdrc_tcp443_block_google),
 ];
 
 
@@ -0,0 +1,306 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRC — sentry config goldens: the normalized snapshot of a charged
+// sentry's effective network configuration, and its diff against the golden
+// committed for the nameplate
+//
+// No single sentry_config case notices a rbjs_sentry.sh change that widens
+// policy in a way none of them asserts on. The golden does: it is the whole
+// effective configuration — iptables ruleset, dnsmasq config, the sysctls the
+// script writes, the main routing table — normalized so the same policy always
+// renders the same bytes, and committed per nameplate under RBTDRC_GOLDEN_DIR.
+// Any change to it arrives as a golden update in code review.
+//
+// Normalization: the ruleset renders through rbtdrc_iptables' canonical form
+// (backend, counters and save-time comments gone); dnsmasq comments and blank
+// lines drop, and the addresses resolve-then-freeze pinned are masked, since
+// they follow upstream DNS; sysctl and route lines sort. Redaction: values the
+// container runtime picks at charge — interface names, the uplink address,
+// network and gateway — become placeholders. Nameplate and regime addresses
+// stay, because they are policy.
+//
+// Drift reads as section-tagged lines (`[iptables] + filter: -A OUTPUT …`).
+// The golden case in rbtdrc_crucible fails on any of them, and fails where no
+// golden is committed; under `--bless` it rewrites the golden instead.
+
+use std::path::{Path, PathBuf};
+
+use crate::rbtdrc_iptables::{rbtdrc_Ruleset, rbtdrc_SentryFacts};
+
+/// Repo-relative home of the committed goldens, one file per nameplate.
+pub const RBTDRC_GOLDEN_DIR: &str = "Tools/rbk/rbtd/golden";
+
+const ZRBTDRC_GOLDEN_SUFFIX: &str = ".sentry-config.txt";
+
+/// Sysctls rbjs_sentry.sh writes or depends on, as /proc/sys paths; globs
+/// cover the per-interface knobs. The sentry's snapshot script expands them.
+pub const RBTDRC_GOLDEN_SYSCTLS: &[&str] = &[
+    "net/ipv4/ip_forward",
+    "net/ipv4/ip_local_port_range",
+    "net/ipv4/conf/*/rp_filter",
+    "net/ipv4/conf/*/route_localnet",
+    "net/ipv6/conf/all/disable_ipv6",
+];
+
+const ZRBTDRC_SECTION_IPTABLES: &str = "[iptables]";
+const ZRBTDRC_SECTION_DNSMASQ: &str = "[dnsmasq]";
+const ZRBTDRC_SECTION_SYSCTL: &str = "[sysctl]";
+const ZRBTDRC_SECTION_ROUTES: &str = "[routes]";
+
+const ZRBTDRC_REDACT_ENCLAVE_IF: &str = "<enclave-if>";
+const ZRBTDRC_REDACT_UPLINK_IF: &str = "<uplink-if>";
+const ZRBTDRC_REDACT_UPLINK_IP: &str = "<uplink-ip>";
+const ZRBTDRC_REDACT_UPLINK_NET: &str = "<uplink-net>";
+const ZRBTDRC_REDACT_UPLINK_GW: &str = "<uplink-gw>";
+const ZRBTDRC_REDACT_RESOLVED: &str = "<resolved>";
+
+/// Rendered for a section with nothing in it, so absence is explicit.
+const ZRBTDRC_EMPTY_SECTION: &str = "(none)";
+
+/// The committed golden for `nameplate` under `root`.
+pub fn rbtdrc_golden_path(root: &Path, nameplate: &str) -> PathBuf {
+    root.join(RBTDRC_GOLDEN_DIR).join(format!("{}{}", nameplate, ZRBTDRC_GOLDEN_SUFFIX))
+}
+
+/// The sentry's configuration as read over writ, before normalization.
+#[derive(Debug, Clone, Default)]
+pub struct rbtdrc_SentryRaw {
+    /// `iptables-save` output.
+    pub iptables_save: String,
+    /// /etc/dnsmasq.conf; empty where the nameplate runs no dnsmasq.
+    pub dnsmasq_conf: String,
+    /// One `<proc-sys-path> = <value>` line per expanded RBTDRC_GOLDEN_SYSCTLS entry.
+    pub sysctl: String,
+    /// `ip -4 route show` output.
+    pub routes: String,
+}
+
+/// Literal replacements applied at value boundaries, so redacting 10.0.0.2
+/// leaves 10.0.0.25 alone and `eth1` is found inside `conf.eth1.rp_filter`.
+struct zrbtdrc_Redactor {
+    pairs: Vec<(String, &'static str)>,
+}
+
+impl zrbtdrc_Redactor {
+    fn add(&mut self, value: &str, placeholder: &'static str) {
+        if !value.is_empty() && !self.pairs.iter().any(|(v, _)| v == value) {
+            self.pairs.push((value.to_string(), placeholder));
+            self.pairs.sort_by_key(|(v, _)| std::cmp::Reverse(v.len()));
+        }
+    }
+
+    fn redact(&self, line: &str) -> String {
+        let mut line = line.to_string();
+        for (value, placeholder) in &self.pairs {
+            let mut out = String::with_capacity(line.len());
+            let mut rest = 0;
+            for (at, _) in line.match_indices(value.as_str()) {
+                if at < rest {
+                    continue;
+                }
+                let end = at + value.len();
+                if zrbtdrc_boundary(&line, at, end) {
+                    out.push_str(&line[rest..at]);
+                    out.push_str(placeholder);
+                    rest = end;
+                }
+            }
+            out.push_str(&line[rest..]);
+            line = out;
+        }
+        line
+    }
+}
+
+/// Whether `text[at..end]` stands alone: each neighbour is neither
+/// alphanumeric nor a dot that continues a dotted number.
+fn zrbtdrc_boundary(text: &str, at: usize, end: usize) -> bool {
+    let bytes = text.as_bytes();
+    let joins = |neighbour: Option<&u8>, beyond: Option<&u8>| match neighbour {
+        Some(c) if c.is_ascii_alphanumeric() => true,
+        Some(b'.') => beyond.is_some_and(|b| b.is_ascii_digit()),
+        _ => false,
+    };
+    let before = at.checked_sub(1).and_then(|i| bytes.get(i));
+    let before_beyond = at.checked_sub(2).and_then(|i| bytes.get(i));
+    !joins(before, before_beyond) && !joins(bytes.get(end), bytes.get(end + 1))
+}
+
+/// The uplink network and gateway as the routing table names them: the
+/// kernel route on the uplink, and the default route's next hop.
+fn zrbtdrc_uplink_routes(routes: &str, uplink_if: &str) -> (Option<String>, Option<String>) {
+    let mut net = None;
+    let mut gateway = None;
+    for line in routes.lines() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let on_uplink = words.windows(2).any(|w| w == ["dev", uplink_if]);
+        if words.first() == Some(&"default") {
+            if let Some(w) = words.windows(2).find(|w| w[0] == "via") {
+                gateway = Some(w[1].to_string());
+            }
+        } else if on_uplink && words.windows(2).any(|w| w == ["proto", "kernel"]) {
+            net = words.first().map(|w| w.to_string());
+        }
+    }
+    (net, gateway)
+}
+
+/// One dnsmasq line, normalized: resolve-then-freeze `address=/<domain>/<ip>`
+/// pins keep the domain and mask the address upstream DNS chose.
+fn zrbtdrc_dnsmasq_line(line: &str) -> String {
+    if let Some(pin) = line.strip_prefix("address=/") {
+        if let Some((domain, addr)) = pin.split_once('/') {
+            if !addr.is_empty() {
+                return format!("address=/{}/{}", domain, ZRBTDRC_REDACT_RESOLVED);
+            }
+        }
+    }
+    line.to_string()
+}
+
+fn zrbtdrc_section(out: &mut Vec<String>, name: &str, lines: Vec<String>) {
+    if !out.is_empty() {
+        out.push(String::new());
+    }
+    out.push(name.to_string());
+    if lines.is_empty() {
+        out.push(ZRBTDRC_EMPTY_SECTION.to_string());
+    } else {
+        out.extend(lines);
+    }
+}
+
+/// Render the normalized, redacted snapshot the golden for `nameplate` holds.
+pub fn rbtdrc_sentry_snapshot(
+    nameplate: &str,
+    raw: &rbtdrc_SentryRaw,
+    facts: &rbtdrc_SentryFacts,
+) -> Result<String, String> {
+    let (uplink_net, uplink_gw) = zrbtdrc_uplink_routes(&raw.routes, &facts.uplink_if);
+    let mut redactor = zrbtdrc_Redactor { pairs: Vec::new() };
+    redactor.add(&facts.enclave_if, ZRBTDRC_REDACT_ENCLAVE_IF);
+    redactor.add(&facts.uplink_if, ZRBTDRC_REDACT_UPLINK_IF);
+    redactor.add(&facts.uplink_ip.to_string(), ZRBTDRC_REDACT_UPLINK_IP);
+    redactor.add(uplink_net.as_deref().unwrap_or_default(), ZRBTDRC_REDACT_UPLINK_NET);
+    redactor.add(uplink_gw.as_deref().unwrap_or_default(), ZRBTDRC_REDACT_UPLINK_GW);
+
+    let ruleset = rbtdrc_Ruleset::parse(&raw.iptables_save)?;
+    let iptables: Vec<String> =
+        ruleset.canonical_lines().iter().map(|l| redactor.redact(l)).collect();
+
+    let mut dnsmasq: Vec<String> = Vec::new();
+    for line in raw.dnsmasq_conf.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = redactor.redact(&zrbtdrc_dnsmasq_line(line));
+        // One pin per domain, however many addresses it resolved to.
+        if !dnsmasq.contains(&line) {
+            dnsmasq.push(line);
+        }
+    }
+
+    let mut sysctl: Vec<String> = Vec::new();
+    for line in raw.sysctl.lines().filter(|l| !l.trim().is_empty()) {
+        let (path, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("sysctl line without '=': {}", line))?;
+        let key = path.trim().trim_start_matches("/proc/sys/").replace('/', ".");
+        let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        sysctl.push(redactor.redact(&format!("{} = {}", key, value)));
+    }
+    sysctl.sort();
+
+    let mut routes: Vec<String> = raw
+        .routes
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| redactor.redact(&l.split_whitespace().collect::<Vec<_>>().join(" ")))
+        .collect();
+    routes.sort();
+
+    let mut out = vec![
+        format!("# Sentry effective network configuration — nameplate {}", nameplate),
+        "# Written by rbtdrc_sentry_config_golden; update with --bless, never by hand."
+            .to_string(),
+    ];
+    let redacted = [
+        ZRBTDRC_REDACT_ENCLAVE_IF,
+        ZRBTDRC_REDACT_UPLINK_IF,
+        ZRBTDRC_REDACT_UPLINK_IP,
+        ZRBTDRC_REDACT_UPLINK_NET,
+        ZRBTDRC_REDACT_UPLINK_GW,
+        ZRBTDRC_REDACT_RESOLVED,
+    ];
+    out.push(format!("# Redacted: {}", redacted.join(" ")));
+    let mut body = Vec::new();
+    zrbtdrc_section(&mut body, ZRBTDRC_SECTION_IPTABLES, iptables);
+    zrbtdrc_section(&mut body, ZRBTDRC_SECTION_DNSMASQ, dnsmasq);
+    zrbtdrc_section(&mut body, ZRBTDRC_SECTION_SYSCTL, sysctl);
+    zrbtdrc_section(&mut body, ZRBTDRC_SECTION_ROUTES, routes);
+    out.push(String::new());
+    out.extend(body);
+    Ok(out.join("\n") + "\n")
+}
+
+/// Line diff of `golden` against `actual`: `- ` lines only the golden has,
+/// `+ ` lines only the snapshot has, each tagged with its section, in file
+/// order. Empty when they match.
+pub fn rbtdrc_golden_diff(golden: &str, actual: &str) -> Vec<String> {
+    let old: Vec<&str> = golden.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    // Longest common subsequence, filled from the ends so the walk runs forward.
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let mut diff = Vec::new();
+    let mut section = "";
+    let (mut i, mut j) = (0, 0);
+    let mut note = |section: &str, mark: char, line: &str| {
+        let tag = if section.is_empty() { String::new() } else { format!("{} ", section) };
+        diff.push(format!("{}{} {}", tag, mark, line));
+    };
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            if old[i].starts_with('[') {
+                section = old[i];
+            }
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || common[i][j + 1] > common[i + 1][j]) {
+            if new[j].starts_with('[') {
+                section = new[j];
+            }
+            note(section, '+', new[j]);
+            j += 1;
+        } else {
+            if old[i].starts_with('[') {
+                section = old[i];
+            }
+            note(section, '-', old[i]);
+            i += 1;
+        }
+    }
+    diff
+}
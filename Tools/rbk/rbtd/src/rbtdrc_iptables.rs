@@ -0,0 +1,971 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRC — sentry iptables: iptables-save parser, rule evaluator, and the
+// policy model the sentry_config cases hold the sentry's ruleset to
+//
+// The sentry's ruleset is read as `iptables-save` output and parsed into
+// tables, chains, policies and structured rules. Both backends' save formats
+// read into the same model: nft's reordered options, `-m conntrack --ctstate`
+// for legacy's `-m state --state`, counter prefixes and warning comments all
+// normalize away, so a rule's canonical rendering is backend-independent.
+//
+// The policy model is not a list of expected rule lines. It is a set of claims
+// derived from the nameplate — "FORWARD DROP for enclave→0.0.0.0/0 except
+// 192.0.32.0/20" — each carrying representative packets, and each checked by
+// walking the parsed chains the way the kernel would (jumps, RETURN, goto,
+// chain policy). Any rule arrangement with the same effect passes; a failure
+// names the claim, the packet, and the rule or policy that decided it. A rule
+// carrying a match the evaluator does not model is reported, never guessed at.
+
+use std::fmt;
+use std::net::Ipv4Addr;
+
+use crate::rbtdrc_policy::{
+    rbtdrc_Cidr, rbtdrc_Policy, RBTDRC_KEY_ACCESS_MODE, RBTDRC_KEY_DNS_MODE,
+    RBTDRC_KEY_ENCLAVE_BOTTLE_IP, RBTDRC_KEY_ENCLAVE_SENTRY_IP, RBTDRC_KEY_ENTRY_MODE,
+    RBTDRC_KEY_ENTRY_PORT, RBTDRC_KEY_ENTRY_PORT_ENCLAVE, RBTDRC_MODE_ALLOWLIST,
+    RBTDRC_MODE_DISABLED, RBTDRC_MODE_ENABLED, RBTDRC_MODE_GLOBAL,
+};
+
+/// Jump depth at which evaluation gives up — a chain loop, not a policy.
+const ZRBTDRC_MAX_JUMP_DEPTH: usize = 16;
+
+/// Targets that record or mark a packet and let it continue down the chain.
+const ZRBTDRC_NONTERMINAL_TARGETS: &[&str] = &["LOG", "NFLOG", "MARK", "CONNMARK", "TRACE"];
+
+/// Stands in for "the rest of the internet" — an address no nameplate allows.
+const ZRBTDRC_OUTSIDE_ADDR: Ipv4Addr = Ipv4Addr::new(1, 1, 1, 1);
+
+/// Port of the representative non-DNS connection.
+const ZRBTDRC_PROBE_PORT: u16 = 443;
+
+/// A port no sentry offers to anyone.
+const ZRBTDRC_CLOSED_PORT: u16 = 22;
+
+/// Destination port of a reply packet.
+const ZRBTDRC_EPHEMERAL_PORT: u16 = 40000;
+
+const ZRBTDRC_DNS_PORT: u16 = 53;
+
+pub const RBTDRC_TABLE_FILTER: &str = "filter";
+pub const RBTDRC_TABLE_NAT: &str = "nat";
+
+// ── iptables-save parser ─────────────────────────────────────
+
+/// Which iptables backend wrote the save output, from its header comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_Backend {
+    Legacy,
+    Nft,
+    Unknown,
+}
+
+/// A rule match with its `!` negation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Cond<T> {
+    pub negated: bool,
+    pub value: T,
+}
+
+impl<T: fmt::Display> rbtdrc_Cond<T> {
+    fn render(&self, flag: &str) -> String {
+        let bang = if self.negated { "! " } else { "" };
+        format!("{}{} {}", bang, flag, self.value)
+    }
+}
+
+/// A `--dport` value: one port or an inclusive `first:last` range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct rbtdrc_PortRange {
+    pub first: u16,
+    pub last: u16,
+}
+
+impl rbtdrc_PortRange {
+    fn parse(text: &str) -> Result<rbtdrc_PortRange, String> {
+        let port = |p: &str| p.parse::<u16>().map_err(|_| format!("invalid port '{}'", text));
+        match text.split_once(':') {
+            Some((first, last)) => Ok(rbtdrc_PortRange { first: port(first)?, last: port(last)? }),
+            None => port(text).map(|p| rbtdrc_PortRange { first: p, last: p }),
+        }
+    }
+
+    pub fn contains(&self, port: u16) -> bool {
+        (self.first..=self.last).contains(&port)
+    }
+}
+
+impl fmt::Display for rbtdrc_PortRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.first == self.last {
+            write!(f, "{}", self.first)
+        } else {
+            write!(f, "{}:{}", self.first, self.last)
+        }
+    }
+}
+
+/// One `-A` line, with the matches the evaluator understands pulled out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Rule {
+    pub chain: String,
+    pub source: Option<rbtdrc_Cond<rbtdrc_Cidr>>,
+    pub destination: Option<rbtdrc_Cond<rbtdrc_Cidr>>,
+    pub in_iface: Option<rbtdrc_Cond<String>>,
+    pub out_iface: Option<rbtdrc_Cond<String>>,
+    /// Lower-case protocol name; numeric protocols are named.
+    pub protocol: Option<rbtdrc_Cond<String>>,
+    /// Conntrack states, sorted and comma-joined — from either `--state` or
+    /// `--ctstate`.
+    pub ctstate: Option<rbtdrc_Cond<String>>,
+    pub dport: Option<rbtdrc_Cond<rbtdrc_PortRange>>,
+    /// Tokens of matches the evaluator does not model, verbatim.
+    pub unmodeled: Vec<String>,
+    pub target: Option<String>,
+    /// `-g` rather than `-j`: the target chain does not return here.
+    pub goto: bool,
+    pub target_args: Vec<String>,
+}
+
+impl fmt::Display for rbtdrc_Rule {
+    /// Canonical rendering in legacy iptables-save option order, with
+    /// conntrack state always as `-m conntrack --ctstate`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![format!("-A {}", self.chain)];
+        parts.extend(self.source.as_ref().map(|c| c.render("-s")));
+        parts.extend(self.destination.as_ref().map(|c| c.render("-d")));
+        parts.extend(self.in_iface.as_ref().map(|c| c.render("-i")));
+        parts.extend(self.out_iface.as_ref().map(|c| c.render("-o")));
+        parts.extend(self.protocol.as_ref().map(|c| c.render("-p")));
+        parts.extend(self.dport.as_ref().map(|c| c.render("--dport")));
+        parts.extend(self.ctstate.as_ref().map(|c| c.render("-m conntrack --ctstate")));
+        parts.extend(self.unmodeled.iter().cloned());
+        if let Some(target) = &self.target {
+            parts.push(format!("{} {}", if self.goto { "-g" } else { "-j" }, target));
+        }
+        parts.extend(self.target_args.iter().cloned());
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Chain {
+    pub name: String,
+    /// Built-in chains carry a policy; user chains (`-` in the save) do not.
+    pub policy: Option<String>,
+    pub rules: Vec<rbtdrc_Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Table {
+    pub name: String,
+    pub chains: Vec<rbtdrc_Chain>,
+}
+
+/// A parsed `iptables-save` dump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Ruleset {
+    pub backend: rbtdrc_Backend,
+    pub tables: Vec<rbtdrc_Table>,
+}
+
+/// Split a save line into words, honouring the double quotes iptables-save
+/// puts around comment text.
+fn zrbtdrc_tokens(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quoted = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                in_token = true;
+            }
+            '\\' if quoted => current.extend(chars.next()),
+            c if c.is_whitespace() && !quoted => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+    if quoted {
+        return Err("unterminated quote".to_string());
+    }
+    if in_token {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+fn zrbtdrc_protocol_name(text: &str) -> String {
+    match text {
+        "1" => "icmp".to_string(),
+        "6" => "tcp".to_string(),
+        "17" => "udp".to_string(),
+        other => other.to_ascii_lowercase(),
+    }
+}
+
+fn zrbtdrc_parse_rule(tokens: &[String]) -> Result<rbtdrc_Rule, String> {
+    let chain = tokens.get(1).ok_or("-A without a chain")?;
+    let mut rule = rbtdrc_Rule {
+        chain: chain.clone(),
+        source: None,
+        destination: None,
+        in_iface: None,
+        out_iface: None,
+        protocol: None,
+        ctstate: None,
+        dport: None,
+        unmodeled: Vec::new(),
+        target: None,
+        goto: false,
+        target_args: Vec::new(),
+    };
+    let mut negated = false;
+    let mut i = 2;
+    while i < tokens.len() {
+        let flag = tokens[i].as_str();
+        i += 1;
+        if flag == "!" {
+            negated = true;
+            continue;
+        }
+        // Older iptables wrote the negation after the flag: `-d ! 10.0.0.0/8`.
+        let mut value = || -> Result<(bool, String), String> {
+            let mut inner = false;
+            if tokens.get(i).map(String::as_str) == Some("!") {
+                inner = true;
+                i += 1;
+            }
+            let value = tokens.get(i).ok_or_else(|| format!("{} without a value", flag))?;
+            i += 1;
+            Ok((negated || inner, value.clone()))
+        };
+        match flag {
+            "-s" | "--source" | "-d" | "--destination" => {
+                let (negated, text) = value()?;
+                let cond = Some(rbtdrc_Cond { negated, value: rbtdrc_Cidr::parse(&text)? });
+                if flag.starts_with("-s") || flag == "--source" {
+                    rule.source = cond;
+                } else {
+                    rule.destination = cond;
+                }
+            }
+            "-i" | "--in-interface" => {
+                let (negated, value) = value()?;
+                rule.in_iface = Some(rbtdrc_Cond { negated, value });
+            }
+            "-o" | "--out-interface" => {
+                let (negated, value) = value()?;
+                rule.out_iface = Some(rbtdrc_Cond { negated, value });
+            }
+            "-p" | "--protocol" => {
+                let (negated, text) = value()?;
+                rule.protocol = Some(rbtdrc_Cond { negated, value: zrbtdrc_protocol_name(&text) });
+            }
+            "-m" | "--match" => {
+                let (_, module) = value()?;
+                // Protocol, state and comment modules are implied by their options.
+                if !["tcp", "udp", "state", "conntrack", "comment"].contains(&module.as_str()) {
+                    rule.unmodeled.extend(["-m".to_string(), module]);
+                }
+            }
+            "--dport" | "--destination-port" => {
+                let (negated, text) = value()?;
+                rule.dport = Some(rbtdrc_Cond { negated, value: rbtdrc_PortRange::parse(&text)? });
+            }
+            "--state" | "--ctstate" => {
+                let (negated, text) = value()?;
+                let mut states: Vec<&str> = text.split(',').collect();
+                states.sort_unstable();
+                rule.ctstate = Some(rbtdrc_Cond { negated, value: states.join(",") });
+            }
+            "--comment" => {
+                value()?;
+            }
+            "-j" | "--jump" | "-g" | "--goto" => {
+                let (_, target) = value()?;
+                rule.target = Some(target);
+                rule.goto = flag == "-g" || flag == "--goto";
+                rule.target_args = tokens[i..].to_vec();
+                break;
+            }
+            other => {
+                if negated {
+                    rule.unmodeled.push("!".to_string());
+                }
+                rule.unmodeled.push(other.to_string());
+                if let Some(next) = tokens.get(i).filter(|t| !t.starts_with('-')) {
+                    rule.unmodeled.push(next.clone());
+                    i += 1;
+                }
+            }
+        }
+        negated = false;
+    }
+    Ok(rule)
+}
+
+impl rbtdrc_Ruleset {
+    /// Parse `iptables-save` output from either backend. Counter prefixes
+    /// (`-c` saves) and comments are dropped; a rule for an undeclared chain
+    /// or a line outside any table is refused.
+    pub fn parse(text: &str) -> Result<rbtdrc_Ruleset, String> {
+        let mut backend = rbtdrc_Backend::Unknown;
+        let mut tables: Vec<rbtdrc_Table> = Vec::new();
+        let mut open = false;
+        for (index, raw) in text.lines().enumerate() {
+            let number = index + 1;
+            let line = raw.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if backend == rbtdrc_Backend::Unknown && comment.contains("Generated by") {
+                    backend = if comment.contains("nft") || comment.contains("nf_tables") {
+                        rbtdrc_Backend::Nft
+                    } else {
+                        rbtdrc_Backend::Legacy
+                    };
+                }
+                continue;
+            }
+            let at = |e: String| format!("iptables-save line {}: {} ({})", number, e, line);
+            if let Some(name) = line.strip_prefix('*') {
+                tables.push(rbtdrc_Table { name: name.to_string(), chains: Vec::new() });
+                open = true;
+                continue;
+            }
+            if line == "COMMIT" {
+                open = false;
+                continue;
+            }
+            let table = match tables.last_mut() {
+                Some(t) if open => t,
+                _ => return Err(at("outside any table".to_string())),
+            };
+            if let Some(decl) = line.strip_prefix(':') {
+                let mut words = decl.split_whitespace();
+                let name = words.next().ok_or_else(|| at("chain without a name".to_string()))?;
+                let policy = words.next().filter(|p| *p != "-").map(str::to_string);
+                let name = name.to_string();
+                table.chains.push(rbtdrc_Chain { name, policy, rules: Vec::new() });
+                continue;
+            }
+            let body = match line.strip_prefix('[') {
+                Some(counted) => counted.split_once(']').map(|(_, r)| r.trim()).unwrap_or(line),
+                None => line,
+            };
+            let tokens = zrbtdrc_tokens(body).map_err(at)?;
+            if tokens.first().map(String::as_str) != Some("-A") {
+                return Err(at("unrecognized line".to_string()));
+            }
+            let rule = zrbtdrc_parse_rule(&tokens).map_err(at)?;
+            let chain = table
+                .chains
+                .iter_mut()
+                .find(|c| c.name == rule.chain)
+                .ok_or_else(|| at(format!("rule for undeclared chain {}", rule.chain)))?;
+            chain.rules.push(rule);
+        }
+        if open {
+            return Err("iptables-save output ends without COMMIT".to_string());
+        }
+        Ok(rbtdrc_Ruleset { backend, tables })
+    }
+
+    pub fn chain(&self, table: &str, chain: &str) -> Option<&rbtdrc_Chain> {
+        self.tables
+            .iter()
+            .find(|t| t.name == table)
+            .and_then(|t| t.chains.iter().find(|c| c.name == chain))
+    }
+
+    /// Every chain and rule in canonical form, one line each, prefixed with
+    /// its table — the shape rbtdrc_ruleset_diff compares.
+    pub fn canonical_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for table in &self.tables {
+            for chain in &table.chains {
+                let policy = chain.policy.as_deref().unwrap_or("-");
+                lines.push(format!("{}: :{} {}", table.name, chain.name, policy));
+            }
+            for chain in &table.chains {
+                lines.extend(chain.rules.iter().map(|r| format!("{}: {}", table.name, r)));
+            }
+        }
+        lines
+    }
+
+    /// Walk `chain` of `table` for `packet` and report what decided it: the
+    /// first terminating rule reached, or the built-in chain's policy.
+    pub fn decide(
+        &self,
+        table: &str,
+        chain: &str,
+        packet: &rbtdrc_Packet,
+    ) -> Result<rbtdrc_Decision, String> {
+        let start = self
+            .chain(table, chain)
+            .ok_or_else(|| format!("no {} chain in the {} table", chain, table))?;
+        match self.zrbtdrc_walk(table, start, packet, 0)? {
+            Some(decision) => Ok(decision),
+            None => Ok(rbtdrc_Decision {
+                target: start.policy.clone().unwrap_or_else(|| "RETURN".to_string()),
+                target_args: Vec::new(),
+                rule: None,
+                chain: start.name.clone(),
+            }),
+        }
+    }
+
+    fn zrbtdrc_walk(
+        &self,
+        table: &str,
+        chain: &rbtdrc_Chain,
+        packet: &rbtdrc_Packet,
+        depth: usize,
+    ) -> Result<Option<rbtdrc_Decision>, String> {
+        if depth > ZRBTDRC_MAX_JUMP_DEPTH {
+            let limit = ZRBTDRC_MAX_JUMP_DEPTH;
+            return Err(format!("jumps nest deeper than {} at {}", limit, chain.name));
+        }
+        for rule in &chain.rules {
+            if !rule.matches(packet)? {
+                continue;
+            }
+            let Some(target) = rule.target.as_deref() else {
+                continue;
+            };
+            if target == "RETURN" {
+                return Ok(None);
+            }
+            if let Some(sub) = self.chain(table, target) {
+                if let Some(decision) = self.zrbtdrc_walk(table, sub, packet, depth + 1)? {
+                    return Ok(Some(decision));
+                }
+                if rule.goto {
+                    return Ok(None);
+                }
+                continue;
+            }
+            if ZRBTDRC_NONTERMINAL_TARGETS.contains(&target) {
+                continue;
+            }
+            return Ok(Some(rbtdrc_Decision {
+                target: target.to_string(),
+                target_args: rule.target_args.clone(),
+                rule: Some(rule.clone()),
+                chain: chain.name.clone(),
+            }));
+        }
+        Ok(None)
+    }
+}
+
+/// Lines removed from and added to a ruleset, in canonical form — so a
+/// legacy dump and an nft dump of the same rules do not differ. Rules that
+/// are all present on both sides but in another order are reported per chain.
+pub fn rbtdrc_ruleset_diff(before: &rbtdrc_Ruleset, after: &rbtdrc_Ruleset) -> Vec<String> {
+    let (old, new) = (before.canonical_lines(), after.canonical_lines());
+    let mut unmatched = new.clone();
+    let mut diff = Vec::new();
+    for line in &old {
+        match unmatched.iter().position(|l| l == line) {
+            Some(at) => {
+                unmatched.remove(at);
+            }
+            None => diff.push(format!("- {}", line)),
+        }
+    }
+    diff.extend(unmatched.iter().map(|l| format!("+ {}", l)));
+    if diff.is_empty() && old != new {
+        for table in &before.tables {
+            for chain in &table.chains {
+                let reordered = after
+                    .chain(&table.name, &chain.name)
+                    .is_some_and(|other| other.rules != chain.rules);
+                if reordered {
+                    diff.push(format!("~ {}: rules of {} reordered", table.name, chain.name));
+                }
+            }
+        }
+    }
+    diff
+}
+
+// ── Evaluation ───────────────────────────────────────────────
+
+/// A representative packet, as a chain sees it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Packet {
+    pub protocol: &'static str,
+    pub src: Ipv4Addr,
+    pub dst: Ipv4Addr,
+    pub dport: Option<u16>,
+    pub in_iface: Option<String>,
+    pub out_iface: Option<String>,
+    pub ctstate: &'static [&'static str],
+}
+
+impl fmt::Display for rbtdrc_Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let port = self.dport.map(|p| format!(":{}", p)).unwrap_or_default();
+        let state = self.ctstate.join(",");
+        write!(f, "{} {}→{}{} {}", self.protocol, self.src, self.dst, port, state)?;
+        match (&self.in_iface, &self.out_iface) {
+            (Some(i), Some(o)) => write!(f, " ({}→{})", i, o),
+            (Some(i), None) => write!(f, " (in {})", i),
+            (None, Some(o)) => write!(f, " (out {})", o),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// iptables interface match: exact, or a `+`-suffixed prefix.
+fn zrbtdrc_iface_matches(pattern: &str, iface: &str) -> bool {
+    match pattern.strip_suffix('+') {
+        Some(prefix) => iface.starts_with(prefix),
+        None => pattern == iface,
+    }
+}
+
+impl rbtdrc_Rule {
+    /// Whether `packet` meets every match of this rule. Errs when the modeled
+    /// matches all hold but the rule also carries one the evaluator cannot judge.
+    pub fn matches(&self, packet: &rbtdrc_Packet) -> Result<bool, String> {
+        let iface = |cond: &rbtdrc_Cond<String>, iface: &Option<String>| {
+            iface.as_deref().is_some_and(|i| zrbtdrc_iface_matches(&cond.value, i))
+        };
+        let checks = [
+            self.source.as_ref().map(|c| c.negated ^ c.value.contains(packet.src)),
+            self.destination.as_ref().map(|c| c.negated ^ c.value.contains(packet.dst)),
+            self.in_iface.as_ref().map(|c| c.negated ^ iface(c, &packet.in_iface)),
+            self.out_iface.as_ref().map(|c| c.negated ^ iface(c, &packet.out_iface)),
+            self.protocol
+                .as_ref()
+                .map(|c| c.negated ^ (c.value == "all" || c.value == packet.protocol)),
+            self.dport
+                .as_ref()
+                .map(|c| c.negated ^ packet.dport.is_some_and(|p| c.value.contains(p))),
+            self.ctstate
+                .as_ref()
+                .map(|c| c.negated ^ c.value.split(',').any(|s| packet.ctstate.contains(&s))),
+        ];
+        if !checks.iter().all(|c| c.unwrap_or(true)) {
+            return Ok(false);
+        }
+        if !self.unmodeled.is_empty() {
+            let unmodeled = self.unmodeled.join(" ");
+            return Err(format!("cannot evaluate `{}`: unmodeled match {}", self, unmodeled));
+        }
+        Ok(true)
+    }
+}
+
+/// What a chain walk ended on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Decision {
+    pub target: String,
+    pub target_args: Vec<String>,
+    /// The deciding rule; None when the chain's policy decided.
+    pub rule: Option<rbtdrc_Rule>,
+    /// The chain the deciding rule or policy belongs to.
+    pub chain: String,
+}
+
+impl fmt::Display for rbtdrc_Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.rule {
+            Some(rule) => write!(f, "{} by `{}`", self.target, rule),
+            None => write!(f, "{} by {} policy", self.target, self.chain),
+        }
+    }
+}
+
+// ── Policy model ─────────────────────────────────────────────
+
+/// Which sentry_config case holds a claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_RuleGroup {
+    Policies,
+    Input,
+    Forward,
+    Output,
+    Prerouting,
+    Postrouting,
+}
+
+impl fmt::Display for rbtdrc_RuleGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            rbtdrc_RuleGroup::Policies => "chain policy",
+            rbtdrc_RuleGroup::Input => "INPUT",
+            rbtdrc_RuleGroup::Forward => "FORWARD",
+            rbtdrc_RuleGroup::Output => "OUTPUT",
+            rbtdrc_RuleGroup::Prerouting => "nat PREROUTING",
+            rbtdrc_RuleGroup::Postrouting => "nat POSTROUTING",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The outcome a claim requires of its packets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum rbtdrc_Want {
+    Accept,
+    /// DROP or REJECT.
+    Drop,
+    /// DNAT to this `addr:port`.
+    Dnat(String),
+    Masquerade,
+    /// Leaves the nat chain untranslated.
+    Return,
+}
+
+impl rbtdrc_Want {
+    fn satisfied_by(&self, target: &str, args: &[String]) -> bool {
+        match self {
+            rbtdrc_Want::Accept | rbtdrc_Want::Return => target == "ACCEPT",
+            rbtdrc_Want::Drop => target == "DROP" || target == "REJECT",
+            rbtdrc_Want::Dnat(to) => {
+                target == "DNAT"
+                    && args.windows(2).any(|w| w[0] == "--to-destination" && w[1] == *to)
+            }
+            rbtdrc_Want::Masquerade => target == "MASQUERADE",
+        }
+    }
+}
+
+impl fmt::Display for rbtdrc_Want {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            rbtdrc_Want::Accept => write!(f, "ACCEPT"),
+            rbtdrc_Want::Drop => write!(f, "DROP"),
+            rbtdrc_Want::Dnat(to) => write!(f, "DNAT to {}", to),
+            rbtdrc_Want::Masquerade => write!(f, "MASQUERADE"),
+            rbtdrc_Want::Return => write!(f, "RETURN"),
+        }
+    }
+}
+
+/// One claim of the policy model. A Policies claim is about the chain's
+/// policy and carries no packets; every other claim holds for each packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Expectation {
+    pub group: rbtdrc_RuleGroup,
+    pub table: &'static str,
+    pub chain: &'static str,
+    pub want: rbtdrc_Want,
+    pub what: String,
+    pub packets: Vec<rbtdrc_Packet>,
+}
+
+impl fmt::Display for rbtdrc_Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.group == rbtdrc_RuleGroup::Policies {
+            write!(f, "{} policy {}", self.chain, self.want)
+        } else {
+            write!(f, "{} {} for {}", self.chain, self.want, self.what)
+        }
+    }
+}
+
+impl rbtdrc_Expectation {
+    /// Hold the ruleset to this claim. The error reads as what the ruleset
+    /// lacks, then the first packet that shows it and what decided that packet.
+    pub fn check(&self, ruleset: &rbtdrc_Ruleset) -> Result<(), String> {
+        if self.group == rbtdrc_RuleGroup::Policies {
+            let policy = ruleset.chain(self.table, self.chain).and_then(|c| c.policy.clone());
+            return match policy {
+                Some(p) if self.want.satisfied_by(&p, &[]) => Ok(()),
+                Some(p) => Err(format!("{} policy is {}, expected {}", self.chain, p, self.want)),
+                None => Err(format!("{} has no policy in the {} table", self.chain, self.table)),
+            };
+        }
+        for packet in &self.packets {
+            let decision = ruleset
+                .decide(self.table, self.chain, packet)
+                .map_err(|e| format!("{}: {}", self, e))?;
+            if !self.want.satisfied_by(&decision.target, &decision.target_args) {
+                return Err(format!(
+                    "{} lacks {} for {}: {} meets {}",
+                    self.chain, self.want, self.what, packet, decision
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What the model needs that the nameplate does not say: the sentry's
+/// interface names and uplink address, and the upstream resolver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_SentryFacts {
+    pub enclave_if: String,
+    pub uplink_if: String,
+    pub uplink_ip: Ipv4Addr,
+    pub dns_server: Ipv4Addr,
+}
+
+fn zrbtdrc_addr(policy: &rbtdrc_Policy, key: &str) -> Result<Ipv4Addr, String> {
+    let line = policy.require(key)?;
+    line.value.parse().map_err(|_| format!("{} is not an IPv4 address ({})", key, line))
+}
+
+fn zrbtdrc_port(policy: &rbtdrc_Policy, key: &str) -> Result<u16, String> {
+    let line = policy.require(key)?;
+    line.value.parse().map_err(|_| format!("{} is not a port ({})", key, line))
+}
+
+fn zrbtdrc_mode<'a>(
+    policy: &'a rbtdrc_Policy,
+    key: &str,
+    known: &[&str],
+) -> Result<&'a str, String> {
+    let line = policy.require(key)?;
+    if !known.contains(&line.value.as_str()) {
+        return Err(format!("{} has unknown mode '{}' ({})", key, line.value, line));
+    }
+    Ok(line.value.as_str())
+}
+
+/// Derive the claims the sentry's ruleset must meet from the nameplate —
+/// the same modes, addresses and allowlist rbjs_sentry.sh builds it from.
+pub fn rbtdrc_policy_model(
+    policy: &rbtdrc_Policy,
+    facts: &rbtdrc_SentryFacts,
+) -> Result<Vec<rbtdrc_Expectation>, String> {
+    use rbtdrc_RuleGroup::*;
+    use rbtdrc_Want::*;
+
+    let uplink_modes = [RBTDRC_MODE_DISABLED, RBTDRC_MODE_GLOBAL, RBTDRC_MODE_ALLOWLIST];
+    let access = zrbtdrc_mode(policy, RBTDRC_KEY_ACCESS_MODE, &uplink_modes)?;
+    let dns_mode = zrbtdrc_mode(policy, RBTDRC_KEY_DNS_MODE, &uplink_modes)?;
+    let entry_modes = [RBTDRC_MODE_DISABLED, RBTDRC_MODE_ENABLED];
+    let entry = zrbtdrc_mode(policy, RBTDRC_KEY_ENTRY_MODE, &entry_modes)?;
+    let sentry = zrbtdrc_addr(policy, RBTDRC_KEY_ENCLAVE_SENTRY_IP)?;
+    let bottle = zrbtdrc_addr(policy, RBTDRC_KEY_ENCLAVE_BOTTLE_IP)?;
+    let workstation_port = zrbtdrc_port(policy, RBTDRC_KEY_ENTRY_PORT)?;
+    let enclave_port = zrbtdrc_port(policy, RBTDRC_KEY_ENTRY_PORT_ENCLAVE)?;
+    let cidrs = policy.allowed_cidrs()?;
+    let (dns, uplink_ip, outside) = (facts.dns_server, facts.uplink_ip, ZRBTDRC_OUTSIDE_ADDR);
+
+    let (enclave_if, uplink_if) = (facts.enclave_if.as_str(), facts.uplink_if.as_str());
+    let packet = |protocol, src, dst, dport, in_if: Option<&str>, out_if: Option<&str>, ctstate| {
+        rbtdrc_Packet {
+            protocol,
+            src,
+            dst,
+            dport,
+            in_iface: in_if.map(str::to_string),
+            out_iface: out_if.map(str::to_string),
+            ctstate,
+        }
+    };
+    let (enc, upl) = (Some(enclave_if), Some(uplink_if));
+    const NEW: &[&str] = &["NEW"];
+    const ESTABLISHED: &[&str] = &["ESTABLISHED"];
+    const DNAT_NEW: &[&str] = &["NEW", "DNAT"];
+    let probe = Some(ZRBTDRC_PROBE_PORT);
+    let closed = Some(ZRBTDRC_CLOSED_PORT);
+    let dns_port = Some(ZRBTDRC_DNS_PORT);
+
+    // Addresses beyond the allowlist: each CIDR's outer edges, the outside
+    // stand-in, and the resolver on a non-DNS port — less any an allowed CIDR covers.
+    let mut beyond: Vec<Ipv4Addr> = Vec::new();
+    if access != RBTDRC_MODE_GLOBAL {
+        let edges = cidrs.iter().flat_map(|c| [c.first().checked_sub(1), c.last().checked_add(1)]);
+        let candidates = edges.flatten().map(Ipv4Addr::from).chain([outside, dns]);
+        for addr in candidates {
+            let allowed = access == RBTDRC_MODE_ALLOWLIST && cidrs.iter().any(|c| c.contains(addr));
+            if !allowed && !beyond.contains(&addr) {
+                beyond.push(addr);
+            }
+        }
+    }
+    let cidr_list: Vec<String> = cidrs.iter().map(|c| c.to_string()).collect();
+    let except = |what: &str| match access {
+        RBTDRC_MODE_ALLOWLIST => format!("{}→0.0.0.0/0 except {}", what, cidr_list.join(" ")),
+        _ => format!("{}→0.0.0.0/0", what),
+    };
+
+    let mut model = Vec::new();
+    let mut expect = |group, table, chain, want, what: String, packets: Vec<rbtdrc_Packet>| {
+        model.push(rbtdrc_Expectation { group, table, chain, want, what, packets });
+    };
+    let filter = RBTDRC_TABLE_FILTER;
+    let nat = RBTDRC_TABLE_NAT;
+
+    for chain in ["INPUT", "FORWARD", "OUTPUT"] {
+        expect(Policies, filter, chain, Drop, "policy".to_string(), Vec::new());
+    }
+
+    // INPUT — the sentry itself, as reached from either side.
+    expect(Input, filter, "INPUT", Accept, "ESTABLISHED,RELATED return traffic".to_string(), vec![
+        packet("tcp", outside, uplink_ip, Some(ZRBTDRC_EPHEMERAL_PORT), upl, None, ESTABLISHED),
+    ]);
+    expect(Input, filter, "INPUT", Accept, "enclave ICMP".to_string(), vec![
+        packet("icmp", bottle, sentry, None, enc, None, NEW),
+    ]);
+    expect(Input, filter, "INPUT", Drop, "uplink→sentry NEW".to_string(), vec![
+        packet("tcp", outside, uplink_ip, closed, upl, None, NEW),
+    ]);
+    let what = "enclave→sentry other than DNS and ICMP".to_string();
+    expect(Input, filter, "INPUT", Drop, what, vec![
+        packet("tcp", bottle, sentry, closed, enc, None, NEW),
+    ]);
+    let enclave_dns = vec![
+        packet("udp", bottle, sentry, dns_port, enc, None, NEW),
+        packet("tcp", bottle, sentry, dns_port, enc, None, NEW),
+    ];
+    let want = if dns_mode == RBTDRC_MODE_DISABLED { Drop } else { Accept };
+    expect(Input, filter, "INPUT", want, "enclave DNS to the sentry".to_string(), enclave_dns);
+
+    // FORWARD — the bottle's only path anywhere.
+    let what = "ESTABLISHED,RELATED return traffic".to_string();
+    expect(Forward, filter, "FORWARD", Accept, what, vec![
+        packet("tcp", outside, bottle, Some(ZRBTDRC_EPHEMERAL_PORT), upl, enc, ESTABLISHED),
+        packet("tcp", bottle, outside, Some(ZRBTDRC_EPHEMERAL_PORT), enc, upl, ESTABLISHED),
+    ]);
+    expect(Forward, filter, "FORWARD", Drop, "enclave ICMP across the boundary".to_string(), vec![
+        packet("icmp", bottle, outside, None, enc, upl, NEW),
+    ]);
+    let what = "uplink→enclave NEW without entry DNAT".to_string();
+    expect(Forward, filter, "FORWARD", Drop, what, vec![
+        packet("tcp", outside, bottle, Some(enclave_port), upl, enc, NEW),
+        packet("tcp", outside, bottle, closed, upl, enc, NEW),
+    ]);
+    if entry == RBTDRC_MODE_ENABLED {
+        let what = format!("entry DNAT to {}:{}", bottle, enclave_port);
+        expect(Forward, filter, "FORWARD", Accept, what, vec![
+            packet("tcp", outside, bottle, Some(enclave_port), upl, enc, DNAT_NEW),
+        ]);
+    }
+    match access {
+        RBTDRC_MODE_GLOBAL => {
+            expect(Forward, filter, "FORWARD", Accept, "enclave→0.0.0.0/0".to_string(), vec![
+                packet("tcp", bottle, outside, probe, enc, upl, NEW),
+            ]);
+        }
+        _ => {
+            if access == RBTDRC_MODE_ALLOWLIST {
+                for cidr in &cidrs {
+                    expect(Forward, filter, "FORWARD", Accept, format!("enclave→{}", cidr), vec![
+                        packet("tcp", bottle, cidr.host(), probe, enc, upl, NEW),
+                    ]);
+                }
+            }
+            let packets = beyond.iter().map(|a| packet("tcp", bottle, *a, probe, enc, upl, NEW));
+            if !beyond.is_empty() {
+                expect(Forward, filter, "FORWARD", Drop, except("enclave"), packets.collect());
+            }
+            let what = "enclave DNS that bypasses the sentry".to_string();
+            expect(Forward, filter, "FORWARD", Drop, what, vec![
+                packet("udp", bottle, dns, dns_port, enc, upl, NEW),
+                packet("tcp", bottle, dns, dns_port, enc, upl, NEW),
+            ]);
+        }
+    }
+
+    // OUTPUT — the sentry must not be a pivot.
+    expect(Output, filter, "OUTPUT", Accept, "sentry ICMP into the enclave".to_string(), vec![
+        packet("icmp", sentry, bottle, None, None, enc, NEW),
+    ]);
+    expect(Output, filter, "OUTPUT", Drop, "sentry ICMP to the uplink".to_string(), vec![
+        packet("icmp", uplink_ip, outside, None, None, upl, NEW),
+    ]);
+    let resolver = vec![
+        packet("udp", uplink_ip, dns, dns_port, None, upl, NEW),
+        packet("tcp", uplink_ip, dns, dns_port, None, upl, NEW),
+    ];
+    match access {
+        RBTDRC_MODE_DISABLED => {
+            let mut packets = resolver;
+            packets.push(packet("tcp", uplink_ip, outside, probe, None, upl, NEW));
+            expect(Output, filter, "OUTPUT", Drop, "sentry→0.0.0.0/0".to_string(), packets);
+        }
+        RBTDRC_MODE_GLOBAL => {
+            expect(Output, filter, "OUTPUT", Accept, "sentry→0.0.0.0/0".to_string(), vec![
+                packet("tcp", uplink_ip, outside, probe, None, upl, NEW),
+            ]);
+        }
+        _ => {
+            let what = format!("sentry DNS to the resolver {}", dns);
+            expect(Output, filter, "OUTPUT", Accept, what, resolver);
+            for cidr in &cidrs {
+                expect(Output, filter, "OUTPUT", Accept, format!("sentry→{}", cidr), vec![
+                    packet("tcp", uplink_ip, cidr.host(), probe, None, upl, NEW),
+                ]);
+            }
+            let mut packets: Vec<rbtdrc_Packet> = beyond
+                .iter()
+                .map(|a| packet("tcp", uplink_ip, *a, probe, None, upl, NEW))
+                .collect();
+            if beyond.contains(&outside) {
+                packets.push(packet("udp", uplink_ip, outside, dns_port, None, upl, NEW));
+            }
+            if !packets.is_empty() {
+                let what = format!("{} and {}:53", except("sentry"), dns);
+                expect(Output, filter, "OUTPUT", Drop, what, packets);
+            }
+        }
+    }
+
+    // nat PREROUTING — the entry DNAT, and nothing from inside reflected by it.
+    let workstation = Some(workstation_port);
+    let entry_from = |src, in_if| packet("tcp", src, uplink_ip, workstation, in_if, None, NEW);
+    if entry == RBTDRC_MODE_ENABLED {
+        let to = format!("{}:{}", bottle, enclave_port);
+        let what = format!("workstation port {}", workstation_port);
+        expect(Prerouting, nat, "PREROUTING", Dnat(to), what, vec![entry_from(outside, upl)]);
+        let what = format!("enclave-sourced port {}", workstation_port);
+        expect(Prerouting, nat, "PREROUTING", Return, what, vec![
+            entry_from(bottle, enc),
+            entry_from(sentry, enc),
+        ]);
+    } else {
+        let what = format!("workstation port {} (entry disabled)", workstation_port);
+        expect(Prerouting, nat, "PREROUTING", Return, what, vec![entry_from(outside, upl)]);
+    }
+
+    // nat POSTROUTING — entry return-path symmetry and enclave egress NAT.
+    if entry == RBTDRC_MODE_ENABLED {
+        let what = format!("entry traffic to {}:{}", bottle, enclave_port);
+        expect(Postrouting, nat, "POSTROUTING", Masquerade, what, vec![
+            packet("tcp", outside, bottle, Some(enclave_port), upl, enc, DNAT_NEW),
+        ]);
+    }
+    let egress = vec![packet("tcp", bottle, outside, probe, enc, upl, NEW)];
+    if access == RBTDRC_MODE_DISABLED {
+        let what = "enclave→uplink (access disabled)".to_string();
+        expect(Postrouting, nat, "POSTROUTING", Return, what, egress);
+    } else {
+        expect(Postrouting, nat, "POSTROUTING", Masquerade, "enclave→uplink".to_string(), egress);
+    }
+
+    Ok(model)
+}
@@ -0,0 +1,563 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRC — policy-derived containment probes generated from a nameplate
+//
+// The hand-written security battery in rbtdrc_crucible pins tadmor's own
+// allowlist (www.internic.net, example.org, 192.0.32.0/20). This module reads
+// the policy out of any nameplate's rbrn.env instead — uplink DNS and access
+// modes, allowed domains and CIDRs, entry mode and workstation port — and
+// synthesizes one probe per allowed entry plus adversarial near-misses:
+//
+//   domain  allowed → resolves in the bottle. Near-misses must not resolve:
+//           the parent domain, the same name under a sibling TLD, and the name
+//           glued onto a longer label (a suffix match without a label
+//           boundary). The sentry freezes allowed names with dnsmasq
+//           `address=/<domain>/`, which also covers subdomains, so a candidate
+//           under any allowed domain is never offered as a near-miss.
+//   CIDR    allowed → TCP 443 reaches a known host inside it (an allowed
+//           domain's address, picked at run time). Near-misses are the
+//           addresses one below and one above the block.
+//   entry   enabled → the workstation port accepts on the host's loopback;
+//           the ports either side of it do not. Loopback is shared with every
+//           other charged crucible, so a near-miss that accepts counts only
+//           when the sentry itself listens on it (rbtdrc_listening_ports).
+//
+// A `global` mode yields only the allow probes (it allows everything, so no
+// near-miss can be denied); a `disabled` mode turns every entry into a deny
+// probe tied to the mode line. Every probe carries the nameplate line it
+// tests, so the crucible cases that run them report each verdict against its
+// policy line. Pure: no I/O, so the generator is proven without a crucible.
+
+use std::fmt;
+use std::net::Ipv4Addr;
+
+use crate::rbtdre_engine::rbtdre_Verdict;
+
+/// Nameplate file the policy is read from, inside the nameplate's mooring dir.
+pub const RBTDRC_POLICY_FILE: &str = "rbrn.env";
+
+pub const RBTDRC_KEY_DNS_MODE: &str = "RBRN_UPLINK_DNS_MODE";
+pub const RBTDRC_KEY_ACCESS_MODE: &str = "RBRN_UPLINK_ACCESS_MODE";
+pub const RBTDRC_KEY_ALLOWED_DOMAINS: &str = "RBRN_UPLINK_ALLOWED_DOMAINS";
+pub const RBTDRC_KEY_ALLOWED_CIDRS: &str = "RBRN_UPLINK_ALLOWED_CIDRS";
+pub const RBTDRC_KEY_ENTRY_MODE: &str = "RBRN_ENTRY_MODE";
+pub const RBTDRC_KEY_ENTRY_PORT: &str = "RBRN_ENTRY_PORT_WORKSTATION";
+pub const RBTDRC_KEY_ENTRY_PORT_ENCLAVE: &str = "RBRN_ENTRY_PORT_ENCLAVE";
+pub const RBTDRC_KEY_ENCLAVE_SENTRY_IP: &str = "RBRN_ENCLAVE_SENTRY_IP";
+pub const RBTDRC_KEY_ENCLAVE_BOTTLE_IP: &str = "RBRN_ENCLAVE_BOTTLE_IP";
+
+pub const RBTDRC_MODE_DISABLED: &str = "rbnne_disabled";
+pub const RBTDRC_MODE_ENABLED: &str = "rbnne_enabled";
+pub const RBTDRC_MODE_GLOBAL: &str = "rbnne_global";
+pub const RBTDRC_MODE_ALLOWLIST: &str = "rbnne_allowlist";
+
+/// Label glued in front of an allowed domain for the label-boundary near-miss.
+const ZRBTDRC_GLUE_LABEL: &str = "not";
+
+// ── Nameplate policy ─────────────────────────────────────────
+
+/// One assignment line of the nameplate, kept verbatim for the report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_PolicyLine {
+    /// 1-based line number in rbrn.env.
+    pub number: usize,
+    pub text: String,
+    pub key: String,
+    /// Value with one layer of surrounding quotes removed.
+    pub value: String,
+}
+
+impl fmt::Display for rbtdrc_PolicyLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", RBTDRC_POLICY_FILE, self.number, self.text)
+    }
+}
+
+/// The assignment lines of one nameplate, in file order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Policy {
+    pub lines: Vec<rbtdrc_PolicyLine>,
+}
+
+impl rbtdrc_Policy {
+    /// Read the `KEY=value` lines of an rbrn.env body. Comments, blank lines
+    /// and anything that is not an upper-case assignment are passed over. A key
+    /// assigned twice is refused: a verdict must map back to exactly one line.
+    pub fn parse(content: &str) -> Result<rbtdrc_Policy, String> {
+        let mut lines: Vec<rbtdrc_PolicyLine> = Vec::new();
+        for (index, raw) in content.lines().enumerate() {
+            let text = raw.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let assignment = text.strip_prefix("export ").unwrap_or(text);
+            let Some((key, value)) = assignment.split_once('=') else {
+                continue;
+            };
+            let is_key = !key.is_empty()
+                && key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+            if !is_key {
+                continue;
+            }
+            if let Some(prior) = lines.iter().find(|l| l.key == key) {
+                return Err(format!(
+                    "{} is assigned on lines {} and {}",
+                    key,
+                    prior.number,
+                    index + 1
+                ));
+            }
+            lines.push(rbtdrc_PolicyLine {
+                number: index + 1,
+                text: text.to_string(),
+                key: key.to_string(),
+                value: zrbtdrc_unquote(value).to_string(),
+            });
+        }
+        Ok(rbtdrc_Policy { lines })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&rbtdrc_PolicyLine> {
+        self.lines.iter().find(|l| l.key == key)
+    }
+
+    pub fn require(&self, key: &str) -> Result<&rbtdrc_PolicyLine, String> {
+        self.get(key)
+            .ok_or_else(|| format!("{} is not set in {}", key, RBTDRC_POLICY_FILE))
+    }
+
+    /// The allowed domains, in nameplate order — empty when the key is absent.
+    pub fn allowed_domains(&self) -> Vec<String> {
+        zrbtdrc_words(self.get(RBTDRC_KEY_ALLOWED_DOMAINS))
+    }
+
+    /// The allowed CIDRs, parsed — empty when the key is absent.
+    pub fn allowed_cidrs(&self) -> Result<Vec<rbtdrc_Cidr>, String> {
+        let Some(list) = self.get(RBTDRC_KEY_ALLOWED_CIDRS) else {
+            return Ok(Vec::new());
+        };
+        zrbtdrc_words(Some(list))
+            .iter()
+            .map(|c| rbtdrc_Cidr::parse(c).map_err(|e| format!("{} ({})", e, list)))
+            .collect()
+    }
+
+    /// Where the uplink may carry traffic: None under global access (nothing
+    /// to hold a capture to), no CIDRs under disabled, the list under allowlist.
+    pub fn uplink_egress(&self) -> Result<Option<Vec<rbtdrc_Cidr>>, String> {
+        let mode = self.require(RBTDRC_KEY_ACCESS_MODE)?;
+        match mode.value.as_str() {
+            RBTDRC_MODE_GLOBAL => Ok(None),
+            RBTDRC_MODE_DISABLED => Ok(Some(Vec::new())),
+            RBTDRC_MODE_ALLOWLIST => self.allowed_cidrs().map(Some),
+            _ => Err(zrbtdrc_unknown_mode(mode)),
+        }
+    }
+}
+
+fn zrbtdrc_unquote(value: &str) -> &str {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote).and_then(|v| v.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    value
+}
+
+fn zrbtdrc_words(line: Option<&rbtdrc_PolicyLine>) -> Vec<String> {
+    line.map(|l| l.value.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+// ── CIDR arithmetic ──────────────────────────────────────────
+
+/// An IPv4 block, normalized to its network address as iptables does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct rbtdrc_Cidr {
+    pub network: Ipv4Addr,
+    pub prefix: u8,
+}
+
+impl rbtdrc_Cidr {
+    /// Parse `a.b.c.d/n`; a bare address reads as a /32.
+    pub fn parse(text: &str) -> Result<rbtdrc_Cidr, String> {
+        let (addr, prefix) = match text.split_once('/') {
+            Some((a, p)) => {
+                let prefix = p
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|p| *p <= 32)
+                    .ok_or_else(|| format!("CIDR '{}' has an invalid prefix length", text))?;
+                (a, prefix)
+            }
+            None => (text, 32),
+        };
+        let addr: Ipv4Addr = addr
+            .parse()
+            .map_err(|_| format!("CIDR '{}' has an invalid address", text))?;
+        let network = Ipv4Addr::from(u32::from(addr) & zrbtdrc_mask(prefix));
+        Ok(rbtdrc_Cidr { network, prefix })
+    }
+
+    /// A host inside the block: the address after the network address, or
+    /// the block itself for a /31 or /32.
+    pub fn host(&self) -> Ipv4Addr {
+        let offset = if self.prefix >= 31 { 0 } else { 1 };
+        Ipv4Addr::from(self.first() + offset)
+    }
+
+    pub fn first(&self) -> u32 {
+        u32::from(self.network)
+    }
+
+    pub fn last(&self) -> u32 {
+        self.first() | !zrbtdrc_mask(self.prefix)
+    }
+
+    pub fn contains(&self, addr: Ipv4Addr) -> bool {
+        u32::from(addr) & zrbtdrc_mask(self.prefix) == self.first()
+    }
+}
+
+impl fmt::Display for rbtdrc_Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix)
+    }
+}
+
+fn zrbtdrc_mask(prefix: u8) -> u32 {
+    if prefix == 0 { 0 } else { u32::MAX << (32 - u32::from(prefix)) }
+}
+
+// ── Probes ───────────────────────────────────────────────────
+
+/// What the nameplate says should happen to a probe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_Expect {
+    Allow,
+    Deny,
+}
+
+/// What a probe reaches for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum rbtdrc_Target {
+    /// Resolve a domain in the bottle — ifrit `dns-resolve` / `dns-resolve-block`.
+    Domain(String),
+    /// TCP 443 from the bottle — ifrit `tcp443-connect` / `tcp443-block`.
+    Address(Ipv4Addr),
+    /// TCP 443 from the bottle to a known host inside the block: the address
+    /// of an allowed domain, resolved on the sentry at run time.
+    HostWithin(rbtdrc_Cidr),
+    /// TCP connect from the host to a loopback port.
+    EntryPort(u16),
+}
+
+impl fmt::Display for rbtdrc_Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            rbtdrc_Target::Domain(d) => write!(f, "resolve {}", d),
+            rbtdrc_Target::Address(a) => write!(f, "tcp/443 {}", a),
+            rbtdrc_Target::HostWithin(c) => write!(f, "tcp/443 host within {}", c),
+            rbtdrc_Target::EntryPort(p) => write!(f, "host port {}", p),
+        }
+    }
+}
+
+/// The policy family a probe belongs to — one crucible case runs each family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrc_PolicyFamily {
+    Domains,
+    Cidrs,
+    Entry,
+}
+
+/// One synthesized containment probe and the nameplate line it tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrc_Probe {
+    pub target: rbtdrc_Target,
+    pub expect: rbtdrc_Expect,
+    /// Why this probe exists — "allowed domain", "near-miss: parent of ...".
+    pub rationale: String,
+    pub line: rbtdrc_PolicyLine,
+}
+
+impl rbtdrc_Probe {
+    pub fn family(&self) -> rbtdrc_PolicyFamily {
+        match self.target {
+            rbtdrc_Target::Domain(_) => rbtdrc_PolicyFamily::Domains,
+            rbtdrc_Target::Address(_) | rbtdrc_Target::HostWithin(_) => rbtdrc_PolicyFamily::Cidrs,
+            rbtdrc_Target::EntryPort(_) => rbtdrc_PolicyFamily::Entry,
+        }
+    }
+}
+
+fn zrbtdrc_probe(
+    target: rbtdrc_Target,
+    expect: rbtdrc_Expect,
+    rationale: String,
+    line: &rbtdrc_PolicyLine,
+) -> rbtdrc_Probe {
+    rbtdrc_Probe { target, expect, rationale, line: line.clone() }
+}
+
+fn zrbtdrc_unknown_mode(line: &rbtdrc_PolicyLine) -> String {
+    format!("{} has unknown mode '{}' ({})", line.key, line.value, line)
+}
+
+/// Synthesize every probe the nameplate's policy implies, domains first, then
+/// CIDRs, then entry ports. Errs on a missing mode, an unknown mode value, an
+/// unparseable CIDR, or an enabled entry without a valid workstation port.
+pub fn rbtdrc_policy_probes(policy: &rbtdrc_Policy) -> Result<Vec<rbtdrc_Probe>, String> {
+    let mut probes = Vec::new();
+    zrbtdrc_domain_probes(policy, &mut probes)?;
+    zrbtdrc_cidr_probes(policy, &mut probes)?;
+    zrbtdrc_entry_probes(policy, &mut probes)?;
+    Ok(probes)
+}
+
+/// True when `name` is an allowed domain or a subdomain of one — dnsmasq's
+/// `address=/<domain>/` matching.
+pub fn rbtdrc_domain_covered(name: &str, allowed: &[String]) -> bool {
+    allowed.iter().any(|a| {
+        name.eq_ignore_ascii_case(a)
+            || name.len() > a.len()
+                && name[name.len() - a.len()..].eq_ignore_ascii_case(a)
+                && name.as_bytes()[name.len() - a.len() - 1] == b'.'
+    })
+}
+
+/// Adversarial near-misses of one allowed domain, with the reason for each.
+fn zrbtdrc_domain_near_misses(domain: &str) -> Vec<(String, String)> {
+    let labels: Vec<&str> = domain.split('.').collect();
+    let mut misses = Vec::new();
+    if labels.len() >= 3 {
+        misses.push((labels[1..].join("."), format!("near-miss: parent of {}", domain)));
+    }
+    if labels.len() >= 2 {
+        let tld = labels[labels.len() - 1].to_ascii_lowercase();
+        let sibling = match tld.as_str() {
+            "org" => "com",
+            "com" => "net",
+            "net" => "org",
+            _ => "com",
+        };
+        let mut swapped = labels[..labels.len() - 1].to_vec();
+        swapped.push(sibling);
+        misses.push((swapped.join("."), format!("near-miss: {} under a sibling TLD", domain)));
+    }
+    misses.push((
+        format!("{}{}", ZRBTDRC_GLUE_LABEL, domain),
+        format!("near-miss: {} without a label boundary", domain),
+    ));
+    misses
+}
+
+fn zrbtdrc_domain_probes(
+    policy: &rbtdrc_Policy,
+    probes: &mut Vec<rbtdrc_Probe>,
+) -> Result<(), String> {
+    let mode = policy.require(RBTDRC_KEY_DNS_MODE)?;
+    let Some(list) = policy.get(RBTDRC_KEY_ALLOWED_DOMAINS) else {
+        return Ok(());
+    };
+    let allowed = policy.allowed_domains();
+    match mode.value.as_str() {
+        RBTDRC_MODE_ALLOWLIST | RBTDRC_MODE_GLOBAL => {
+            let mut offered: Vec<String> = Vec::new();
+            for domain in &allowed {
+                probes.push(zrbtdrc_probe(
+                    rbtdrc_Target::Domain(domain.clone()),
+                    rbtdrc_Expect::Allow,
+                    "allowed domain".to_string(),
+                    list,
+                ));
+                if mode.value != RBTDRC_MODE_ALLOWLIST {
+                    continue;
+                }
+                for (name, rationale) in zrbtdrc_domain_near_misses(domain) {
+                    if rbtdrc_domain_covered(&name, &allowed) || offered.contains(&name) {
+                        continue;
+                    }
+                    offered.push(name.clone());
+                    probes.push(zrbtdrc_probe(
+                        rbtdrc_Target::Domain(name),
+                        rbtdrc_Expect::Deny,
+                        rationale,
+                        list,
+                    ));
+                }
+            }
+        }
+        RBTDRC_MODE_DISABLED => {
+            for domain in &allowed {
+                probes.push(zrbtdrc_probe(
+                    rbtdrc_Target::Domain(domain.clone()),
+                    rbtdrc_Expect::Deny,
+                    "listed domain under disabled DNS".to_string(),
+                    mode,
+                ));
+            }
+        }
+        _ => return Err(zrbtdrc_unknown_mode(mode)),
+    }
+    Ok(())
+}
+
+fn zrbtdrc_cidr_probes(
+    policy: &rbtdrc_Policy,
+    probes: &mut Vec<rbtdrc_Probe>,
+) -> Result<(), String> {
+    let mode = policy.require(RBTDRC_KEY_ACCESS_MODE)?;
+    let Some(list) = policy.get(RBTDRC_KEY_ALLOWED_CIDRS) else {
+        return Ok(());
+    };
+    let cidrs = policy.allowed_cidrs()?;
+    match mode.value.as_str() {
+        RBTDRC_MODE_ALLOWLIST | RBTDRC_MODE_GLOBAL => {
+            for cidr in &cidrs {
+                probes.push(zrbtdrc_probe(
+                    rbtdrc_Target::HostWithin(*cidr),
+                    rbtdrc_Expect::Allow,
+                    "allowed CIDR".to_string(),
+                    list,
+                ));
+                if mode.value != RBTDRC_MODE_ALLOWLIST {
+                    continue;
+                }
+                let below = cidr.first().checked_sub(1).map(|a| (a, "below"));
+                let above = cidr.last().checked_add(1).map(|a| (a, "above"));
+                for (addr, side) in below.into_iter().chain(above) {
+                    let addr = Ipv4Addr::from(addr);
+                    if cidrs.iter().any(|c| c.contains(addr)) {
+                        continue;
+                    }
+                    probes.push(zrbtdrc_probe(
+                        rbtdrc_Target::Address(addr),
+                        rbtdrc_Expect::Deny,
+                        format!("near-miss: one address {} {}", side, cidr),
+                        list,
+                    ));
+                }
+            }
+        }
+        RBTDRC_MODE_DISABLED => {
+            for cidr in &cidrs {
+                probes.push(zrbtdrc_probe(
+                    rbtdrc_Target::Address(cidr.host()),
+                    rbtdrc_Expect::Deny,
+                    format!("listed CIDR {} under disabled access", cidr),
+                    mode,
+                ));
+            }
+        }
+        _ => return Err(zrbtdrc_unknown_mode(mode)),
+    }
+    Ok(())
+}
+
+fn zrbtdrc_entry_probes(
+    policy: &rbtdrc_Policy,
+    probes: &mut Vec<rbtdrc_Probe>,
+) -> Result<(), String> {
+    let mode = policy.require(RBTDRC_KEY_ENTRY_MODE)?;
+    let port_line = policy.get(RBTDRC_KEY_ENTRY_PORT);
+    let port = port_line
+        .map(|l| {
+            l.value
+                .parse::<u16>()
+                .ok()
+                .filter(|p| *p > 0)
+                .ok_or_else(|| format!("invalid workstation port ({})", l))
+        })
+        .transpose()?;
+    match (mode.value.as_str(), port_line.zip(port)) {
+        (RBTDRC_MODE_ENABLED, Some((line, port))) => {
+            probes.push(zrbtdrc_probe(
+                rbtdrc_Target::EntryPort(port),
+                rbtdrc_Expect::Allow,
+                "entry workstation port".to_string(),
+                line,
+            ));
+            let below = port.checked_sub(1).filter(|p| *p > 0).map(|p| (p, "below"));
+            let above = port.checked_add(1).map(|p| (p, "above"));
+            for (neighbor, side) in below.into_iter().chain(above) {
+                probes.push(zrbtdrc_probe(
+                    rbtdrc_Target::EntryPort(neighbor),
+                    rbtdrc_Expect::Deny,
+                    format!("near-miss: one port {} the entry port", side),
+                    line,
+                ));
+            }
+        }
+        (RBTDRC_MODE_ENABLED, None) => {
+            return Err(format!(
+                "{} is enabled but {} is not set",
+                RBTDRC_KEY_ENTRY_MODE, RBTDRC_KEY_ENTRY_PORT
+            ));
+        }
+        (RBTDRC_MODE_DISABLED, Some((_, port))) => {
+            probes.push(zrbtdrc_probe(
+                rbtdrc_Target::EntryPort(port),
+                rbtdrc_Expect::Deny,
+                "workstation port under disabled entry".to_string(),
+                mode,
+            ));
+        }
+        (RBTDRC_MODE_DISABLED, None) => {}
+        _ => return Err(zrbtdrc_unknown_mode(mode)),
+    }
+    Ok(())
+}
+
+/// One report row: the probe's verdict, expectation and target, then the
+/// nameplate line it tests.
+pub fn rbtdrc_policy_report_line(probe: &rbtdrc_Probe, verdict: &rbtdre_Verdict) -> String {
+    let (word, detail) = match verdict {
+        rbtdre_Verdict::Pass => ("PASS", String::new()),
+        rbtdre_Verdict::Fail(d) => ("FAIL", format!(" — {}", d.lines().next().unwrap_or(""))),
+        rbtdre_Verdict::Skip(d) => ("SKIP", format!(" — {}", d)),
+    };
+    let expect = match probe.expect {
+        rbtdrc_Expect::Allow => "allow",
+        rbtdrc_Expect::Deny => "deny",
+    };
+    format!(
+        "{} {} {} ({}){} ← {}",
+        word, expect, probe.target, probe.rationale, detail, probe.line
+    )
+}
+
+/// Ports in LISTEN state in a /proc/net/tcp (or tcp6) table — the columns are
+/// `sl local_address rem_address st …`, addresses as hex `ADDR:PORT`, and
+/// state 0A is LISTEN. Lines that do not parse (the header) are passed over.
+pub fn rbtdrc_listening_ports(table: &str) -> Vec<u16> {
+    let mut ports: Vec<u16> = table
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.get(3) != Some(&"0A") {
+                return None;
+            }
+            let (_, port) = fields.get(1)?.rsplit_once(':')?;
+            u16::from_str_radix(port, 16).ok()
+        })
+        .collect();
+    ports.sort_unstable();
+    ports.dedup();
+    ports
+}
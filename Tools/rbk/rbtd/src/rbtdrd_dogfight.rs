@@ -53,6 +53,7 @@ use crate::rbtdri_invocation::{
     rbtdri_gar_ref_fact, rbtdri_invoke_or_fail, rbtdri_ordain_capture_full, rbtdri_Context,
     RBTDRI_BURE_CONFIRM_KEY, RBTDRI_BURE_CONFIRM_SKIP,
 };
+use crate::rbtdrz_facts::{rbtdrz_DigestRef, rbtdrz_Fact};
 use crate::rbtdgc_consts::{RBTDGC_ABJURE_HALLMARK, RBTDGC_RBRV_FILE, RBTDGC_SUMMON_HALLMARK};
 
 /// Container runtime for the bare executability proof. Hardcoded to docker;
@@ -183,21 +184,13 @@ fn zrbtdrd_build_run_lifecycle_impl(ctx: &mut rbtdri_Context, dir: &Path) -> rbt
             image_ref
         ));
     }
-    let (ref_portion, digest) = match label.split_once("@sha256:") {
-        Some(pair) => pair,
-        None => {
-            return rbtdre_Verdict::Fail(format!(
-                "resolved-base: label not @sha256:-pinned: {}",
-                label
-            ))
+    let pinned = match rbtdrz_DigestRef::parse(&label) {
+        Ok(v) => v,
+        Err(e) => {
+            return rbtdre_Verdict::Fail(format!("resolved-base: label not digest-pinned: {}", e))
         }
     };
-    if digest.len() != 64 || !digest.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
-        return rbtdre_Verdict::Fail(format!(
-            "resolved-base: malformed sha256 digest in label: {}",
-            label
-        ));
-    }
+    let ref_portion = pinned.name();
     // The ref-portion must equal the committed RBRV_IMAGE_1_ORIGIN with its tag
     // stripped — the exact transform rbgjb03 applies (${origin%:*}). A mismatch is
     // a provenance lie: the label names a base the vessel never committed.
@@ -255,5 +248,7 @@ pub static RBTDRD_FIXTURE_DOGFIGHT: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRD_CASES_DOGFIGHT,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: Some(60), max_secs: Some(1800), invocations: None },
+    case_timeout_secs: Some(1800),
 };
@@ -18,51 +18,208 @@
 
 // RCG output discipline: all emission via rbtdrg_*! — no direct println!/eprintln!
 
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::{BufRead, BufReader, Write};
+use std::panic::AssertUnwindSafe;
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::mpsc;
+use std::sync::{mpsc, Once};
 use std::time::{Duration, Instant};
 
+// ── Panic boundary ─────────────────────────────────────────────
+//
+// A case function or lifecycle hook that panics (an `unwrap()` on a missing
+// fact, an index past a short stdout) must not unwind past the fixture's
+// teardown: that would abort the suite process with the crucible still
+// charged. Every case function, setup hook, and teardown hook therefore runs
+// under `rbtdre_catch_panic`, which turns the panic into an Err carrying the
+// panic message and source location. The engine then treats it as a Fail
+// verdict (case), a setup failure (setup), or a teardown warning (teardown),
+// and the suite stops or continues through its ordinary break-on-failure.
+//
+// The location is only visible to a panic hook, not to `catch_unwind`, so one
+// process-wide hook is installed on first use. It records the location only
+// while the panicking thread is inside a boundary (a thread-local flag, same
+// discipline as the rbtdri thread-locals) and suppresses the default stderr
+// print there — the engine reports the panic through rbtdrg instead. Outside
+// a boundary it defers to the prior hook, so test-harness and fatal panics
+// print exactly as before.
+
+static RBTDRE_PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    static RBTDRE_PANIC_GUARDED: Cell<bool> = const { Cell::new(false) };
+    static RBTDRE_PANIC_SITE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn zrbtdre_install_panic_hook() {
+    RBTDRE_PANIC_HOOK.call_once(|| {
+        let prior = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if RBTDRE_PANIC_GUARDED.with(|g| g.get()) {
+                let site = info
+                    .location()
+                    .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+                    .unwrap_or_else(|| "<unknown location>".to_string());
+                RBTDRE_PANIC_SITE.with(|s| *s.borrow_mut() = Some(site));
+            } else {
+                prior(info);
+            }
+        }));
+    });
+}
+
+/// Run `f` under the engine's panic boundary. Returns Ok with `f`'s value, or
+/// Err(`<what> panicked at <file:line:col>: <message>`) if it panicked. `f`
+/// runs on the calling thread, so the rbtdrc/rbtdri thread-locals stay visible
+/// to it; a `RefCell` borrow held across the panic is released by the unwind,
+/// so the context is intact for the teardown that follows.
+pub fn rbtdre_catch_panic<T>(what: &str, f: impl FnOnce() -> T) -> Result<T, String> {
+    zrbtdre_install_panic_hook();
+    let outer = RBTDRE_PANIC_GUARDED.with(|g| g.replace(true));
+    let outcome = std::panic::catch_unwind(AssertUnwindSafe(f));
+    RBTDRE_PANIC_GUARDED.with(|g| g.set(outer));
+    outcome.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "<non-string panic payload>".to_string());
+        let site = RBTDRE_PANIC_SITE
+            .with(|s| s.borrow_mut().take())
+            .unwrap_or_else(|| "<unknown location>".to_string());
+        format!("{} panicked at {}: {}", what, site, message)
+    })
+}
+
 // ── Heartbeat ──────────────────────────────────────────────────
 
 /// Interval between progress heartbeats for a blocking case.
 ///
 /// A case's function is an opaque blocking call — for ordain-bearing cases it
-/// waits out a multi-minute cloud build whose per-poll status is captured (and
-/// thus silenced) by the tabtarget invocation in rbtdri. Without a heartbeat
-/// the console shows nothing between a case's start and its terminal verdict.
+/// waits out a multi-minute cloud build whose per-poll status is captured (and,
+/// without `--verbose`, silenced) by the tabtarget invocation in rbtdri. Without
+/// a heartbeat the console shows nothing between a case's start and its
+/// terminal verdict.
 const RBTDRE_HEARTBEAT_INTERVAL_SECS: u64 = 30;
 
+/// How often the watchdog thread polls the interrupt source while a case runs.
+const RBTDRE_INTERRUPT_POLL_MILLIS: u64 = 200;
+
+/// Why the watchdog tripped a case's kill switch.
+enum zrbtdre_Stop {
+    Timeout,
+    Interrupt,
+}
+
 /// Run a case's function, emitting a periodic `running <case> (Ns elapsed)`
-/// heartbeat to the console while it blocks.
+/// heartbeat to the console while it blocks — naming the tabtarget launch in
+/// flight and the last line it printed, when there is one.
 ///
 /// The case function runs on the **calling** thread so the `rbtdrc` thread-local
 /// invocation context (established before the fixture runs) stays visible to it.
 /// A background thread emits the heartbeat and exits the instant the case
 /// returns — the dropped sender disconnects the channel, so short cases incur no
-/// added latency and only genuinely long waits ever tick.
-fn rbtdre_run_with_heartbeat(case: &rbtdre_Case, case_dir: &Path) -> rbtdre_Verdict {
+/// added latency and only genuinely long waits ever tick. The function runs
+/// under the panic boundary; a panic comes back as a Fail verdict.
+///
+/// The same background thread is the watchdog: the case runs with an rbtdri
+/// kill switch armed, which the watchdog trips when the budget (`budget_secs`)
+/// expires or an interrupt arrives (rbtdrt_interrupt) — killing the in-flight
+/// tabtarget process groups so the blocked case function returns. The case
+/// thread cannot be abandoned (it holds the thread-local context the teardown
+/// needs), so the verdict is always the case function's own return, replaced
+/// by a `TIMEOUT:` or `INTERRUPTED:` Fail when the switch fired.
+fn rbtdre_run_with_heartbeat(
+    case: &rbtdre_Case,
+    case_dir: &Path,
+    budget_secs: Option<u64>,
+) -> rbtdre_Verdict {
     let (tx, rx) = mpsc::channel::<()>();
     let name = case.name;
     let start = Instant::now();
     let interval = Duration::from_secs(RBTDRE_HEARTBEAT_INTERVAL_SECS);
-
-    let handle = std::thread::spawn(move || loop {
-        match rx.recv_timeout(interval) {
-            // Case finished (sender dropped) — stop heartbeating.
-            Ok(_) | Err(mpsc::RecvTimeoutError::Disconnected) => return,
+    let poll = Duration::from_millis(RBTDRE_INTERRUPT_POLL_MILLIS);
+    let interrupt = crate::rbtdrt_interrupt::rbtdrt_current();
+    let switch = crate::rbtdri_invocation::rbtdri_KillSwitch::new();
+    let watchdog = switch.clone();
+
+    let handle = std::thread::spawn(move || {
+        let mut next_beat = start + interval;
+        let deadline = budget_secs.map(|s| start + Duration::from_secs(s));
+        loop {
+            let wake = deadline.map_or(next_beat, |d| d.min(next_beat)).min(Instant::now() + poll);
+            match rx.recv_timeout(wake.saturating_duration_since(Instant::now())) {
+                // Case finished (sender dropped) — stop heartbeating.
+                Ok(_) | Err(mpsc::RecvTimeoutError::Disconnected) => return None,
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+            }
+            let now = Instant::now();
+            if interrupt.raised() {
+                let killed = watchdog.trip();
+                crate::rbtdrg_info_now!(
+                    "interrupt ({}) during {} — killed {} in-flight tabtarget process group(s)",
+                    interrupt.signal_name(), name, killed
+                );
+                return Some(zrbtdre_Stop::Interrupt);
+            }
+            if deadline.is_some_and(|d| now >= d) {
+                let killed = watchdog.trip();
+                crate::rbtdrg_info_now!(
+                    "timeout {} after {}s — killed {} in-flight tabtarget process group(s)",
+                    name, start.elapsed().as_secs(), killed
+                );
+                return Some(zrbtdre_Stop::Timeout);
+            }
             // Interval elapsed with the case still running — emit progress.
-            Err(mpsc::RecvTimeoutError::Timeout) => {
-                crate::rbtdrg_info_now!("running {} ({}s elapsed)", name, start.elapsed().as_secs());
+            if now >= next_beat {
+                let elapsed = start.elapsed().as_secs();
+                match watchdog.in_flight() {
+                    Some((colophon, last)) if last.is_empty() => crate::rbtdrg_info_now!(
+                        "running {} ({}s elapsed) — in flight: {}",
+                        name, elapsed, colophon
+                    ),
+                    Some((colophon, last)) => crate::rbtdrg_info_now!(
+                        "running {} ({}s elapsed) — in flight: {}, last: {}",
+                        name, elapsed, colophon, last
+                    ),
+                    None => crate::rbtdrg_info_now!("running {} ({}s elapsed)", name, elapsed),
+                }
+                next_beat += interval;
             }
         }
     });
 
-    let verdict = (case.func)(case_dir);
+    crate::rbtdri_invocation::rbtdri_arm_kill_switch(Some(switch));
+    let verdict = rbtdre_catch_panic("case", || (case.func)(case_dir))
+        .unwrap_or_else(rbtdre_Verdict::Fail);
+    crate::rbtdri_invocation::rbtdri_arm_kill_switch(None);
     drop(tx);
-    let _ = handle.join();
-    verdict
+    let stop = handle.join().ok().flatten();
+
+    let after = |verdict: rbtdre_Verdict| match verdict {
+        rbtdre_Verdict::Pass => "pass".to_string(),
+        rbtdre_Verdict::Fail(detail) => detail,
+        rbtdre_Verdict::Skip(reason) => format!("skip: {}", reason),
+    };
+    match stop {
+        Some(zrbtdre_Stop::Timeout) => rbtdre_Verdict::Fail(format!(
+            "{} case exceeded its {}s budget; in-flight tabtargets were killed\n\n\
+             case verdict after the kill:\n{}",
+            RBTDRE_WORD_TIMEOUT,
+            budget_secs.unwrap_or(0),
+            after(verdict)
+        )),
+        Some(zrbtdre_Stop::Interrupt) => rbtdre_Verdict::Fail(format!(
+            "{} run interrupted by {}; in-flight tabtargets were killed\n\n\
+             case verdict after the kill:\n{}",
+            RBTDRE_WORD_INTERRUPTED,
+            interrupt.signal_name(),
+            after(verdict)
+        )),
+        None => verdict,
+    }
 }
 
 // ── Verdict ────────────────────────────────────────────────────
@@ -113,31 +270,238 @@ pub fn rbtdre_resolve_fail_fast(
     }
 }
 
-/// The one flag the fixture/suite runners accept — requests keep-going mode,
-/// resolved against the fixture's disposition by `rbtdre_resolve_fail_fast`.
+/// Requests keep-going mode, resolved against the fixture's disposition by
+/// `rbtdre_resolve_fail_fast`. Accepted by the fixture and suite runners.
 pub const RBTDRE_FLAG_KEEP_GOING: &str = "--keep-going";
 
-/// Split raw runner CLI args into positionals and the keep-going flag.
-/// Position-independent, so `rbtd <fixture> --keep-going` and
-/// `rbtd --keep-going <fixture>` read identically. Any other `--`-prefixed
-/// token is rejected loud — a typo'd flag must never silently read as a
-/// fixture or suite name.
-pub fn rbtdre_parse_keep_going(args: &[String]) -> Result<(Vec<String>, bool), String> {
-    let mut positionals = Vec::new();
-    let mut keep_going = false;
-    for arg in args {
-        if arg == RBTDRE_FLAG_KEEP_GOING {
-            keep_going = true;
-        } else if arg.starts_with("--") {
-            return Err(format!(
-                "unknown flag '{}' (known: {})",
-                arg, RBTDRE_FLAG_KEEP_GOING
-            ));
+/// Resume a suite at its first non-green fixture from the prior run's
+/// checkpoint (see rbtdrb_checkpoint). Accepted by the suite runner only.
+pub const RBTDRE_FLAG_RESUME: &str = "--resume";
+
+/// Case-selection flags, each taking a value and repeatable. Accepted by the
+/// fixture runner only — see `rbtdre_Selection`.
+pub const RBTDRE_FLAG_ONLY: &str = "--only";
+pub const RBTDRE_FLAG_SKIP: &str = "--skip";
+pub const RBTDRE_FLAG_TAG: &str = "--tag";
+
+/// Print the execution plan instead of running (see rbtdrp_plan). Accepted by
+/// the fixture and suite runners.
+pub const RBTDRE_FLAG_PLAN: &str = "--plan";
+
+/// Echo every tabtarget's output lines to the console as they arrive (see
+/// `rbtdri_set_echo`). Accepted by the fixture and suite runners.
+pub const RBTDRE_FLAG_VERBOSE: &str = "--verbose";
+
+/// Launch every tabtarget with an allowlisted environment instead of theurge's
+/// inherited one, reporting the dropped variables (see `rbtdri_Hermetic`).
+/// `--hermetic-keep` takes a variable name, is repeatable, and adds it to the
+/// allowlist; it requires `--hermetic`. Accepted by the fixture and suite runners.
+pub const RBTDRE_FLAG_HERMETIC: &str = "--hermetic";
+pub const RBTDRE_FLAG_HERMETIC_KEEP: &str = "--hermetic-keep";
+
+/// Run each credless (reveille) fixture's tabtargets in a loopback-only
+/// network namespace, failing any case that reaches for the network (see
+/// `rbtdri_set_netns`). Accepted by the fixture and suite runners.
+pub const RBTDRE_FLAG_NETNS: &str = "--netns";
+
+/// Run the suite in a disposable git worktree at HEAD, leaving the operator's
+/// checkout untouched; commits the run makes are offered as a branch (see
+/// rbtdrw_worktree). Accepted by the suite runner only.
+pub const RBTDRE_FLAG_WORKTREE: &str = "--worktree";
+
+/// Record every tabtarget launch into a cassette directory, or replay a
+/// recorded one without spawning (see rbtdry_cassette). Each takes the
+/// directory as its value. Accepted by the fixture runner only.
+pub const RBTDRE_FLAG_RECORD: &str = "--record";
+pub const RBTDRE_FLAG_REPLAY: &str = "--replay";
+
+/// Rewrite the committed sentry config golden from the live sentry instead of
+/// comparing against it (see rbtdrc_golden). Accepted by the fixture and
+/// single runners.
+pub const RBTDRE_FLAG_BLESS: &str = "--bless";
+
+/// The flag set each runner accepts, handed to `rbtdre_parse_runner_args`.
+pub const RBTDRE_FIXTURE_FLAGS: &[&str] = &[
+    RBTDRE_FLAG_KEEP_GOING,
+    RBTDRE_FLAG_ONLY,
+    RBTDRE_FLAG_SKIP,
+    RBTDRE_FLAG_TAG,
+    RBTDRE_FLAG_PLAN,
+    RBTDRE_FLAG_VERBOSE,
+    RBTDRE_FLAG_HERMETIC,
+    RBTDRE_FLAG_HERMETIC_KEEP,
+    RBTDRE_FLAG_NETNS,
+    RBTDRE_FLAG_RECORD,
+    RBTDRE_FLAG_REPLAY,
+    RBTDRE_FLAG_BLESS,
+];
+pub const RBTDRE_SUITE_FLAGS: &[&str] = &[
+    RBTDRE_FLAG_KEEP_GOING,
+    RBTDRE_FLAG_RESUME,
+    RBTDRE_FLAG_PLAN,
+    RBTDRE_FLAG_VERBOSE,
+    RBTDRE_FLAG_HERMETIC,
+    RBTDRE_FLAG_HERMETIC_KEEP,
+    RBTDRE_FLAG_NETNS,
+    RBTDRE_FLAG_WORKTREE,
+];
+
+/// Runner CLI args after flag extraction.
+#[derive(Debug, Default)]
+pub struct rbtdre_RunnerArgs {
+    pub positionals: Vec<String>,
+    pub keep_going: bool,
+    pub resume: bool,
+    pub plan: bool,
+    pub verbose: bool,
+    pub hermetic: bool,
+    pub hermetic_keep: Vec<String>,
+    pub netns: bool,
+    pub worktree: bool,
+    pub record: Option<String>,
+    pub replay: Option<String>,
+    pub bless: bool,
+    pub selection: rbtdre_Selection,
+}
+
+/// Split raw runner CLI args into positionals and flags, accepting only the
+/// flags in `known`. Position-independent, so `rbtd <fixture> --keep-going`
+/// and `rbtd --keep-going <fixture>` read identically. Any other
+/// `--`-prefixed token is rejected loud — a typo'd flag must never silently
+/// read as a fixture or suite name.
+pub fn rbtdre_parse_runner_args(
+    args: &[String],
+    known: &[&str],
+) -> Result<rbtdre_RunnerArgs, String> {
+    let mut parsed = rbtdre_RunnerArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg.starts_with("--") && !known.contains(&arg.as_str()) {
+            return Err(format!("unknown flag '{}' (known: {})", arg, known.join(", ")));
+        }
+        let mut value = || match args.next() {
+            Some(v) if !v.starts_with("--") => Ok(v.clone()),
+            _ => Err(format!("flag '{}' needs a value", arg)),
+        };
+        match arg.as_str() {
+            RBTDRE_FLAG_KEEP_GOING => parsed.keep_going = true,
+            RBTDRE_FLAG_RESUME => parsed.resume = true,
+            RBTDRE_FLAG_PLAN => parsed.plan = true,
+            RBTDRE_FLAG_VERBOSE => parsed.verbose = true,
+            RBTDRE_FLAG_HERMETIC => parsed.hermetic = true,
+            RBTDRE_FLAG_HERMETIC_KEEP => parsed.hermetic_keep.push(value()?),
+            RBTDRE_FLAG_NETNS => parsed.netns = true,
+            RBTDRE_FLAG_WORKTREE => parsed.worktree = true,
+            RBTDRE_FLAG_ONLY => parsed.selection.only.push(value()?),
+            RBTDRE_FLAG_SKIP => parsed.selection.skip.push(value()?),
+            RBTDRE_FLAG_TAG => parsed.selection.tags.push(value()?),
+            RBTDRE_FLAG_RECORD => parsed.record = Some(value()?),
+            RBTDRE_FLAG_REPLAY => parsed.replay = Some(value()?),
+            RBTDRE_FLAG_BLESS => parsed.bless = true,
+            _ => parsed.positionals.push(arg.clone()),
+        }
+    }
+    Ok(parsed)
+}
+
+// ── Case selection ─────────────────────────────────────────────
+
+/// Fixture-runner case selection: the in-between of `rbtd <fixture>` (every
+/// case) and `rbtd single` (one case, no charge/quench) — charge once, run the
+/// selected cases, quench. Empty selects every case.
+///
+/// A case is selected when it matches some `--only` pattern (if any are
+/// given), carries some `--tag` tag (if any are given), and matches no
+/// `--skip` pattern. Patterns are globs (`*`, `?`) over the case name, with or
+/// without its module prefix: `sortie_dns_*` and `rbtdrc_sortie_dns_*` both
+/// select `rbtdrc_sortie_dns_rebinding`.
+#[derive(Debug, Default, Clone)]
+pub struct rbtdre_Selection {
+    pub only: Vec<String>,
+    pub skip: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl rbtdre_Selection {
+    /// The empty selection — every case.
+    pub const ALL: rbtdre_Selection =
+        rbtdre_Selection { only: Vec::new(), skip: Vec::new(), tags: Vec::new() };
+
+    pub fn is_all(&self) -> bool {
+        self.only.is_empty() && self.skip.is_empty() && self.tags.is_empty()
+    }
+
+    pub fn selects(&self, case: &rbtdre_Case) -> bool {
+        let named = |pattern: &String| {
+            rbtdre_glob_match(pattern, case.name)
+                || case
+                    .name
+                    .split_once('_')
+                    .is_some_and(|(_, bare)| rbtdre_glob_match(pattern, bare))
+        };
+        (self.only.is_empty() || self.only.iter().any(named))
+            && (self.tags.is_empty() || self.tags.iter().any(|t| case.tags.contains(&t.as_str())))
+            && !self.skip.iter().any(named)
+    }
+}
+
+/// Whole-string glob match: `*` matches any run (including empty), `?` any
+/// one character, everything else literally.
+pub fn rbtdre_glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0usize, 0usize);
+    // Backtrack point: the last `*` seen and the text position it resumes at.
+    let mut star: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
         } else {
-            positionals.push(arg.clone());
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+/// Apply a selection to a fixture's cases, preserving case order. Like
+/// `rbtdre_resolve_fail_fast`, this resolves policy before setup, so a refused
+/// or empty selection costs no charge.
+///
+/// For a `StateProgressing` fixture, case N establishes case N+1's
+/// precondition, so only a leading run of cases is coherent: a selection that
+/// drops a case yet runs a later one is refused.
+pub fn rbtdre_select_cases(
+    cases: &[rbtdre_Case],
+    disposition: rbtdre_Disposition,
+    selection: &rbtdre_Selection,
+) -> Result<Vec<rbtdre_Case>, String> {
+    let selected: Vec<rbtdre_Case> =
+        cases.iter().filter(|c| selection.selects(c)).copied().collect();
+    if selected.is_empty() {
+        return Err("rbtd: case selection matches no cases".to_string());
+    }
+    if disposition == rbtdre_Disposition::StateProgressing {
+        if let Some(gap) = cases.iter().position(|c| !selection.selects(c)) {
+            if let Some(later) = cases[gap..].iter().find(|c| selection.selects(c)) {
+                return Err(format!(
+                    "rbtd: case selection refused for StateProgressing fixture — \
+                     it drops '{}' but runs the later '{}', whose precondition the \
+                     dropped case establishes. Select a leading run of cases, or run \
+                     individual cases via the SingleCase tabtarget.",
+                    cases[gap].name, later.name
+                ));
+            }
         }
     }
-    Ok((positionals, keep_going))
+    Ok(selected)
 }
 
 // ── Working-tree hygiene ───────────────────────────────────────
@@ -174,6 +538,210 @@ pub fn rbtdre_tree_clean(root: &Path) -> Result<(), String> {
     }
 }
 
+/// HEAD commit of the repository rooted at `root` (`git rev-parse HEAD`).
+/// Paired with `rbtdre_tree_clean`, this pins "the tree" exactly: a clean tree
+/// at a known commit — what the suite checkpoint records and a resume checks.
+pub fn rbtdre_tree_commit(root: &Path) -> Result<String, String> {
+    match std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(root)
+        .output()
+    {
+        Ok(out) if out.status.success() => {
+            Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+        }
+        Ok(out) => Err(format!(
+            "git rev-parse HEAD failed (exit {}): {}",
+            out.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&out.stderr).trim()
+        )),
+        Err(e) => Err(format!("git rev-parse invocation failed: {}", e)),
+    }
+}
+
+/// One path's state in a `rbtdre_TreeSnapshot`: its porcelain status code
+/// (blank for a clean tracked file) and what is on disk — a blob hash for a
+/// file, `link:<target>` for a symlink, `dir` for a directory entry, or None
+/// when nothing exists at the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdre_TreeEntry {
+    pub status: String,
+    pub content: Option<String>,
+}
+
+/// The tree as a read-only fixture must leave it: HEAD, plus an entry for every
+/// tracked file and for every path `git status` reports — untracked files one by
+/// one, and ignored files that sit under a tracked kit directory (any directory
+/// below the repo root holding a tracked file). Ignored paths elsewhere — the
+/// root's operator scratch files — are not the tree and are left out. Content
+/// hashes, not just status codes, are what make an edit to an already-dirty or
+/// already-ignored file visible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdre_TreeSnapshot {
+    pub head: String,
+    pub entries: BTreeMap<String, rbtdre_TreeEntry>,
+}
+
+fn zrbtdre_git_stdout(root: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let out = std::process::Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .map_err(|e| format!("git {} exec failed: {}", args[0], e))?;
+    if !out.status.success() {
+        return Err(format!(
+            "git {} exited {}: {}",
+            args.join(" "),
+            out.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&out.stderr).trim()
+        ));
+    }
+    Ok(out.stdout)
+}
+
+/// Working-tree blob hashes for `paths`, in order, from one
+/// `git hash-object --stdin-paths` so a large tracked set costs a single spawn.
+fn zrbtdre_hash_paths(root: &Path, paths: &[&str]) -> Result<Vec<String>, String> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut child = std::process::Command::new("git")
+        .args(["hash-object", "--stdin-paths"])
+        .current_dir(root)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("git hash-object exec failed: {}", e))?;
+    let input = paths.join("\n") + "\n";
+    let mut stdin = child.stdin.take().ok_or("git hash-object stdin unavailable")?;
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let out = child
+        .wait_with_output()
+        .map_err(|e| format!("git hash-object wait failed: {}", e))?;
+    let _ = writer.join();
+    if !out.status.success() {
+        return Err(format!(
+            "git hash-object exited {}: {}",
+            out.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&out.stderr).trim()
+        ));
+    }
+    let hashes: Vec<String> =
+        String::from_utf8_lossy(&out.stdout).lines().map(str::to_string).collect();
+    if hashes.len() != paths.len() {
+        return Err(format!(
+            "git hash-object returned {} hash(es) for {} path(s)",
+            hashes.len(),
+            paths.len()
+        ));
+    }
+    Ok(hashes)
+}
+
+/// Snapshot the tree rooted at `root` for the read-only guard — see
+/// `rbtdre_TreeSnapshot` for what is covered. Status comes from
+/// `git status --porcelain -z --untracked-files=all --ignored=matching`, so a
+/// new ignored directory is one entry rather than its whole contents.
+pub fn rbtdre_tree_snapshot(root: &Path) -> Result<rbtdre_TreeSnapshot, String> {
+    let head = rbtdre_tree_commit(root)?;
+
+    let tracked_raw = zrbtdre_git_stdout(root, &["ls-files", "-z"])?;
+    let tracked_text = String::from_utf8_lossy(&tracked_raw);
+    let tracked: Vec<&str> = tracked_text.split('\0').filter(|p| !p.is_empty()).collect();
+    let mut kit_dirs: BTreeSet<&str> = BTreeSet::new();
+    for path in &tracked {
+        let mut dir = *path;
+        while let Some(cut) = dir.rfind('/') {
+            dir = &dir[..cut];
+            if !kit_dirs.insert(dir) {
+                break;
+            }
+        }
+    }
+
+    let status_raw = zrbtdre_git_stdout(
+        root,
+        &["status", "--porcelain", "-z", "--untracked-files=all", "--ignored=matching"],
+    )?;
+    let status_text = String::from_utf8_lossy(&status_raw);
+    let mut statuses: BTreeMap<&str, &str> = BTreeMap::new();
+    let mut fields = status_text.split('\0').filter(|f| !f.is_empty());
+    while let Some(field) = fields.next() {
+        if field.len() < 4 {
+            return Err(format!("git status: unparseable porcelain entry {:?}", field));
+        }
+        let (code, path) = (&field[..2], &field[3..]);
+        if code.contains('R') || code.contains('C') {
+            // A rename or copy carries its source path as the next field.
+            fields.next();
+        }
+        if code == "!!" {
+            let parent = path.trim_end_matches('/').rsplit_once('/').map(|(d, _)| d);
+            if !parent.is_some_and(|d| kit_dirs.contains(d)) {
+                continue;
+            }
+        }
+        statuses.insert(path, code);
+    }
+
+    let mut paths: BTreeSet<&str> = tracked.iter().copied().collect();
+    paths.extend(statuses.keys().copied());
+    let mut entries: BTreeMap<String, rbtdre_TreeEntry> = BTreeMap::new();
+    let mut files: Vec<&str> = Vec::new();
+    for path in paths {
+        let content = match std::fs::symlink_metadata(root.join(path)) {
+            Err(_) => None,
+            Ok(meta) if meta.file_type().is_symlink() => {
+                let target = std::fs::read_link(root.join(path))
+                    .map(|t| t.display().to_string())
+                    .unwrap_or_default();
+                Some(format!("link:{}", target))
+            }
+            Ok(meta) if meta.is_dir() => Some("dir".to_string()),
+            Ok(_) => {
+                files.push(path);
+                None
+            }
+        };
+        let status = statuses.get(path).copied().unwrap_or("").to_string();
+        entries.insert(path.to_string(), rbtdre_TreeEntry { status, content });
+    }
+    for (path, hash) in files.iter().zip(zrbtdre_hash_paths(root, &files)?) {
+        if let Some(entry) = entries.get_mut(*path) {
+            entry.content = Some(hash);
+        }
+    }
+    Ok(rbtdre_TreeSnapshot { head, entries })
+}
+
+/// Every difference between two snapshots, one line per mutated path in path
+/// order — `created`, `deleted`, or `modified` (content or status changed) —
+/// preceded by a `HEAD moved` line when a commit landed in between. Empty when
+/// the tree was left alone.
+pub fn rbtdre_tree_mutations(
+    before: &rbtdre_TreeSnapshot,
+    after: &rbtdre_TreeSnapshot,
+) -> Vec<String> {
+    let mut mutations = Vec::new();
+    if before.head != after.head {
+        mutations.push(format!("HEAD moved: {} → {}", before.head, after.head));
+    }
+    let paths: BTreeSet<&String> = before.entries.keys().chain(after.entries.keys()).collect();
+    for path in paths {
+        let was = before.entries.get(path).and_then(|e| e.content.as_ref());
+        let now = after.entries.get(path).and_then(|e| e.content.as_ref());
+        let kind = match (was, now) {
+            (None, Some(_)) => "created",
+            (Some(_), None) => "deleted",
+            _ if before.entries.get(path) != after.entries.get(path) => "modified",
+            _ => continue,
+        };
+        mutations.push(format!("{}: {}", kind, path));
+    }
+    mutations
+}
+
 // ── Fixture config-evolution console ───────────────────────────
 //
 // The home for the domain-intimate actions a fixture performs to evolve and
@@ -200,11 +768,16 @@ pub fn rbtdre_tree_clean(root: &Path) -> Result<(), String> {
 /// terminal step with no consumers) and is a clean no-op, not an error. An
 /// empty `paths` is itself a no-op — guarding it is essential, since a bare
 /// `git status --porcelain --` with no pathspec would survey the WHOLE tree
-/// and defeat the scoping.
+/// and defeat the scoping. A replay commits nothing either: it runs on the
+/// operator's working tree, unguarded, and its tabtargets changed no files.
 fn rbtdre_commit_paths(root: &Path, paths: &[String], message: &str) -> Result<(), String> {
     if paths.is_empty() {
         return Ok(());
     }
+    if crate::rbtdri_invocation::rbtdri_replaying() {
+        crate::rbtdrg_info_now!("rbtd: replay — commit withheld: {}", message);
+        return Ok(());
+    }
 
     let mut status_args: Vec<&str> = vec!["status", "--porcelain", "--"];
     status_args.extend(paths.iter().map(String::as_str));
@@ -422,10 +995,17 @@ pub fn rbtdre_config_zero(file: &Path, field: &str) -> Result<(), String> {
 //     signal, printed for the operator, not a failure.
 // Every violation prints declared-vs-observed so an adjustment is a one-line
 // edit at the declaration site.
+//
+// The same schedule applies per case (`rbtdre_Case::tariff`), measured around
+// the case body alone: its wall-clock and the tally delta across it. Every
+// passing case prints its footprint as `tariff case <fixture>/<case>: ...` —
+// the `case` word keeps it a non-match for the per-fixture line's parse — and
+// a too-fast case turns its Pass into a Fail. Failed and skipped cases print
+// no case line: their footprint is not a healthy green's.
 
 /// A fixture's declared cost expectation. See the section header for the
 /// asymmetry between the vacuity floor (min) and the two drift warnings.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct rbtdre_Tariff {
     pub min_secs: Option<u64>,
     pub max_secs: Option<u64>,
@@ -448,6 +1028,9 @@ impl rbtdre_Tariff {
 /// grep-clean repo-wide.
 pub const RBTDRE_TARIFF_TOKEN: &str = "tariff";
 
+/// Word following the grep token on a per-case tariff line.
+pub const RBTDRE_TARIFF_CASE_WORD: &str = "case";
+
 /// Outcome of evaluating a fixture's observed footprint against its declared
 /// tariff. Produced by the pure `rbtdre_evaluate_tariff` seam from (declared,
 /// observed) alone — no I/O — so the three violation kinds are unit-testable
@@ -583,6 +1166,35 @@ pub fn rbtdre_print_tariff_table(rows: &[rbtdre_TariffRow]) {
     }
 }
 
+/// Per-case tariff seam: print a passing case's footprint under the grep token
+/// and evaluate it against the case's declaration. A too-fast case becomes a
+/// Fail (the vacuity floor, as for a fixture); too-slow and count-drift only
+/// print their advisories. Failed and skipped verdicts pass through unprinted.
+pub fn rbtdre_case_tariff(
+    fixture: &str,
+    case: &rbtdre_Case,
+    verdict: rbtdre_Verdict,
+    elapsed: Duration,
+    invocations: u32,
+    colors: &rbtdre_Colors,
+) -> rbtdre_Verdict {
+    if !matches!(verdict, rbtdre_Verdict::Pass) {
+        return verdict;
+    }
+    let report = rbtdre_evaluate_tariff(&case.tariff, elapsed.as_secs(), invocations);
+    let label = format!("{} {}/{}", RBTDRE_TARIFF_CASE_WORD, fixture, case.name);
+    rbtdre_print_tariff(&label, &case.tariff, &report, colors);
+    if report.too_fast {
+        return rbtdre_Verdict::Fail(format!(
+            "{} tariff too-fast — elapsed {}s below declared min {} (vacuous green)",
+            case.name,
+            report.elapsed_secs,
+            rbtdre_fmt_secs(case.tariff.min_secs),
+        ));
+    }
+    verdict
+}
+
 // ── Colophon census (negative direction) ────────────────────────
 //
 // The positive direction (an invoked colophon absent from the fixture's
@@ -598,19 +1210,19 @@ pub const RBTDRE_CENSUS_TOKEN: &str = "census";
 /// Evaluate the declared/used colophon census for a just-completed fixture run
 /// and print a per-colophon usage report under the grep token. Reads the
 /// rbtdri census state armed for this thread's run (`None` — no manifest
-/// entry — is vacuously true, census tracking was never engaged). Returns
-/// false if at least one REQUIRED colophon went unused; the caller folds
-/// that into the fixture's failure count. Permitted-tier usage is reported
-/// as advisory lines only — an unused permitted colophon never affects the
-/// verdict, since it is conditional-by-design and may legitimately go
-/// unreached on a healthy run.
-pub fn rbtdre_check_census(fixture_name: &str, colors: &rbtdre_Colors) -> bool {
+/// entry — is vacuously clean, census tracking was never engaged). Returns
+/// the REQUIRED colophons that went unused — empty when the census is clean;
+/// the caller folds a non-empty return into the fixture's failure count.
+/// Permitted-tier usage is reported as advisory lines only — an unused
+/// permitted colophon never affects the verdict, since it is
+/// conditional-by-design and may legitimately go unreached on a healthy run.
+pub fn rbtdre_check_census(fixture_name: &str, colors: &rbtdre_Colors) -> Vec<&'static str> {
     let declared = match crate::rbtdri_invocation::rbtdri_census_declared() {
         Some(d) => d,
-        None => return true,
+        None => return Vec::new(),
     };
     let used = crate::rbtdri_invocation::rbtdri_census_used();
-    let mut all_used = true;
+    let mut unused = Vec::new();
     for colophon in declared {
         if used.contains(*colophon) {
             crate::rbtdrg_info_now!(
@@ -621,7 +1233,7 @@ pub fn rbtdre_check_census(fixture_name: &str, colors: &rbtdre_Colors) -> bool {
                 "{}{}{} {} census — colophon '{}' declared but never invoked",
                 colors.red, RBTDRE_WORD_FAILED, colors.reset, fixture_name, colophon
             );
-            all_used = false;
+            unused.push(*colophon);
         }
     }
     for colophon in crate::rbtdri_invocation::rbtdri_census_permitted() {
@@ -631,16 +1243,23 @@ pub fn rbtdre_check_census(fixture_name: &str, colors: &rbtdre_Colors) -> bool {
             );
         }
     }
-    all_used
+    unused
 }
 
 // ── Case and Fixture ───────────────────────────────────────────
 
 /// A named test case with a function that receives its isolated temp directory.
 /// The `name` field holds the raw stringified function name (from the `case!` macro).
+/// `tags` are free-form selectors for the fixture runner's `--tag` filter
+/// (see `rbtdre_Selection`); most cases carry none. `tariff` is the case's own
+/// cost declaration, checked against the case's footprint when it passes — a
+/// slow case inside a big fixture is otherwise invisible in the fixture's sum.
+#[derive(Clone, Copy)]
 pub struct rbtdre_Case {
     pub name: &'static str,
     pub func: fn(&Path) -> rbtdre_Verdict,
+    pub tags: &'static [&'static str],
+    pub tariff: rbtdre_Tariff,
 }
 
 /// A complete fixture definition — name, disposition, optional setup/teardown
@@ -668,11 +1287,21 @@ pub struct rbtdre_Fixture {
     /// reveille-suite members; a guarded fixture's cases carry no tweaks of their
     /// own (in the reveille tier the slot belongs to the guard).
     pub credless: bool,
+    /// Read-only declaration: the fixture inspects the tree and never changes it.
+    /// When true, the engine snapshots the tree before setup and after teardown
+    /// (`rbtdre_tree_snapshot`) and fails the fixture with every mutated path —
+    /// a read-only fixture has no business calling the config-evolution verbs.
+    pub read_only: bool,
     /// Declared cost expectation — the wall-clock and invocation footprint a
     /// healthy green is checked against as the fixture completes. `UNCHECKED`
     /// (the undeclared default) leaves the fixture entirely unevaluated. See
     /// `rbtdre_Tariff`.
     pub tariff: rbtdre_Tariff,
+    /// Per-case wall-clock budget in seconds. When a case outlives it, the
+    /// engine kills the case's in-flight tabtargets and records a `TIMEOUT:`
+    /// failure; teardown runs as usual. None defers to the hosting suite's
+    /// `case_timeout_secs`; a fixture that declares one wins over the suite.
+    pub case_timeout_secs: Option<u64>,
 }
 
 /// A named suite — an ordered set of fixtures run as one sequential batch.
@@ -685,16 +1314,38 @@ pub struct rbtdre_Fixture {
 pub struct rbtdre_Suite {
     pub name: &'static str,
     pub fixtures: &'static [&'static rbtdre_Fixture],
+    /// Default per-case wall-clock budget for members that declare none —
+    /// reveille-tier suites run tight, cloud-build ladders generous. None runs
+    /// undeclared members unbudgeted.
+    pub case_timeout_secs: Option<u64>,
 }
 
 /// Case registration macro. Derives case name from function name via `stringify!`.
 /// Compiler enforces uniqueness — duplicate function names won't compile.
+/// `case!(func, tags: ["dns", "sortie"])` registers the case with tags, and
+/// `case!(func, tariff: rbtdre_Tariff { .. })` with a per-case tariff; both may
+/// be given, tags first.
 #[macro_export]
 macro_rules! case {
     ($func:path) => {
+        $crate::case!($func, tags: [], tariff: $crate::rbtdre_engine::rbtdre_Tariff::UNCHECKED)
+    };
+    ($func:path, tags: [$($tag:literal),* $(,)?]) => {
+        $crate::case!(
+            $func,
+            tags: [$($tag),*],
+            tariff: $crate::rbtdre_engine::rbtdre_Tariff::UNCHECKED
+        )
+    };
+    ($func:path, tariff: $tariff:expr) => {
+        $crate::case!($func, tags: [], tariff: $tariff)
+    };
+    ($func:path, tags: [$($tag:literal),* $(,)?], tariff: $tariff:expr) => {
         $crate::rbtdre_engine::rbtdre_Case {
             name: stringify!($func),
             func: $func,
+            tags: &[$($tag),*],
+            tariff: $tariff,
         }
     };
 }
@@ -740,6 +1391,52 @@ pub const RBTDRE_TRACE_FILE: &str = "trace.txt";
 pub const RBTDRE_WORD_PASSED: &str = "PASSED:";
 pub const RBTDRE_WORD_FAILED: &str = "FAILED:";
 pub const RBTDRE_WORD_SKIPPED: &str = "SKIPPED:";
+/// Section word opening every fixture run on the operator stream. Verdict
+/// lines name the bare case, so a log reader (dowse) attributes them to the
+/// fixture of the section they fall in.
+pub const RBTDRE_WORD_FIXTURE: &str = "FIXTURE:";
+
+/// Verdict word opening the detail of a case killed by its wall-clock budget.
+/// The case is a Fail like any other — same counts, same fail-fast — but the
+/// trace and the report lead with this word so a hang is never mistaken for an
+/// ordinary assertion failure.
+pub const RBTDRE_WORD_TIMEOUT: &str = "TIMEOUT:";
+/// Verdict word opening the detail of a case cut short by SIGINT/SIGTERM
+/// (rbtdrt_interrupt). A Fail like a timeout, so the counts, the report, and
+/// the suite checkpoint all treat the interrupted case as not green.
+pub const RBTDRE_WORD_INTERRUPTED: &str = "INTERRUPTED:";
+/// Verdict word opening the detail of a case whose tabtargets reached for the
+/// network under isolation (rbtdri --netns). A Fail whatever the case returned.
+pub const RBTDRE_WORD_HERMETICITY: &str = "HERMETICITY:";
+/// Verdict word opening the report of a read-only fixture that left the tree
+/// changed (`rbtdre_Fixture::read_only`). Fails the fixture whatever its cases
+/// returned.
+pub const RBTDRE_WORD_MUTATED: &str = "MUTATED:";
+/// Synthetic case recording network reaches from a fixture's setup or
+/// teardown, which belong to no case of its own.
+pub const RBTDRE_GUARD_NETNS: &str = "netns-guard";
+/// Synthetic case recording a read-only fixture's tree mutations.
+pub const RBTDRE_GUARD_READ_ONLY: &str = "read-only-guard";
+
+/// Fold the network-isolation violations recorded since the last drain into a
+/// case's verdict: any violation fails the case, keeping its own verdict below.
+fn rbtdre_hermeticity_verdict(verdict: rbtdre_Verdict) -> rbtdre_Verdict {
+    let violations = crate::rbtdri_invocation::rbtdri_netns_take_violations();
+    if violations.is_empty() {
+        return verdict;
+    }
+    let own = match verdict {
+        rbtdre_Verdict::Pass => "pass".to_string(),
+        rbtdre_Verdict::Fail(detail) => detail,
+        rbtdre_Verdict::Skip(reason) => format!("skip: {}", reason),
+    };
+    rbtdre_Verdict::Fail(format!(
+        "{} {}\n\ncase verdict under isolation:\n{}",
+        RBTDRE_WORD_HERMETICITY,
+        violations.join("\n"),
+        own
+    ))
+}
 
 /// Write verdict and detail to a trace file in the case temp directory.
 fn rbtdre_write_trace(case_dir: &Path, display_name: &str, verdict: &rbtdre_Verdict) {
@@ -757,6 +1454,28 @@ fn rbtdre_write_trace(case_dir: &Path, display_name: &str, verdict: &rbtdre_Verd
 
 // ── Dispatch ───────────────────────────────────────────────────
 
+/// One case's concluded verdict and wall-clock, kept in run order on the
+/// `rbtdre_RunResult` so the run report can render every case without
+/// re-reading the per-case trace files.
+pub struct rbtdre_CaseRecord {
+    pub name: &'static str,
+    pub verdict: rbtdre_Verdict,
+    pub elapsed: Duration,
+    /// Tabtarget invocations made by the case body (the tally delta across it).
+    pub invocations: u32,
+}
+
+/// The failing case record a fixture-level guard adds to the run's cases, so
+/// the report carries the failure it counted: `word` then every finding.
+fn rbtdre_guard_record(name: &'static str, word: &str, findings: &[String]) -> rbtdre_CaseRecord {
+    rbtdre_CaseRecord {
+        name,
+        verdict: rbtdre_Verdict::Fail(format!("{} {}", word, findings.join("\n"))),
+        elapsed: Duration::ZERO,
+        invocations: 0,
+    }
+}
+
 /// Aggregate results from running a fixture's cases.
 pub struct rbtdre_RunResult {
     pub passed: usize,
@@ -770,35 +1489,74 @@ pub struct rbtdre_RunResult {
     /// Observed tabtarget-invocation count over the whole fixture, read from the
     /// invocation-layer tally. Zero on the case-only runners.
     pub invocations: u32,
+    /// Every case that ran, in run order. Cases never reached (fail-fast
+    /// stopped before them) are absent, not recorded as skipped. A failed
+    /// fixture-level guard follows them as a synthetic case (RBTDRE_GUARD_*).
+    pub cases: Vec<rbtdre_CaseRecord>,
+    /// The fixture's tariff evaluation. None on the case-only runners.
+    pub tariff: Option<rbtdre_TariffReport>,
+    /// Required colophons the negative census found unused — empty when clean.
+    /// None when the check did not run: the case-only runners, and a fixture
+    /// whose failed or skipped cases gated it off.
+    pub census: Option<Vec<&'static str>>,
+    /// An interrupt stopped the run: no case was launched after it.
+    pub interrupted: bool,
 }
 
 /// Run all cases sequentially, dispatching each with per-case temp dir isolation.
+/// `case_timeout_secs` is the wall-clock budget applied to every case (None =
+/// unbudgeted). `fixture` names the hosting fixture in each per-case tariff line.
+/// An interrupt stops the loop: the in-flight case is killed and failed, and no
+/// later case is launched.
 pub fn rbtdre_run_cases(
+    fixture: &str,
     cases: &[rbtdre_Case],
     colors: &rbtdre_Colors,
     fail_fast: bool,
+    case_timeout_secs: Option<u64>,
     root_temp: &Path,
 ) -> Result<rbtdre_RunResult, String> {
     let mut passed = 0usize;
     let mut failed = 0usize;
     let mut skipped = 0usize;
+    let mut records = Vec::new();
+    let interrupt = crate::rbtdrt_interrupt::rbtdrt_current();
 
     for case in cases {
+        if interrupt.raised() {
+            break;
+        }
         let case_dir = root_temp.join(case.name);
         std::fs::create_dir_all(&case_dir).map_err(|e| {
             format!("rbtd: failed to create case dir '{}': {}", case.name, e)
         })?;
 
-        let verdict = rbtdre_run_with_heartbeat(case, &case_dir);
+        crate::rbtdri_invocation::rbtdri_set_phase(case.name);
+        crate::rbtdrc_crucible::rbtdrc_reset_chains();
+        let tally_before = crate::rbtdri_invocation::rbtdri_tariff_count();
+        let case_started = Instant::now();
+        let verdict = rbtdre_run_with_heartbeat(case, &case_dir, case_timeout_secs);
+        let elapsed = case_started.elapsed();
+        let invocations =
+            crate::rbtdri_invocation::rbtdri_tariff_count().saturating_sub(tally_before);
+        // A replayed case's wall-clock is the tape's: no tariff, and no line
+        // for dowse to fold into the case's history.
+        let verdict = if crate::rbtdri_invocation::rbtdri_replaying() {
+            verdict
+        } else {
+            rbtdre_case_tariff(fixture, case, verdict, elapsed, invocations, colors)
+        };
+        let verdict = rbtdre_hermeticity_verdict(verdict);
         rbtdre_write_trace(&case_dir, case.name, &verdict);
 
-        match &verdict {
+        let stop = match &verdict {
             rbtdre_Verdict::Pass => {
                 crate::rbtdrg_info_now!(
                     "{}{}{} {}",
                     colors.green, RBTDRE_WORD_PASSED, colors.reset, case.name
                 );
                 passed += 1;
+                false
             }
             rbtdre_Verdict::Fail(msg) => {
                 crate::rbtdrg_info_now!(
@@ -807,9 +1565,7 @@ pub fn rbtdre_run_cases(
                 );
                 crate::rbtdrg_info_now!("{}", msg);
                 failed += 1;
-                if fail_fast {
-                    break;
-                }
+                fail_fast
             }
             rbtdre_Verdict::Skip(_) => {
                 crate::rbtdrg_info_now!(
@@ -817,7 +1573,12 @@ pub fn rbtdre_run_cases(
                     colors.yellow, RBTDRE_WORD_SKIPPED, colors.reset, case.name
                 );
                 skipped += 1;
+                false
             }
+        };
+        records.push(rbtdre_CaseRecord { name: case.name, verdict, elapsed, invocations });
+        if stop {
+            break;
         }
     }
 
@@ -828,6 +1589,10 @@ pub fn rbtdre_run_cases(
         temp_dir: root_temp.to_path_buf(),
         elapsed_secs: 0,
         invocations: 0,
+        cases: records,
+        tariff: None,
+        census: None,
+        interrupted: interrupt.raised(),
     })
 }
 
@@ -844,6 +1609,25 @@ pub fn rbtdre_print_summary(result: &rbtdre_RunResult, colors: &rbtdre_Colors) {
         "{}{} passed, {} failed, {} skipped ({} total){}",
         color, result.passed, result.failed, result.skipped, total, end_color,
     );
+    if result.interrupted {
+        let interrupt = crate::rbtdrt_interrupt::rbtdrt_current();
+        let killed = result.cases.iter().find(|c| {
+            matches!(&c.verdict, rbtdre_Verdict::Fail(d) if d.starts_with(RBTDRE_WORD_INTERRUPTED))
+        });
+        let last = result
+            .cases
+            .iter()
+            .rfind(|c| c.name != RBTDRE_GUARD_NETNS && c.name != RBTDRE_GUARD_READ_ONLY);
+        let at = match (killed, last) {
+            (Some(case), _) => format!("in case {}", case.name),
+            (None, Some(last)) => format!("after case {}", last.name),
+            (None, None) => "before the first case".to_string(),
+        };
+        crate::rbtdrg_info_now!(
+            "{}{}{} run stopped by {} {} — no later case was launched",
+            colors.red, RBTDRE_WORD_INTERRUPTED, colors.reset, interrupt.signal_name(), at
+        );
+    }
     crate::rbtdrg_info_now!("Trace dir: {}", result.temp_dir.display());
 }
 
@@ -857,13 +1641,34 @@ pub fn rbtdre_find_case<'a>(
     cases.iter().find(|c| c.name == target)
 }
 
-/// List all cases by name.
+/// List all cases by name, with their tags.
 pub fn rbtdre_list_cases(cases: &[rbtdre_Case]) {
     for case in cases {
-        crate::rbtdrg_info_now!("  {}", case.name);
+        if case.tags.is_empty() {
+            crate::rbtdrg_info_now!("  {}", case.name);
+        } else {
+            crate::rbtdrg_info_now!("  {}  [{}]", case.name, case.tags.join(", "));
+        }
     }
 }
 
+/// Operator-chosen run policy handed to `rbtdre_run_fixture` by the fixture
+/// and suite runners.
+#[derive(Default)]
+pub struct rbtdre_RunOptions {
+    /// `--keep-going`: run past a failing case where the disposition allows it
+    /// (see `rbtdre_resolve_fail_fast`).
+    pub keep_going: bool,
+    /// Hosting suite's default case budget; the fixture's own
+    /// `case_timeout_secs` wins when declared.
+    pub case_timeout_secs: Option<u64>,
+    /// Which of the fixture's cases to run (fixture runner only).
+    pub selection: rbtdre_Selection,
+    /// Repository root a read-only fixture's tree snapshots are taken in. None
+    /// leaves `rbtdre_Fixture::read_only` unenforced (engine unit tests).
+    pub tree_root: Option<PathBuf>,
+}
+
 /// Run a fixture: setup hook → cases → teardown hook (finally-shaped).
 ///
 /// Expects the invocation context to already be installed in the thread-local
@@ -871,37 +1676,120 @@ pub fn rbtdre_list_cases(cases: &[rbtdre_Case]) {
 ///
 /// Setup failure short-circuits cases but still invokes teardown. Teardown
 /// errors are surfaced as warnings via stderr; the function never panics on
-/// teardown failure.
+/// teardown failure. Setup, every case, and teardown run under the panic
+/// boundary (`rbtdre_catch_panic`): a panicking setup is a setup failure, a
+/// panicking case is a Fail verdict, and a panicking teardown is a warning —
+/// teardown runs in every one of those outcomes. A case that outlives its
+/// budget is killed and failed the same way, with teardown to follow, and so
+/// is the case in flight when an interrupt arrives (rbtdrt_interrupt) — the
+/// interrupt also stops further cases, but never the teardown.
 pub fn rbtdre_run_fixture(
     fixture: &'static rbtdre_Fixture,
     colors: &rbtdre_Colors,
     root_temp: &Path,
-    keep_going: bool,
+    options: &rbtdre_RunOptions,
 ) -> Result<rbtdre_RunResult, String> {
     // Policy resolution precedes setup: a StateProgressing fixture refuses
     // keep-going before any charge or case work begins, so the refusal leaves
     // no partial state behind.
-    let fail_fast = rbtdre_resolve_fail_fast(fixture.disposition, keep_going)?;
+    let fail_fast = rbtdre_resolve_fail_fast(fixture.disposition, options.keep_going)?;
+    let case_timeout_secs = fixture.case_timeout_secs.or(options.case_timeout_secs);
+    let cases = rbtdre_select_cases(fixture.cases, fixture.disposition, &options.selection)?;
+    let partial = cases.len() < fixture.cases.len();
+    crate::rbtdrg_info_now!("{} {}", RBTDRE_WORD_FIXTURE, fixture.name);
+    if partial {
+        crate::rbtdrg_info_now!(
+            "rbtd: selection runs {} of {} case(s) in '{}'",
+            cases.len(), fixture.cases.len(), fixture.name
+        );
+    }
 
     // Reset the invocation tally and start the wall-clock BEFORE setup, so the
     // tariff footprint spans setup/teardown (charge/quench) as well as the
     // cases — the whole fixture, not just its case bodies. The tally is a
     // thread-local and the cases run on this thread, so the count is coherent.
     crate::rbtdri_invocation::rbtdri_tariff_reset();
+    crate::rbtdri_invocation::rbtdri_netns_take_violations();
+
+    // The read-only guard's baseline is taken before setup, so a charge that
+    // writes the tree is caught as surely as a case that does. A tree git
+    // cannot snapshot fails the fixture before any of its work begins.
+    let read_only_root = options.tree_root.as_deref().filter(|_| fixture.read_only);
+    let tree_before = match read_only_root {
+        Some(root) => Some(rbtdre_tree_snapshot(root).map_err(|e| {
+            format!("rbtd: fixture '{}' read-only baseline snapshot failed: {}", fixture.name, e)
+        })?),
+        None => None,
+    };
     let started = Instant::now();
 
+    crate::rbtdri_invocation::rbtdri_set_phase("setup");
     let setup_result = match fixture.setup {
-        Some(f) => f(),
+        Some(f) => rbtdre_catch_panic("setup", f).and_then(|r| r),
         None => Ok(()),
     };
 
     let mut run_result = match setup_result {
-        Ok(()) => rbtdre_run_cases(fixture.cases, colors, fail_fast, root_temp),
+        Ok(()) => rbtdre_run_cases(
+            fixture.name, &cases, colors, fail_fast, case_timeout_secs, root_temp,
+        ),
         Err(msg) => Err(format!("rbtd: fixture '{}' setup failed: {}", fixture.name, msg)),
     };
 
+    crate::rbtdri_invocation::rbtdri_set_phase("teardown");
     if let Some(f) = fixture.teardown {
-        f();
+        if let Err(msg) = rbtdre_catch_panic("teardown", f) {
+            crate::rbtdrg_error_now!("rbtd: fixture '{}' {}", fixture.name, msg);
+        }
+    }
+    crate::rbtdri_invocation::rbtdri_set_phase("");
+
+    // Network reaches from setup or teardown belong to no case; each is
+    // reported, and together they fail the fixture once.
+    let stray_violations = crate::rbtdri_invocation::rbtdri_netns_take_violations();
+    for violation in &stray_violations {
+        crate::rbtdrg_info_now!(
+            "{}{}{} {} setup/teardown {} {}",
+            colors.red, RBTDRE_WORD_FAILED, colors.reset, fixture.name, RBTDRE_WORD_HERMETICITY,
+            violation
+        );
+    }
+    if let Ok(result) = run_result.as_mut() {
+        if !stray_violations.is_empty() {
+            result.failed += 1;
+            result.cases.push(rbtdre_guard_record(
+                RBTDRE_GUARD_NETNS,
+                RBTDRE_WORD_HERMETICITY,
+                &stray_violations,
+            ));
+        }
+    }
+
+    // Read-only guard — the tree after teardown must match the baseline path
+    // for path. Each mutated path is reported, and together they fail the
+    // fixture once; a snapshot that cannot be taken fails it the same way.
+    if let (Some(root), Some(before)) = (read_only_root, tree_before.as_ref()) {
+        let mutations = match rbtdre_tree_snapshot(root) {
+            Ok(after) => rbtdre_tree_mutations(before, &after),
+            Err(e) => vec![format!("post-teardown snapshot failed: {}", e)],
+        };
+        for mutation in &mutations {
+            crate::rbtdrg_info_now!(
+                "{}{}{} {} read-only {} {}",
+                colors.red, RBTDRE_WORD_FAILED, colors.reset, fixture.name, RBTDRE_WORD_MUTATED,
+                mutation
+            );
+        }
+        if let Ok(result) = run_result.as_mut() {
+            if !mutations.is_empty() {
+                result.failed += 1;
+                result.cases.push(rbtdre_guard_record(
+                    RBTDRE_GUARD_READ_ONLY,
+                    RBTDRE_WORD_MUTATED,
+                    &mutations,
+                ));
+            }
+        }
     }
 
     // Tariff evaluation — the fixture is complete (setup + cases + teardown), so
@@ -910,16 +1798,32 @@ pub fn rbtdre_run_fixture(
     // into the case-failure count so the suite stops through the runner's
     // EXISTING break-on-failure — no second stopping mechanism. A setup failure
     // (Err run_result) already fails the fixture and is left untouched.
+    //
+    // A case-selected (partial) run is held to no tariff: its footprint is a
+    // fraction of the fixture's by construction, so it is evaluated UNCHECKED
+    // and its per-fixture line is withheld — dowse would otherwise fold the
+    // fraction into the fixture's history and drag its proposals down. An
+    // interrupted run is partial the same way, whenever the interrupt landed.
+    // A replayed run is whole but unmetered: its launches cost nothing, so it
+    // too is held to no tariff and prints no line, yet keeps the census.
     let elapsed_secs = started.elapsed().as_secs();
     let invocations = crate::rbtdri_invocation::rbtdri_tariff_count();
+    let interrupted = crate::rbtdrt_interrupt::rbtdrt_current().raised();
+    let partial = partial || interrupted;
+    let unmetered = partial || crate::rbtdri_invocation::rbtdri_replaying();
+    let tariff = if unmetered { rbtdre_Tariff::UNCHECKED } else { fixture.tariff };
     if let Ok(result) = run_result.as_mut() {
         result.elapsed_secs = elapsed_secs;
         result.invocations = invocations;
-        let report = rbtdre_evaluate_tariff(&fixture.tariff, elapsed_secs, invocations);
-        rbtdre_print_tariff(fixture.name, &fixture.tariff, &report, colors);
+        result.interrupted = interrupted;
+        let report = rbtdre_evaluate_tariff(&tariff, elapsed_secs, invocations);
+        if !unmetered {
+            rbtdre_print_tariff(fixture.name, &tariff, &report, colors);
+        }
         if report.too_fast {
             result.failed += 1;
         }
+        result.tariff = Some(report);
 
         // Census negative check — gated on zero failed AND zero skipped cases.
         // A failed fixture suppresses the check rather than piling on a second,
@@ -928,27 +1832,35 @@ pub fn rbtdre_run_fixture(
         // keeps suite-passenger protection intact: a self-skipping fixture
         // (polity-denial, parley on a credential-less machine) must not
         // census-fail over the colophons its skipped cases would have invoked.
-        if result.failed == 0 && result.skipped == 0
-            && !rbtdre_check_census(fixture.name, colors)
-        {
-            result.failed += 1;
+        // A case-selected run is not exhaustive either, for the same reason.
+        if !partial && result.failed == 0 && result.skipped == 0 {
+            let unused = rbtdre_check_census(fixture.name, colors);
+            if !unused.is_empty() {
+                result.failed += 1;
+            }
+            result.census = Some(unused);
         }
     }
 
     run_result
 }
 
-/// Run a single case without charge/quench lifecycle.
+/// Run a single case without charge/quench lifecycle. A case that outlives
+/// `case_timeout_secs` is killed and failed as it would be in a fixture run.
 pub fn rbtdre_run_single_case(
     case: &rbtdre_Case,
     colors: &rbtdre_Colors,
     root_temp: &Path,
+    case_timeout_secs: Option<u64>,
 ) -> Result<rbtdre_RunResult, String> {
     let case_dir = root_temp.join(case.name);
     std::fs::create_dir_all(&case_dir)
         .map_err(|e| format!("rbtd: failed to create case dir '{}': {}", case.name, e))?;
 
-    let verdict = rbtdre_run_with_heartbeat(case, &case_dir);
+    crate::rbtdri_invocation::rbtdri_set_phase(case.name);
+    let case_started = Instant::now();
+    let verdict = rbtdre_run_with_heartbeat(case, &case_dir, case_timeout_secs);
+    let elapsed = case_started.elapsed();
     rbtdre_write_trace(&case_dir, case.name, &verdict);
 
     let (passed, failed, skipped) = match &verdict {
@@ -982,5 +1894,9 @@ pub fn rbtdre_run_single_case(
         temp_dir: root_temp.to_path_buf(),
         elapsed_secs: 0,
         invocations: 0,
+        cases: vec![rbtdre_CaseRecord { name: case.name, verdict, elapsed, invocations: 0 }],
+        tariff: None,
+        census: None,
+        interrupted: crate::rbtdrt_interrupt::rbtdrt_current().raised(),
     })
 }
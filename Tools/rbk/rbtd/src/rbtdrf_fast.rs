@@ -1899,7 +1899,9 @@ pub static RBTDRF_FIXTURE_ENROLLMENT_VALIDATION: rbtdre_Fixture = rbtdre_Fixture
     teardown: None,
     cases: RBTDRF_CASES_ENROLLMENT_VALIDATION,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
 
 pub static RBTDRF_FIXTURE_REGIME_VALIDATION: rbtdre_Fixture = rbtdre_Fixture {
@@ -1909,7 +1911,9 @@ pub static RBTDRF_FIXTURE_REGIME_VALIDATION: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_REGIME_VALIDATION,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(30), invocations: Some(19) },
+    case_timeout_secs: None,
 };
 
 pub static RBTDRF_FIXTURE_REGIME_SMOKE: rbtdre_Fixture = rbtdre_Fixture {
@@ -1919,7 +1923,9 @@ pub static RBTDRF_FIXTURE_REGIME_SMOKE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_REGIME_SMOKE,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: Some(2), max_secs: Some(60), invocations: Some(47) },
+    case_timeout_secs: None,
 };
 
 pub static RBTDRF_CASES_DOCKERFILE_HYGIENE: &[rbtdre_Case] = &[
@@ -1941,7 +1947,9 @@ pub static RBTDRF_FIXTURE_DOCKERFILE_HYGIENE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_DOCKERFILE_HYGIENE,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(20), invocations: Some(19) },
+    case_timeout_secs: None,
 };
 
 pub static RBTDRF_CASES_FOUNDRY_PATH: &[rbtdre_Case] = &[
@@ -1960,7 +1968,9 @@ pub static RBTDRF_FIXTURE_FOUNDRY_PATH: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_FOUNDRY_PATH,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
 
 pub static RBTDRF_CASES_CLIPBOARD: &[rbtdre_Case] = &[
@@ -1975,7 +1985,9 @@ pub static RBTDRF_FIXTURE_CLIPBOARD: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_CLIPBOARD,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 
 pub static RBTDRF_CASES_RECIPE_VALIDATION: &[rbtdre_Case] = &[
@@ -1998,7 +2010,9 @@ pub static RBTDRF_FIXTURE_RECIPE_VALIDATION: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_RECIPE_VALIDATION,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
 
 // ── Podvm-resolve cases ─────────────────────────────────────
@@ -2094,5 +2108,7 @@ pub static RBTDRF_FIXTURE_PODVM_RESOLVE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRF_CASES_PODVM_RESOLVE,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(2) },
+    case_timeout_secs: None,
 };
@@ -943,5 +943,7 @@ pub static RBTDRH_FIXTURE_CHAINING_FACT_BAND: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRH_CASES_CHAINING_FACT_BAND,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(25), invocations: Some(21) },
+    case_timeout_secs: None,
 };
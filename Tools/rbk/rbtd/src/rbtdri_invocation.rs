@@ -23,6 +23,8 @@
 //   2. Tabtarget execution with BURV isolation — per-invocation output/temp dirs
 //   3. Ifrit verdict parsing — extract verdict from ifrit stdout + exit code
 //   4. BURV fact file reading — extract structured output from tabtarget results
+//   5. Invocation transcript — a JSONL record of every funnelled launch
+//   6. Cassettes — record funnelled launches, or replay them without bash
 
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -31,6 +33,7 @@ use std::sync::OnceLock;
 use crate::rbtdgc_consts::{RBTDGC_ORDAIN_HALLMARK, RBTDGC_VERB_ORDAIN};
 use crate::rbtdre_engine::rbtdre_Verdict;
 use crate::rbtdrx_platform::{rbtdrx_is_cygwin, rbtdrx_native_to_posix, rbtdrx_posix_to_native};
+use crate::rbtdrz_facts::{rbtdrz_read_fact, rbtdrz_ArkStem, rbtdrz_GarRoot, rbtdrz_Hallmark};
 
 /// BUK dispatch output subdirectory — tabtargets write facts to BURV_OUTPUT_ROOT_DIR/current.
 /// Matches BURD_OUTPUT_DIR = "${BURC_OUTPUT_ROOT_DIR}/current" from bud_dispatch.sh.
@@ -93,9 +96,15 @@ pub struct rbtdri_InvokeResult {
 /// `rbw-cC.Charge.{fixture}.sh`). Non-crucible fixtures (regime-*, calibrant-*,
 /// canonical-*, etc.) carry their fixture name in this slot too, but no
 /// nameplate-shaped consumer reads them.
+///
+/// `imprint` is what fixture-imprinted tabtargets resolve against: the fixture
+/// name, unless the manifest binds the fixture to another nameplate
+/// (`rbtdrm_fixture_imprint`) — a second crucible fixture over the same
+/// nameplate, like chaos-tadmor over tadmor.
 pub struct rbtdri_Context {
     pub(crate) project_root: PathBuf,
     pub(crate) fixture: String,
+    pub(crate) imprint: String,
     pub(crate) burv_temp_root: PathBuf,
     pub(crate) burv_output_root: PathBuf,
     pub(crate) invoke_count: u32,
@@ -103,6 +112,18 @@ pub struct rbtdri_Context {
     /// invoke's BURV root instead of minting a fresh one (see
     /// `chain_next_invoke`). Consumed and cleared by `rbtdri_invoke_impl`.
     pub(crate) chain_next: bool,
+    /// Named chain sessions opened by `chain_open` (see there).
+    pub(crate) chains: Vec<rbtdri_ChainSession>,
+    /// One-shot join set by `chain_into`: the session the NEXT invoke runs in,
+    /// and the hand-off facts it must find. Consumed by `rbtdri_invoke_impl`.
+    pub(crate) chain_pending: Option<(String, Vec<String>)>,
+    /// Where the invocation transcript is written (see `set_transcript_dir`);
+    /// None records nothing.
+    pub(crate) transcript_dir: Option<PathBuf>,
+    /// Launches recorded so far — numbers the transcript lines and spill files.
+    pub(crate) transcript_seq: u32,
+    /// Set after the first transcript write failure, so it is reported once.
+    pub(crate) transcript_failed: bool,
 }
 
 impl rbtdri_Context {
@@ -115,10 +136,16 @@ impl rbtdri_Context {
         Self {
             project_root: project_root.to_path_buf(),
             fixture: fixture.to_string(),
+            imprint: crate::rbtdrm_manifest::rbtdrm_fixture_imprint(fixture).to_string(),
             burv_temp_root: burv_temp_root.to_path_buf(),
             burv_output_root: burv_output_root.to_path_buf(),
             invoke_count: 0,
             chain_next: false,
+            chains: Vec::new(),
+            chain_pending: None,
+            transcript_dir: None,
+            transcript_seq: 0,
+            transcript_failed: false,
         }
     }
 
@@ -126,6 +153,11 @@ impl rbtdri_Context {
         &self.fixture
     }
 
+    /// The nameplate this fixture's imprinted tabtargets resolve against.
+    pub fn imprint(&self) -> &str {
+        &self.imprint
+    }
+
     pub fn project_root(&self) -> &Path {
         &self.project_root
     }
@@ -149,10 +181,90 @@ impl rbtdri_Context {
     /// invokes that need it, leaving every other invoke's isolation intact.
     /// One-shot: consumed by the next invoke and cleared. Depth-1 only — bud
     /// keeps a single generation, so only the immediate predecessor is visible.
+    /// A flow longer than a pair, or one with helper invokes between its links,
+    /// wants a named chain session instead (`chain_open`).
     pub fn chain_next_invoke(&mut self) {
         self.chain_next = true;
     }
 
+    /// Open a named chain session: a BURV root lineage that any number of
+    /// invokes can join, in any interleaving with unchained invokes. Where
+    /// `chain_next_invoke` links an invoke to whatever ran just before it, a
+    /// session links each joined invoke (`chain_into`) to the previous invoke
+    /// *of that session*, so a helper invoke between two links neither joins
+    /// nor breaks the chain — the operator's shared `../output-buk` flow,
+    /// replayed for exactly the tabtargets named into it.
+    ///
+    /// Each fixture context holds its own sessions, and the engine drops them
+    /// between cases (`chain_reset`). Opening a name that is already open
+    /// abandons its old lineage as well.
+    pub fn chain_open(&mut self, name: &str) {
+        self.chains.retain(|c| c.name != name);
+        self.chains.push(rbtdri_ChainSession {
+            name: name.to_string(),
+            invoke_num: None,
+            links: Vec::new(),
+        });
+    }
+
+    /// Mark the NEXT invoke to run in session `name`, and assert the hand-off:
+    /// each fact in `handoff` must be in the session's `current/` when that
+    /// invoke launches, so bud promotes it into the invoke's `previous/`. The
+    /// first link of a session has nothing to hand off from, so it takes
+    /// none. Refused (Err) for a session that is not open, a hand-off into
+    /// an empty session, or a join on top of another pending join.
+    pub fn chain_into(&mut self, name: &str, handoff: &[&str]) -> Result<(), String> {
+        let session = self
+            .chains
+            .iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| format!("rbtdri: chain '{}' is not open", name))?;
+        if session.invoke_num.is_none() && !handoff.is_empty() {
+            return Err(format!(
+                "rbtdri: chain '{}' has no link yet — its first link cannot take a hand-off",
+                name
+            ));
+        }
+        if self.chain_next || self.chain_pending.is_some() {
+            return Err(format!(
+                "rbtdri: chain '{}' joined while another chain is pending for the next invoke",
+                name
+            ));
+        }
+        self.chain_pending =
+            Some((name.to_string(), handoff.iter().map(|f| f.to_string()).collect()));
+        Ok(())
+    }
+
+    /// Close session `name`, returning the colophons launched into it in
+    /// order. Err if it is not open, still has a join pending, or never
+    /// launched — a chain that ran nothing proved nothing.
+    pub fn chain_close(&mut self, name: &str) -> Result<Vec<String>, String> {
+        let index = self
+            .chains
+            .iter()
+            .position(|c| c.name == name)
+            .ok_or_else(|| format!("rbtdri: chain '{}' is not open", name))?;
+        if self.chain_pending.as_ref().is_some_and(|(p, _)| p == name) {
+            return Err(format!("rbtdri: chain '{}' closed with a join still pending", name));
+        }
+        let session = self.chains.remove(index);
+        if session.links.is_empty() {
+            return Err(format!("rbtdri: chain '{}' closed without launching a link", name));
+        }
+        Ok(session.links)
+    }
+
+    /// Drop every chain mark: the one-shot `chain_next`, a pending join, and
+    /// every open session. Chains are case-scoped; the engine calls this
+    /// between cases, so a case that failed mid-chain cannot leave a join for
+    /// the next case's first invoke or a session for it to reuse.
+    pub fn chain_reset(&mut self) {
+        self.chain_next = false;
+        self.chain_pending = None;
+        self.chains.clear();
+    }
+
     /// Read the suite-monotonic BURV invoke counter. The suite loop reads it
     /// after each fixture and seeds the next Context, so per-invoke dir names
     /// stay unique across fixtures (see set_invoke_count).
@@ -168,6 +280,24 @@ impl rbtdri_Context {
     pub fn set_invoke_count(&mut self, count: u32) {
         self.invoke_count = count;
     }
+
+    /// Record every funnelled launch into `dir` (see the invocation transcript
+    /// section). The runners point this at the fixture's trace dir; a context
+    /// never given one records nothing.
+    pub fn set_transcript_dir(&mut self, dir: &Path) {
+        self.transcript_dir = Some(dir.to_path_buf());
+    }
+}
+
+/// A named chain session (see `rbtdri_Context::chain_open`).
+#[derive(Debug)]
+pub(crate) struct rbtdri_ChainSession {
+    pub(crate) name: String,
+    /// The BURV invoke number the session's links share; None until the
+    /// first link launches.
+    pub(crate) invoke_num: Option<u32>,
+    /// Colophons launched into the session, in order.
+    pub(crate) links: Vec<String>,
 }
 
 // ── Credless guard ───────────────────────────────────────────
@@ -414,6 +544,1012 @@ pub fn rbtdri_find_tabtarget_global(
     }
 }
 
+// ── Case kill switch (timeout and interrupt watchdog) ────────
+//
+// Every engine-run case runs with a kill switch armed on its thread. Every
+// tabtarget that `rbtdri_invoke_impl` launches while the switch is armed is
+// placed in its own process group and registered here by pid (= pgid); the
+// engine's watchdog thread, holding a clone of the same switch, trips it when
+// the case budget expires or an interrupt arrives (rbtdrt_interrupt). A
+// terminal Ctrl-C therefore reaches theurge alone, never a child directly: the
+// children die by the switch, after which the teardown's launches (run with
+// the switch disarmed) proceed. Tripping kills every registered group — bash, the
+// tabtarget's docker/gcloud children, and anything else that did not leave the
+// group — and refuses every later launch through the funnel, so a case blocked
+// on a wedged container returns promptly and its fixture's teardown runs.
+//
+// Only the funnelled `rbtdri_invoke*` path registers: the direct-Command bypass
+// helpers run `Command::output()` themselves and never expose a pid, so a case
+// wedged there is reported as timed out but not unblocked.
+//
+// The switch also carries what the case is blocked on: the colophon of the
+// launch in flight and the last line it printed, which the engine's heartbeat
+// reports in place of a bare elapsed time.
+
+/// Shared between a case's thread and the engine's watchdog.
+pub struct rbtdri_KillSwitch {
+    state: std::sync::Mutex<zrbtdri_KillState>,
+}
+
+struct zrbtdri_KillState {
+    tripped: bool,
+    groups: Vec<u32>,
+    in_flight: Option<(String, String)>,
+}
+
+impl rbtdri_KillSwitch {
+    pub fn new() -> std::sync::Arc<rbtdri_KillSwitch> {
+        std::sync::Arc::new(rbtdri_KillSwitch {
+            state: std::sync::Mutex::new(zrbtdri_KillState {
+                tripped: false,
+                groups: Vec::new(),
+                in_flight: None,
+            }),
+        })
+    }
+
+    /// Whether the watchdog has fired (budget expired or run interrupted).
+    pub fn tripped(&self) -> bool {
+        self.zrbtdri_lock().tripped
+    }
+
+    /// Fire the switch: kill every registered process group and refuse later
+    /// launches. Returns how many groups were in flight. Idempotent.
+    pub fn trip(&self) -> usize {
+        let mut state = self.zrbtdri_lock();
+        state.tripped = true;
+        for pgid in &state.groups {
+            zrbtdri_kill_group(*pgid);
+        }
+        state.groups.len()
+    }
+
+    /// The launch now in flight, as (colophon, last line it printed); the
+    /// line is empty until the child prints one. None between launches.
+    pub fn in_flight(&self) -> Option<(String, String)> {
+        self.zrbtdri_lock().in_flight.clone()
+    }
+
+    /// Register a freshly spawned group. A registration that loses the race
+    /// with `trip` is killed on the spot rather than left running unwatched.
+    fn zrbtdri_register(&self, pgid: u32, colophon: &str) {
+        let mut state = self.zrbtdri_lock();
+        if state.tripped {
+            zrbtdri_kill_group(pgid);
+        }
+        state.groups.push(pgid);
+        state.in_flight = Some((colophon.to_string(), String::new()));
+    }
+
+    fn zrbtdri_release(&self, pgid: u32) {
+        let mut state = self.zrbtdri_lock();
+        state.groups.retain(|g| *g != pgid);
+        state.in_flight = None;
+    }
+
+    /// Note the latest non-blank line the in-flight launch printed.
+    fn zrbtdri_note_line(&self, line: &str) {
+        if let Some((_, last)) = self.zrbtdri_lock().in_flight.as_mut() {
+            line.clone_into(last);
+        }
+    }
+
+    // Poison-tolerant: a panic elsewhere must not disable the watchdog.
+    fn zrbtdri_lock(&self) -> std::sync::MutexGuard<'_, zrbtdri_KillState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+thread_local! {
+    /// The current case's kill switch, armed for the life of every engine-run
+    /// case function. Thread-local for the same reason as the credless guard: the case
+    /// function runs on the thread that installed it.
+    static RBTDRI_KILL_SWITCH: std::cell::RefCell<Option<std::sync::Arc<rbtdri_KillSwitch>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Arm (Some) or disarm (None) the kill switch for the current thread. Armed by
+/// the engine around one case function — not around setup/teardown,
+/// which must be allowed to run after the switch trips.
+pub fn rbtdri_arm_kill_switch(switch: Option<std::sync::Arc<rbtdri_KillSwitch>>) {
+    RBTDRI_KILL_SWITCH.with(|k| *k.borrow_mut() = switch);
+}
+
+/// SIGKILL a whole process group. Best-effort: the group may already be gone.
+/// A direct syscall, so the kill path spawns nothing and cannot fail to fork
+/// under the load that made the case overrun. Off unix there are no process
+/// groups to address, so the child's process tree is terminated instead.
+fn zrbtdri_kill_group(pgid: u32) {
+    #[cfg(unix)]
+    {
+        // Group 0 would be theurge's own; nothing past pid_t is a group.
+        let pgid = match libc::pid_t::try_from(pgid) {
+            Ok(p) if p > 0 => p,
+            _ => return,
+        };
+        // SAFETY: kill(2) takes plain integers and touches no memory; a
+        // negative pid addresses the group, and ESRCH for a gone one is ignored.
+        unsafe {
+            libc::kill(-pgid, libc::SIGKILL);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pgid.to_string()])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+}
+
+// ── Cassette (record/replay) ─────────────────────────────────
+//
+// A cassette (rbtdry_cassette) armed here either records every funnelled
+// launch or replays them all without spawning. Armed by the fixture runner
+// around one fixture — setup, cases and teardown alike, since each fixture
+// tapes to its own directory. A replayed launch still passes every refusal
+// gate and the Command constructor (so the census and tariff tally see it),
+// but is not transcribed: the cassette is its record.
+
+thread_local! {
+    static RBTDRI_CASSETTE: std::cell::RefCell<Option<crate::rbtdry_cassette::rbtdry_Cassette>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Arm (Some) or disarm (None) a cassette for the current thread; returns the
+/// one it replaces, so the runner can report what a replay served.
+pub fn rbtdri_arm_cassette(
+    cassette: Option<crate::rbtdry_cassette::rbtdry_Cassette>,
+) -> Option<crate::rbtdry_cassette::rbtdry_Cassette> {
+    RBTDRI_CASSETTE.with(|c| std::mem::replace(&mut *c.borrow_mut(), cassette))
+}
+
+/// Whether launches on this thread are being replayed. The engine holds a
+/// replayed run to no tariff: its wall-clock is the tape's, not the cloud's.
+pub fn rbtdri_replaying() -> bool {
+    RBTDRI_CASSETTE.with(|c| c.borrow().as_ref().is_some_and(|c| c.replaying()))
+}
+
+// ── Invocation transcript ────────────────────────────────────
+//
+// A case holds its `rbtdri_InvokeResult` only as long as it cares to; the
+// transcript is the durable record. Every launch through `rbtdri_invoke_impl`
+// appends one JSON line to RBTDRI_TRANSCRIPT_FILE in the context's transcript
+// dir — which colophon ran in which phase, with which args, env overrides and
+// BURV dirs, for how long, and how it exited — and streams its stdout and
+// stderr into numbered trace files beside it (`<seq>-<colophon>.stdout` /
+// `.stderr`), so a failed run can be reconstructed from one place. The trace
+// files are written line by line while the child runs, not after it exits: a
+// launch that hangs, or whose case is killed, leaves everything it printed up
+// to that point. A launch that could not be spawned is recorded with its error
+// and no exit code.
+//
+// The same line-by-line readers optionally echo each line to the console
+// (`rbtdri_set_echo`, the runners' `--verbose`), and note it on the case kill
+// switch for the engine's heartbeat. The case still receives the whole
+// captured stdout and stderr in its `rbtdri_InvokeResult`.
+//
+// Refused launches (census, tweak-slot conflict, tripped kill switch) never
+// spawn and are not recorded; the direct-Command bypass helpers do not pass
+// through the funnel and are not recorded either. The transcript is best-
+// effort: a write failure is reported once and never fails the launch.
+
+/// Invocation transcript filename, written into the transcript dir.
+pub const RBTDRI_TRANSCRIPT_FILE: &str = "invocations.jsonl";
+
+thread_local! {
+    /// The fixture phase now running — "setup", a case name, or "teardown" —
+    /// stamped on each transcript record. Set by the engine; thread-local like
+    /// the context channel it rides beside.
+    static RBTDRI_PHASE: std::cell::Cell<&'static str> = const { std::cell::Cell::new("") };
+}
+
+/// Name the fixture phase now running, for the transcript.
+pub fn rbtdri_set_phase(phase: &'static str) {
+    RBTDRI_PHASE.with(|p| p.set(phase));
+}
+
+thread_local! {
+    /// Whether launches on this thread echo their output lines to the console.
+    /// Set once by the runners from `--verbose`; off by default, so tests and
+    /// quiet runs see only theurge's own lines.
+    static RBTDRI_ECHO: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Echo (true) or silence (false) launch output on the console.
+pub fn rbtdri_set_echo(echo: bool) {
+    RBTDRI_ECHO.with(|e| e.set(echo));
+}
+
+// ── Hermetic child environment ───────────────────────────────
+//
+// By default a tabtarget inherits theurge's whole environment, less the two
+// dispatch-mode selectors `rbtdri_invoke_impl` clears. Hermetic mode (the
+// runners' --hermetic) instead builds every child's environment from an
+// allowlist: the RBTDRI_HERMETIC_KEEP names, any key under an
+// RBTDRI_HERMETIC_KEEP_PREFIXES prefix, and the operator's declared extras
+// (--hermetic-keep). Everything else theurge inherited is dropped, and the
+// runner reports the dropped names up front — so a green hermetic run shows
+// its results do not hang on a variable exported in the maintainer's shell.
+//
+// Applied in `rbtdri_tabtarget_command`, so the direct-Command bypass launches
+// are hermetic too; per-launch overrides (BURV roots, credless tweak,
+// extra_env) are set after the clear and always reach the child. The report
+// names variables, never their values, which may carry credentials.
+
+/// Inherited variables a hermetic child always keeps, when theurge has them.
+pub const RBTDRI_HERMETIC_KEEP: &[&str] = &["PATH", "HOME", "TERM"];
+
+/// Inherited variable prefixes a hermetic child always keeps: the BURV
+/// isolation keys and the BURE regime-override keys.
+pub const RBTDRI_HERMETIC_KEEP_PREFIXES: &[&str] = &["BURV_", "BURE_"];
+
+/// Hermetic-report filename, written into the trace root.
+pub const RBTDRI_HERMETIC_REPORT_FILE: &str = "hermetic-env.txt";
+
+/// A hermetic allowlist: the fixed keep set plus the operator's declared extras.
+#[derive(Debug, Clone, Default)]
+pub struct rbtdri_Hermetic {
+    pub extras: Vec<String>,
+}
+
+impl rbtdri_Hermetic {
+    pub fn new(extras: &[String]) -> Self {
+        Self { extras: extras.to_vec() }
+    }
+
+    /// Whether an inherited variable named `key` reaches a hermetic child.
+    pub fn admits(&self, key: &str) -> bool {
+        RBTDRI_HERMETIC_KEEP.contains(&key)
+            || RBTDRI_HERMETIC_KEEP_PREFIXES.iter().any(|p| key.starts_with(p))
+            || self.extras.iter().any(|e| e == key)
+    }
+
+    /// Split an inherited environment into the pairs kept and the names
+    /// dropped, each sorted by name.
+    pub fn partition<V>(
+        &self,
+        inherited: impl IntoIterator<Item = (String, V)>,
+    ) -> (Vec<(String, V)>, Vec<String>) {
+        let mut kept = Vec::new();
+        let mut dropped = Vec::new();
+        for (key, value) in inherited {
+            if self.admits(&key) {
+                kept.push((key, value));
+            } else {
+                dropped.push(key);
+            }
+        }
+        kept.sort_by(|a, b| a.0.cmp(&b.0));
+        dropped.sort();
+        (kept, dropped)
+    }
+
+    /// The diff report over an inherited environment: what was kept, what was
+    /// dropped, and any declared extra theurge does not itself have (and so
+    /// cannot pass on). One line per entry, names only.
+    pub fn report<V>(&self, inherited: impl IntoIterator<Item = (String, V)>) -> Vec<String> {
+        let (kept, dropped) = self.partition(inherited);
+        let mut lines = vec![format!(
+            "hermetic: {} inherited variable(s) kept, {} dropped",
+            kept.len(),
+            dropped.len()
+        )];
+        lines.extend(kept.iter().map(|(k, _)| format!("  kept     {}", k)));
+        lines.extend(dropped.iter().map(|k| format!("  dropped  {}", k)));
+        for extra in &self.extras {
+            if !kept.iter().any(|(k, _)| k == extra) {
+                lines.push(format!(
+                    "  absent   {} (declared, but theurge has no such variable)",
+                    extra
+                ));
+            }
+        }
+        lines
+    }
+}
+
+/// Theurge's own environment, keyed by lossy name — the input to a hermetic
+/// partition. Values stay `OsString`, so a kept value passes through intact.
+pub fn rbtdri_inherited_env() -> Vec<(String, std::ffi::OsString)> {
+    std::env::vars_os().map(|(k, v)| (k.to_string_lossy().into_owned(), v)).collect()
+}
+
+thread_local! {
+    /// The hermetic allowlist launches on this thread build their child
+    /// environment from, or None to inherit. Set once by the runners.
+    static RBTDRI_HERMETIC: std::cell::RefCell<Option<rbtdri_Hermetic>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Make launches on this thread hermetic (Some) or inheriting (None).
+pub fn rbtdri_set_hermetic(hermetic: Option<rbtdri_Hermetic>) {
+    RBTDRI_HERMETIC.with(|h| *h.borrow_mut() = hermetic);
+}
+
+/// Whether launches on this thread are hermetic.
+pub fn rbtdri_hermetic() -> bool {
+    RBTDRI_HERMETIC.with(|h| h.borrow().is_some())
+}
+
+// ── Network isolation (reveille) ─────────────────────────────
+//
+// "Credless, no external dependency" is the reveille tier's promise; the
+// credless guard enforces the first half. Network isolation (the runners'
+// --netns) enforces the second: while a credless fixture runs, every tabtarget
+// launch enters a fresh Linux user + network namespace whose only interface is
+// loopback, so a DNS lookup or connect attempt cannot leave the host and fails.
+// Built in `rbtdri_tabtarget_command`, so the bypass launches are isolated too;
+// theurge's own non-tabtarget subprocesses are not, matching the tariff and
+// census scope.
+//
+// A failed network access is then attributed, by watching rather than by
+// reading error text. The namespace routes every address to loopback (a local
+// route for 0.0.0.0/0 and ::/0), so a connect anywhere is answered at once by
+// the namespace's own kernel instead of timing out, and a watcher forked into
+// the namespace before exec reads every packet on loopback. Each TCP SYN or
+// UDP datagram addressed off loopback — and each DNS query, whatever resolver
+// it is aimed at — is written down a per-launch tap to theurge, which turns
+// it into a hermeticity violation. Every launch is audited the same way,
+// bypass launches included, however the tabtarget reports the failure — and
+// only a packet counts: output that merely reads like a resolver failure is
+// not a reach. The engine drains the violations after each
+// case and fails the case on any, so a case that tolerates a failing
+// tabtarget still cannot pass over a network reach.
+//
+// The namespace is entered between fork and exec with raw syscalls on
+// preformatted buffers — no allocation there, nor in the watcher. A host that
+// cannot create the namespace (no unprivileged user namespaces, non-Linux) is
+// refused up front by `rbtdri_netns_probe`, never silently run unisolated.
+
+/// Bytes in one tap record: address family (4 or 6), L4 protocol, destination
+/// port (big-endian), destination address (16 bytes, IPv4 in the first four),
+/// query-name length, and the query name in DNS wire form, truncated to fit.
+pub const RBTDRI_NETNS_RECORD_LEN: usize = 96;
+
+/// Offset of the query name within a tap record.
+const RBTDRI_NETNS_RECORD_QNAME: usize = 21;
+
+/// How long a drain waits for watchers to report after their launches ended.
+const RBTDRI_NETNS_DRAIN_MILLIS: u64 = 2000;
+
+/// How often a watcher checks whether its launch has ended.
+#[cfg(target_os = "linux")]
+const RBTDRI_NETNS_WATCH_POLL_MILLIS: libc::c_int = 20;
+
+thread_local! {
+    /// Whether the runner asked for network isolation. Launches are isolated
+    /// only while the credless guard is armed too — see `rbtdri_netns_active`.
+    static RBTDRI_NETNS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    /// Violations recorded since the engine last drained them.
+    static RBTDRI_NETNS_VIOLATIONS: std::cell::RefCell<Vec<String>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+    /// Read ends of the taps of isolated launches not yet drained, each with
+    /// the tabtarget it watches.
+    #[cfg(target_os = "linux")]
+    static RBTDRI_NETNS_TAPS: std::cell::RefCell<Vec<(String, std::os::fd::OwnedFd)>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Request (true) or release (false) network isolation for credless fixtures.
+pub fn rbtdri_set_netns(requested: bool) {
+    RBTDRI_NETNS.with(|n| n.set(requested));
+}
+
+/// Whether launches on this thread run network-isolated now: isolation was
+/// requested and the running fixture is credless.
+pub fn rbtdri_netns_active() -> bool {
+    RBTDRI_NETNS.with(|n| n.get()) && rbtdri_credless_armed()
+}
+
+/// Take the hermeticity violations recorded since the last call, first
+/// collecting what every isolated launch's watcher reported.
+pub fn rbtdri_netns_take_violations() -> Vec<String> {
+    #[cfg(target_os = "linux")]
+    zrbtdri_netns_collect();
+    RBTDRI_NETNS_VIOLATIONS.with(|v| std::mem::take(&mut *v.borrow_mut()))
+}
+
+fn zrbtdri_netns_violation(launch: &str, what: &str) {
+    let violation = format!(
+        "hermeticity violation: '{}' reached for the network under isolation: {}",
+        launch, what
+    );
+    crate::rbtdrg_error_now!("rbtdri: {}", violation);
+    RBTDRI_NETNS_VIOLATIONS.with(|v| v.borrow_mut().push(violation));
+}
+
+/// The tap record for one packet seen leaving on loopback, given its
+/// ethertype and network-layer bytes — or None for a packet that reaches for
+/// nothing: a TCP segment other than an opening SYN, a datagram between
+/// loopback addresses, anything neither TCP nor UDP. A datagram to port 53 is
+/// a DNS query wherever it is addressed, and carries its query name along.
+/// Runs in the watcher, so it allocates nothing.
+pub fn rbtdri_netns_reach(ethertype: u16, packet: &[u8]) -> Option<[u8; RBTDRI_NETNS_RECORD_LEN]> {
+    const TCP: u8 = 6;
+    const UDP: u8 = 17;
+    let mut record = [0u8; RBTDRI_NETNS_RECORD_LEN];
+    let (l4, loopback) = match ethertype {
+        0x0800 if packet.len() >= 20 => {
+            let ihl = usize::from(packet[0] & 0x0f) * 4;
+            record[0] = 4;
+            record[1] = packet[9];
+            record[4..8].copy_from_slice(&packet[16..20]);
+            (packet.get(ihl..)?, packet[16] == 127)
+        }
+        0x86dd if packet.len() >= 40 => {
+            record[0] = 6;
+            record[1] = packet[6];
+            record[4..20].copy_from_slice(&packet[24..40]);
+            let loopback = packet[24..39].iter().all(|b| *b == 0) && packet[39] == 1;
+            (&packet[40..], loopback)
+        }
+        _ => return None,
+    };
+    let opening = match record[1] {
+        TCP => l4.len() >= 14 && l4[13] & 0x12 == 0x02,
+        UDP => l4.len() >= 8,
+        _ => false,
+    };
+    if !opening {
+        return None;
+    }
+    record[2..4].copy_from_slice(&l4[2..4]);
+    let dns = l4[2..4] == [0, 53];
+    if loopback && !dns {
+        return None;
+    }
+    if dns && record[1] == UDP {
+        let name = l4.get(8 + 12..).unwrap_or(&[]);
+        let len = name.len().min(RBTDRI_NETNS_RECORD_LEN - RBTDRI_NETNS_RECORD_QNAME);
+        record[RBTDRI_NETNS_RECORD_QNAME - 1] = len as u8;
+        record[RBTDRI_NETNS_RECORD_QNAME..RBTDRI_NETNS_RECORD_QNAME + len]
+            .copy_from_slice(&name[..len]);
+    }
+    Some(record)
+}
+
+/// Render a tap record as the reach it records, e.g. `tcp 192.0.2.1:80` or
+/// `udp 10.0.0.2:53 query example.com`.
+pub fn rbtdri_netns_describe(record: &[u8; RBTDRI_NETNS_RECORD_LEN]) -> String {
+    let proto = if record[1] == 6 { "tcp" } else { "udp" };
+    let port = u16::from_be_bytes([record[2], record[3]]);
+    let addr = if record[0] == 4 {
+        let octets: [u8; 4] = record[4..8].try_into().unwrap_or_default();
+        std::net::Ipv4Addr::from(octets).to_string()
+    } else {
+        let octets: [u8; 16] = record[4..20].try_into().unwrap_or_default();
+        format!("[{}]", std::net::Ipv6Addr::from(octets))
+    };
+    let mut described = format!("{} {}:{}", proto, addr, port);
+    let len = usize::from(record[RBTDRI_NETNS_RECORD_QNAME - 1]);
+    let mut name = &record[RBTDRI_NETNS_RECORD_QNAME..RBTDRI_NETNS_RECORD_QNAME + len];
+    let mut labels = Vec::new();
+    while let Some((&n, rest)) = name.split_first() {
+        let n = usize::from(n);
+        if n == 0 || n > rest.len() {
+            break;
+        }
+        labels.push(String::from_utf8_lossy(&rest[..n]).into_owned());
+        name = &rest[n..];
+    }
+    if !labels.is_empty() {
+        described.push_str(" query ");
+        described.push_str(&labels.join("."));
+    }
+    described
+}
+
+/// Read every pending tap until its watcher is done, recording a violation
+/// per distinct reach. A tap still open past the drain deadline is reported
+/// as unaudited rather than waited on.
+#[cfg(target_os = "linux")]
+fn zrbtdri_netns_collect() {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+    let taps = RBTDRI_NETNS_TAPS.with(|t| std::mem::take(&mut *t.borrow_mut()));
+    let deadline =
+        std::time::Instant::now() + std::time::Duration::from_millis(RBTDRI_NETNS_DRAIN_MILLIS);
+    for (launch, fd) in taps {
+        let mut file = std::fs::File::from(fd);
+        let mut bytes = Vec::new();
+        let mut chunk = [0u8; RBTDRI_NETNS_RECORD_LEN * 8];
+        let finished = loop {
+            match file.read(&mut chunk) {
+                Ok(0) => break true,
+                Ok(n) => bytes.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    let left = deadline.saturating_duration_since(std::time::Instant::now());
+                    if left.is_zero() {
+                        break false;
+                    }
+                    let mut pfd =
+                        libc::pollfd { fd: file.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+                    let wait = left.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+                    // SAFETY: one pollfd on the stack, for a descriptor `file` owns.
+                    unsafe {
+                        libc::poll(&mut pfd, 1, wait);
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(_) => break false,
+            }
+        };
+        let mut seen = Vec::new();
+        for record in bytes.chunks_exact(RBTDRI_NETNS_RECORD_LEN) {
+            let Ok(record) = <&[u8; RBTDRI_NETNS_RECORD_LEN]>::try_from(record) else {
+                continue;
+            };
+            let reach = rbtdri_netns_describe(record);
+            if !seen.contains(&reach) {
+                zrbtdri_netns_violation(&launch, &reach);
+                seen.push(reach);
+            }
+        }
+        if !finished {
+            zrbtdri_netns_violation(&launch, "unaudited — its watcher never reported");
+        }
+    }
+}
+
+/// Open the tap for one isolated launch: the read end is held for the drain,
+/// the write end returned for the watcher. Only the read end is non-blocking:
+/// a watcher facing a full tap waits for the drain rather than dropping a
+/// record, and a record is under PIPE_BUF, so no write of one lands short.
+/// Err when no pipe can be had.
+#[cfg(target_os = "linux")]
+fn zrbtdri_netns_tap(launch: &str) -> std::io::Result<std::os::fd::OwnedFd> {
+    use std::os::fd::FromRawFd;
+    let mut fds = [0 as libc::c_int; 2];
+    // SAFETY: pipe2 writes two descriptors into the array on success.
+    if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    // SAFETY: fcntl on a descriptor just opened above.
+    if unsafe { libc::fcntl(fds[0], libc::F_SETFL, libc::O_NONBLOCK) } != 0 {
+        let err = std::io::Error::last_os_error();
+        // SAFETY: both descriptors are open and owned by nothing else.
+        unsafe {
+            libc::close(fds[0]);
+            libc::close(fds[1]);
+        }
+        return Err(err);
+    }
+    // SAFETY: both descriptors were just opened and are owned by nothing else.
+    let (read, write) = unsafe {
+        (std::os::fd::OwnedFd::from_raw_fd(fds[0]), std::os::fd::OwnedFd::from_raw_fd(fds[1]))
+    };
+    RBTDRI_NETNS_TAPS.with(|t| t.borrow_mut().push((launch.to_string(), read)));
+    Ok(write)
+}
+
+/// Confirm this host can isolate a launch, by running a no-op under the
+/// namespace. Called by the runners before any fixture work.
+#[cfg(target_os = "linux")]
+pub fn rbtdri_netns_probe() -> Result<(), String> {
+    let mut cmd = Command::new(rbtdri_bash_program());
+    cmd.args(["-c", ":"]);
+    zrbtdri_netns_isolate(&mut cmd, None);
+    match cmd.status() {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("rbtdri: network-isolation probe exited {}", status)),
+        Err(e) => Err(format!(
+            "rbtdri: cannot create an isolated network namespace on this host: {}",
+            e
+        )),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn rbtdri_netns_probe() -> Result<(), String> {
+    Err("rbtdri: network isolation needs Linux network namespaces".to_string())
+}
+
+/// Arrange for `cmd` to enter a fresh user + network namespace, loopback up
+/// and every address routed to it. With `launch` named, a watcher reports the
+/// launch's reaches down a tap; a launch whose tap cannot be opened is
+/// recorded as a violation then and there — it would run unaudited.
+#[cfg(target_os = "linux")]
+fn zrbtdri_netns_isolate(cmd: &mut Command, launch: Option<&str>) {
+    use std::os::fd::AsRawFd;
+    use std::os::unix::process::CommandExt;
+    // SAFETY: getuid/getgid cannot fail and touch no memory.
+    let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+    let uid_map = format!("{} {} 1", uid, uid).into_bytes();
+    let gid_map = format!("{} {} 1", gid, gid).into_bytes();
+    let tap = launch.and_then(|launch| match zrbtdri_netns_tap(launch) {
+        Ok(tap) => Some(tap),
+        Err(e) => {
+            zrbtdri_netns_violation(launch, &format!("unaudited — no tap: {}", e));
+            None
+        }
+    });
+    // SAFETY: the hook runs in the forked child before exec and makes only raw
+    // syscalls on buffers formatted above — async-signal-safe. The closure
+    // owns the tap's write end, so theurge's copy closes with the Command.
+    unsafe {
+        cmd.pre_exec(move || {
+            zrbtdri_netns_enter(&uid_map, &gid_map, tap.as_ref().map(|t| t.as_raw_fd()))
+        });
+    }
+}
+
+/// Child side of `zrbtdri_netns_isolate`: unshare, map this user to itself,
+/// raise loopback, route everything to it, and open the packet socket the
+/// watcher reads — forking the watcher when there is a tap to report down.
+/// Each step's failure aborts the spawn with its errno.
+#[cfg(target_os = "linux")]
+fn zrbtdri_netns_enter(
+    uid_map: &[u8],
+    gid_map: &[u8],
+    tap: Option<libc::c_int>,
+) -> std::io::Result<()> {
+    use std::io::Error;
+    // SAFETY: raw syscalls on stack data and the caller's buffers.
+    unsafe {
+        if libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNET) != 0 {
+            return Err(Error::last_os_error());
+        }
+        zrbtdri_proc_write(c"/proc/self/setgroups", b"deny")?;
+        zrbtdri_proc_write(c"/proc/self/uid_map", uid_map)?;
+        zrbtdri_proc_write(c"/proc/self/gid_map", gid_map)?;
+
+        let sock = libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0);
+        if sock < 0 {
+            return Err(Error::last_os_error());
+        }
+        let mut req: libc::ifreq = std::mem::zeroed();
+        req.ifr_name[0] = b'l' as libc::c_char;
+        req.ifr_name[1] = b'o' as libc::c_char;
+        let raised = libc::ioctl(sock, libc::SIOCGIFFLAGS, &mut req) == 0 && {
+            req.ifr_ifru.ifru_flags |= libc::IFF_UP as libc::c_short;
+            libc::ioctl(sock, libc::SIOCSIFFLAGS, &mut req) == 0
+        } && libc::ioctl(sock, libc::SIOCGIFINDEX, &mut req) == 0;
+        let err = Error::last_os_error();
+        libc::close(sock);
+        if !raised {
+            return Err(err);
+        }
+        let lo = req.ifr_ifru.ifru_ifindex;
+
+        zrbtdri_netns_route_local(libc::AF_INET as u8, lo)?;
+        // A host without IPv6 has no IPv6 to reach for, and nothing to route.
+        let _ = zrbtdri_netns_route_local(libc::AF_INET6 as u8, lo);
+
+        let all = (libc::ETH_P_ALL as u16).to_be();
+        let watch =
+            libc::socket(libc::AF_PACKET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, all.into());
+        if watch < 0 {
+            return Err(Error::last_os_error());
+        }
+        let mut at: libc::sockaddr_ll = std::mem::zeroed();
+        at.sll_family = libc::AF_PACKET as u16;
+        at.sll_protocol = all;
+        at.sll_ifindex = lo;
+        let at_len = std::mem::size_of::<libc::sockaddr_ll>() as libc::socklen_t;
+        if libc::bind(watch, (&at as *const libc::sockaddr_ll).cast(), at_len) != 0 {
+            let err = Error::last_os_error();
+            libc::close(watch);
+            return Err(err);
+        }
+        let Some(tap) = tap else {
+            libc::close(watch);
+            return Ok(());
+        };
+        let launch = libc::getpid();
+        match libc::fork() {
+            -1 => {
+                let err = Error::last_os_error();
+                libc::close(watch);
+                Err(err)
+            }
+            0 => zrbtdri_netns_watch(watch, tap, launch),
+            _ => {
+                libc::close(watch);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Add a local route for the whole of `family`'s address space on interface
+/// `lo`, over rtnetlink, and wait for the kernel's acknowledgement.
+#[cfg(target_os = "linux")]
+unsafe fn zrbtdri_netns_route_local(family: u8, lo: libc::c_int) -> std::io::Result<()> {
+    use std::io::Error;
+    const RTM_NEWROUTE: u16 = 24;
+    const RTA_OIF: u16 = 4;
+    const RT_TABLE_LOCAL: u8 = 255;
+    const RTPROT_BOOT: u8 = 3;
+    const RT_SCOPE_HOST: u8 = 254;
+    const RTN_LOCAL: u8 = 2;
+    const NLMSG_ERROR: u16 = 2;
+    let flags = (libc::NLM_F_REQUEST | libc::NLM_F_ACK | libc::NLM_F_CREATE | libc::NLM_F_EXCL)
+        as u16;
+
+    // nlmsghdr (16) + rtmsg (12) + an RTA_OIF attribute (8).
+    let mut msg = [0u8; 36];
+    msg[0..4].copy_from_slice(&36u32.to_ne_bytes());
+    msg[4..6].copy_from_slice(&RTM_NEWROUTE.to_ne_bytes());
+    msg[6..8].copy_from_slice(&flags.to_ne_bytes());
+    msg[8..12].copy_from_slice(&1u32.to_ne_bytes());
+    msg[16] = family;
+    msg[20] = RT_TABLE_LOCAL;
+    msg[21] = RTPROT_BOOT;
+    msg[22] = RT_SCOPE_HOST;
+    msg[23] = RTN_LOCAL;
+    msg[28..30].copy_from_slice(&8u16.to_ne_bytes());
+    msg[30..32].copy_from_slice(&RTA_OIF.to_ne_bytes());
+    msg[32..36].copy_from_slice(&lo.to_ne_bytes());
+
+    let sock =
+        libc::socket(libc::AF_NETLINK, libc::SOCK_RAW | libc::SOCK_CLOEXEC, libc::NETLINK_ROUTE);
+    if sock < 0 {
+        return Err(Error::last_os_error());
+    }
+    let mut kernel: libc::sockaddr_nl = std::mem::zeroed();
+    kernel.nl_family = libc::AF_NETLINK as u16;
+    let kernel_len = std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t;
+    let mut ack = [0u8; 64];
+    let sent = libc::sendto(
+        sock,
+        msg.as_ptr().cast(),
+        msg.len(),
+        0,
+        (&kernel as *const libc::sockaddr_nl).cast(),
+        kernel_len,
+    ) == msg.len() as isize;
+    let got = if sent { libc::recv(sock, ack.as_mut_ptr().cast(), ack.len(), 0) } else { -1 };
+    let err = Error::last_os_error();
+    libc::close(sock);
+    if got < 20 {
+        return Err(err);
+    }
+    // The acknowledgement is an NLMSG_ERROR whose error field is 0 on success.
+    let kind = u16::from_ne_bytes([ack[4], ack[5]]);
+    let code = i32::from_ne_bytes([ack[16], ack[17], ack[18], ack[19]]);
+    if kind != NLMSG_ERROR || code != 0 {
+        return Err(Error::from_raw_os_error(-code));
+    }
+    Ok(())
+}
+
+/// The watcher, forked into the namespace before exec: shed every descriptor
+/// but its packet socket and tap, then write a tap record for each packet the
+/// launch sends that reaches for the network, until the launch has ended (its
+/// parent is no longer the launch) and the socket is drained. Allocation-free,
+/// and never returns.
+#[cfg(target_os = "linux")]
+unsafe fn zrbtdri_netns_watch(watch: libc::c_int, tap: libc::c_int, launch: libc::pid_t) -> ! {
+    for fd in 0..1024 {
+        if fd != watch && fd != tap {
+            libc::close(fd);
+        }
+    }
+    let mut packet = [0u8; 256];
+    loop {
+        let ended = libc::getppid() != launch;
+        let mut pfd = libc::pollfd { fd: watch, events: libc::POLLIN, revents: 0 };
+        libc::poll(&mut pfd, 1, RBTDRI_NETNS_WATCH_POLL_MILLIS);
+        loop {
+            let mut from: libc::sockaddr_ll = std::mem::zeroed();
+            let mut from_len = std::mem::size_of::<libc::sockaddr_ll>() as libc::socklen_t;
+            let n = libc::recvfrom(
+                watch,
+                packet.as_mut_ptr().cast(),
+                packet.len(),
+                libc::MSG_DONTWAIT,
+                (&mut from as *mut libc::sockaddr_ll).cast(),
+                &mut from_len,
+            );
+            if n <= 0 {
+                break;
+            }
+            // Loopback shows each packet twice; the outgoing copy is the send.
+            if from.sll_pkttype != libc::PACKET_OUTGOING {
+                continue;
+            }
+            let seen = &packet[..(n as usize).min(packet.len())];
+            if let Some(record) = rbtdri_netns_reach(u16::from_be(from.sll_protocol), seen) {
+                libc::write(tap, record.as_ptr().cast(), record.len());
+            }
+        }
+        if ended {
+            libc::_exit(0);
+        }
+    }
+}
+
+/// Write `data` to a /proc file in one syscall, as the id-map files demand.
+#[cfg(target_os = "linux")]
+unsafe fn zrbtdri_proc_write(path: &std::ffi::CStr, data: &[u8]) -> std::io::Result<()> {
+    let fd = libc::open(path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let wrote = libc::write(fd, data.as_ptr().cast(), data.len());
+    let err = std::io::Error::last_os_error();
+    libc::close(fd);
+    if wrote != data.len() as isize {
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// One funnelled launch as the transcript records it.
+#[derive(Debug)]
+pub struct rbtdri_LaunchRecord {
+    pub seq: u32,
+    pub phase: &'static str,
+    pub fixture: String,
+    pub colophon: String,
+    pub tabtarget: String,
+    pub args: Vec<String>,
+    /// Env overrides this launch applied: the credless tweak and extra_env.
+    pub env: Vec<(String, String)>,
+    pub burv_output: String,
+    pub burv_temp: String,
+    /// Whether this launch reused the prior invoke's BURV root.
+    pub chained: bool,
+    /// Whether the child's environment was built from the hermetic allowlist.
+    pub hermetic: bool,
+    /// Whether the child ran in an isolated network namespace.
+    pub netns: bool,
+    /// Launch wall-clock start, in seconds since the Unix epoch.
+    pub started_secs: u64,
+    pub duration_ms: u128,
+    /// None when the launch could not be spawned or was killed by a signal.
+    pub exit_code: Option<i32>,
+    /// Whether the case kill switch had tripped when the launch ended.
+    pub killed: bool,
+    pub stdout_file: String,
+    pub stderr_file: String,
+    pub error: Option<String>,
+}
+
+impl rbtdri_LaunchRecord {
+    /// Spill-file stem: zero-padded sequence number and colophon.
+    pub fn spill_stem(seq: u32, colophon: &str) -> String {
+        format!("{:05}-{}", seq, colophon)
+    }
+
+    /// Render as one JSON line (no trailing newline).
+    pub fn render_json(&self) -> String {
+        use crate::rbtdrr_report::rbtdrr_json_str as q;
+        let args: Vec<String> = self.args.iter().map(|a| q(a)).collect();
+        let env: Vec<String> =
+            self.env.iter().map(|(k, v)| format!("{}:{}", q(k), q(v))).collect();
+        format!(
+            "{{\"seq\":{},\"phase\":{},\"fixture\":{},\"colophon\":{},\"tabtarget\":{},\
+             \"args\":[{}],\"env\":{{{}}},\"burv_output\":{},\"burv_temp\":{},\"chained\":{},\
+             \"hermetic\":{},\"netns\":{},\"started_secs\":{},\"duration_ms\":{},\
+             \"exit_code\":{},\"killed\":{},\"stdout_file\":{},\"stderr_file\":{},\"error\":{}}}",
+            self.seq,
+            q(self.phase),
+            q(&self.fixture),
+            q(&self.colophon),
+            q(&self.tabtarget),
+            args.join(","),
+            env.join(","),
+            q(&self.burv_output),
+            q(&self.burv_temp),
+            self.chained,
+            self.hermetic,
+            self.netns,
+            self.started_secs,
+            self.duration_ms,
+            self.exit_code.map_or_else(|| "null".to_string(), |c| c.to_string()),
+            self.killed,
+            q(&self.stdout_file),
+            q(&self.stderr_file),
+            self.error.as_deref().map_or_else(|| "null".to_string(), q),
+        )
+    }
+}
+
+/// Report a transcript write failure, once per context.
+fn zrbtdri_transcript_fault(ctx: &mut rbtdri_Context, dir: &Path, e: std::io::Error) {
+    if !std::mem::replace(&mut ctx.transcript_failed, true) {
+        crate::rbtdrg_error_now!(
+            "rbtdri: invocation transcript not written under '{}': {}",
+            dir.display(),
+            e
+        );
+    }
+}
+
+/// Create a launch's stdout and stderr trace files before it spawns. None
+/// (no trace, the launch still runs) when there is no transcript dir or the
+/// files cannot be created.
+fn zrbtdri_open_traces(
+    ctx: &mut rbtdri_Context,
+    stdout_file: &str,
+    stderr_file: &str,
+) -> Option<(std::fs::File, std::fs::File)> {
+    let dir = ctx.transcript_dir.clone()?;
+    let opened = std::fs::create_dir_all(&dir).and_then(|_| {
+        Ok((
+            std::fs::File::create(dir.join(stdout_file))?,
+            std::fs::File::create(dir.join(stderr_file))?,
+        ))
+    });
+    opened.map_err(|e| zrbtdri_transcript_fault(ctx, &dir, e)).ok()
+}
+
+/// Append a launch's record to the transcript. Best-effort: the first
+/// failure is reported, later ones are silent.
+fn zrbtdri_transcribe(ctx: &mut rbtdri_Context, record: &rbtdri_LaunchRecord) {
+    let Some(dir) = ctx.transcript_dir.clone() else {
+        return;
+    };
+    let written = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(RBTDRI_TRANSCRIPT_FILE))
+        .and_then(|mut f| {
+            use std::io::Write;
+            writeln!(f, "{}", record.render_json())
+        });
+    if let Err(e) = written {
+        zrbtdri_transcript_fault(ctx, &dir, e);
+    }
+}
+
+/// Drain one of a child's output streams on its own thread, line by line
+/// until it closes: append each line to `trace` as it arrives, echo it when
+/// `echo` names the stream, and note it on the kill switch. The thread returns
+/// every byte read — the capture the case receives. A trace write failure
+/// drops the trace, not the capture. One thread per stream, so a child that
+/// fills one pipe while the other is being read cannot deadlock.
+fn zrbtdri_stream<R: std::io::Read + Send + 'static>(
+    source: Option<R>,
+    trace: Option<std::fs::File>,
+    echo: Option<(String, &'static str)>,
+    switch: Option<std::sync::Arc<rbtdri_KillSwitch>>,
+) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || match source {
+        Some(source) => zrbtdri_drain(source, trace, echo, switch),
+        None => Vec::new(),
+    })
+}
+
+fn zrbtdri_drain(
+    source: impl std::io::Read,
+    mut trace: Option<std::fs::File>,
+    echo: Option<(String, &'static str)>,
+    switch: Option<std::sync::Arc<rbtdri_KillSwitch>>,
+) -> Vec<u8> {
+    use std::io::{BufRead, Write};
+    let mut reader = std::io::BufReader::new(source);
+    let mut captured = Vec::new();
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        captured.extend_from_slice(&line);
+        if let Some(f) = trace.as_mut() {
+            if f.write_all(&line).and_then(|_| f.flush()).is_err() {
+                trace = None;
+            }
+        }
+        let text = String::from_utf8_lossy(&line);
+        let text = text.trim_end();
+        if let Some((colophon, stream)) = &echo {
+            crate::rbtdrg_trace_now!("{} {}| {}", colophon, stream, text);
+        }
+        if let Some(switch) = &switch {
+            if !text.trim().is_empty() {
+                switch.zrbtdri_note_line(text);
+            }
+        }
+    }
+    captured
+}
+
 // ── Tabtarget invocation with BURV isolation ─────────────────
 
 static RBTDRI_BASH_PROGRAM: OnceLock<String> = OnceLock::new();
@@ -451,6 +1587,9 @@ pub fn rbtdri_bash_program() -> &'static str {
 /// so the call site is unconditional. Callers chain `.args(...)`,
 /// `.current_dir(...)`, and `.env(...)` as on any `Command::new` result.
 ///
+/// Under hermetic mode the child's environment is rebuilt here from the
+/// allowlist (see `rbtdri_Hermetic`) before any launch-specific key is set.
+///
 /// The credless guard, the tariff tally, AND the census used-set recording all
 /// land here — the one constructor every tabtarget launch goes through,
 /// including the direct-Command case helpers that bypass `rbtdri_invoke*`. A
@@ -466,6 +1605,18 @@ pub fn rbtdri_tabtarget_command(tabtarget: &Path) -> Command {
     zrbtdri_census_record(tabtarget);
     let mut cmd = Command::new(rbtdri_bash_program());
     cmd.arg(rbtdrx_native_to_posix(tabtarget));
+    RBTDRI_HERMETIC.with(|h| {
+        if let Some(hermetic) = h.borrow().as_ref() {
+            let (kept, _) = hermetic.partition(rbtdri_inherited_env());
+            cmd.env_clear().envs(kept);
+        }
+    });
+    // Off Linux the runners refuse --netns up front (rbtdri_netns_probe).
+    #[cfg(target_os = "linux")]
+    if rbtdri_netns_active() {
+        let launch = tabtarget.file_name().map(|n| n.to_string_lossy());
+        zrbtdri_netns_isolate(&mut cmd, Some(launch.as_deref().unwrap_or("tabtarget")));
+    }
     if rbtdri_credless_armed() {
         cmd.env(
             RBTDRI_BURE_TWEAK_NAME_KEY,
@@ -488,13 +1639,28 @@ pub fn rbtdri_tabtarget_command(tabtarget: &Path) -> Command {
 /// refuse. Used-set recording does NOT live here: it rides
 /// `rbtdri_tabtarget_command` (reached below on the allowed path), the
 /// universal chokepoint that bypass launches also pass through.
+///
+/// Kill-switch registration also lands here, not in the Command constructor:
+/// only this path owns the spawned child and so knows the pid to kill.
+/// The invocation transcript is written here for the same reason: only this
+/// path holds the child's whole outcome.
+///
+/// The tabtarget arrives as its lookup's outcome, not a path: the one-shot
+/// chain marks are consumed before either refusal (a failed lookup, a census
+/// refusal), so an invoke that never launches cannot leave its join pending
+/// for whatever invoke comes next.
 fn rbtdri_invoke_impl(
     ctx: &mut rbtdri_Context,
     colophon: &str,
-    tabtarget: &Path,
+    tabtarget: Result<PathBuf, String>,
     args: &[&str],
     extra_env: &[(&str, &str)],
 ) -> Result<rbtdri_InvokeResult, String> {
+    let chain_next = std::mem::take(&mut ctx.chain_next);
+    let chain_pending = ctx.chain_pending.take();
+    let tabtarget = tabtarget?;
+    let tabtarget = tabtarget.as_path();
+
     if let Some(declared) = rbtdri_census_declared() {
         let permitted = rbtdri_census_permitted();
         if !declared.iter().any(|d| *d == colophon) && !permitted.iter().any(|p| *p == colophon) {
@@ -507,17 +1673,22 @@ fn rbtdri_invoke_impl(
         }
     }
 
-    let invoke_num = if std::mem::take(&mut ctx.chain_next) {
+    let (invoke_num, chained) = match chain_pending {
+        Some((name, handoff)) => zrbtdri_chain_link(ctx, &name, &handoff, colophon)?,
         // Chain off the immediately-prior invoke: reuse its root (do NOT mint a
         // fresh one or bump the counter), so bud's promotion carries that
         // invoke's current/ into this one's previous/. Depth-1 by construction.
-        ctx.invoke_count.checked_sub(1).ok_or_else(|| {
-            "rbtdri: chain_next_invoke set with no prior invoke to chain from".to_string()
-        })?
-    } else {
-        let n = ctx.invoke_count;
-        ctx.invoke_count += 1;
-        n
+        None if chain_next => (
+            ctx.invoke_count.checked_sub(1).ok_or_else(|| {
+                "rbtdri: chain_next_invoke set with no prior invoke to chain from".to_string()
+            })?,
+            true,
+        ),
+        None => {
+            let n = ctx.invoke_count;
+            ctx.invoke_count += 1;
+            (n, false)
+        }
     };
 
     let dir_name = rbtdri_invoke_dir_name(invoke_num);
@@ -568,28 +1739,178 @@ fn rbtdri_invoke_impl(
         cmd.env(key, value);
     }
 
+    // Under an armed kill switch the launch runs in its own process group so
+    // the watchdog can kill the whole tree, and a tripped switch refuses outright.
+    let switch = RBTDRI_KILL_SWITCH.with(|k| k.borrow().clone());
+    if let Some(switch) = &switch {
+        if switch.tripped() {
+            return Err(format!(
+                "rbtdri: case budget expired or run interrupted — launch of '{}' refused",
+                tabtarget.display()
+            ));
+        }
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+    }
+
+    let key = crate::rbtdry_cassette::rbtdry_Key::new(colophon, tabtarget, args);
+    let replayed = RBTDRI_CASSETTE.with(|c| {
+        let mut cassette = c.borrow_mut();
+        let cassette = cassette.as_mut().filter(|c| c.replaying())?;
+        Some(cassette.serve(&key, &burv_output))
+    });
+    if let Some(replayed) = replayed {
+        return replayed;
+    }
+
+    let seq = ctx.transcript_seq;
+    ctx.transcript_seq += 1;
+    let stem = rbtdri_LaunchRecord::spill_stem(seq, colophon);
+    let stdout_file = format!("{}.stdout", stem);
+    let stderr_file = format!("{}.stderr", stem);
+    let (trace_out, trace_err) = zrbtdri_open_traces(ctx, &stdout_file, &stderr_file).unzip();
+    let echo = RBTDRI_ECHO.with(|e| e.get());
+
+    let started_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let started = std::time::Instant::now();
     let output = cmd
-        .output()
-        .map_err(|e| format!("rbtdri: failed to execute '{}': {}", tabtarget.display(), e))?;
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .and_then(|mut child| {
+            let pgid = child.id();
+            if let Some(switch) = &switch {
+                switch.zrbtdri_register(pgid, colophon);
+            }
+            let echo_as = |stream| echo.then(|| (colophon.to_string(), stream));
+            let out =
+                zrbtdri_stream(child.stdout.take(), trace_out, echo_as("stdout"), switch.clone());
+            let err =
+                zrbtdri_stream(child.stderr.take(), trace_err, echo_as("stderr"), switch.clone());
+            let status = child.wait();
+            let stdout = out.join().unwrap_or_default();
+            let stderr = err.join().unwrap_or_default();
+            if let Some(switch) = &switch {
+                switch.zrbtdri_release(pgid);
+            }
+            Ok(std::process::Output { status: status?, stdout, stderr })
+        })
+        .map_err(|e| format!("rbtdri: failed to execute '{}': {}", tabtarget.display(), e));
 
-    Ok(rbtdri_InvokeResult {
+    let mut env: Vec<(String, String)> = Vec::new();
+    if rbtdri_credless_armed() {
+        env.push((
+            RBTDRI_BURE_TWEAK_NAME_KEY.to_string(),
+            crate::rbtdgc_consts::RBTDGC_TWEAK_CREDLESS_GUARD.to_string(),
+        ));
+    }
+    env.extend(extra_env.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+    let record = rbtdri_LaunchRecord {
+        seq,
+        phase: RBTDRI_PHASE.with(|p| p.get()),
+        fixture: ctx.fixture.clone(),
+        colophon: colophon.to_string(),
+        tabtarget: tabtarget.display().to_string(),
+        args: args.iter().map(|a| a.to_string()).collect(),
+        env,
+        burv_output: burv_output.display().to_string(),
+        burv_temp: burv_temp.display().to_string(),
+        chained,
+        hermetic: rbtdri_hermetic(),
+        netns: rbtdri_netns_active(),
+        started_secs,
+        duration_ms: started.elapsed().as_millis(),
+        exit_code: output.as_ref().ok().and_then(|o| o.status.code()),
+        killed: switch.as_ref().is_some_and(|s| s.tripped()),
+        stdout_file,
+        stderr_file,
+        error: output.as_ref().err().cloned(),
+    };
+    zrbtdri_transcribe(ctx, &record);
+    let output = output?;
+
+    let result = rbtdri_InvokeResult {
         stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
         stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
         exit_code: output.status.code().unwrap_or(-1),
         burv_output,
-    })
+    };
+    // Recording failure fails the launch: a tape with a silent gap would
+    // replay as a divergence nobody can explain.
+    RBTDRI_CASSETTE.with(|c| match c.borrow_mut().as_mut() {
+        Some(cassette) => cassette.capture(&key, &result),
+        None => Ok(()),
+    })?;
+    Ok(result)
 }
 
-/// Invoke a fixture-imprinted tabtarget (colophon + ctx.fixture). For crucible
-/// fixtures the fixture name is also a nameplate moniker, which is the
-/// imprint shape this resolves against.
+/// Resolve a chain-session join to the invoke number it runs under, and
+/// whether that reuses an earlier link's root. The first link mints a fresh
+/// root for the session; every later one reuses it, after checking that the
+/// hand-off facts are waiting in its `current/` for bud to promote.
+fn zrbtdri_chain_link(
+    ctx: &mut rbtdri_Context,
+    name: &str,
+    handoff: &[String],
+    colophon: &str,
+) -> Result<(u32, bool), String> {
+    let index = ctx
+        .chains
+        .iter()
+        .position(|c| c.name == name)
+        .ok_or_else(|| format!("rbtdri: chain '{}' closed before its joined invoke", name))?;
+    let Some(invoke_num) = ctx.chains[index].invoke_num else {
+        if !handoff.is_empty() {
+            return Err(format!(
+                "rbtdri: chain '{}' was reopened — '{}' has no link to take a hand-off from",
+                name, colophon
+            ));
+        }
+        let n = ctx.invoke_count;
+        ctx.invoke_count += 1;
+        ctx.chains[index].invoke_num = Some(n);
+        ctx.chains[index].links.push(colophon.to_string());
+        return Ok((n, false));
+    };
+
+    let current = ctx
+        .burv_output_root
+        .join(rbtdri_invoke_dir_name(invoke_num))
+        .join(RBTDRI_BURV_OUTPUT_SUBDIR);
+    let missing: Vec<&str> = handoff
+        .iter()
+        .filter(|f| std::fs::read_to_string(current.join(f)).map_or(true, |v| v.trim().is_empty()))
+        .map(String::as_str)
+        .collect();
+    let session = &mut ctx.chains[index];
+    if !missing.is_empty() {
+        return Err(format!(
+            "rbtdri: chain '{}' hand-off to '{}' broken — '{}' left no {} in {}",
+            name,
+            colophon,
+            session.links.last().map_or("", String::as_str),
+            missing.join(", "),
+            current.display()
+        ));
+    }
+    session.links.push(colophon.to_string());
+    Ok((invoke_num, true))
+}
+
+/// Invoke a fixture-imprinted tabtarget (colophon + ctx.imprint). For crucible
+/// fixtures the imprint is a nameplate moniker — the fixture name itself, or
+/// the nameplate the manifest binds it to.
 pub fn rbtdri_invoke(
     ctx: &mut rbtdri_Context,
     colophon: &str,
     args: &[&str],
 ) -> Result<rbtdri_InvokeResult, String> {
-    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, &ctx.fixture)?;
-    rbtdri_invoke_impl(ctx, colophon, &tabtarget, args, &[])
+    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, &ctx.imprint);
+    rbtdri_invoke_impl(ctx, colophon, tabtarget, args, &[])
 }
 
 /// Invoke a fixture-imprinted tabtarget (like `rbtdri_invoke`) with extra
@@ -601,8 +1922,8 @@ pub fn rbtdri_invoke_env(
     args: &[&str],
     extra_env: &[(&str, &str)],
 ) -> Result<rbtdri_InvokeResult, String> {
-    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, &ctx.fixture)?;
-    rbtdri_invoke_impl(ctx, colophon, &tabtarget, args, extra_env)
+    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, &ctx.imprint);
+    rbtdri_invoke_impl(ctx, colophon, tabtarget, args, extra_env)
 }
 
 /// Invoke a global tabtarget (no imprint) with optional extra environment variables.
@@ -612,19 +1933,19 @@ pub fn rbtdri_invoke_global(
     args: &[&str],
     extra_env: &[(&str, &str)],
 ) -> Result<rbtdri_InvokeResult, String> {
-    let tabtarget = rbtdri_find_tabtarget_global(&ctx.project_root, colophon)?;
-    rbtdri_invoke_impl(ctx, colophon, &tabtarget, args, extra_env)
+    let tabtarget = rbtdri_find_tabtarget_global(&ctx.project_root, colophon);
+    rbtdri_invoke_impl(ctx, colophon, tabtarget, args, extra_env)
 }
 
-/// Invoke a tabtarget with an explicit imprint (overrides ctx.fixture for discovery).
+/// Invoke a tabtarget with an explicit imprint (overrides ctx.imprint for discovery).
 pub fn rbtdri_invoke_imprint(
     ctx: &mut rbtdri_Context,
     colophon: &str,
     imprint: &str,
     args: &[&str],
 ) -> Result<rbtdri_InvokeResult, String> {
-    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, imprint)?;
-    rbtdri_invoke_impl(ctx, colophon, &tabtarget, args, &[])
+    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, imprint);
+    rbtdri_invoke_impl(ctx, colophon, tabtarget, args, &[])
 }
 
 /// Invoke a tabtarget with an explicit imprint and extra environment variables
@@ -636,8 +1957,8 @@ pub fn rbtdri_invoke_imprint_env(
     args: &[&str],
     extra_env: &[(&str, &str)],
 ) -> Result<rbtdri_InvokeResult, String> {
-    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, imprint)?;
-    rbtdri_invoke_impl(ctx, colophon, &tabtarget, args, extra_env)
+    let tabtarget = rbtdri_find_tabtarget(&ctx.project_root, colophon, imprint);
+    rbtdri_invoke_impl(ctx, colophon, tabtarget, args, extra_env)
 }
 
 // ── BURV fact file reading ───────────────────────────────────
@@ -741,7 +2062,7 @@ pub(crate) fn rbtdri_invoke_or_fail(
     Ok(result)
 }
 
-/// Run an ordain on `vessel_dir` and return the captured hallmark string.
+/// Run an ordain on `vessel_dir` and return the captured, shape-checked hallmark.
 /// Writes invocation logs to `dir` under the `label` prefix; returns Fail
 /// verdict-bearing Err so callers can early-return cleanly.
 pub(crate) fn rbtdri_ordain_capture(
@@ -750,7 +2071,7 @@ pub(crate) fn rbtdri_ordain_capture(
     vessel_dir: &str,
     extra_env: &[(&str, &str)],
     label: &str,
-) -> Result<String, rbtdre_Verdict> {
+) -> Result<rbtdrz_Hallmark, rbtdre_Verdict> {
     let result = rbtdri_invoke_or_fail(
         ctx,
         RBTDGC_VERB_ORDAIN,
@@ -761,25 +2082,26 @@ pub(crate) fn rbtdri_ordain_capture(
         dir,
         label,
     )?;
-    let hallmark = rbtdri_read_burv_fact(&result, RBTDRI_FACT_HALLMARK).map_err(|e| {
+    let hallmark: rbtdrz_Hallmark = rbtdrz_read_fact(&result, RBTDRI_FACT_HALLMARK).map_err(|e| {
         rbtdre_Verdict::Fail(format!(
             "read hallmark fact after {} {}: {}",
             RBTDGC_VERB_ORDAIN, vessel_dir, e
         ))
     })?;
-    let _ = std::fs::write(dir.join(format!("{}-hallmark.txt", label)), &hallmark);
+    let _ = std::fs::write(dir.join(format!("{}-hallmark.txt", label)), hallmark.as_str());
     Ok(hallmark)
 }
 
 /// Same as `rbtdri_ordain_capture` but also returns gar_root and ark_stem facts
 /// needed by verification tails to construct local docker refs after wrest.
+/// The stem must name the captured hallmark.
 pub(crate) fn rbtdri_ordain_capture_full(
     ctx: &mut rbtdri_Context,
     dir: &Path,
     vessel_dir: &str,
     extra_env: &[(&str, &str)],
     label: &str,
-) -> Result<(String, String, String), rbtdre_Verdict> {
+) -> Result<(rbtdrz_Hallmark, rbtdrz_GarRoot, rbtdrz_ArkStem), rbtdre_Verdict> {
     let result = rbtdri_invoke_or_fail(
         ctx,
         RBTDGC_VERB_ORDAIN,
@@ -790,25 +2112,33 @@ pub(crate) fn rbtdri_ordain_capture_full(
         dir,
         label,
     )?;
-    let hallmark = rbtdri_read_burv_fact(&result, RBTDRI_FACT_HALLMARK).map_err(|e| {
+    let hallmark: rbtdrz_Hallmark = rbtdrz_read_fact(&result, RBTDRI_FACT_HALLMARK).map_err(|e| {
         rbtdre_Verdict::Fail(format!(
             "read hallmark fact after {} {}: {}",
             RBTDGC_VERB_ORDAIN, vessel_dir, e
         ))
     })?;
-    let gar_root = rbtdri_read_burv_fact(&result, RBTDRI_FACT_GAR_ROOT).map_err(|e| {
+    let gar_root = rbtdrz_read_fact(&result, RBTDRI_FACT_GAR_ROOT).map_err(|e| {
         rbtdre_Verdict::Fail(format!(
             "read gar_root fact after {} {}: {}",
             RBTDGC_VERB_ORDAIN, vessel_dir, e
         ))
     })?;
-    let ark_stem = rbtdri_read_burv_fact(&result, RBTDRI_FACT_ARK_STEM).map_err(|e| {
+    let ark_stem: rbtdrz_ArkStem = rbtdrz_read_fact(&result, RBTDRI_FACT_ARK_STEM).map_err(|e| {
         rbtdre_Verdict::Fail(format!(
             "read ark_stem fact after {} {}: {}",
             RBTDGC_VERB_ORDAIN, vessel_dir, e
         ))
     })?;
-    let _ = std::fs::write(dir.join(format!("{}-hallmark.txt", label)), &hallmark);
+    // Each fact is well-formed alone; a stem naming another hallmark would
+    // still build refs to a subtree this ordain never pushed.
+    if ark_stem.hallmark() != hallmark.as_str() {
+        return Err(rbtdre_Verdict::Fail(format!(
+            "ordain {} facts disagree: ark_stem '{}' names a different hallmark than '{}'",
+            vessel_dir, ark_stem, hallmark
+        )));
+    }
+    let _ = std::fs::write(dir.join(format!("{}-hallmark.txt", label)), hallmark.as_str());
     Ok((hallmark, gar_root, ark_stem))
 }
 
@@ -829,10 +2159,10 @@ pub(crate) fn rbtdri_gar_ref_categorical(category: &str, basename: &str, hallmar
 /// Fact-rooted GAR ref: `{gar_root}/{ark_stem}/{basename}:{hallmark}` — the
 /// per-vessel build namespace named by ordain-captured facts.
 pub(crate) fn rbtdri_gar_ref_fact(
-    gar_root: &str,
-    ark_stem: &str,
+    gar_root: &rbtdrz_GarRoot,
+    ark_stem: &rbtdrz_ArkStem,
     basename: &str,
-    hallmark: &str,
+    hallmark: &rbtdrz_Hallmark,
 ) -> String {
     format!("{}/{}/{}:{}", gar_root, ark_stem, basename, hallmark)
 }
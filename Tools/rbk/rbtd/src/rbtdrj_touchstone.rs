@@ -926,6 +926,8 @@ pub static RBTDRJ_FIXTURE_TOUCHSTONE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRJ_CASES_TOUCHSTONE,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(20) },
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRJ_FIXTURE_TOUCHSTONE.cases.len() == 20);
@@ -469,7 +469,9 @@ pub static RBTDRK_FIXTURE_FREEHOLD_ESTABLISH: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRK_CASES_FREEHOLD_ESTABLISH,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 const _: () = assert!(RBTDRK_FIXTURE_FREEHOLD_ESTABLISH.cases.len() == 6);
 
@@ -489,6 +491,8 @@ pub static RBTDRK_FIXTURE_FREEHOLD_CHURN: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRK_CASES_FREEHOLD_CHURN,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 const _: () = assert!(RBTDRK_FIXTURE_FREEHOLD_CHURN.cases.len() == 1);
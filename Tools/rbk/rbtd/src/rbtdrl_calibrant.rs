@@ -246,7 +246,9 @@ pub static RBTDRL_FIXTURE_VERDICTS: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_VERDICTS,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_VERDICTS.cases.len() == 4);
 
@@ -257,7 +259,9 @@ pub static RBTDRL_FIXTURE_FAIL_FAST: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_FAIL_FAST,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_FAIL_FAST.cases.len() == 3);
 
@@ -268,7 +272,9 @@ pub static RBTDRL_FIXTURE_PROGRESSING: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_PROGRESSING,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_PROGRESSING.cases.len() == 2);
 
@@ -279,7 +285,9 @@ pub static RBTDRL_FIXTURE_SENTINEL: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_SENTINEL,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_SENTINEL.cases.len() == 1);
 
@@ -290,7 +298,9 @@ pub static RBTDRL_FIXTURE_COVERAGE_ALIGNED: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_COVERAGE_ALIGNED,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_COVERAGE_ALIGNED.cases.len() == 1);
 
@@ -301,7 +311,9 @@ pub static RBTDRL_FIXTURE_COVERAGE_UNDECLARED: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_COVERAGE_UNDECLARED,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_COVERAGE_UNDECLARED.cases.len() == 1);
 
@@ -312,6 +324,8 @@ pub static RBTDRL_FIXTURE_COVERAGE_UNUSED: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRL_CASES_COVERAGE_UNUSED,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRL_FIXTURE_COVERAGE_UNUSED.cases.len() == 1);
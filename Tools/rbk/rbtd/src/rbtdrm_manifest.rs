@@ -46,6 +46,10 @@ pub const RBTDRM_FIXTURE_TADMOR: &str = "tadmor";
 pub const RBTDRM_FIXTURE_MORIAH: &str = "moriah";
 pub const RBTDRM_FIXTURE_SRJCL: &str = "srjcl";
 pub const RBTDRM_FIXTURE_PLUML: &str = "pluml";
+// Chaos crucible over the tadmor nameplate (rbtdrc_chaos): degrades the
+// sentry case by case and holds the bottle to failing closed. Its imprint is
+// tadmor, not its own name — see rbtdrm_fixture_imprint.
+pub const RBTDRM_FIXTURE_CHAOS_TADMOR: &str = "chaos-tadmor";
 // Bare fixtures (GCP credentials, no container runtime)
 pub const RBTDRM_FIXTURE_HALLMARK_LIFECYCLE: &str = "hallmark-lifecycle";
 pub const RBTDRM_FIXTURE_BATCH_VOUCH: &str = "batch-vouch";
@@ -229,6 +233,24 @@ pub const RBTDRM_FIXTURE_TOUCHSTONE: &str = "touchstone";
 // "kludge sentry" are composed at the call site from RBTDGC_VERB_KLUDGE and the
 // relevant RBTDGC_CONTAINER_* constant.
 
+/// The imprint a fixture's imprinted tabtargets resolve against. A crucible
+/// fixture is named for its nameplate; a second crucible fixture over the same
+/// nameplate cannot be, so it is bound here. Every other fixture is its own
+/// imprint.
+pub fn rbtdrm_fixture_imprint(fixture: &str) -> &str {
+    match fixture {
+        RBTDRM_FIXTURE_CHAOS_TADMOR => RBTDRM_FIXTURE_TADMOR,
+        _ => fixture,
+    }
+}
+
+/// Whether a fixture may run from a cassette under `--replay`. Chaos-tadmor
+/// may not: its cases degrade a live sentry and time its recovery, and a tape
+/// that plays back a kill and a settled PID 1 proves nothing about either.
+pub fn rbtdrm_replayable(fixture: &str) -> bool {
+    fixture != RBTDRM_FIXTURE_CHAOS_TADMOR
+}
+
 /// Per-fixture required colophons. Returns None for unknown fixtures.
 pub fn rbtdrm_required_colophons(fixture: &str) -> Option<&'static [&'static str]> {
     match fixture {
@@ -243,20 +265,33 @@ pub fn rbtdrm_required_colophons(fixture: &str) -> Option<&'static [&'static str
             RBTDGC_CRUCIBLE_BARK,
             RBTDGC_CRUCIBLE_ACTIVE,
         ]),
-        // srjcl reaches the bottle to probe jupyter, but never execs into
-        // sentry or pentacle.
+        // The chaos crucible degrades sentry through writ and, for the
+        // container kill and restart, smite; it drives the ifrit egress
+        // battery from the bottle (bark) and never execs into pentacle.
+        RBTDRM_FIXTURE_CHAOS_TADMOR => Some(&[
+            RBTDGC_CRUCIBLE_CHARGE,
+            RBTDGC_CRUCIBLE_QUENCH,
+            RBTDGC_CRUCIBLE_WRIT,
+            RBTDGC_CRUCIBLE_SMITE,
+            RBTDGC_CRUCIBLE_BARK,
+            RBTDGC_CRUCIBLE_ACTIVE,
+        ]),
+        // srjcl reaches the bottle to probe jupyter, and execs into sentry
+        // only to snapshot its config for the golden; never into pentacle.
         RBTDRM_FIXTURE_SRJCL => Some(&[
             RBTDGC_CRUCIBLE_CHARGE,
             RBTDGC_CRUCIBLE_QUENCH,
+            RBTDGC_CRUCIBLE_WRIT,
             RBTDGC_CRUCIBLE_BARK,
             RBTDGC_CRUCIBLE_ACTIVE,
         ]),
-        // pluml drives the rendering server over host-side HTTP alone; charge
-        // and quench (with their active assertions) are its whole colophon
-        // surface.
+        // pluml drives the rendering server over host-side HTTP; beyond charge
+        // and quench (with their active assertions), it execs into sentry only
+        // to snapshot its config for the golden.
         RBTDRM_FIXTURE_PLUML => Some(&[
             RBTDGC_CRUCIBLE_CHARGE,
             RBTDGC_CRUCIBLE_QUENCH,
+            RBTDGC_CRUCIBLE_WRIT,
             RBTDGC_CRUCIBLE_ACTIVE,
         ]),
         RBTDRM_FIXTURE_HALLMARK_LIFECYCLE => Some(&[
@@ -498,6 +533,9 @@ pub fn rbtdrm_permitted_colophons(fixture: &str) -> &'static [&'static str] {
         // Banish fires only when the reset's divine finds the pinned
         // touchmark already present — the steady-state pass never reaches it.
         RBTDRM_FIXTURE_CHAINING_LIVERY => &[RBTDGC_BANISH_LODE],
+        // Bark fires only when an operator runs the policy cases (the ifrit
+        // probes) against pluml by hand — its own set never reaches the bottle.
+        RBTDRM_FIXTURE_PLUML => &[RBTDGC_CRUCIBLE_BARK],
         _ => &[],
     }
 }
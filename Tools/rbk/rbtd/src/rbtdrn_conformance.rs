@@ -1275,5 +1275,7 @@ pub static RBTDRN_FIXTURE_CONFORMANCE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRN_CASES_CONFORMANCE,
     credless: true,
+    read_only: true,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
@@ -49,9 +49,9 @@ use crate::rbtdre_engine::{
 };
 use crate::rbtdri_invocation::{
     rbtdri_gar_ref_categorical, rbtdri_gar_ref_fact, rbtdri_invoke_or_fail, rbtdri_ordain_capture,
-    rbtdri_ordain_capture_full, rbtdri_read_burv_fact, rbtdri_Context, RBTDRI_BURE_CONFIRM_KEY,
-    RBTDRI_BURE_CONFIRM_SKIP,
+    rbtdri_ordain_capture_full, rbtdri_Context, RBTDRI_BURE_CONFIRM_KEY, RBTDRI_BURE_CONFIRM_SKIP,
 };
+use crate::rbtdrz_facts::{rbtdrz_read_fact, rbtdrz_Touchmark};
 use crate::rbtdgc_consts::{
     RBTDGC_ABJURE_HALLMARK,
     RBTDGC_ANOINT_GRAFT,
@@ -147,6 +147,9 @@ const RBTDRO_FORGE_BASE_ANCHOR_VAR: &str = "RBRV_IMAGE_1_ANCHOR";
 /// rbgc_constants.sh RBF_FACT_LODE_TOUCHMARK.
 const RBTDRO_FACT_LODE_TOUCHMARK: &str = "rbf_fact_lode_touchmark";
 
+/// Chain session the forge vessel's ensconce->feoff succession runs in.
+const RBTDRO_CHAIN_TOUCHMARK: &str = "forge-touchmark";
+
 /// GAR Lode namespace root in an elected anchor locator. Mirrors
 /// rbgc_constants.sh RBGC_GAR_CATEGORY_LODES.
 const RBTDRO_LODE_ROOT: &str = "rbi_ld";
@@ -303,7 +306,7 @@ fn rbtdro_ensconce(
     dir: &Path,
     vessel_sigil: &str,
     label: &str,
-) -> Result<String, rbtdre_Verdict> {
+) -> Result<rbtdrz_Touchmark, rbtdre_Verdict> {
     let result = rbtdri_invoke_or_fail(
         ctx,
         "ensconce",
@@ -318,17 +321,21 @@ fn rbtdro_ensconce(
     // current/. Read it now, before the chained feoff runs: that feoff's
     // dispatch promotes this current/ into its previous/ (where feoff reads the
     // fact), moving it out of current/ where this read looks.
-    let touchmark = rbtdri_read_burv_fact(&result, RBTDRO_FACT_LODE_TOUCHMARK).map_err(|e| {
-        rbtdre_Verdict::Fail(format!("read touchmark fact after ensconce {}: {}", vessel_sigil, e))
-    })?;
-    let _ = std::fs::write(dir.join(format!("{}-touchmark.txt", label)), &touchmark);
+    let touchmark: rbtdrz_Touchmark =
+        rbtdrz_read_fact(&result, RBTDRO_FACT_LODE_TOUCHMARK).map_err(|e| {
+            rbtdre_Verdict::Fail(format!(
+                "read touchmark fact after ensconce {}: {}",
+                vessel_sigil, e
+            ))
+        })?;
+    let _ = std::fs::write(dir.join(format!("{}-touchmark.txt", label)), touchmark.as_str());
     Ok(touchmark)
 }
 
 /// Feoff one vessel's base anchor from the chained bole touchmark (rbw-rvf).
 /// The chain LINK extracted out of conjure: it reads the touchmark the preceding
 /// ensconce handed forward and rewrites RBRV_IMAGE_n_ANCHOR. Must be chained off
-/// that ensconce (chain_next_invoke before it) so the touchmark fact lands in
+/// that ensconce (joined to its chain session) so the touchmark fact lands in
 /// feoff's previous/. Leaves conjure a pure head — ordain reads no fact.
 fn rbtdro_feoff(
     ctx: &mut rbtdri_Context,
@@ -494,11 +501,11 @@ fn zrbtdro_conclave_reliquary_impl(
         Err(v) => return v,
     };
 
-    let touchmark = match rbtdri_read_burv_fact(&result, RBTDRO_FACT_LODE_TOUCHMARK) {
+    let touchmark: rbtdrz_Touchmark = match rbtdrz_read_fact(&result, RBTDRO_FACT_LODE_TOUCHMARK) {
         Ok(s) => s,
         Err(e) => return rbtdre_Verdict::Fail(format!("read reliquary fact: {}", e)),
     };
-    let _ = std::fs::write(dir.join("reliquary-touchmark.txt"), &touchmark);
+    let _ = std::fs::write(dir.join("reliquary-touchmark.txt"), touchmark.as_str());
 
     // Wildcard-yoke: single invocation writes RBRV_RELIQUARY into every
     // vessel under ${RBRR_VESSEL_DIR}.
@@ -558,7 +565,9 @@ pub static RBTDRO_FIXTURE_KLUDGE_TADMOR: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRO_CASES_KLUDGE_TADMOR,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(600), invocations: None },
+    case_timeout_secs: Some(600),
 };
 
 /// Build ccyolo sentry and bottle locally, then anoint graft-demo off the
@@ -824,10 +833,14 @@ fn zrbtdro_ordain_airgap_chain_impl(ctx: &mut rbtdri_Context, dir: &Path) -> rbt
 
     // Ensconce the forge vessel's upstream rust base into a bole Lode. Capture is
     // pure — it emits the touchmark chaining fact but writes no vessel config, so
-    // there is nothing to commit yet, and ensconce must immediately precede feoff
-    // so the depth-1 chain carries the touchmark forward. Capture the fresh
-    // touchmark now; feoff below must rewrite the forge anchor to it (a stale
-    // committed anchor proves feoff never fired).
+    // there is nothing to commit yet. Ensconce opens the touchmark chain session
+    // that carries the touchmark forward to feoff. Capture the fresh touchmark
+    // now; feoff below must rewrite the forge anchor to it (a stale committed
+    // anchor proves feoff never fired).
+    ctx.chain_open(RBTDRO_CHAIN_TOUCHMARK);
+    if let Err(e) = ctx.chain_into(RBTDRO_CHAIN_TOUCHMARK, &[]) {
+        return rbtdre_Verdict::Fail(e);
+    }
     let touchmark = match rbtdro_ensconce(ctx, dir, forge_sigil, "ensconce-upstream") {
         Ok(t) => t,
         Err(v) => return v,
@@ -835,11 +848,15 @@ fn zrbtdro_ordain_airgap_chain_impl(ctx: &mut rbtdri_Context, dir: &Path) -> rbt
 
     // Chain feoff off the ensconce above: theurge isolates each invoke in its own
     // BURV root, so without this the touchmark fact never lands in feoff's
-    // previous/ and the express-or-chain resolve dies on the broken chain. This
-    // makes feoff reuse the ensconce's root, so bud promotes the touchmark into
-    // feoff's previous/ — the operator's shared ../output-buk flow, restored for
-    // just this pair. (Ordain below is NOT chained — conjure reads no fact.)
-    ctx.chain_next_invoke();
+    // previous/ and the express-or-chain resolve dies on the broken chain. The
+    // session makes feoff reuse the ensconce's root, so bud promotes the
+    // touchmark into feoff's previous/ — the operator's shared ../output-buk
+    // flow, restored for just this pair — and refuses the launch outright if the
+    // touchmark is not waiting there. (Ordain below is NOT chained — conjure
+    // reads no fact.)
+    if let Err(e) = ctx.chain_into(RBTDRO_CHAIN_TOUCHMARK, &[RBTDRO_FACT_LODE_TOUCHMARK]) {
+        return rbtdre_Verdict::Fail(e);
+    }
 
     // Feoff the forge vessel — the chain LINK extracted out of conjure reads the
     // touchmark the ensconce handed forward and writes RBRV_IMAGE_n_ANCHOR. The
@@ -848,6 +865,9 @@ fn zrbtdro_ordain_airgap_chain_impl(ctx: &mut rbtdri_Context, dir: &Path) -> rbt
     if let Err(v) = rbtdro_feoff(ctx, dir, forge_sigil, "feoff-forge") {
         return v;
     }
+    if let Err(e) = ctx.chain_close(RBTDRO_CHAIN_TOUCHMARK) {
+        return rbtdre_Verdict::Fail(e);
+    }
 
     // Assert feoff fired: the forge anchor must now be the Lode locator for the
     // touchmark the ensconce just minted. A broken chain or a no-op (the bug this
@@ -1012,7 +1032,7 @@ fn zrbtdro_ordain_bind_plantuml_impl(ctx: &mut rbtdri_Context, dir: &Path) -> rb
         Ok(r) => r,
         Err(v) => return v,
     };
-    if !plumb_compact.stdout.contains(&hallmark) {
+    if !plumb_compact.stdout.contains(hallmark.as_str()) {
         return rbtdre_Verdict::Fail(format!(
             "plumb_compact: hallmark '{}' not in stdout",
             hallmark
@@ -1221,6 +1241,8 @@ pub static RBTDRO_FIXTURE_ONBOARDING_SEQUENCE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRO_CASES_ONBOARDING_SEQUENCE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: Some(60), max_secs: None, invocations: None },
+    case_timeout_secs: Some(3600),
 };
 const _: () = assert!(RBTDRO_FIXTURE_ONBOARDING_SEQUENCE.cases.len() == 8);
@@ -498,6 +498,8 @@ pub static RBTDRP_FIXTURE_DEPOT_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRP_CASES_DEPOT_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 const _: () = assert!(RBTDRP_FIXTURE_DEPOT_LIFECYCLE.cases.len() == 6);
@@ -0,0 +1,366 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRP — execution plan for `rbtd <fixture> --plan` and `rbtd suite <suite> --plan`
+//
+// A plan runs nothing: no tree guard, no context, no charge. It resolves what
+// a run would do — each fixture's disposition, credless guard, case budget,
+// cases (after any selection), required and permitted colophons from
+// rbtdrm_manifest, and declared tariff — and sums two ETA envelopes: declared
+// (the rbtd_list_fixtures sum, narrowed to the plan's fixtures) and observed
+// (per-fixture min/max elapsed from the dowse history, when a log dir is
+// reachable). A fixture whose tariff is UNCHECKED contributes nothing to the
+// declared envelope and is flagged, so the envelope is never read as complete
+// when it is not.
+//
+// Rendered twice from one model: text lines for the console and a single JSON
+// document for tooling, written into the trace root beside the run report.
+
+use std::path::Path;
+
+use crate::rbtdre_engine::{
+    rbtdre_Fixture, rbtdre_Selection, rbtdre_Tariff, rbtdre_select_cases,
+    rbtdre_tariff_declared,
+};
+use crate::rbtdrm_manifest::{rbtdrm_permitted_colophons, rbtdrm_required_colophons};
+use crate::rbtdrr_report::{
+    rbtdrr_json_opt_u64, rbtdrr_json_str, rbtdrr_json_tariff_declared,
+};
+use crate::rbtdrw_dowse::rbtdrw_History;
+
+/// Plan JSON filename, written into the trace root.
+pub const RBTDRP_PLAN_FILE: &str = "plan.json";
+
+/// Station log dir env var, read for observed history: BURS_LOG_DIR as BUK
+/// dispatch resolved it (BURV override included), the dir dowse is handed.
+pub const RBTDRP_LOG_DIR_KEY: &str = "BURD_LOG_DIR";
+
+/// Observed elapsed history for one fixture, summarized from dowse sightings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrp_Observed {
+    pub runs: usize,
+    pub min_secs: u64,
+    pub max_secs: u64,
+    pub last_secs: u64,
+}
+
+/// One planned case: name, tags, and its own declared tariff.
+#[derive(Debug, Clone)]
+pub struct rbtdrp_PlannedCase {
+    pub name: &'static str,
+    pub tags: &'static [&'static str],
+    pub tariff: rbtdre_Tariff,
+}
+
+/// One fixture as it would run.
+#[derive(Debug, Clone)]
+pub struct rbtdrp_FixturePlan {
+    pub name: &'static str,
+    pub disposition: String,
+    pub credless: bool,
+    /// Effective per-case budget — fixture declaration over the suite default.
+    pub case_timeout_secs: Option<u64>,
+    pub cases: Vec<rbtdrp_PlannedCase>,
+    /// Declared case count; above `cases.len()` when a selection narrows the run.
+    pub declared_cases: usize,
+    /// None when the manifest has no entry for the fixture.
+    pub required: Option<&'static [&'static str]>,
+    pub permitted: &'static [&'static str],
+    pub tariff: rbtdre_Tariff,
+    pub observed: Option<rbtdrp_Observed>,
+}
+
+impl rbtdrp_FixturePlan {
+    /// No tariff bound declared — the fixture is never tariff-evaluated and
+    /// adds nothing to the declared envelope.
+    pub fn unchecked(&self) -> bool {
+        self.tariff == rbtdre_Tariff::UNCHECKED
+    }
+}
+
+/// Summed ETA envelope over a plan's fixtures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct rbtdrp_Envelope {
+    pub min_secs: u64,
+    pub max_secs: u64,
+    /// Fixtures that contributed nothing (undeclared, or no history).
+    pub missing: usize,
+}
+
+/// A resolved plan: `mode` is "suite" or "fixture", as in the run report.
+#[derive(Debug, Clone)]
+pub struct rbtdrp_Plan {
+    pub mode: &'static str,
+    pub name: String,
+    pub fixtures: Vec<rbtdrp_FixturePlan>,
+    /// Why observed history is absent, when it is (unset or unreadable log dir).
+    pub history_note: Option<String>,
+}
+
+/// Summarize a fixture's sightings from the dowse history, if it has any.
+fn zrbtdrp_observed(history: Option<&rbtdrw_History>, fixture: &str) -> Option<rbtdrp_Observed> {
+    let mut sightings = history?.fixtures.get(fixture)?.clone();
+    if sightings.is_empty() {
+        return None;
+    }
+    sightings.sort();
+    Some(rbtdrp_Observed {
+        runs: sightings.len(),
+        min_secs: sightings.iter().map(|s| s.1).min().unwrap_or(0),
+        max_secs: sightings.iter().map(|s| s.1).max().unwrap_or(0),
+        last_secs: sightings.last().map(|s| s.1).unwrap_or(0),
+    })
+}
+
+/// Resolve the plan for `fixtures`. `suite_timeout_secs` is the hosting
+/// suite's default case budget (None for a single-fixture run). A selection is
+/// applied exactly as the runner would, so a refused selection is refused here.
+pub fn rbtdrp_build(
+    mode: &'static str,
+    name: &str,
+    fixtures: &[&'static rbtdre_Fixture],
+    suite_timeout_secs: Option<u64>,
+    selection: &rbtdre_Selection,
+    history: Result<rbtdrw_History, String>,
+) -> Result<rbtdrp_Plan, String> {
+    let (history, history_note) = match history {
+        Ok(h) => (Some(h), None),
+        Err(msg) => (None, Some(msg)),
+    };
+    let mut planned = Vec::with_capacity(fixtures.len());
+    for fixture in fixtures {
+        let cases = rbtdre_select_cases(fixture.cases, fixture.disposition, selection)?;
+        planned.push(rbtdrp_FixturePlan {
+            name: fixture.name,
+            disposition: format!("{:?}", fixture.disposition),
+            credless: fixture.credless,
+            case_timeout_secs: fixture.case_timeout_secs.or(suite_timeout_secs),
+            cases: cases
+                .iter()
+                .map(|c| rbtdrp_PlannedCase { name: c.name, tags: c.tags, tariff: c.tariff })
+                .collect(),
+            declared_cases: fixture.cases.len(),
+            required: rbtdrm_required_colophons(fixture.name),
+            permitted: rbtdrm_permitted_colophons(fixture.name),
+            tariff: fixture.tariff,
+            observed: zrbtdrp_observed(history.as_ref(), fixture.name),
+        });
+    }
+    Ok(rbtdrp_Plan {
+        mode,
+        name: name.to_string(),
+        fixtures: planned,
+        history_note,
+    })
+}
+
+impl rbtdrp_Plan {
+    /// Declared envelope: the sum of declared min/max across the plan. A
+    /// fixture missing either bound counts as missing.
+    pub fn declared_envelope(&self) -> rbtdrp_Envelope {
+        let mut env = rbtdrp_Envelope::default();
+        for f in &self.fixtures {
+            env.min_secs += f.tariff.min_secs.unwrap_or(0);
+            env.max_secs += f.tariff.max_secs.unwrap_or(0);
+            if f.tariff.min_secs.is_none() || f.tariff.max_secs.is_none() {
+                env.missing += 1;
+            }
+        }
+        env
+    }
+
+    /// Observed envelope: the sum of each fixture's fastest and slowest
+    /// observed green. Fixtures never observed count as missing.
+    pub fn observed_envelope(&self) -> rbtdrp_Envelope {
+        let mut env = rbtdrp_Envelope::default();
+        for f in &self.fixtures {
+            match &f.observed {
+                Some(o) => {
+                    env.min_secs += o.min_secs;
+                    env.max_secs += o.max_secs;
+                }
+                None => env.missing += 1,
+            }
+        }
+        env
+    }
+
+    /// Console rendering, one line per entry; the caller emits each line.
+    pub fn render_text(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "plan {} '{}' — {} fixture(s), {} case(s); nothing will run",
+            self.mode,
+            self.name,
+            self.fixtures.len(),
+            self.fixtures.iter().map(|f| f.cases.len()).sum::<usize>()
+        )];
+        let total = self.fixtures.len();
+        for (i, f) in self.fixtures.iter().enumerate() {
+            let cases = if f.cases.len() < f.declared_cases {
+                format!("{} of {} case(s)", f.cases.len(), f.declared_cases)
+            } else {
+                format!("{} case(s)", f.cases.len())
+            };
+            lines.push(format!(
+                "plan [{}/{}] {} — {}, {}, {}, case budget {}",
+                i + 1,
+                total,
+                f.name,
+                f.disposition,
+                if f.credless { "credless" } else { "credentialed" },
+                cases,
+                f.case_timeout_secs.map_or_else(|| "none".to_string(), |s| format!("{}s", s)),
+            ));
+            let observed = match &f.observed {
+                Some(o) => format!(
+                    "observed {} run(s) min={}s max={}s last={}s",
+                    o.runs, o.min_secs, o.max_secs, o.last_secs
+                ),
+                None => "observed —".to_string(),
+            };
+            lines.push(format!(
+                "plan     tariff declared {}{}; {}",
+                rbtdre_tariff_declared(&f.tariff),
+                if f.unchecked() { " UNCHECKED" } else { "" },
+                observed
+            ));
+            if f.cases.len() < f.declared_cases {
+                lines.push(
+                    "plan     selection narrows the run — tariff and census not enforced"
+                        .to_string(),
+                );
+            }
+            lines.push(match f.required {
+                Some(r) => format!("plan     required colophons ({}): {}", r.len(), r.join(", ")),
+                None => "plan     required colophons: no manifest entry".to_string(),
+            });
+            if !f.permitted.is_empty() {
+                lines.push(format!("plan     permitted colophons: {}", f.permitted.join(", ")));
+            }
+            for c in &f.cases {
+                let mut line = format!("plan       {}", c.name);
+                if !c.tags.is_empty() {
+                    line.push_str(&format!("  [{}]", c.tags.join(", ")));
+                }
+                if c.tariff != rbtdre_Tariff::UNCHECKED {
+                    line.push_str(&format!("  tariff {}", rbtdre_tariff_declared(&c.tariff)));
+                }
+                lines.push(line);
+            }
+        }
+
+        let unchecked: Vec<&str> =
+            self.fixtures.iter().filter(|f| f.unchecked()).map(|f| f.name).collect();
+        let declared = self.declared_envelope();
+        lines.push(format!(
+            "plan ETA declared: min {}s, max {}s ({} of {} fixture(s) missing a bound)",
+            declared.min_secs, declared.max_secs, declared.missing, total
+        ));
+        if !unchecked.is_empty() {
+            lines.push(format!("plan UNCHECKED tariff: {}", unchecked.join(", ")));
+        }
+        match &self.history_note {
+            Some(note) => lines.push(format!("plan ETA observed: unavailable — {}", note)),
+            None => {
+                let observed = self.observed_envelope();
+                lines.push(format!(
+                    "plan ETA observed: min {}s, max {}s ({} of {} fixture(s) never observed)",
+                    observed.min_secs, observed.max_secs, observed.missing, total
+                ));
+            }
+        }
+        lines
+    }
+
+    /// JSON rendering — one document, flat enough for the report escapers.
+    pub fn render_json(&self) -> String {
+        let strs = |items: &[&str]| {
+            format!(
+                "[{}]",
+                items.iter().map(|s| rbtdrr_json_str(s)).collect::<Vec<_>>().join(",")
+            )
+        };
+        let envelope = |e: rbtdrp_Envelope| {
+            format!(
+                "{{\"min_secs\":{},\"max_secs\":{},\"missing\":{}}}",
+                e.min_secs, e.max_secs, e.missing
+            )
+        };
+        let fixtures: Vec<String> = self
+            .fixtures
+            .iter()
+            .map(|f| {
+                let cases: Vec<String> = f
+                    .cases
+                    .iter()
+                    .map(|c| {
+                        format!(
+                            "{{\"name\":{},\"tags\":{},\"tariff\":{}}}",
+                            rbtdrr_json_str(c.name),
+                            strs(c.tags),
+                            rbtdrr_json_tariff_declared(&c.tariff)
+                        )
+                    })
+                    .collect();
+                let observed = match &f.observed {
+                    Some(o) => format!(
+                        "{{\"runs\":{},\"min_secs\":{},\"max_secs\":{},\"last_secs\":{}}}",
+                        o.runs, o.min_secs, o.max_secs, o.last_secs
+                    ),
+                    None => "null".to_string(),
+                };
+                format!(
+                    "{{\"name\":{},\"disposition\":{},\"credless\":{},\"case_timeout_secs\":{},\
+                     \"declared_cases\":{},\"cases\":[{}],\"required_colophons\":{},\
+                     \"permitted_colophons\":{},\"tariff\":{},\"unchecked\":{},\"observed\":{}}}",
+                    rbtdrr_json_str(f.name),
+                    rbtdrr_json_str(&f.disposition),
+                    f.credless,
+                    rbtdrr_json_opt_u64(f.case_timeout_secs),
+                    f.declared_cases,
+                    cases.join(","),
+                    f.required.map_or_else(|| "null".to_string(), strs),
+                    strs(f.permitted),
+                    rbtdrr_json_tariff_declared(&f.tariff),
+                    f.unchecked(),
+                    observed,
+                )
+            })
+            .collect();
+        let observed_eta = match &self.history_note {
+            Some(_) => "null".to_string(),
+            None => envelope(self.observed_envelope()),
+        };
+        format!(
+            "{{\"mode\":{},\"name\":{},\"fixtures\":[{}],\
+             \"eta\":{{\"declared\":{},\"observed\":{}}},\"history_note\":{}}}\n",
+            rbtdrr_json_str(self.mode),
+            rbtdrr_json_str(&self.name),
+            fixtures.join(","),
+            envelope(self.declared_envelope()),
+            observed_eta,
+            self.history_note.as_deref().map_or_else(|| "null".to_string(), rbtdrr_json_str),
+        )
+    }
+
+    /// Write the JSON rendering into `trace_root`, returning its path.
+    pub fn write_json(&self, trace_root: &Path) -> Result<std::path::PathBuf, String> {
+        let path = trace_root.join(RBTDRP_PLAN_FILE);
+        std::fs::write(&path, self.render_json())
+            .map_err(|e| format!("rbtd: failed to write plan '{}': {}", path.display(), e))?;
+        Ok(path)
+    }
+}
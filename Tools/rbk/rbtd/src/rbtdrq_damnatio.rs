@@ -735,5 +735,7 @@ pub static RBTDRQ_FIXTURE_DAMNATIO: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRQ_CASES_DAMNATIO,
     credless: true,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
@@ -1011,5 +1011,7 @@ pub static RBTDRQ_FIXTURE_PYX: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRQ_CASES_PYX,
     credless: true,
+    read_only: true,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
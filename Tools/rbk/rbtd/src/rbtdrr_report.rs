@@ -0,0 +1,468 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRR — machine-readable run report: JUnit XML + JSON Lines event stream
+//
+// The console lines (PASSED:/FAILED:/SKIPPED:, tariff, census) are for the
+// operator; this module renders the same run for dashboards, so nothing
+// downstream has to scrape console text. Both files land in the trace root
+// beside the per-case trace dirs:
+//
+//   * RBTDRR_EVENTS_FILE — JSON Lines, appended as the run progresses: one
+//     `run_start`, one `environment` summarizing the host fingerprint
+//     (rbtdrx_fingerprint), then per fixture its `case` events and one `fixture_end`
+//     (or `fixture_error` when setup failed), then one `run_end`. A run that
+//     dies mid-suite still leaves every completed fixture's events behind.
+//   * RBTDRR_JUNIT_FILE — JUnit XML, one <testsuite> per fixture. Rewritten
+//     whole after every fixture, so the file on disk is always well-formed.
+//
+// Tariff too-fast and census failures fold into the fixture's failure count
+// (rbtdre_run_fixture), so each is rendered as a synthetic failing testcase
+// (`tariff`, `census`) only when it fails — the JUnit failure total then
+// matches the engine's. The full tariff footprint and census outcome ride the
+// <testsuite> properties and the `fixture_end` event regardless.
+//
+// Zero-dependency by design: the JSON and XML here are flat enough that the
+// two escapers below are the whole serializer.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::rbtdre_engine::{
+    rbtdre_Fixture, rbtdre_RunResult, rbtdre_Tariff, rbtdre_Verdict,
+};
+use crate::rbtdrx_fingerprint::{rbtdrx_Fingerprint, RBTDRX_SUMMARY_KEYS, RBTDRX_UNAVAILABLE};
+
+/// JUnit XML report filename, written into the trace root.
+pub const RBTDRR_JUNIT_FILE: &str = "report-junit.xml";
+
+/// JSON Lines event-stream filename, written into the trace root.
+pub const RBTDRR_EVENTS_FILE: &str = "report-events.jsonl";
+
+/// Verdict words as they appear in the report's `verdict` field.
+const RBTDRR_VERDICT_PASS: &str = "pass";
+const RBTDRR_VERDICT_FAIL: &str = "fail";
+const RBTDRR_VERDICT_SKIP: &str = "skip";
+
+// ── Escapers ─────────────────────────────────────────────────
+
+/// Render `s` as a JSON string literal, quotes included.
+pub fn rbtdrr_json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escape `s` for an XML attribute value or text node. XML 1.0 admits no
+/// control characters besides tab/newline/CR — failure details routinely carry
+/// ANSI color escapes from captured tabtarget stderr — so the rest become
+/// U+FFFD rather than producing a document no parser will open.
+pub fn rbtdrr_xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => out.push('\u{FFFD}'),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render an optional bound as a JSON number, or `null` when unchecked.
+pub fn rbtdrr_json_opt_u64(o: Option<u64>) -> String {
+    o.map_or_else(|| "null".to_string(), |v| v.to_string())
+}
+
+pub fn rbtdrr_json_opt_u32(o: Option<u32>) -> String {
+    o.map_or_else(|| "null".to_string(), |v| v.to_string())
+}
+
+/// Render a declared tariff as a JSON object of its three bounds.
+pub fn rbtdrr_json_tariff_declared(t: &rbtdre_Tariff) -> String {
+    format!(
+        "{{\"min_secs\":{},\"max_secs\":{},\"invocations\":{}}}",
+        rbtdrr_json_opt_u64(t.min_secs),
+        rbtdrr_json_opt_u64(t.max_secs),
+        rbtdrr_json_opt_u32(t.invocations),
+    )
+}
+
+/// Wall-clock seconds since the epoch — the report's event stamp.
+fn zrbtdrr_now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// ── Collected run model ──────────────────────────────────────
+
+/// One case as the report renders it — owned copies of what the engine's
+/// `rbtdre_CaseRecord` carries.
+struct zrbtdrr_Case {
+    name: String,
+    verdict: &'static str,
+    detail: String,
+    elapsed_secs: f64,
+}
+
+/// One fixture as the report renders it. `error` is set when the fixture never
+/// produced a run result (setup failed, or the runner errored).
+struct zrbtdrr_Fixture {
+    name: String,
+    properties: Vec<(String, String)>,
+    cases: Vec<zrbtdrr_Case>,
+    error: Option<String>,
+    elapsed_secs: u64,
+}
+
+/// A run report under construction. Created once per theurge launch (fixture
+/// or suite runner), fed each fixture's outcome as it completes, and closed
+/// with `finish`. Write failures are reported once and then ignored — the
+/// report is an observer and must never change a run's verdict.
+pub struct rbtdrr_Report {
+    run_name: String,
+    junit_path: PathBuf,
+    events_path: PathBuf,
+    fixtures: Vec<zrbtdrr_Fixture>,
+    write_failed: bool,
+}
+
+impl rbtdrr_Report {
+    /// Open a report under `trace_root`: truncate both files and emit the
+    /// `run_start` event. `mode` is the runner kind (`fixture` or `suite`) and
+    /// `run_name` the fixture or suite name the operator launched.
+    pub fn new(trace_root: &Path, mode: &str, run_name: &str) -> Self {
+        let mut report = Self {
+            run_name: run_name.to_string(),
+            junit_path: trace_root.join(RBTDRR_JUNIT_FILE),
+            events_path: trace_root.join(RBTDRR_EVENTS_FILE),
+            fixtures: Vec::new(),
+            write_failed: false,
+        };
+        if let Err(e) = std::fs::write(&report.events_path, "") {
+            let path = report.events_path.clone();
+            report.zrbtdrr_note_failure(&path, &e.to_string());
+        }
+        report.zrbtdrr_event(&format!(
+            "{{\"event\":\"run_start\",\"mode\":{},\"name\":{},\"stamp\":{}}}",
+            rbtdrr_json_str(mode),
+            rbtdrr_json_str(run_name),
+            zrbtdrr_now_secs(),
+        ));
+        report.zrbtdrr_write_junit();
+        report
+    }
+
+    /// Record the `environment` event: the summary keys of the host fingerprint
+    /// and where the whole of it was written.
+    pub fn record_environment(&mut self, fingerprint: &rbtdrx_Fingerprint, file: &Path) {
+        let summary: Vec<String> = RBTDRX_SUMMARY_KEYS
+            .iter()
+            .map(|k| {
+                let v = fingerprint.get(k).unwrap_or(RBTDRX_UNAVAILABLE);
+                format!("{}:{}", rbtdrr_json_str(k), rbtdrr_json_str(v))
+            })
+            .collect();
+        self.zrbtdrr_event(&format!(
+            "{{\"event\":\"environment\",\"file\":{},{}}}",
+            rbtdrr_json_str(&file.display().to_string()),
+            summary.join(","),
+        ));
+    }
+
+    /// Record a fixture that completed with a run result: one `case` event per
+    /// case, one `fixture_end` event, and a refreshed JUnit file.
+    pub fn record_fixture(&mut self, fixture: &rbtdre_Fixture, result: &rbtdre_RunResult) {
+        let mut cases = Vec::new();
+        for record in &result.cases {
+            let (verdict, detail) = match &record.verdict {
+                rbtdre_Verdict::Pass => (RBTDRR_VERDICT_PASS, String::new()),
+                rbtdre_Verdict::Fail(d) => (RBTDRR_VERDICT_FAIL, d.clone()),
+                rbtdre_Verdict::Skip(r) => (RBTDRR_VERDICT_SKIP, r.clone()),
+            };
+            let elapsed_secs = record.elapsed.as_secs_f64();
+            self.zrbtdrr_event(&format!(
+                "{{\"event\":\"case\",\"fixture\":{},\"case\":{},\"verdict\":{},\
+                 \"detail\":{},\"elapsed_ms\":{},\"invocations\":{}}}",
+                rbtdrr_json_str(fixture.name),
+                rbtdrr_json_str(record.name),
+                rbtdrr_json_str(verdict),
+                rbtdrr_json_str(&detail),
+                record.elapsed.as_millis(),
+                record.invocations,
+            ));
+            cases.push(zrbtdrr_Case {
+                name: record.name.to_string(),
+                verdict,
+                detail,
+                elapsed_secs,
+            });
+        }
+
+        let tariff_json = match &result.tariff {
+            Some(t) => format!(
+                "{{\"declared\":{},\"too_fast\":{},\"too_slow\":{},\"count_drift\":{}}}",
+                rbtdrr_json_tariff_declared(&fixture.tariff),
+                t.too_fast, t.too_slow, t.count_drift,
+            ),
+            None => "null".to_string(),
+        };
+        let census_json = match &result.census {
+            Some(unused) => format!(
+                "{{\"unused\":[{}]}}",
+                unused.iter().map(|c| rbtdrr_json_str(c)).collect::<Vec<_>>().join(","),
+            ),
+            None => "null".to_string(),
+        };
+        self.zrbtdrr_event(&format!(
+            "{{\"event\":\"fixture_end\",\"fixture\":{},\"disposition\":{},\"credless\":{},\
+             \"passed\":{},\"failed\":{},\"skipped\":{},\"elapsed_secs\":{},\
+             \"invocations\":{},\"tariff\":{},\"census\":{}}}",
+            rbtdrr_json_str(fixture.name),
+            rbtdrr_json_str(&format!("{:?}", fixture.disposition)),
+            fixture.credless,
+            result.passed, result.failed, result.skipped,
+            result.elapsed_secs, result.invocations,
+            tariff_json, census_json,
+        ));
+
+        // Synthetic testcases for the two fixture-level failure folds.
+        if let Some(t) = result.tariff.as_ref().filter(|t| t.too_fast) {
+            cases.push(zrbtdrr_Case {
+                name: "tariff".to_string(),
+                verdict: RBTDRR_VERDICT_FAIL,
+                detail: format!(
+                    "tariff too-fast — elapsed {}s below declared min {}s (vacuous green)",
+                    t.elapsed_secs,
+                    fixture.tariff.min_secs.unwrap_or(0),
+                ),
+                elapsed_secs: 0.0,
+            });
+        }
+        if let Some(unused) = result.census.as_ref().filter(|u| !u.is_empty()) {
+            cases.push(zrbtdrr_Case {
+                name: "census".to_string(),
+                verdict: RBTDRR_VERDICT_FAIL,
+                detail: format!("declared but never invoked: {}", unused.join(", ")),
+                elapsed_secs: 0.0,
+            });
+        }
+
+        let mut properties = zrbtdrr_fixture_properties(fixture);
+        properties.push(("invocations".to_string(), result.invocations.to_string()));
+        if let Some(t) = &result.tariff {
+            properties.push(("tariff.too_fast".to_string(), t.too_fast.to_string()));
+            properties.push(("tariff.too_slow".to_string(), t.too_slow.to_string()));
+            properties.push(("tariff.count_drift".to_string(), t.count_drift.to_string()));
+        }
+        properties.push((
+            "census".to_string(),
+            match &result.census {
+                None => "unchecked".to_string(),
+                Some(u) if u.is_empty() => "clean".to_string(),
+                Some(u) => format!("unused: {}", u.join(", ")),
+            },
+        ));
+
+        self.fixtures.push(zrbtdrr_Fixture {
+            name: fixture.name.to_string(),
+            properties,
+            cases,
+            error: None,
+            elapsed_secs: result.elapsed_secs,
+        });
+        self.zrbtdrr_write_junit();
+    }
+
+    /// Record a fixture that produced no run result — a setup failure or a
+    /// runner error. Rendered as a JUnit <error> and a `fixture_error` event.
+    pub fn record_fixture_error(&mut self, fixture: &rbtdre_Fixture, message: &str) {
+        self.zrbtdrr_event(&format!(
+            "{{\"event\":\"fixture_error\",\"fixture\":{},\"error\":{}}}",
+            rbtdrr_json_str(fixture.name),
+            rbtdrr_json_str(message),
+        ));
+        self.fixtures.push(zrbtdrr_Fixture {
+            name: fixture.name.to_string(),
+            properties: zrbtdrr_fixture_properties(fixture),
+            cases: Vec::new(),
+            error: Some(message.to_string()),
+            elapsed_secs: 0,
+        });
+        self.zrbtdrr_write_junit();
+    }
+
+    /// Close the report: emit `run_end` with the run totals and write the final
+    /// JUnit file. Logs where both files landed.
+    pub fn finish(mut self, passed: usize, failed: usize, skipped: usize) {
+        self.zrbtdrr_event(&format!(
+            "{{\"event\":\"run_end\",\"name\":{},\"fixtures\":{},\"passed\":{},\
+             \"failed\":{},\"skipped\":{},\"stamp\":{}}}",
+            rbtdrr_json_str(&self.run_name),
+            self.fixtures.len(),
+            passed, failed, skipped,
+            zrbtdrr_now_secs(),
+        ));
+        self.zrbtdrr_write_junit();
+        crate::rbtdrg_info_now!(
+            "Run report: {} and {}",
+            self.junit_path.display(),
+            self.events_path.display()
+        );
+    }
+
+    /// Render the whole run as a JUnit XML document.
+    pub fn render_junit(&self) -> String {
+        let mut tests = 0usize;
+        let mut failures = 0usize;
+        let mut errors = 0usize;
+        let mut skipped = 0usize;
+        let mut time = 0u64;
+        let mut body = String::new();
+
+        for f in &self.fixtures {
+            let f_tests = f.cases.len() + usize::from(f.error.is_some());
+            let f_failures = f.cases.iter().filter(|c| c.verdict == RBTDRR_VERDICT_FAIL).count();
+            let f_errors = usize::from(f.error.is_some());
+            let f_skipped = f.cases.iter().filter(|c| c.verdict == RBTDRR_VERDICT_SKIP).count();
+            tests += f_tests;
+            failures += f_failures;
+            errors += f_errors;
+            skipped += f_skipped;
+            time += f.elapsed_secs;
+
+            let name = rbtdrr_xml_escape(&f.name);
+            body.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" \
+                 skipped=\"{}\" time=\"{}\">\n",
+                name, f_tests, f_failures, f_errors, f_skipped, f.elapsed_secs,
+            ));
+            body.push_str("    <properties>\n");
+            for (k, v) in &f.properties {
+                body.push_str(&format!(
+                    "      <property name=\"{}\" value=\"{}\"/>\n",
+                    rbtdrr_xml_escape(k),
+                    rbtdrr_xml_escape(v),
+                ));
+            }
+            body.push_str("    </properties>\n");
+            if let Some(err) = &f.error {
+                body.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"setup\" time=\"0\">\n      \
+                     <error message=\"{}\">{}</error>\n    </testcase>\n",
+                    name,
+                    rbtdrr_xml_escape(zrbtdrr_first_line(err)),
+                    rbtdrr_xml_escape(err),
+                ));
+            }
+            for c in &f.cases {
+                let open = format!(
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"",
+                    name,
+                    rbtdrr_xml_escape(&c.name),
+                    c.elapsed_secs,
+                );
+                match c.verdict {
+                    RBTDRR_VERDICT_FAIL => body.push_str(&format!(
+                        "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        open,
+                        rbtdrr_xml_escape(zrbtdrr_first_line(&c.detail)),
+                        rbtdrr_xml_escape(&c.detail),
+                    )),
+                    RBTDRR_VERDICT_SKIP => body.push_str(&format!(
+                        "{}>\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                        open,
+                        rbtdrr_xml_escape(&c.detail),
+                    )),
+                    _ => body.push_str(&format!("{}/>\n", open)),
+                }
+            }
+            body.push_str("  </testsuite>\n");
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" \
+             skipped=\"{}\" time=\"{}\">\n{}</testsuites>\n",
+            rbtdrr_xml_escape(&self.run_name),
+            tests, failures, errors, skipped, time, body,
+        )
+    }
+
+    fn zrbtdrr_event(&mut self, line: &str) {
+        let appended = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.events_path)
+            .and_then(|mut f| writeln!(f, "{}", line));
+        if let Err(e) = appended {
+            let path = self.events_path.clone();
+            self.zrbtdrr_note_failure(&path, &e.to_string());
+        }
+    }
+
+    fn zrbtdrr_write_junit(&mut self) {
+        if let Err(e) = std::fs::write(&self.junit_path, self.render_junit()) {
+            let path = self.junit_path.clone();
+            self.zrbtdrr_note_failure(&path, &e.to_string());
+        }
+    }
+
+    fn zrbtdrr_note_failure(&mut self, path: &Path, err: &str) {
+        if !self.write_failed {
+            self.write_failed = true;
+            crate::rbtdrg_error_now!("run report: cannot write '{}': {}", path.display(), err);
+        }
+    }
+}
+
+/// The declaration-side properties every fixture's <testsuite> carries.
+fn zrbtdrr_fixture_properties(fixture: &rbtdre_Fixture) -> Vec<(String, String)> {
+    vec![
+        ("disposition".to_string(), format!("{:?}", fixture.disposition)),
+        ("credless".to_string(), fixture.credless.to_string()),
+        (
+            "tariff.declared".to_string(),
+            crate::rbtdre_engine::rbtdre_tariff_declared(&fixture.tariff),
+        ),
+    ]
+}
+
+/// A failure's summary line for the JUnit `message` attribute — the full
+/// detail rides the element body.
+fn zrbtdrr_first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or("")
+}
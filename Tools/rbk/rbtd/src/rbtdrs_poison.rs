@@ -437,5 +437,7 @@ pub static RBTDRS_FIXTURE_REGIME_POISON: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRS_CASES_REGIME_POISON,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
@@ -0,0 +1,130 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRT — interrupt: SIGINT/SIGTERM handling for the runners
+//
+// An operator who interrupts a battery must not be left with a charged
+// crucible. The signal handler does only what is async-signal-safe: it counts
+// the interrupt and records the signal. Everything else happens on ordinary
+// threads that poll the count:
+//   - the engine's heartbeat/watchdog thread trips the running case's kill
+//     switch, killing the in-flight tabtarget process groups (rbtdri)
+//   - the case loop launches no further case once the count is non-zero
+//   - the fixture's teardown runs as on any other outcome, on the thread that
+//     owns the rbtdrc invocation context, so `rbtdrc_take_context` afterwards
+//     sees the context intact
+//   - the suite loop starts no further fixture
+// A second interrupt exits the process from inside the handler, at once —
+// the operator's explicit choice to abandon the teardown.
+//
+// Setup is not a case and runs with no kill switch armed: its launches share
+// theurge's process group, so a terminal Ctrl-C reaches them directly. Setup
+// then ends, failed or not, no case launches, and teardown runs.
+//
+// The runners consult the interrupt source installed on their thread, which
+// defaults to the process-wide one the handler feeds. Tests install a private
+// source so a simulated interrupt never reaches cases running in parallel.
+
+use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+
+/// Exit status base for a signal-terminated run (shell convention: 128 + N).
+pub const RBTDRT_EXIT_BASE: i32 = 128;
+
+/// A count of interrupts received, and the signal that raised the first.
+pub struct rbtdrt_Interrupt {
+    count: AtomicUsize,
+    signal: AtomicI32,
+}
+
+impl rbtdrt_Interrupt {
+    pub const fn new() -> rbtdrt_Interrupt {
+        rbtdrt_Interrupt { count: AtomicUsize::new(0), signal: AtomicI32::new(0) }
+    }
+
+    /// Record one interrupt by `signal`; returns how many preceded it.
+    pub fn raise(&self, signal: i32) -> usize {
+        let prior = self.count.fetch_add(1, Ordering::SeqCst);
+        if prior == 0 {
+            self.signal.store(signal, Ordering::SeqCst);
+        }
+        prior
+    }
+
+    /// Whether an interrupt has been received.
+    pub fn raised(&self) -> bool {
+        self.count.load(Ordering::SeqCst) > 0
+    }
+
+    /// The first interrupt's signal number, 0 when none was received.
+    pub fn signal(&self) -> i32 {
+        self.signal.load(Ordering::SeqCst)
+    }
+
+    /// Display name of the first interrupt's signal.
+    pub fn signal_name(&self) -> &'static str {
+        match self.signal() {
+            libc::SIGINT => "SIGINT",
+            libc::SIGTERM => "SIGTERM",
+            _ => "signal",
+        }
+    }
+}
+
+impl Default for rbtdrt_Interrupt {
+    fn default() -> Self {
+        rbtdrt_Interrupt::new()
+    }
+}
+
+/// The process-wide source the signal handler feeds.
+static RBTDRT_PROCESS: rbtdrt_Interrupt = rbtdrt_Interrupt::new();
+
+thread_local! {
+    /// The source the runners on this thread consult.
+    static RBTDRT_WATCHED: std::cell::Cell<&'static rbtdrt_Interrupt> =
+        const { std::cell::Cell::new(&RBTDRT_PROCESS) };
+}
+
+/// The interrupt source for the current thread. `&'static`, so the engine can
+/// hand it to the watchdog thread it spawns.
+pub fn rbtdrt_current() -> &'static rbtdrt_Interrupt {
+    RBTDRT_WATCHED.with(|w| w.get())
+}
+
+/// Install `source` as the current thread's interrupt source (test seam).
+pub fn rbtdrt_watch(source: &'static rbtdrt_Interrupt) {
+    RBTDRT_WATCHED.with(|w| w.set(source));
+}
+
+extern "C" fn zrbtdrt_on_signal(signal: libc::c_int) {
+    if RBTDRT_PROCESS.raise(signal) > 0 {
+        // Second interrupt: leave now. _exit is async-signal-safe; no unwinding,
+        // no teardown, no buffered-output flush.
+        unsafe { libc::_exit(RBTDRT_EXIT_BASE + signal) };
+    }
+}
+
+/// Install the SIGINT/SIGTERM handlers. Idempotent; called by the fixture,
+/// suite, and single-case runners before any case work begins.
+pub fn rbtdrt_install_handlers() {
+    static INSTALLED: std::sync::Once = std::sync::Once::new();
+    INSTALLED.call_once(|| {
+        let handler = zrbtdrt_on_signal as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        for signal in [libc::SIGINT, libc::SIGTERM] {
+            unsafe { libc::signal(signal, handler) };
+        }
+    });
+}
@@ -395,5 +395,7 @@ pub static RBTDRU_FIXTURE_CUPEL: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRU_CASES_CUPEL,
     credless: true,
+    read_only: true,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: None, invocations: Some(0) },
+    case_timeout_secs: None,
 };
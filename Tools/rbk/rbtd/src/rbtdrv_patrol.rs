@@ -35,6 +35,7 @@ use crate::rbtdri_invocation::{
     RBTDRI_BURE_CONFIRM_KEY, RBTDRI_BURE_CONFIRM_SKIP,
     RBTDRI_BURE_TWEAK_NAME_KEY, RBTDRI_BURE_TWEAK_VALUE_KEY,
 };
+use crate::rbtdrz_facts::{rbtdrz_read_fact, rbtdrz_Touchmark};
 use crate::rbtdgc_consts::{
     RBTDGC_ABJURE_HALLMARK, RBTDGC_ACCOUNT_PAYOR,
     RBTDGC_ACCOUNT_RETRIEVER, RBTDGC_AFFIANCE_MANOR, RBTDGC_AUDIT_HALLMARKS,
@@ -67,7 +68,9 @@ pub static RBTDRV_FIXTURE_HALLMARK_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_HALLMARK_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_LODE_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
@@ -77,7 +80,9 @@ pub static RBTDRV_FIXTURE_LODE_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_LODE_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_RELIQUARY_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
@@ -87,7 +92,9 @@ pub static RBTDRV_FIXTURE_RELIQUARY_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_RELIQUARY_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_WSL_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
@@ -97,7 +104,9 @@ pub static RBTDRV_FIXTURE_WSL_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_WSL_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_PODVM_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
@@ -107,7 +116,9 @@ pub static RBTDRV_FIXTURE_PODVM_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_PODVM_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_FOEDUS_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
@@ -117,7 +128,9 @@ pub static RBTDRV_FIXTURE_FOEDUS_LIFECYCLE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_FOEDUS_LIFECYCLE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_FOEDUS_REUSE: rbtdre_Fixture = rbtdre_Fixture {
@@ -127,7 +140,9 @@ pub static RBTDRV_FIXTURE_FOEDUS_REUSE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_FOEDUS_REUSE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_BATCH_VOUCH: rbtdre_Fixture = rbtdre_Fixture {
@@ -137,7 +152,9 @@ pub static RBTDRV_FIXTURE_BATCH_VOUCH: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_BATCH_VOUCH,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 pub static RBTDRV_FIXTURE_ACCESS_PROBE: rbtdre_Fixture = rbtdre_Fixture {
@@ -147,7 +164,9 @@ pub static RBTDRV_FIXTURE_ACCESS_PROBE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_ACCESS_PROBE,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(60), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
 pub static RBTDRV_FIXTURE_CREDENTIAL_READINESS: rbtdre_Fixture = rbtdre_Fixture {
@@ -157,7 +176,9 @@ pub static RBTDRV_FIXTURE_CREDENTIAL_READINESS: rbtdre_Fixture = rbtdre_Fixture
     teardown: None,
     cases: RBTDRV_CASES_CREDENTIAL_READINESS,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(60), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
 pub static RBTDRV_FIXTURE_POLITY_DENIAL: rbtdre_Fixture = rbtdre_Fixture {
@@ -167,7 +188,9 @@ pub static RBTDRV_FIXTURE_POLITY_DENIAL: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_POLITY_DENIAL,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(300),
 };
 
 pub static RBTDRV_FIXTURE_PARLEY: rbtdre_Fixture = rbtdre_Fixture {
@@ -177,7 +200,9 @@ pub static RBTDRV_FIXTURE_PARLEY: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_PARLEY,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff { min_secs: None, max_secs: Some(120), invocations: None },
+    case_timeout_secs: Some(300),
 };
 
 // Chaining-fact livery — the cloud sibling of the local chaining-fact band
@@ -192,7 +217,9 @@ pub static RBTDRV_FIXTURE_CHAINING_LIVERY: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: RBTDRV_CASES_CHAINING_LIVERY,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(3600),
 };
 
 // ── Hallmark / ark vocabulary and docker helpers ─────────────
@@ -488,7 +515,7 @@ fn rbtdrv_hallmark_lifecycle(dir: &Path) -> rbtdre_Verdict {
             Err(e) => return rbtdre_Verdict::Fail(format!("read post-ordain audit facts: {}", e)),
         };
         let mut expected_after_ordain = baseline.clone();
-        expected_after_ordain.push(hallmark.clone());
+        expected_after_ordain.push(hallmark.to_string());
         expected_after_ordain.sort();
         if after_ordain != expected_after_ordain {
             return rbtdre_Verdict::Fail(format!(
@@ -609,14 +636,14 @@ pub static RBTDRV_CASES_HALLMARK_LIFECYCLE: &[rbtdre_Case] = &[case!(rbtdrv_hall
 /// Read the bare Lode touchmark fact from a capture invocation and stamp it to
 /// the case scratch dir. The host-side capture handoff is identical across every
 /// Lode kind; only the capture result differs. Ok(touchmark) to continue,
-/// Err(Fail) to short-circuit on a missing/empty fact.
+/// Err(Fail) to short-circuit on a missing, empty, or malformed fact.
 fn zrbtdrv_read_touchmark(
     result: &rbtdri_InvokeResult,
     dir: &Path,
-) -> Result<String, rbtdre_Verdict> {
-    let touchmark = rbtdri_read_burv_fact(result, RBTDRV_FACT_LODE_TOUCHMARK)
+) -> Result<rbtdrz_Touchmark, rbtdre_Verdict> {
+    let touchmark: rbtdrz_Touchmark = rbtdrz_read_fact(result, RBTDRV_FACT_LODE_TOUCHMARK)
         .map_err(|e| rbtdre_Verdict::Fail(format!("read touchmark fact: {}", e)))?;
-    let _ = std::fs::write(dir.join("02-touchmark.txt"), &touchmark);
+    let _ = std::fs::write(dir.join("02-touchmark.txt"), touchmark.as_str());
     Ok(touchmark)
 }
 
@@ -894,11 +921,12 @@ fn rbtdrv_lode_collision(dir: &Path) -> rbtdre_Verdict {
             Err(e) => return rbtdre_Verdict::Fail(format!("fresh ensconce invocation: {}", e)),
         };
         let _ = std::fs::write(dir.join("01-ensconce-fresh-stdout.txt"), &fresh.stdout);
-        let touchmark = match rbtdri_read_burv_fact(&fresh, RBTDRV_FACT_LODE_TOUCHMARK) {
+        let read = rbtdrz_read_fact::<rbtdrz_Touchmark>(&fresh, RBTDRV_FACT_LODE_TOUCHMARK);
+        let touchmark = match read {
             Ok(v) => v,
             Err(e) => return rbtdre_Verdict::Fail(format!("read touchmark fact: {}", e)),
         };
-        let _ = std::fs::write(dir.join("02-touchmark.txt"), &touchmark);
+        let _ = std::fs::write(dir.join("02-touchmark.txt"), touchmark.as_str());
 
         let pin = &[
             ("BURE_TWEAK_NAME", RBTDRV_ENSCONCE_STAMP_TWEAK_NAME),
@@ -953,7 +981,7 @@ fn rbtdrv_lode_collision(dir: &Path) -> rbtdre_Verdict {
             Err(e) => return rbtdre_Verdict::Fail(format!("final divine invocation: {}", e)),
         };
         let _ = std::fs::write(dir.join("06-divine-final.txt"), &final_divine.stdout);
-        if final_divine.stdout.contains(&touchmark) {
+        if final_divine.stdout.contains(touchmark.as_str()) {
             return rbtdre_Verdict::Fail(format!(
                 "final divine still shows banished touchmark {} — cleanup failed\nstdout:\n{}",
                 touchmark, final_divine.stdout));
@@ -1010,6 +1038,9 @@ pub static RBTDRV_CASES_LODE_LIFECYCLE: &[rbtdre_Case] = &[
 /// touchmark and the chained fact the feoff consumes.
 const RBTDRV_LIVERY_TOUCHMARK: &str = "b260623000000";
 
+/// Chain session the livery's ensconce->feoff succession runs in.
+const RBTDRV_LIVERY_CHAIN: &str = "livery-touchmark";
+
 /// The staged temp vessel's rbrv.env — one populated base ORIGIN slot, which is
 /// all feoff needs to locate the slot whose ANCHOR it elects, and NO ANCHOR line,
 /// so an RBRV_IMAGE_1_ANCHOR= present after feoff proves the write fired (a no-op
@@ -1088,6 +1119,12 @@ fn zrbtdrv_chaining_livery_body(ctx: &mut rbtdri_Context, dir: &Path) -> rbtdre_
         (RBTDRI_BURE_TWEAK_NAME_KEY, RBTDRV_ENSCONCE_STAMP_TWEAK_NAME),
         (RBTDRI_BURE_TWEAK_VALUE_KEY, RBTDRV_LIVERY_TOUCHMARK),
     ];
+    // The ensconce->feoff pair runs as a named chain session, so feoff's
+    // hand-off is asserted before it launches rather than inferred after.
+    ctx.chain_open(RBTDRV_LIVERY_CHAIN);
+    if let Err(e) = ctx.chain_into(RBTDRV_LIVERY_CHAIN, &[]) {
+        return rbtdre_Verdict::Fail(e);
+    }
     let ensconce = match rbtdri_invoke_global(ctx, RBTDGC_ENSCONCE_BOLE, &[busybox_dir], pin) {
         Ok(r) if r.exit_code == 0 => r,
         Ok(r) => return rbtdre_Verdict::Fail(format!("ensconce failed (exit {})\n{}", r.exit_code, r.stderr)),
@@ -1098,27 +1135,26 @@ fn zrbtdrv_chaining_livery_body(ctx: &mut rbtdri_Context, dir: &Path) -> rbtdre_
     // The producer's handoff is the bare touchmark fact in current/. Read it
     // now, BEFORE the chained feoff promotes current/ into previous/ (where
     // feoff reads it but this read no longer would).
-    let touchmark = match rbtdri_read_burv_fact(&ensconce, RBTDRV_FACT_LODE_TOUCHMARK) {
+    let read = rbtdrz_read_fact::<rbtdrz_Touchmark>(&ensconce, RBTDRV_FACT_LODE_TOUCHMARK);
+    let touchmark = match read {
         Ok(v) => v,
         Err(e) => return rbtdre_Verdict::Fail(format!("read touchmark fact: {}", e)),
     };
-    let _ = std::fs::write(dir.join("02-touchmark.txt"), &touchmark);
+    let _ = std::fs::write(dir.join("02-touchmark.txt"), touchmark.as_str());
 
     // The real touchmark must be the pinned value (ensconce honored the stamp
     // and round-tripped it through the fact) AND carry the band matrix's
     // synthetic bole-seed shape: 'b' + 12 digits (cf. rbtdrh_chain.rs
     // RBTDRH_BOLE_TOUCHMARK) — the proof that synthetic seed is faithful to
     // what a live ensconce emits and a live feoff consumes.
-    if touchmark != RBTDRV_LIVERY_TOUCHMARK {
+    if touchmark.as_str() != RBTDRV_LIVERY_TOUCHMARK {
         return rbtdre_Verdict::Fail(format!(
             "ensconce emitted touchmark '{}', expected the pinned '{}' (stamp tweak not honored?)",
             touchmark, RBTDRV_LIVERY_TOUCHMARK
         ));
     }
-    let shape_ok = touchmark.len() == 13
-        && touchmark.starts_with('b')
-        && touchmark[1..].chars().all(|c| c.is_ascii_digit());
-    if !shape_ok {
+    // The typed read already pinned <kind><12 digits>; only the kind remains.
+    if touchmark.kind() != "b" {
         return rbtdre_Verdict::Fail(format!(
             "real ensconce touchmark '{}' is not the bole-seed shape ('b' + 12 digits)",
             touchmark
@@ -1128,7 +1164,7 @@ fn zrbtdrv_chaining_livery_body(ctx: &mut rbtdri_Context, dir: &Path) -> rbtdre_
     // Step 2: stage a temp vessel and chain feoff off the ensconce. The chain
     // makes feoff reuse the ensconce's BURV root, so bud promotes the touchmark
     // from current/ into feoff's previous/ — the operator's shared ../output-buk
-    // flow, restored for exactly this pair (rbtdri chain_next). feoff resolves
+    // flow, restored for exactly this pair (rbtdri chain sessions). feoff resolves
     // the vessel by PATH, so its rbrv.env rewrite lands in the case temp dir — no
     // tracked config is touched. No express touchmark is passed, so feoff MUST
     // take the value from the chain or die loud.
@@ -1142,7 +1178,9 @@ fn zrbtdrv_chaining_livery_body(ctx: &mut rbtdri_Context, dir: &Path) -> rbtdre_
     }
     let vessel_posix = crate::rbtdrx_platform::rbtdrx_native_to_posix(&vessel_dir);
 
-    ctx.chain_next_invoke();
+    if let Err(e) = ctx.chain_into(RBTDRV_LIVERY_CHAIN, &[RBTDRV_FACT_LODE_TOUCHMARK]) {
+        return rbtdre_Verdict::Fail(e);
+    }
     let _ = std::fs::write(dir.join("03-feoff.txt"), "feoffing temp vessel off chained touchmark");
     let feoff = match rbtdri_invoke_global(
         ctx,
@@ -1158,6 +1196,9 @@ fn zrbtdrv_chaining_livery_body(ctx: &mut rbtdri_Context, dir: &Path) -> rbtdre_
         Err(e) => return rbtdre_Verdict::Fail(format!("feoff invocation: {}", e)),
     };
     let _ = std::fs::write(dir.join("03-feoff-stdout.txt"), &feoff.stdout);
+    if let Err(e) = ctx.chain_close(RBTDRV_LIVERY_CHAIN) {
+        return rbtdre_Verdict::Fail(e);
+    }
 
     // Step 3: the temp vessel's elected anchor must bear the REAL chained
     // touchmark's bole locator. The staged rbrv.env carried no ANCHOR line, so
@@ -2465,7 +2506,7 @@ fn rbtdrv_podvm_lifecycle(dir: &Path) -> rbtdre_Verdict {
             Err(e) => return rbtdre_Verdict::Fail(format!("post-refresh divine invocation: {}", e)),
         };
         let _ = std::fs::write(dir.join("03c-divine-after-refresh.txt"), &after_refresh.stdout);
-        if !after_refresh.stdout.contains(&touchmark) {
+        if !after_refresh.stdout.contains(touchmark.as_str()) {
             return rbtdre_Verdict::Fail(format!(
                 "post-refresh divine missing touchmark {} — refresh lost the Lode\nstdout:\n{}",
                 touchmark, after_refresh.stdout
@@ -14,27 +14,43 @@
 //
 // Author: Brad Hyslop <bhyslop@scaleinvariant.org>
 //
-// RBTDRW — dowse: observed-tariff census over the station's logs-buk history
+// RBTDRW — dowse: observed-tariff census and verdict history over the
+// station's logs-buk history
 //
 // Read-only report verb, never a fixture: fixtures assert, and suite verdicts
 // stay functions of the tree — host log history informs the operator only.
-// Dowse parses the per-fixture tariff lines rbtdre_print_tariff emits into the
-// BUK self-logs and prints observed cost history per suite and per fixture,
-// the observed complement to the declared-cost ETA listing.
+// Dowse parses the per-fixture and per-case tariff lines rbtdre_print_tariff
+// emits into the BUK self-logs and prints observed cost history per suite, per
+// fixture, and per case, the observed complement to the declared-cost ETA
+// listing.
 //
 // Parse contract: the per-fixture line
 //   `tariff <name>: elapsed=<N>s invocations=<N> [declared ...]`
 // appears exactly once per fixture per run. The suite-end drift-table rows
 // carry no colon after the fixture name and the advisory/FAILED lines put a
 // non-name token after `tariff`, so both are deliberate non-matches — one run
-// is never double-counted. Hist lines may or may not carry the dispatch
-// timestamp prefix (interactive tee is uncurated), so the scan keys on the
-// `tariff` token, never on column position.
+// is never double-counted. The per-case line
+//   `tariff case <fixture>/<case>: elapsed=<N>s invocations=<N> [declared ...]`
+// appears once per passing case; its `case` word is the non-name token that
+// keeps it out of the per-fixture parse, and only the case parse accepts it.
+// Hist lines may or may not carry the dispatch timestamp prefix (interactive
+// tee is uncurated), so the scan keys on the `tariff` token, never on column
+// position.
+//
+// Only greens are observed. A red fixture still prints its per-fixture line
+// (the suite drift table wants it), but fail-fast cut its footprint short, so
+// the gather withholds it. Red is read off the FAILED lines: a bare
+// `FAILED: <case>` belongs to the fixture whose per-fixture line follows it,
+// and the guard, too-fast, and census FAILED lines name their fixture.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
-use crate::rbtdre_engine::RBTDRE_TARIFF_TOKEN;
+use crate::rbtdra_almanac::RBTDRA_FIXTURES;
+use crate::rbtdre_engine::{
+    rbtdre_Tariff, RBTDRE_TARIFF_CASE_WORD, RBTDRE_TARIFF_TOKEN, RBTDRE_WORD_FAILED,
+    RBTDRE_WORD_FIXTURE, RBTDRE_WORD_PASSED, RBTDRE_WORD_SKIPPED,
+};
 
 /// One observed fixture footprint parsed from a per-fixture tariff line.
 #[derive(Debug, PartialEq)]
@@ -53,9 +69,18 @@ pub enum rbtdrw_LogKind {
     FixtureRun,
 }
 
+/// One observed case footprint parsed from a per-case tariff line.
+#[derive(Debug, PartialEq)]
+pub struct rbtdrw_CaseObservation {
+    pub fixture: String,
+    pub case: String,
+    pub elapsed_secs: u64,
+    pub invocations: u32,
+}
+
 /// Parse one self-log line as a per-fixture tariff line. None for every other
-/// line shape, including the drift-table header/rows and the advisory lines
-/// (see the module header for why those must not match).
+/// line shape, including the drift-table header/rows, the advisory lines, and
+/// the per-case lines (see the module header for why those must not match).
 pub fn rbtdrw_parse_tariff_line(line: &str) -> Option<rbtdrw_Observation> {
     let mut toks = line
         .split_whitespace()
@@ -65,6 +90,40 @@ pub fn rbtdrw_parse_tariff_line(line: &str) -> Option<rbtdrw_Observation> {
     if fixture.is_empty() {
         return None;
     }
+    let (elapsed_secs, invocations) = zrbtdrw_parse_footprint(toks)?;
+    Some(rbtdrw_Observation {
+        fixture: fixture.to_string(),
+        elapsed_secs,
+        invocations,
+    })
+}
+
+/// Parse one self-log line as a per-case tariff line. None for every other
+/// line shape, the per-fixture line included.
+pub fn rbtdrw_parse_case_tariff_line(line: &str) -> Option<rbtdrw_CaseObservation> {
+    let mut toks = line
+        .split_whitespace()
+        .skip_while(|t| *t != RBTDRE_TARIFF_TOKEN)
+        .skip(1);
+    if toks.next()? != RBTDRE_TARIFF_CASE_WORD {
+        return None;
+    }
+    let (fixture, case) = toks.next()?.strip_suffix(':')?.split_once('/')?;
+    if fixture.is_empty() || case.is_empty() {
+        return None;
+    }
+    let (elapsed_secs, invocations) = zrbtdrw_parse_footprint(toks)?;
+    Some(rbtdrw_CaseObservation {
+        fixture: fixture.to_string(),
+        case: case.to_string(),
+        elapsed_secs,
+        invocations,
+    })
+}
+
+/// Parse the `elapsed=<N>s invocations=<N>` pair that follows the name on
+/// both tariff line shapes.
+fn zrbtdrw_parse_footprint<'a>(mut toks: impl Iterator<Item = &'a str>) -> Option<(u64, u32)> {
     let elapsed_secs = toks
         .next()?
         .strip_prefix("elapsed=")?
@@ -76,11 +135,7 @@ pub fn rbtdrw_parse_tariff_line(line: &str) -> Option<rbtdrw_Observation> {
         .strip_prefix("invocations=")?
         .parse()
         .ok()?;
-    Some(rbtdrw_Observation {
-        fixture: fixture.to_string(),
-        elapsed_secs,
-        invocations,
-    })
+    Some((elapsed_secs, invocations))
 }
 
 /// Classify a logs-buk filename as a theurge history log, yielding its kind
@@ -112,62 +167,166 @@ pub fn rbtdrw_parse_log_name(name: &str) -> Option<(rbtdrw_LogKind, String)> {
     Some((kind, stamp))
 }
 
-/// Scan the station's log dir and print the observed-tariff census: per suite,
-/// each run with its fixture count and summed elapsed; per fixture, an
-/// elapsed min/max/last summary then every observation with its source. All
-/// history, no truncation — this is a census, and its own output self-logs.
-pub fn rbtdrw_dowse(log_dir: &Path) -> Result<(), String> {
-    let entries = std::fs::read_dir(log_dir)
-        .map_err(|e| format!("dowse: cannot read log dir '{}': {}", log_dir.display(), e))?;
+/// One fixture observation as the census holds it: dispatch stamp, elapsed
+/// seconds, invocation count, and a source label naming the run kind.
+pub type rbtdrw_Sighting = (String, u64, u32, String);
 
-    let mut scanned = 0usize;
-    let mut carrying = 0usize;
-    // suite → runs of (stamp, fixtures observed, elapsed sum)
-    let mut suites: BTreeMap<String, Vec<(String, usize, u64)>> = BTreeMap::new();
-    // fixture → observations of (stamp, elapsed, invocations, source label)
-    let mut fixtures: BTreeMap<String, Vec<(String, u64, u32, String)>> = BTreeMap::new();
+/// Observed history gathered from a log dir — the census before rendering.
+#[derive(Debug, Default)]
+pub struct rbtdrw_History {
+    /// Theurge hist logs found.
+    pub scanned: usize,
+    /// Of those, logs carrying at least one per-fixture tariff line.
+    pub carrying: usize,
+    /// Per-fixture lines withheld because their fixture ran red.
+    pub withheld: usize,
+    /// suite → runs of (stamp, fixtures observed, elapsed sum)
+    pub suites: BTreeMap<String, Vec<(String, usize, u64)>>,
+    /// fixture → sightings, in log-dir walk order (sort for chronology)
+    pub fixtures: BTreeMap<String, Vec<rbtdrw_Sighting>>,
+    /// `<fixture>/<case>` → sightings, likewise
+    pub cases: BTreeMap<String, Vec<rbtdrw_Sighting>>,
+}
 
+/// Walk the station's log dir, handing each theurge hist log's kind, stamp,
+/// and content to `visit`. Read-only; every dowse report walks through here.
+fn zrbtdrw_walk(
+    log_dir: &Path,
+    mut visit: impl FnMut(rbtdrw_LogKind, String, &str),
+) -> Result<(), String> {
+    let entries = std::fs::read_dir(log_dir)
+        .map_err(|e| format!("dowse: cannot read log dir '{}': {}", log_dir.display(), e))?;
     for entry in entries {
         let entry = entry.map_err(|e| format!("dowse: cannot walk '{}': {}", log_dir.display(), e))?;
         let name = entry.file_name();
         let Some((kind, stamp)) = rbtdrw_parse_log_name(&name.to_string_lossy()) else {
             continue;
         };
-        scanned += 1;
         // Lossy read: self-logs can carry non-UTF8 bytes from container output.
         let bytes = std::fs::read(entry.path())
             .map_err(|e| format!("dowse: cannot read '{}': {}", entry.path().display(), e))?;
-        let content = String::from_utf8_lossy(&bytes);
-        let obs: Vec<rbtdrw_Observation> =
-            content.lines().filter_map(rbtdrw_parse_tariff_line).collect();
-        if obs.is_empty() {
+        visit(kind, stamp, &String::from_utf8_lossy(&bytes));
+    }
+    Ok(())
+}
+
+/// Source label naming a hist log's run kind.
+fn zrbtdrw_label(kind: &rbtdrw_LogKind) -> String {
+    match kind {
+        rbtdrw_LogKind::Suite(s) => format!("suite {}", s),
+        rbtdrw_LogKind::FixtureRun => "fixture-run".to_string(),
+    }
+}
+
+/// Strip ANSI colour escapes (`ESC [ ... m`) from one token.
+fn zrbtdrw_strip_ansi(tok: &str) -> String {
+    let mut out = String::with_capacity(tok.len());
+    let mut chars = tok.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Fixtures a hist log shows red (see the module header): each bare FAILED
+/// line marks the fixture whose per-fixture tariff line comes next, and each
+/// reason-bearing FAILED line marks the fixture it names.
+fn zrbtdrw_red_fixtures(content: &str) -> BTreeSet<String> {
+    let mut red = BTreeSet::new();
+    let mut pending = false;
+    for line in content.lines() {
+        if let Some(o) = rbtdrw_parse_tariff_line(line) {
+            if std::mem::take(&mut pending) {
+                red.insert(o.fixture);
+            }
             continue;
         }
-        carrying += 1;
-        let label = match &kind {
-            rbtdrw_LogKind::Suite(s) => format!("suite {}", s),
-            rbtdrw_LogKind::FixtureRun => "fixture-run".to_string(),
+        let toks: Vec<String> = line.split_whitespace().map(zrbtdrw_strip_ansi).collect();
+        let Some(i) = toks.iter().position(|t| t == RBTDRE_WORD_FAILED) else {
+            continue;
         };
-        if let rbtdrw_LogKind::Suite(s) = &kind {
-            suites.entry(s.clone()).or_default().push((
+        match &toks[i + 1..] {
+            [_case] => pending = true,
+            [fixture, _, ..] => {
+                red.insert(fixture.clone());
+            }
+            [] => {}
+        }
+    }
+    red
+}
+
+/// Walk the station's log dir and gather every green per-fixture tariff
+/// observation. Read-only; shared by the dowse census, `--propose`, and the
+/// runners' `--plan` ETA.
+pub fn rbtdrw_gather(log_dir: &Path) -> Result<rbtdrw_History, String> {
+    let mut history = rbtdrw_History::default();
+
+    zrbtdrw_walk(log_dir, |kind, stamp, content| {
+        history.scanned += 1;
+        let red = zrbtdrw_red_fixtures(content);
+        let (obs, reds): (Vec<rbtdrw_Observation>, Vec<rbtdrw_Observation>) = content
+            .lines()
+            .filter_map(rbtdrw_parse_tariff_line)
+            .partition(|o| !red.contains(&o.fixture));
+        history.withheld += reds.len();
+        let case_obs: Vec<rbtdrw_CaseObservation> =
+            content.lines().filter_map(rbtdrw_parse_case_tariff_line).collect();
+        if obs.is_empty() && case_obs.is_empty() {
+            return;
+        }
+        history.carrying += 1;
+        let label = zrbtdrw_label(&kind);
+        if let (rbtdrw_LogKind::Suite(s), false) = (&kind, obs.is_empty()) {
+            history.suites.entry(s.clone()).or_default().push((
                 stamp.clone(),
                 obs.len(),
                 obs.iter().map(|o| o.elapsed_secs).sum(),
             ));
         }
         for o in obs {
-            fixtures
+            history
+                .fixtures
                 .entry(o.fixture)
                 .or_default()
                 .push((stamp.clone(), o.elapsed_secs, o.invocations, label.clone()));
         }
-    }
+        for o in case_obs {
+            history
+                .cases
+                .entry(format!("{}/{}", o.fixture, o.case))
+                .or_default()
+                .push((stamp.clone(), o.elapsed_secs, o.invocations, label.clone()));
+        }
+    })?;
+    Ok(history)
+}
+
+/// Scan the station's log dir and print the observed-tariff census: per suite,
+/// each run with its fixture count and summed elapsed; per fixture, an
+/// elapsed min/max/last summary then every observation with its source; per
+/// case, the summary line alone (a big fixture's cases times its runs would
+/// bury the fixture census). All history, no truncation — this is a census,
+/// and its own output self-logs.
+pub fn rbtdrw_dowse(log_dir: &Path) -> Result<(), String> {
+    let rbtdrw_History { scanned, carrying, withheld, suites, fixtures, cases } =
+        rbtdrw_gather(log_dir)?;
 
     crate::rbtdrg_info_now!(
-        "dowse: {} theurge log(s) under '{}', {} carrying tariff observations",
+        "dowse: {} theurge log(s) under '{}', {} carrying tariff observations, \
+         {} red fixture run(s) withheld",
         scanned,
         log_dir.display(),
-        carrying
+        carrying,
+        withheld
     );
     if carrying == 0 {
         crate::rbtdrg_info_now!(
@@ -208,5 +367,710 @@ pub fn rbtdrw_dowse(log_dir: &Path) -> Result<(), String> {
         }
     }
 
+    for (case, mut obs) in cases {
+        obs.sort();
+        crate::rbtdrg_info_now!(
+            "dowse case {} — {} observation(s), elapsed min={}s max={}s last={}s inv={}..{}",
+            case,
+            obs.len(),
+            obs.iter().map(|o| o.1).min().unwrap_or(0),
+            obs.iter().map(|o| o.1).max().unwrap_or(0),
+            obs.last().map(|o| o.1).unwrap_or(0),
+            obs.iter().map(|o| o.2).min().unwrap_or(0),
+            obs.iter().map(|o| o.2).max().unwrap_or(0),
+        );
+    }
+
+    Ok(())
+}
+
+// ── Proposals (`rbtd dowse <log-dir> --propose`) ─────────────────
+//
+// Seeds tariff declarations from the census instead of by hand. Per registered
+// fixture, the observed elapsed distribution yields a floor and a ceiling:
+//   min_secs = p<floor>  shaved by the margin, rounded down (None when 0 — a
+//              zero floor catches nothing)
+//   max_secs = p<ceiling> padded by the margin, rounded up
+//   invocations = the observed count when every observation agrees; None when
+//              it varies (a legitimately-variable fixture declares no count)
+// Percentiles are nearest-rank over the fixture's observations. A fixture with
+// fewer than the minimum observation count is reported insufficient — a floor
+// guessed from one run would turn a fast healthy run into a failure.
+//
+// The drift report holds each current declaration against the observed
+// envelope (min..max elapsed, the set of counts): a declared floor above the
+// fastest observation would have failed that green too-fast, a ceiling below
+// the slowest would have warned, a count matching no observation always warns.
+
+/// Dowse flag: propose tariffs rather than print the census.
+pub const RBTDRW_FLAG_PROPOSE: &str = "--propose";
+/// Proposal tuning flags, each taking a numeric value.
+pub const RBTDRW_FLAG_FLOOR_PCT: &str = "--floor-pct";
+pub const RBTDRW_FLAG_CEILING_PCT: &str = "--ceiling-pct";
+pub const RBTDRW_FLAG_MARGIN_PCT: &str = "--margin-pct";
+pub const RBTDRW_FLAG_MIN_RUNS: &str = "--min-runs";
+
+/// Proposal tuning — which percentiles bound the envelope, how far the
+/// declaration stands off them, and how many observations are enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct rbtdrw_ProposeParams {
+    pub floor_pct: u64,
+    pub ceiling_pct: u64,
+    pub margin_pct: u64,
+    pub min_runs: usize,
+}
+
+impl Default for rbtdrw_ProposeParams {
+    fn default() -> Self {
+        rbtdrw_ProposeParams { floor_pct: 10, ceiling_pct: 90, margin_pct: 50, min_runs: 3 }
+    }
+}
+
+/// Parsed `rbtd dowse` arguments. `propose` is None and `verdicts` false for
+/// the plain census.
+#[derive(Debug, PartialEq)]
+pub struct rbtdrw_DowseArgs {
+    pub log_dir: String,
+    pub propose: Option<rbtdrw_ProposeParams>,
+    pub verdicts: bool,
+    /// Verdict-history lower stamp bound (`--since`).
+    pub since: Option<String>,
+}
+
+/// Parse `rbtd dowse` arguments: the log dir, then either `--propose` and its
+/// tuning flags or `--verdicts` and its `--since` bound. A mode's flags without
+/// the mode, or both modes at once, are refused rather than silently ignored.
+pub fn rbtdrw_parse_dowse_args(args: &[String]) -> Result<rbtdrw_DowseArgs, String> {
+    let mut log_dir = None;
+    let mut propose = false;
+    let mut verdicts = false;
+    let mut since = None;
+    let mut tuned = false;
+    let mut params = rbtdrw_ProposeParams::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut number = || -> Result<u64, String> {
+            let v = args.next().ok_or_else(|| format!("flag '{}' needs a value", arg))?;
+            v.parse().map_err(|_| format!("flag '{}' needs a number, got '{}'", arg, v))
+        };
+        match arg.as_str() {
+            RBTDRW_FLAG_PROPOSE => propose = true,
+            RBTDRW_FLAG_VERDICTS => verdicts = true,
+            RBTDRW_FLAG_FLOOR_PCT => params.floor_pct = number()?,
+            RBTDRW_FLAG_CEILING_PCT => params.ceiling_pct = number()?,
+            RBTDRW_FLAG_MARGIN_PCT => params.margin_pct = number()?,
+            RBTDRW_FLAG_MIN_RUNS => params.min_runs = number()? as usize,
+            RBTDRW_FLAG_SINCE => {
+                let v = args.next().ok_or_else(|| format!("flag '{}' needs a value", arg))?;
+                zrbtdrw_check_since(v)?;
+                since = Some(v.clone());
+            }
+            a if a.starts_with("--") => return Err(format!("unknown flag '{}'", a)),
+            a if log_dir.is_none() => log_dir = Some(a.to_string()),
+            a => return Err(format!("unexpected argument '{}'", a)),
+        }
+        let mode_flag = [RBTDRW_FLAG_PROPOSE, RBTDRW_FLAG_VERDICTS, RBTDRW_FLAG_SINCE];
+        tuned |= arg.starts_with("--") && !mode_flag.contains(&arg.as_str());
+    }
+    let log_dir = log_dir.ok_or_else(|| "no log dir".to_string())?;
+    if propose && verdicts {
+        return Err(format!(
+            "{} and {} are separate reports",
+            RBTDRW_FLAG_PROPOSE, RBTDRW_FLAG_VERDICTS
+        ));
+    }
+    if tuned && !propose {
+        return Err(format!("tuning flags apply only with {}", RBTDRW_FLAG_PROPOSE));
+    }
+    if since.is_some() && !verdicts {
+        return Err(format!("{} applies only with {}", RBTDRW_FLAG_SINCE, RBTDRW_FLAG_VERDICTS));
+    }
+    if params.floor_pct > 100 || params.ceiling_pct > 100 || params.floor_pct > params.ceiling_pct {
+        return Err(format!(
+            "percentiles must satisfy 0 <= floor ({}) <= ceiling ({}) <= 100",
+            params.floor_pct, params.ceiling_pct
+        ));
+    }
+    if params.margin_pct > 100 {
+        return Err(format!("margin {}% exceeds 100%", params.margin_pct));
+    }
+    if params.min_runs == 0 {
+        return Err("minimum observation count must be at least 1".to_string());
+    }
+    Ok(rbtdrw_DowseArgs { log_dir, propose: propose.then_some(params), verdicts, since })
+}
+
+/// Nearest-rank percentile of an ascending-sorted, non-empty slice.
+pub fn rbtdrw_percentile(sorted: &[u64], pct: u64) -> u64 {
+    let n = sorted.len() as u64;
+    let rank = (pct * n).div_ceil(100).max(1);
+    sorted[(rank.min(n) - 1) as usize]
+}
+
+/// One fixture's proposal outcome.
+#[derive(Debug, PartialEq)]
+pub enum rbtdrw_Proposal {
+    /// Too few observations to propose anything.
+    Insufficient { runs: usize },
+    Proposed {
+        tariff: rbtdre_Tariff,
+        runs: usize,
+        /// The floor and ceiling percentiles the bounds were derived from.
+        floor_secs: u64,
+        ceiling_secs: u64,
+        /// Observed invocation-count range.
+        invocations_lo: u32,
+        invocations_hi: u32,
+    },
+}
+
+/// Propose a tariff from one fixture's sightings under `params`.
+pub fn rbtdrw_propose(
+    sightings: &[rbtdrw_Sighting],
+    params: &rbtdrw_ProposeParams,
+) -> rbtdrw_Proposal {
+    if sightings.len() < params.min_runs {
+        return rbtdrw_Proposal::Insufficient { runs: sightings.len() };
+    }
+    let mut elapsed: Vec<u64> = sightings.iter().map(|s| s.1).collect();
+    elapsed.sort_unstable();
+    let floor_secs = rbtdrw_percentile(&elapsed, params.floor_pct);
+    let ceiling_secs = rbtdrw_percentile(&elapsed, params.ceiling_pct);
+    let min_secs = floor_secs * (100 - params.margin_pct) / 100;
+    let max_secs = (ceiling_secs * (100 + params.margin_pct)).div_ceil(100);
+    let invocations_lo = sightings.iter().map(|s| s.2).min().unwrap_or(0);
+    let invocations_hi = sightings.iter().map(|s| s.2).max().unwrap_or(0);
+    rbtdrw_Proposal::Proposed {
+        tariff: rbtdre_Tariff {
+            min_secs: (min_secs > 0).then_some(min_secs),
+            max_secs: Some(max_secs),
+            invocations: (invocations_lo == invocations_hi).then_some(invocations_lo),
+        },
+        runs: sightings.len(),
+        floor_secs,
+        ceiling_secs,
+        invocations_lo,
+        invocations_hi,
+    }
+}
+
+/// Render a tariff as a ready-to-paste fixture field.
+pub fn rbtdrw_tariff_literal(tariff: &rbtdre_Tariff) -> String {
+    if *tariff == rbtdre_Tariff::UNCHECKED {
+        return "tariff: rbtdre_Tariff::UNCHECKED,".to_string();
+    }
+    let opt = |o: Option<u64>| o.map_or_else(|| "None".to_string(), |v| format!("Some({})", v));
+    format!(
+        "tariff: rbtdre_Tariff {{ min_secs: {}, max_secs: {}, invocations: {} }},",
+        opt(tariff.min_secs),
+        opt(tariff.max_secs),
+        opt(tariff.invocations.map(u64::from)),
+    )
+}
+
+/// Where a current declaration sits outside the observed envelope, one line
+/// per violated bound. Empty when the declaration covers every observation.
+pub fn rbtdrw_drift(declared: &rbtdre_Tariff, sightings: &[rbtdrw_Sighting]) -> Vec<String> {
+    let mut drift = Vec::new();
+    let (Some(lo), Some(hi)) =
+        (sightings.iter().map(|s| s.1).min(), sightings.iter().map(|s| s.1).max())
+    else {
+        return drift;
+    };
+    if let Some(min) = declared.min_secs.filter(|m| *m > lo) {
+        drift.push(format!(
+            "declared min={}s above observed fastest {}s — that green would fail too-fast",
+            min, lo
+        ));
+    }
+    if let Some(max) = declared.max_secs.filter(|m| *m < hi) {
+        drift.push(format!("declared max={}s below observed slowest {}s", max, hi));
+    }
+    if let Some(count) = declared.invocations.filter(|c| !sightings.iter().any(|s| s.2 == *c)) {
+        let inv_lo = sightings.iter().map(|s| s.2).min().unwrap_or(0);
+        let inv_hi = sightings.iter().map(|s| s.2).max().unwrap_or(0);
+        drift.push(format!(
+            "declared invocations={} matches no observation ({}..{})",
+            count, inv_lo, inv_hi
+        ));
+    }
+    drift
+}
+
+/// Print tariff proposals and the drift report for every registered fixture.
+/// Observations of fixtures no longer registered are counted and named once,
+/// never proposed.
+pub fn rbtdrw_dowse_propose(log_dir: &Path, params: &rbtdrw_ProposeParams) -> Result<(), String> {
+    let history = rbtdrw_gather(log_dir)?;
+    crate::rbtdrg_info_now!(
+        "dowse propose: {} theurge log(s) under '{}'; floor p{} less {}%, ceiling p{} plus {}%, \
+         at least {} observation(s)",
+        history.scanned,
+        log_dir.display(),
+        params.floor_pct,
+        params.margin_pct,
+        params.ceiling_pct,
+        params.margin_pct,
+        params.min_runs
+    );
+
+    let none = Vec::new();
+    let mut insufficient = Vec::new();
+    let mut drifted = 0usize;
+    for fixture in RBTDRA_FIXTURES {
+        let sightings = history.fixtures.get(fixture.name).unwrap_or(&none);
+        match rbtdrw_propose(sightings, params) {
+            rbtdrw_Proposal::Insufficient { runs } => {
+                insufficient.push(format!("{} ({})", fixture.name, runs));
+            }
+            rbtdrw_Proposal::Proposed {
+                tariff,
+                runs,
+                floor_secs,
+                ceiling_secs,
+                invocations_lo,
+                invocations_hi,
+            } => {
+                crate::rbtdrg_info_now!(
+                    "dowse propose {} — {} observation(s), p{}={}s p{}={}s, invocations {}..{}:",
+                    fixture.name,
+                    runs,
+                    params.floor_pct,
+                    floor_secs,
+                    params.ceiling_pct,
+                    ceiling_secs,
+                    invocations_lo,
+                    invocations_hi
+                );
+                crate::rbtdrg_info_now!("    {}", rbtdrw_tariff_literal(&tariff));
+            }
+        }
+        for line in rbtdrw_drift(&fixture.tariff, sightings) {
+            drifted += 1;
+            crate::rbtdrg_info_now!("dowse drift {}: {}", fixture.name, line);
+        }
+    }
+
+    if !insufficient.is_empty() {
+        crate::rbtdrg_info_now!(
+            "dowse propose: insufficient (fewer than {} observation(s)) — no proposal: {}",
+            params.min_runs,
+            insufficient.join(", ")
+        );
+    }
+    let unregistered: Vec<&str> = history
+        .fixtures
+        .keys()
+        .map(|k| k.as_str())
+        .filter(|k| !RBTDRA_FIXTURES.iter().any(|f| f.name == *k))
+        .collect();
+    if !unregistered.is_empty() {
+        crate::rbtdrg_info_now!(
+            "dowse propose: observed but not registered (retired?): {}",
+            unregistered.join(", ")
+        );
+    }
+    crate::rbtdrg_info_now!(
+        "dowse drift: {} declared bound(s) outside the observed envelope",
+        drifted
+    );
+    Ok(())
+}
+
+// ── Verdict history (`rbtd dowse <log-dir> --verdicts`) ──────────
+//
+// The same hist logs carry every case verdict line the runners emit:
+//   `PASSED: <case>` / `FAILED: <case>` / `SKIPPED: <case>`
+// The verdict word may be wrapped in colour escapes (interactive TERM), and a
+// verdict line carries exactly one token after the word — the tariff and
+// census FAILED lines append a reason and are deliberate non-matches, so a
+// too-fast case is counted once, by its own FAILED line. The line names the
+// case alone, and one case can serve several fixtures (tadmor and moriah share
+// their security cases), so each reading is keyed `<fixture>/<case>` by the
+// `FIXTURE: <name>` line opening the section it falls in. A log older than
+// that line keys a case by the one registered fixture hosting it, and leaves
+// it bare when none or several do.
+//
+// A suite run concludes with
+//   `Suite '<name>': <n> fixture(s) run, <p> passed, <f> failed, <s> skipped`
+// — green when it counts no failures, red otherwise. A suite log without that
+// line was interrupted; it is listed as incomplete and neither extends nor
+// breaks a green streak.
+//
+// Per case, the chronological verdicts classify as:
+//   steady     — never failed in range
+//   failing    — failed every concluded run in range
+//   regressed  — passed, then failed every run since (one flip, red now)
+//   recovered  — failed, then passed every run since (one flip, green now)
+//   flaky      — flipped between pass and fail two or more times in range:
+//                cloud weather until proven otherwise
+// Skips are counted but never flip a verdict.
+
+/// Dowse flag: print verdict-history analytics rather than the census.
+pub const RBTDRW_FLAG_VERDICTS: &str = "--verdicts";
+/// Verdict-history flag: restrict to hist logs stamped at or after
+/// `YYYYMMDD[-HHMMSS]`.
+pub const RBTDRW_FLAG_SINCE: &str = "--since";
+
+/// Suite-summary lead token (`Suite '<name>': ...`).
+const RBTDRW_SUITE_WORD: &str = "Suite";
+
+/// One case verdict as read back from a hist log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum rbtdrw_Verdict {
+    Pass,
+    Fail,
+    Skip,
+}
+
+/// Parse one self-log line as a case verdict line, yielding the verdict and
+/// case name. None for every other line, the reason-bearing FAILED lines
+/// included (see the section header).
+pub fn rbtdrw_parse_verdict_line(line: &str) -> Option<(rbtdrw_Verdict, String)> {
+    let toks: Vec<String> = line.split_whitespace().map(zrbtdrw_strip_ansi).collect();
+    let (i, verdict) = toks.iter().enumerate().find_map(|(i, t)| {
+        let v = match t.as_str() {
+            RBTDRE_WORD_PASSED => rbtdrw_Verdict::Pass,
+            RBTDRE_WORD_FAILED => rbtdrw_Verdict::Fail,
+            RBTDRE_WORD_SKIPPED => rbtdrw_Verdict::Skip,
+            _ => return None,
+        };
+        Some((i, v))
+    })?;
+    match &toks[i + 1..] {
+        [case] => Some((verdict, case.clone())),
+        _ => None,
+    }
+}
+
+/// Parse one self-log line as a fixture section line, yielding the fixture.
+pub fn rbtdrw_parse_fixture_line(line: &str) -> Option<String> {
+    let toks: Vec<String> = line.split_whitespace().map(zrbtdrw_strip_ansi).collect();
+    match toks.as_slice() {
+        [.., word, fixture] if word == RBTDRE_WORD_FIXTURE => Some(fixture.clone()),
+        _ => None,
+    }
+}
+
+/// Parse one self-log line as a suite summary line, yielding the suite name
+/// and whether the run was green.
+pub fn rbtdrw_parse_suite_line(line: &str) -> Option<(String, bool)> {
+    let mut toks = line.split_whitespace().skip_while(|t| *t != RBTDRW_SUITE_WORD).skip(1);
+    let suite = toks.next()?.strip_prefix('\'')?.strip_suffix("':")?;
+    if suite.is_empty() {
+        return None;
+    }
+    let rest: Vec<&str> = toks.collect();
+    let at = rest.iter().position(|t| *t == "failed,")?;
+    let failed: usize = rest.get(at.checked_sub(1)?)?.parse().ok()?;
+    Some((suite.to_string(), failed == 0))
+}
+
+/// One case verdict as the history holds it: dispatch stamp, verdict, and a
+/// source label naming the run kind.
+pub type rbtdrw_Reading = (String, rbtdrw_Verdict, String);
+
+/// Verdict history gathered from a log dir.
+#[derive(Debug, Default)]
+pub struct rbtdrw_VerdictHistory {
+    /// Theurge hist logs found in range.
+    pub scanned: usize,
+    /// Of those, logs carrying at least one case verdict.
+    pub carrying: usize,
+    /// suite → runs of (stamp, Some(green) or None when incomplete)
+    pub suites: BTreeMap<String, Vec<(String, Option<bool>)>>,
+    /// `<fixture>/<case>` (bare case when unattributed) → readings, in
+    /// log-dir walk order (sort for chronology)
+    pub cases: BTreeMap<String, Vec<rbtdrw_Reading>>,
+}
+
+/// Walk the station's log dir and gather every case verdict and suite outcome
+/// from logs stamped at or after `since` (stamps compare lexically).
+pub fn rbtdrw_gather_verdicts(
+    log_dir: &Path,
+    since: Option<&str>,
+) -> Result<rbtdrw_VerdictHistory, String> {
+    let mut history = rbtdrw_VerdictHistory::default();
+
+    zrbtdrw_walk(log_dir, |kind, stamp, content| {
+        if since.is_some_and(|s| stamp.as_str() < s) {
+            return;
+        }
+        history.scanned += 1;
+        let mut section: Option<String> = None;
+        let mut readings: Vec<(rbtdrw_Verdict, String)> = Vec::new();
+        for line in content.lines() {
+            if let Some(fixture) = rbtdrw_parse_fixture_line(line) {
+                section = Some(fixture);
+            } else if let Some((verdict, case)) = rbtdrw_parse_verdict_line(line) {
+                let fixture = section.as_deref().or_else(|| zrbtdrw_case_fixture(&case));
+                let key = match fixture {
+                    Some(fixture) => format!("{}/{}", fixture, case),
+                    None => case,
+                };
+                readings.push((verdict, key));
+            }
+        }
+        if let rbtdrw_LogKind::Suite(s) = &kind {
+            let green = content
+                .lines()
+                .filter_map(rbtdrw_parse_suite_line)
+                .find(|(name, _)| name == s)
+                .map(|(_, green)| green);
+            history.suites.entry(s.clone()).or_default().push((stamp.clone(), green));
+        }
+        if readings.is_empty() {
+            return;
+        }
+        history.carrying += 1;
+        let label = zrbtdrw_label(&kind);
+        for (verdict, case) in readings {
+            history
+                .cases
+                .entry(case)
+                .or_default()
+                .push((stamp.clone(), verdict, label.clone()));
+        }
+    })?;
+    Ok(history)
+}
+
+/// A case's verdict-history classification (see the section header).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum rbtdrw_CaseClass {
+    Failing,
+    Regressed,
+    Flaky,
+    Recovered,
+    Steady,
+}
+
+impl rbtdrw_CaseClass {
+    pub fn word(self) -> &'static str {
+        match self {
+            rbtdrw_CaseClass::Failing => "failing",
+            rbtdrw_CaseClass::Regressed => "regressed",
+            rbtdrw_CaseClass::Flaky => "flaky",
+            rbtdrw_CaseClass::Recovered => "recovered",
+            rbtdrw_CaseClass::Steady => "steady",
+        }
+    }
+}
+
+/// One case's verdict analytics over the gathered range.
+#[derive(Debug, PartialEq)]
+pub struct rbtdrw_CaseAnalysis {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// Stamp of the earliest FAILED reading in range.
+    pub first_failing: Option<String>,
+    /// Stamp of the latest FAILED reading in range.
+    pub last_failure: Option<String>,
+    /// Stamp opening the current failing run, when the latest verdict is red.
+    pub failing_since: Option<String>,
+    /// Pass↔fail transitions across the concluded verdicts.
+    pub flips: usize,
+    pub class: rbtdrw_CaseClass,
+}
+
+impl rbtdrw_CaseAnalysis {
+    /// Passes over concluded (pass + fail) verdicts, in whole percent. None
+    /// when every reading was a skip.
+    pub fn pass_rate_pct(&self) -> Option<usize> {
+        let concluded = self.passed + self.failed;
+        (concluded > 0).then(|| self.passed * 100 / concluded)
+    }
+}
+
+/// Analyse one case's readings. Input order is irrelevant; readings are taken
+/// in stamp order.
+pub fn rbtdrw_analyze_case(readings: &[rbtdrw_Reading]) -> rbtdrw_CaseAnalysis {
+    let mut sorted: Vec<&rbtdrw_Reading> = readings.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let count = |v: rbtdrw_Verdict| sorted.iter().filter(|r| r.1 == v).count();
+    let concluded: Vec<&rbtdrw_Reading> =
+        sorted.iter().copied().filter(|r| r.1 != rbtdrw_Verdict::Skip).collect();
+    let fails = || concluded.iter().filter(|r| r.1 == rbtdrw_Verdict::Fail);
+    let flips = concluded.windows(2).filter(|w| w[0].1 != w[1].1).count();
+    let failing_since = match concluded.last() {
+        Some(last) if last.1 == rbtdrw_Verdict::Fail => concluded
+            .iter()
+            .rev()
+            .take_while(|r| r.1 == rbtdrw_Verdict::Fail)
+            .last()
+            .map(|r| r.0.clone()),
+        _ => None,
+    };
+
+    let failed = count(rbtdrw_Verdict::Fail);
+    let class = match (failed, flips, failing_since.is_some()) {
+        (0, _, _) => rbtdrw_CaseClass::Steady,
+        (_, 0, _) => rbtdrw_CaseClass::Failing,
+        (_, 1, true) => rbtdrw_CaseClass::Regressed,
+        (_, 1, false) => rbtdrw_CaseClass::Recovered,
+        _ => rbtdrw_CaseClass::Flaky,
+    };
+
+    rbtdrw_CaseAnalysis {
+        passed: count(rbtdrw_Verdict::Pass),
+        failed,
+        skipped: count(rbtdrw_Verdict::Skip),
+        first_failing: fails().next().map(|r| r.0.clone()),
+        last_failure: fails().next_back().map(|r| r.0.clone()),
+        failing_since,
+        flips,
+        class,
+    }
+}
+
+/// A suite's green-streak analytics over the gathered range.
+#[derive(Debug, PartialEq)]
+pub struct rbtdrw_Streaks {
+    pub green: usize,
+    pub red: usize,
+    pub incomplete: usize,
+    /// Consecutive greens ending at the newest concluded run.
+    pub current: usize,
+    /// Longest run of consecutive greens.
+    pub longest: usize,
+    /// Stamp of the newest red run.
+    pub last_red: Option<String>,
+}
+
+/// Analyse one suite's runs. Input order is irrelevant; runs are taken in
+/// stamp order, and incomplete runs are skipped by the streak count.
+pub fn rbtdrw_suite_streaks(runs: &[(String, Option<bool>)]) -> rbtdrw_Streaks {
+    let mut sorted: Vec<&(String, Option<bool>)> = runs.iter().collect();
+    sorted.sort();
+
+    let mut streaks = rbtdrw_Streaks {
+        green: 0,
+        red: 0,
+        incomplete: 0,
+        current: 0,
+        longest: 0,
+        last_red: None,
+    };
+    for (stamp, outcome) in sorted {
+        match outcome {
+            Some(true) => {
+                streaks.green += 1;
+                streaks.current += 1;
+                streaks.longest = streaks.longest.max(streaks.current);
+            }
+            Some(false) => {
+                streaks.red += 1;
+                streaks.current = 0;
+                streaks.last_red = Some(stamp.clone());
+            }
+            None => streaks.incomplete += 1,
+        }
+    }
+    streaks
+}
+
+/// Validate a `--since` bound: `YYYYMMDD` or `YYYYMMDD-HHMMSS`.
+fn zrbtdrw_check_since(since: &str) -> Result<(), String> {
+    let digits = |s: &str, n: usize| s.len() == n && s.chars().all(|c| c.is_ascii_digit());
+    let ok = match since.split_once('-') {
+        Some((day, time)) => digits(day, 8) && digits(time, 6),
+        None => digits(since, 8),
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(format!(
+            "flag '{}' needs a stamp YYYYMMDD[-HHMMSS], got '{}'",
+            RBTDRW_FLAG_SINCE, since
+        ))
+    }
+}
+
+/// The one registered fixture hosting `case`, attributing a verdict read from
+/// a log without section lines. None when no fixture or several host it.
+fn zrbtdrw_case_fixture(case: &str) -> Option<&'static str> {
+    let mut hosts = RBTDRA_FIXTURES.iter().filter(|f| f.cases.iter().any(|c| c.name == case));
+    match (hosts.next(), hosts.next()) {
+        (Some(f), None) => Some(f.name),
+        _ => None,
+    }
+}
+
+/// Scan the station's log dir and print verdict-history analytics: per suite,
+/// its run tally and green streaks; per case, its class, pass rate, and failure
+/// stamps, worst class first so regressions lead and steady cases trail.
+pub fn rbtdrw_dowse_verdicts(log_dir: &Path, since: Option<&str>) -> Result<(), String> {
+    let history = rbtdrw_gather_verdicts(log_dir, since)?;
+
+    crate::rbtdrg_info_now!(
+        "dowse verdicts: {} theurge log(s) under '{}'{}, {} carrying case verdicts",
+        history.scanned,
+        log_dir.display(),
+        since.map(|s| format!(" since {}", s)).unwrap_or_default(),
+        history.carrying
+    );
+    if history.carrying == 0 && history.suites.is_empty() {
+        crate::rbtdrg_info_now!("dowse verdicts: no verdicts in range");
+        return Ok(());
+    }
+
+    for (suite, runs) in &history.suites {
+        let s = rbtdrw_suite_streaks(runs);
+        crate::rbtdrg_info_now!(
+            "dowse suite {} — {} run(s): green={} red={} incomplete={}  \
+             streak current={} longest={}  last red={}",
+            suite,
+            runs.len(),
+            s.green,
+            s.red,
+            s.incomplete,
+            s.current,
+            s.longest,
+            s.last_red.as_deref().unwrap_or("—")
+        );
+    }
+
+    let mut analyses: Vec<(String, rbtdrw_CaseAnalysis)> = history
+        .cases
+        .iter()
+        .map(|(case, readings)| (case.clone(), rbtdrw_analyze_case(readings)))
+        .collect();
+    analyses.sort_by(|a, b| a.1.class.cmp(&b.1.class).then_with(|| a.0.cmp(&b.0)));
+
+    let mut tally: BTreeMap<rbtdrw_CaseClass, usize> = BTreeMap::new();
+    for (shown, a) in &analyses {
+        *tally.entry(a.class).or_default() += 1;
+        let rate = a.pass_rate_pct().map_or_else(|| "—".to_string(), |r| format!("{}%", r));
+        let mut line = format!(
+            "dowse case {:<9} {}  pass={} fail={} skip={} rate={} flips={}",
+            a.class.word(),
+            shown,
+            a.passed,
+            a.failed,
+            a.skipped,
+            rate,
+            a.flips
+        );
+        if let Some(first) = &a.first_failing {
+            line.push_str(&format!("  first failing={}", first));
+        }
+        if let Some(last) = &a.last_failure {
+            line.push_str(&format!("  last failure={}", last));
+        }
+        if let Some(since) = &a.failing_since {
+            line.push_str(&format!("  failing since={}", since));
+        }
+        crate::rbtdrg_info_now!("{}", line);
+    }
+
+    let summary: Vec<String> =
+        tally.iter().map(|(class, n)| format!("{}={}", class.word(), n)).collect();
+    crate::rbtdrg_info_now!(
+        "dowse verdicts: {} case(s) — {}",
+        analyses.len(),
+        summary.join(" ")
+    );
     Ok(())
 }
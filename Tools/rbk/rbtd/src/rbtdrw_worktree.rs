@@ -0,0 +1,147 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRW — disposable worktree for `rbtd suite <suite> --worktree`
+//
+// A suite commits hallmark/yoke evolution through the scoped verbs in
+// rbtdre_engine, so a plain run demands a clean checkout and leaves its commits
+// on the operator's branch. Worktree mode runs the suite somewhere else: a
+// detached `git worktree` at HEAD becomes the run's project root (and its
+// working directory, since fixtures read the root from `current_dir()`), and
+// the operator keeps editing the checkout while a long gauntlet runs. The
+// checkout's uncommitted edits are simply not part of the run.
+//
+// The worktree is a sibling of the checkout, not a temp dir: BUK config names
+// the station file relative to the repo root (`../station-files`), and a
+// sibling resolves it exactly as the checkout does. At the end the worktree is
+// always removed. Commits the run made survive as a branch
+// `rbtd/<suite>/<stamp>` for review; a run that committed nothing leaves no
+// branch. Uncommitted leftovers are listed before removal — they are lost.
+
+use std::path::{Path, PathBuf};
+
+use crate::rbtdre_engine::rbtdre_tree_commit;
+
+/// BUK dispatch env var carrying the run's `YYYYMMDD-HHMMSS-<pid>-<n>` stamp.
+/// Names the worktree and the offered branch, so both match the run's
+/// BURD_TEMP_DIR and logs.
+pub const RBTDRW_BURD_NOW_STAMP_KEY: &str = "BURD_NOW_STAMP";
+
+/// Namespace of the branches a worktree run offers for review.
+pub const RBTDRW_BRANCH_PREFIX: &str = "rbtd/";
+
+/// Infix between the checkout's directory name and the suite in the worktree's
+/// sibling directory name.
+const ZRBTDRW_DIR_INFIX: &str = "-rbtd-";
+
+/// A live worktree: where it is, the commit it started at, and the branch its
+/// commits will be offered on.
+#[derive(Debug)]
+pub struct rbtdrw_Worktree {
+    pub checkout: PathBuf,
+    pub path: PathBuf,
+    pub base: String,
+    pub branch: String,
+}
+
+/// What a finished worktree run left behind.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct rbtdrw_Outcome {
+    /// The offered branch — None when the run committed nothing.
+    pub branch: Option<String>,
+    /// `<short-hash> <subject>` per commit the run made, oldest first.
+    pub commits: Vec<String>,
+    /// `git status --porcelain` lines the worktree held at removal.
+    pub leftovers: Vec<String>,
+}
+
+fn zrbtdrw_git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let out = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| format!("git {} exec failed: {}", args[0], e))?;
+    if !out.status.success() {
+        return Err(format!(
+            "git {} exited {}: {}",
+            args.join(" "),
+            out.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&out.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+impl rbtdrw_Worktree {
+    /// Add a detached worktree at the checkout's HEAD, beside the checkout.
+    /// Refuses when the sibling directory or the branch already exists rather
+    /// than reusing either — each run gets its own.
+    pub fn create(checkout: &Path, suite: &str, stamp: &str) -> Result<rbtdrw_Worktree, String> {
+        let base = rbtdre_tree_commit(checkout)?;
+        let name = checkout
+            .file_name()
+            .ok_or_else(|| format!("checkout '{}' has no directory name", checkout.display()))?;
+        let parent = checkout
+            .parent()
+            .ok_or_else(|| format!("checkout '{}' has no parent", checkout.display()))?;
+        let path = parent.join(format!(
+            "{}{}{}-{}",
+            name.to_string_lossy(),
+            ZRBTDRW_DIR_INFIX,
+            suite,
+            stamp
+        ));
+        if path.exists() {
+            return Err(format!("worktree path '{}' already exists", path.display()));
+        }
+        let branch = format!("{}{}/{}", RBTDRW_BRANCH_PREFIX, suite, stamp);
+        let refname = format!("refs/heads/{}", branch);
+        if zrbtdrw_git(checkout, &["rev-parse", "--verify", "--quiet", &refname]).is_ok() {
+            return Err(format!("branch '{}' already exists", branch));
+        }
+        let path_arg = path.to_string_lossy().into_owned();
+        zrbtdrw_git(checkout, &["worktree", "add", "--detach", &path_arg, &base])?;
+        Ok(rbtdrw_Worktree { checkout: checkout.to_path_buf(), path, base, branch })
+    }
+
+    /// Offer the run's commits as the branch, then remove the worktree. The
+    /// branch is created before the removal, so a failed removal never costs
+    /// the commits; it is reported as an Err naming the path to clean up.
+    pub fn finish(self) -> Result<rbtdrw_Outcome, String> {
+        let leftovers: Vec<String> = zrbtdrw_git(&self.path, &["status", "--porcelain"])?
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let head = rbtdre_tree_commit(&self.path)?;
+        let range = format!("{}..{}", self.base, head);
+        let commits: Vec<String> =
+            zrbtdrw_git(&self.path, &["log", "--reverse", "--format=%h %s", &range])?
+                .lines()
+                .map(str::to_string)
+                .collect();
+        let branch = if commits.is_empty() {
+            None
+        } else {
+            zrbtdrw_git(&self.checkout, &["branch", &self.branch, &head])?;
+            Some(self.branch.clone())
+        };
+        let path_arg = self.path.to_string_lossy().into_owned();
+        zrbtdrw_git(&self.checkout, &["worktree", "remove", "--force", &path_arg]).map_err(|e| {
+            format!("worktree '{}' not removed — `git worktree remove` it: {}", path_arg, e)
+        })?;
+        Ok(rbtdrw_Outcome { branch, commits, leftovers })
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRX — host environment fingerprint, written into every trace root
+//
+// A containment case that fails on one station and passes on another is first
+// a question about the hosts: which kernel, which cgroup hierarchy, which
+// docker, which iptables backend, which bash. Fixture and suite runs collect
+// the answers at run start into RBTDRX_FINGERPRINT_FILE in the trace root, and
+// `rbtd envdiff` lines two of them up.
+//
+// Every probe is best-effort: a tool that is absent or fails reads as
+// RBTDRX_UNAVAILABLE rather than failing the run — "no podman here" is itself
+// the answer. The iptables backend rides the `iptables --version` line
+// (`(nf_tables)` or `(legacy)`).
+//
+// Format: a flat JSON object of string values in a fixed key order, one key per
+// line. `rbtdrx_Fingerprint::parse` reads exactly that shape back — no serde in
+// theurge.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::rbtdre_engine::rbtdre_tree_commit;
+use crate::rbtdri_invocation::rbtdri_bash_program;
+use crate::rbtdrr_report::rbtdrr_json_str;
+use crate::rbtdrx_platform::rbtdrx_is_cygwin;
+
+/// Fingerprint filename, written into the trace root.
+pub const RBTDRX_FINGERPRINT_FILE: &str = "environment.json";
+
+/// Value of a probe whose tool is absent or failed.
+pub const RBTDRX_UNAVAILABLE: &str = "unavailable";
+
+pub const RBTDRX_KEY_OS: &str = "os";
+pub const RBTDRX_KEY_ARCH: &str = "arch";
+pub const RBTDRX_KEY_OS_RELEASE: &str = "os_release";
+pub const RBTDRX_KEY_KERNEL: &str = "kernel";
+pub const RBTDRX_KEY_CGROUP: &str = "cgroup";
+pub const RBTDRX_KEY_DOCKER: &str = "docker";
+pub const RBTDRX_KEY_PODMAN: &str = "podman";
+pub const RBTDRX_KEY_IPTABLES: &str = "iptables";
+pub const RBTDRX_KEY_BASH: &str = "bash";
+pub const RBTDRX_KEY_GIT_HEAD: &str = "git_head";
+pub const RBTDRX_KEY_RUSTC: &str = "rustc";
+pub const RBTDRX_KEY_CARGO: &str = "cargo";
+pub const RBTDRX_KEY_CYGWIN: &str = "cygwin";
+
+/// Keys the run-report summary carries — the ones that settle most
+/// which-host questions at a glance. The file carries them all.
+pub const RBTDRX_SUMMARY_KEYS: &[&str] = &[
+    RBTDRX_KEY_KERNEL,
+    RBTDRX_KEY_CGROUP,
+    RBTDRX_KEY_DOCKER,
+    RBTDRX_KEY_IPTABLES,
+    RBTDRX_KEY_GIT_HEAD,
+];
+
+const ZRBTDRX_OS_RELEASE_FILE: &str = "/etc/os-release";
+const ZRBTDRX_CGROUP_ROOT: &str = "/sys/fs/cgroup";
+/// Present at the cgroup root only under the unified (v2) hierarchy.
+const ZRBTDRX_CGROUP_V2_MARKER: &str = "cgroup.controllers";
+/// The v2 mount a hybrid host nests beside its v1 controllers.
+const ZRBTDRX_CGROUP_HYBRID_SUBDIR: &str = "unified";
+
+/// A host fingerprint: `(key, value)` pairs in collection order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct rbtdrx_Fingerprint {
+    pub entries: Vec<(String, String)>,
+}
+
+/// First non-empty stdout line of `program args`, or RBTDRX_UNAVAILABLE when
+/// it cannot be spawned, exits non-zero, or prints nothing.
+fn zrbtdrx_probe(program: &str, args: &[&str]) -> String {
+    match Command::new(program).args(args).output() {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .map_or_else(|| RBTDRX_UNAVAILABLE.to_string(), str::to_string),
+        _ => RBTDRX_UNAVAILABLE.to_string(),
+    }
+}
+
+/// `PRETTY_NAME` from an os-release body, unquoted.
+pub(crate) fn zrbtdrx_os_release_name(body: &str) -> Option<String> {
+    body.lines()
+        .find_map(|l| l.strip_prefix("PRETTY_NAME="))
+        .map(|v| v.trim().trim_matches('"').to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// cgroup hierarchy under `root`: `v2` (unified), `hybrid` (v1 controllers
+/// with a nested unified mount), `v1`, or `none` when no cgroup fs is mounted.
+pub(crate) fn zrbtdrx_cgroup_version(root: &Path) -> &'static str {
+    if root.join(ZRBTDRX_CGROUP_V2_MARKER).is_file() {
+        "v2"
+    } else if root.join(ZRBTDRX_CGROUP_HYBRID_SUBDIR).join(ZRBTDRX_CGROUP_V2_MARKER).is_file() {
+        "hybrid"
+    } else if root.is_dir() {
+        "v1"
+    } else {
+        "none"
+    }
+}
+
+impl rbtdrx_Fingerprint {
+    /// Probe this host. `project_root` is the tree the run launches from, whose
+    /// HEAD is recorded.
+    pub fn collect(project_root: &Path) -> rbtdrx_Fingerprint {
+        let os_release = std::fs::read_to_string(ZRBTDRX_OS_RELEASE_FILE)
+            .ok()
+            .and_then(|body| zrbtdrx_os_release_name(&body))
+            .unwrap_or_else(|| RBTDRX_UNAVAILABLE.to_string());
+        let git_head =
+            rbtdre_tree_commit(project_root).unwrap_or_else(|_| RBTDRX_UNAVAILABLE.to_string());
+        let pairs = [
+            (RBTDRX_KEY_OS, std::env::consts::OS.to_string()),
+            (RBTDRX_KEY_ARCH, std::env::consts::ARCH.to_string()),
+            (RBTDRX_KEY_OS_RELEASE, os_release),
+            (RBTDRX_KEY_KERNEL, zrbtdrx_probe("uname", &["-sr"])),
+            (RBTDRX_KEY_CGROUP, zrbtdrx_cgroup_version(Path::new(ZRBTDRX_CGROUP_ROOT)).to_string()),
+            (RBTDRX_KEY_DOCKER, zrbtdrx_probe("docker", &["--version"])),
+            (RBTDRX_KEY_PODMAN, zrbtdrx_probe("podman", &["--version"])),
+            (RBTDRX_KEY_IPTABLES, zrbtdrx_probe("iptables", &["--version"])),
+            (RBTDRX_KEY_BASH, zrbtdrx_probe(rbtdri_bash_program(), &["--version"])),
+            (RBTDRX_KEY_GIT_HEAD, git_head),
+            (RBTDRX_KEY_RUSTC, zrbtdrx_probe("rustc", &["--version"])),
+            (RBTDRX_KEY_CARGO, zrbtdrx_probe("cargo", &["--version"])),
+            (RBTDRX_KEY_CYGWIN, rbtdrx_is_cygwin().to_string()),
+        ];
+        rbtdrx_Fingerprint {
+            entries: pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn render(&self) -> String {
+        let body: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(k, v)| format!("  {}: {}", rbtdrr_json_str(k), rbtdrr_json_str(v)))
+            .collect();
+        format!("{{\n{}\n}}\n", body.join(",\n"))
+    }
+
+    /// Read back a flat JSON object of string values — the shape `render`
+    /// writes. Anything else (nesting, numbers, a duplicate key) is an Err.
+    pub fn parse(text: &str) -> Result<rbtdrx_Fingerprint, String> {
+        let mut chars = text.trim().chars().peekable();
+        if chars.next() != Some('{') {
+            return Err("fingerprint is not a JSON object".to_string());
+        }
+        let mut entries: Vec<(String, String)> = Vec::new();
+        loop {
+            zrbtdrx_skip_ws(&mut chars);
+            if chars.peek() == Some(&'}') && entries.is_empty() {
+                chars.next();
+                break;
+            }
+            let key = zrbtdrx_json_string(&mut chars)?;
+            zrbtdrx_skip_ws(&mut chars);
+            if chars.next() != Some(':') {
+                return Err(format!("fingerprint key '{}' has no ':'", key));
+            }
+            zrbtdrx_skip_ws(&mut chars);
+            let value = zrbtdrx_json_string(&mut chars)
+                .map_err(|e| format!("fingerprint key '{}': {}", key, e))?;
+            if entries.iter().any(|(k, _)| *k == key) {
+                return Err(format!("fingerprint repeats key '{}'", key));
+            }
+            entries.push((key, value));
+            zrbtdrx_skip_ws(&mut chars);
+            match chars.next() {
+                Some(',') => continue,
+                Some('}') => break,
+                other => {
+                    return Err(format!("fingerprint: expected ',' or '}}', found {:?}", other))
+                }
+            }
+        }
+        if chars.next().is_some() {
+            return Err("fingerprint has trailing content after the object".to_string());
+        }
+        Ok(rbtdrx_Fingerprint { entries })
+    }
+
+    pub fn read(path: &Path) -> Result<rbtdrx_Fingerprint, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("rbtd: cannot read fingerprint '{}': {}", path.display(), e))?;
+        rbtdrx_Fingerprint::parse(&text).map_err(|e| format!("rbtd: '{}': {}", path.display(), e))
+    }
+
+    /// Write the fingerprint into `trace_root`, returning the file's path.
+    pub fn write(&self, trace_root: &Path) -> Result<std::path::PathBuf, String> {
+        let path = trace_root.join(RBTDRX_FINGERPRINT_FILE);
+        std::fs::write(&path, self.render())
+            .map_err(|e| format!("rbtd: cannot write fingerprint '{}': {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+fn zrbtdrx_skip_ws(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+/// One JSON string literal, opening quote next in `chars`; the inverse of
+/// `rbtdrr_json_str`, plus the escapes it never emits.
+fn zrbtdrx_json_string(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> Result<String, String> {
+    if chars.next() != Some('"') {
+        return Err("expected a string value".to_string());
+    }
+    let mut out = String::new();
+    loop {
+        match chars.next() {
+            None => return Err("unterminated string".to_string()),
+            Some('"') => return Ok(out),
+            Some('\\') => match chars.next() {
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                Some('/') => out.push('/'),
+                Some('n') => out.push('\n'),
+                Some('r') => out.push('\r'),
+                Some('t') => out.push('\t'),
+                Some('b') => out.push('\u{8}'),
+                Some('f') => out.push('\u{c}'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&hex, 16)
+                        .map_err(|_| format!("bad \\u escape '{}'", hex))?;
+                    out.push(char::from_u32(code).unwrap_or('\u{FFFD}'));
+                }
+                other => return Err(format!("bad escape {:?}", other)),
+            },
+            Some(c) => out.push(c),
+        }
+    }
+}
+
+/// Every key whose value differs between `a` and `b`, in `a`'s key order then
+/// `b`'s extras: `key: <a> → <b>`, with `(absent)` for a side lacking the key.
+/// Empty when the two hosts fingerprint alike.
+pub fn rbtdrx_fingerprint_diff(a: &rbtdrx_Fingerprint, b: &rbtdrx_Fingerprint) -> Vec<String> {
+    let mut keys: Vec<&str> = a.entries.iter().map(|(k, _)| k.as_str()).collect();
+    for (k, _) in &b.entries {
+        if !keys.contains(&k.as_str()) {
+            keys.push(k);
+        }
+    }
+    keys.into_iter()
+        .filter_map(|k| {
+            let (left, right) = (a.get(k), b.get(k));
+            (left != right).then(|| {
+                format!("{}: {} → {}", k, left.unwrap_or("(absent)"), right.unwrap_or("(absent)"))
+            })
+        })
+        .collect()
+}
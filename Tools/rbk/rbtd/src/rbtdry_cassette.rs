@@ -0,0 +1,326 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRY — cassettes: record and replay funnelled tabtarget launches
+//
+// Most case logic is parsing — band codes, fact files, GAR refs, docker
+// inspect output — and exercising it should not need a live cloud run. A
+// cassette is a directory holding one entry per launch through
+// `rbtdri_invoke_impl`, in launch order:
+//
+//   <cassette>/<seq>-<colophon>/entry.txt   key and exit code
+//   <cassette>/<seq>-<colophon>/stdout      captured stdout
+//   <cassette>/<seq>-<colophon>/stderr      captured stderr
+//   <cassette>/<seq>-<colophon>/current/    the launch's BURV fact files
+//
+// Recording writes an entry after each launch exits. Replaying spawns no bash:
+// each launch is matched by colophon, imprint and args to the first entry not
+// yet served, its fact files are laid into the launch's BURV output dir the way
+// bud_dispatch would (prior current/ promoted to previous/, then a fresh
+// current/), and its captured result is returned. A launch with no matching
+// entry fails as a launch error, so case logic that diverged from the recorded
+// run fails loud rather than reading stale facts.
+//
+// Only the funnel is taped. The direct-Command bypass helpers, and anything a
+// case runs itself, still run live on replay.
+//
+// Format: `entry.txt` is one `key=value` per line, `arg=` repeated in order,
+// values escaped (`\\`, `\n`) so an arg may carry any text. Hand-rolled and
+// line-oriented like the suite checkpoint — no serde in theurge.
+
+use std::path::{Path, PathBuf};
+
+use crate::rbtdri_invocation::{rbtdri_InvokeResult, RBTDRI_BURV_OUTPUT_SUBDIR};
+
+/// Entry description filename, inside each entry dir.
+pub const RBTDRY_ENTRY_FILE: &str = "entry.txt";
+/// Captured-stream filenames, inside each entry dir.
+pub const RBTDRY_STDOUT_FILE: &str = "stdout";
+pub const RBTDRY_STDERR_FILE: &str = "stderr";
+
+/// BUK dispatch's promoted-output sibling of `current/` (see bud_dispatch.sh).
+const RBTDRY_BURV_PREVIOUS_SUBDIR: &str = "previous";
+
+const RBTDRY_KEY_COLOPHON: &str = "colophon";
+const RBTDRY_KEY_IMPRINT: &str = "imprint";
+const RBTDRY_KEY_ARG: &str = "arg";
+const RBTDRY_KEY_EXIT_CODE: &str = "exit_code";
+
+/// What a launch is matched by: colophon, imprint (empty for a global
+/// tabtarget), and args in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdry_Key {
+    pub colophon: String,
+    pub imprint: String,
+    pub args: Vec<String>,
+}
+
+impl rbtdry_Key {
+    /// Key a launch of `tabtarget`. The imprint is read back off the resolved
+    /// filename (`{colophon}.{frontispiece}.{imprint}.sh`), so every
+    /// discovery route — fixture, explicit imprint, global — keys alike.
+    pub fn new(colophon: &str, tabtarget: &Path, args: &[&str]) -> rbtdry_Key {
+        let name = tabtarget.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let middle = name
+            .strip_prefix(&format!("{}.", colophon))
+            .and_then(|rest| rest.strip_suffix(".sh"))
+            .unwrap_or("");
+        let imprint = middle.split_once('.').map_or("", |(_, imprint)| imprint);
+        rbtdry_Key {
+            colophon: colophon.to_string(),
+            imprint: imprint.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    /// One-line rendering for messages.
+    pub fn describe(&self) -> String {
+        format!(
+            "colophon '{}' imprint '{}' args [{}]",
+            self.colophon,
+            self.imprint,
+            self.args.join(" ")
+        )
+    }
+}
+
+/// One recorded launch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdry_Entry {
+    pub key: rbtdry_Key,
+    pub exit_code: i32,
+}
+
+impl rbtdry_Entry {
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{}={}\n{}={}\n",
+            RBTDRY_KEY_COLOPHON, zrbtdry_escape(&self.key.colophon),
+            RBTDRY_KEY_IMPRINT, zrbtdry_escape(&self.key.imprint),
+        );
+        for arg in &self.key.args {
+            out.push_str(&format!("{}={}\n", RBTDRY_KEY_ARG, zrbtdry_escape(arg)));
+        }
+        out.push_str(&format!("{}={}\n", RBTDRY_KEY_EXIT_CODE, self.exit_code));
+        out
+    }
+
+    pub fn parse(text: &str) -> Result<rbtdry_Entry, String> {
+        let mut colophon = None;
+        let mut imprint = None;
+        let mut args = Vec::new();
+        let mut exit_code = None;
+        for line in text.lines().filter(|l| !l.is_empty()) {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("cassette entry line without '=': '{}'", line))?;
+            let value = zrbtdry_unescape(value);
+            match key {
+                RBTDRY_KEY_COLOPHON => colophon = Some(value),
+                RBTDRY_KEY_IMPRINT => imprint = Some(value),
+                RBTDRY_KEY_ARG => args.push(value),
+                RBTDRY_KEY_EXIT_CODE => {
+                    exit_code = Some(value.parse::<i32>().map_err(|e| {
+                        format!("cassette entry {} '{}': {}", RBTDRY_KEY_EXIT_CODE, value, e)
+                    })?)
+                }
+                other => return Err(format!("cassette entry has unknown key '{}'", other)),
+            }
+        }
+        let missing = |k: &str| format!("cassette entry is missing '{}'", k);
+        Ok(rbtdry_Entry {
+            key: rbtdry_Key {
+                colophon: colophon.ok_or_else(|| missing(RBTDRY_KEY_COLOPHON))?,
+                imprint: imprint.ok_or_else(|| missing(RBTDRY_KEY_IMPRINT))?,
+                args,
+            },
+            exit_code: exit_code.ok_or_else(|| missing(RBTDRY_KEY_EXIT_CODE))?,
+        })
+    }
+}
+
+fn zrbtdry_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn zrbtdry_unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// A cassette armed on the invocation layer: recording launches into its
+/// directory, or replaying them from it.
+#[derive(Debug)]
+pub enum rbtdry_Cassette {
+    Record { dir: PathBuf, seq: u32 },
+    Replay { dir: PathBuf, entries: Vec<(PathBuf, rbtdry_Entry)>, served: Vec<bool> },
+}
+
+impl rbtdry_Cassette {
+    /// Start recording into `dir`, creating it. Refuses a dir that already
+    /// holds entries — two runs interleaved on one tape would replay neither.
+    pub fn record(dir: &Path) -> Result<rbtdry_Cassette, String> {
+        std::fs::create_dir_all(dir).map_err(|e| {
+            format!("rbtdry: cannot create cassette '{}': {}", dir.display(), e)
+        })?;
+        let occupied = std::fs::read_dir(dir)
+            .map_err(|e| format!("rbtdry: cannot read cassette '{}': {}", dir.display(), e))?
+            .next()
+            .is_some();
+        if occupied {
+            return Err(format!(
+                "rbtdry: cassette '{}' is not empty — record into a fresh directory",
+                dir.display()
+            ));
+        }
+        Ok(rbtdry_Cassette::Record { dir: dir.to_path_buf(), seq: 0 })
+    }
+
+    /// Load the cassette in `dir` for replay, entries in recorded order.
+    pub fn replay(dir: &Path) -> Result<rbtdry_Cassette, String> {
+        let mut dirs: Vec<PathBuf> = std::fs::read_dir(dir)
+            .map_err(|e| format!("rbtdry: cannot read cassette '{}': {}", dir.display(), e))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.join(RBTDRY_ENTRY_FILE).is_file())
+            .collect();
+        dirs.sort();
+        let mut entries = Vec::new();
+        for entry_dir in dirs {
+            let path = entry_dir.join(RBTDRY_ENTRY_FILE);
+            let text = std::fs::read_to_string(&path)
+                .map_err(|e| format!("rbtdry: cannot read '{}': {}", path.display(), e))?;
+            let entry = rbtdry_Entry::parse(&text)
+                .map_err(|e| format!("rbtdry: '{}': {}", path.display(), e))?;
+            entries.push((entry_dir, entry));
+        }
+        let served = vec![false; entries.len()];
+        Ok(rbtdry_Cassette::Replay { dir: dir.to_path_buf(), entries, served })
+    }
+
+    pub fn replaying(&self) -> bool {
+        matches!(self, rbtdry_Cassette::Replay { .. })
+    }
+
+    /// Replayed and total entry counts (zero and zero while recording).
+    pub fn served(&self) -> (usize, usize) {
+        match self {
+            rbtdry_Cassette::Record { .. } => (0, 0),
+            rbtdry_Cassette::Replay { served, .. } => {
+                (served.iter().filter(|s| **s).count(), served.len())
+            }
+        }
+    }
+
+    /// Record one finished launch: its key, captured result, and the fact
+    /// files it left in its BURV `current/`.
+    pub fn capture(&mut self, key: &rbtdry_Key, result: &rbtdri_InvokeResult) -> Result<(), String> {
+        let rbtdry_Cassette::Record { dir, seq } = self else {
+            return Err("rbtdry: capture on a replaying cassette".to_string());
+        };
+        let entry_dir = dir.join(format!("{:05}-{}", seq, key.colophon));
+        *seq += 1;
+        let entry = rbtdry_Entry { key: key.clone(), exit_code: result.exit_code };
+        let fail = |e: std::io::Error| {
+            format!("rbtdry: cannot record '{}': {}", entry_dir.display(), e)
+        };
+        std::fs::create_dir_all(&entry_dir).map_err(fail)?;
+        std::fs::write(entry_dir.join(RBTDRY_STDOUT_FILE), &result.stdout).map_err(fail)?;
+        std::fs::write(entry_dir.join(RBTDRY_STDERR_FILE), &result.stderr).map_err(fail)?;
+        let facts = result.burv_output.join(RBTDRI_BURV_OUTPUT_SUBDIR);
+        if facts.is_dir() {
+            zrbtdry_copy_tree(&facts, &entry_dir.join(RBTDRI_BURV_OUTPUT_SUBDIR)).map_err(fail)?;
+        }
+        // Written last: an entry dir without its entry file is never replayed.
+        std::fs::write(entry_dir.join(RBTDRY_ENTRY_FILE), entry.render()).map_err(fail)
+    }
+
+    /// Serve the first unserved entry matching `key`: lay its fact files into
+    /// `burv_output` and return its captured result.
+    pub fn serve(
+        &mut self,
+        key: &rbtdry_Key,
+        burv_output: &Path,
+    ) -> Result<rbtdri_InvokeResult, String> {
+        let rbtdry_Cassette::Replay { dir, entries, served } = self else {
+            return Err("rbtdry: serve on a recording cassette".to_string());
+        };
+        let index = (0..entries.len())
+            .find(|i| !served[*i] && entries[*i].1.key == *key)
+            .ok_or_else(|| {
+                format!(
+                    "rbtdry: cassette '{}' has no unserved entry for {}",
+                    dir.display(),
+                    key.describe()
+                )
+            })?;
+        served[index] = true;
+        let (entry_dir, entry) = &entries[index];
+        let fail = |e: std::io::Error| {
+            format!("rbtdry: cannot replay '{}': {}", entry_dir.display(), e)
+        };
+
+        // bud_dispatch's start-of-dispatch promotion, then a fresh current/.
+        let current = burv_output.join(RBTDRI_BURV_OUTPUT_SUBDIR);
+        let previous = burv_output.join(RBTDRY_BURV_PREVIOUS_SUBDIR);
+        if previous.exists() {
+            std::fs::remove_dir_all(&previous).map_err(fail)?;
+        }
+        if current.exists() {
+            std::fs::rename(&current, &previous).map_err(fail)?;
+        }
+        std::fs::create_dir_all(&current).map_err(fail)?;
+        let facts = entry_dir.join(RBTDRI_BURV_OUTPUT_SUBDIR);
+        if facts.is_dir() {
+            zrbtdry_copy_tree(&facts, &current).map_err(fail)?;
+        }
+
+        let read = |name: &str| std::fs::read(entry_dir.join(name)).map_err(fail);
+        Ok(rbtdri_InvokeResult {
+            stdout: String::from_utf8_lossy(&read(RBTDRY_STDOUT_FILE)?).into_owned(),
+            stderr: String::from_utf8_lossy(&read(RBTDRY_STDERR_FILE)?).into_owned(),
+            exit_code: entry.exit_code,
+            burv_output: burv_output.to_path_buf(),
+        })
+    }
+}
+
+/// Copy a directory tree, creating `to`.
+fn zrbtdry_copy_tree(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            zrbtdry_copy_tree(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,305 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDRZ — typed BURV facts: validated newtypes for the values tabtargets hand back
+//
+// A BURV fact is a bare string until something reads it. These newtypes parse
+// each fact against the exact shape its bash writer emits, so a truncated,
+// swapped, or stale fact fails at read time naming the fact file and the rule
+// it broke — not three steps later as a GAR 404 on a ref built from it.
+
+use std::fmt;
+use std::ops::Deref;
+
+use crate::rbtdri_invocation::{
+    rbtdri_read_burv_fact, rbtdri_InvokeResult, RBTDRI_BURV_OUTPUT_SUBDIR,
+};
+
+// ── Format literals (mirror rbgc_constants.sh) ───────────────
+
+/// RBGC_HALLMARK_PREFIX_{CONJURE,BIND,GRAFT} — stamped `<p><YYMMDDHHMMSS>-r<YYMMDDHHMMSS>`.
+const RBTDRZ_HALLMARK_PREFIXES_STAMPED: [char; 3] = ['c', 'b', 'g'];
+/// RBGC_HALLMARK_PREFIX_KLUDGE — `k<YYMMDDHHMMSS>-<BURD_GIT_CONTEXT>` (rbfk_kludge.sh).
+const RBTDRZ_HALLMARK_PREFIX_KLUDGE: char = 'k';
+/// RBGC_GAR_CATEGORY_HALLMARKS — the ark stem's leading segment.
+const RBTDRZ_GAR_CATEGORY_HALLMARKS: &str = "rbi_hm";
+/// RBGC_GAR_HOST_SUFFIX — the GAR root's host is `<location>-docker.pkg.dev`.
+const RBTDRZ_GAR_HOST_SUFFIX: &str = "-docker.pkg.dev";
+/// RBGC_LODE_KIND_* — the touchmark's kind prefix (rbldk_kind.sh decode table).
+const RBTDRZ_LODE_KINDS: [&str; 5] = ["b", "r", "w", "vw", "vn"];
+/// rbrv_regime.sh z_bind_digest_re — `@sha256:[0-9a-f]{64}$`.
+const RBTDRZ_DIGEST_SEPARATOR: &str = "@sha256:";
+const RBTDRZ_DIGEST_HEX_LEN: usize = 64;
+/// BURD_NOW_STAMP sliced to `YYMMDDHHMMSS` — every stamp in a hallmark or touchmark.
+const RBTDRZ_STAMP_LEN: usize = 12;
+
+fn zrbtdrz_is_stamp(s: &str) -> bool {
+    s.len() == RBTDRZ_STAMP_LEN && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Docker tag charset — a hallmark is used verbatim as an image tag.
+fn zrbtdrz_is_tag_text(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b'-'))
+}
+
+// ── Fact trait + reader ──────────────────────────────────────
+
+/// A BURV fact value with a bash-mirrored shape. `NOUN` names the type in
+/// diagnostics; `parse` returns the violated rule, without the fact context
+/// `rbtdrz_read_fact` wraps around it.
+pub trait rbtdrz_Fact: Sized {
+    const NOUN: &'static str;
+    fn parse(text: &str) -> Result<Self, String>;
+}
+
+/// Read a fact file via `rbtdri_read_burv_fact` and parse it as `T`. A shape
+/// violation names the fact, its file, the offending value, and the rule.
+pub fn rbtdrz_read_fact<T: rbtdrz_Fact>(
+    result: &rbtdri_InvokeResult,
+    fact_name: &str,
+) -> Result<T, String> {
+    let text = rbtdri_read_burv_fact(result, fact_name)?;
+    T::parse(&text).map_err(|rule| {
+        let path = result.burv_output.join(RBTDRI_BURV_OUTPUT_SUBDIR).join(fact_name);
+        format!(
+            "rbtdrz: fact '{}' in {} is not a well-formed {}: {}",
+            fact_name,
+            path.display(),
+            T::NOUN,
+            rule
+        )
+    })
+}
+
+/// Newtype plumbing shared by every fact: borrow as `&str`, display verbatim.
+macro_rules! zrbtdrz_string_fact {
+    ($name:ident) => {
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+// ── Hallmark ─────────────────────────────────────────────────
+
+/// Ordain's build identity, also the image tag: `c|b|g<stamp>-r<stamp>` for
+/// conjure/bind/graft, `k<stamp>-<git context>` for kludge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrz_Hallmark(String);
+zrbtdrz_string_fact!(rbtdrz_Hallmark);
+
+impl rbtdrz_Fact for rbtdrz_Hallmark {
+    const NOUN: &'static str = "hallmark";
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut chars = text.chars();
+        let prefix = chars.next().ok_or("empty hallmark")?;
+        let rest = chars.as_str();
+        if RBTDRZ_HALLMARK_PREFIXES_STAMPED.contains(&prefix) {
+            let ok = match rest.split_once("-r") {
+                Some((build, release)) => zrbtdrz_is_stamp(build) && zrbtdrz_is_stamp(release),
+                None => false,
+            };
+            if !ok {
+                return Err(format!(
+                    "'{}' is not {}<{}-digit stamp>-r<{}-digit stamp>",
+                    text, prefix, RBTDRZ_STAMP_LEN, RBTDRZ_STAMP_LEN
+                ));
+            }
+        } else if prefix == RBTDRZ_HALLMARK_PREFIX_KLUDGE {
+            let ok = match rest.split_once('-') {
+                Some((stamp, context)) => zrbtdrz_is_stamp(stamp) && zrbtdrz_is_tag_text(context),
+                None => false,
+            };
+            if !ok {
+                return Err(format!(
+                    "'{}' is not {}<{}-digit stamp>-<git context in [A-Za-z0-9_.-]>",
+                    text, prefix, RBTDRZ_STAMP_LEN
+                ));
+            }
+        } else {
+            return Err(format!(
+                "'{}' has no known prefix (conjure c, bind b, graft g, kludge k)",
+                text
+            ));
+        }
+        Ok(Self(text.to_string()))
+    }
+}
+
+// ── GAR root ─────────────────────────────────────────────────
+
+/// The depot's registry root: `<location>-docker.pkg.dev/<project>/<repository>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrz_GarRoot(String);
+zrbtdrz_string_fact!(rbtdrz_GarRoot);
+
+impl rbtdrz_Fact for rbtdrz_GarRoot {
+    const NOUN: &'static str = "GAR root";
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let segments: Vec<&str> = text.split('/').collect();
+        if segments.len() != 3 || segments.iter().any(|s| s.is_empty()) {
+            return Err(format!(
+                "'{}' is not <location>{}/<project>/<repository>",
+                text, RBTDRZ_GAR_HOST_SUFFIX
+            ));
+        }
+        let location = segments[0].strip_suffix(RBTDRZ_GAR_HOST_SUFFIX).unwrap_or("");
+        if location.is_empty() {
+            return Err(format!(
+                "host '{}' is not <location>{}",
+                segments[0], RBTDRZ_GAR_HOST_SUFFIX
+            ));
+        }
+        let tainted = |s: &str| s.contains(|c: char| c.is_whitespace() || c == ':' || c == '@');
+        if let Some(bad) = segments.iter().find(|s| tainted(s)) {
+            return Err(format!(
+                "segment '{}' of '{}' carries a tag, digest, or whitespace",
+                bad, text
+            ));
+        }
+        Ok(Self(text.to_string()))
+    }
+}
+
+// ── Ark stem ─────────────────────────────────────────────────
+
+/// A hallmark's subtree under the GAR root: `rbi_hm/<hallmark>`. The embedded
+/// hallmark is parsed too, so callers can cross-check it against the hallmark fact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrz_ArkStem(String);
+zrbtdrz_string_fact!(rbtdrz_ArkStem);
+
+impl rbtdrz_ArkStem {
+    /// The hallmark segment — validated at parse time.
+    pub fn hallmark(&self) -> &str {
+        &self.0[RBTDRZ_GAR_CATEGORY_HALLMARKS.len() + 1..]
+    }
+}
+
+impl rbtdrz_Fact for rbtdrz_ArkStem {
+    const NOUN: &'static str = "ark stem";
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let hallmark = text
+            .strip_prefix(RBTDRZ_GAR_CATEGORY_HALLMARKS)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .ok_or_else(|| {
+                format!("'{}' is not {}/<hallmark>", text, RBTDRZ_GAR_CATEGORY_HALLMARKS)
+            })?;
+        rbtdrz_Hallmark::parse(hallmark).map_err(|e| format!("hallmark segment: {}", e))?;
+        Ok(Self(text.to_string()))
+    }
+}
+
+// ── Touchmark ────────────────────────────────────────────────
+
+/// A Lode's identity: `<kind><stamp>`, kind one of the RBGC_LODE_KIND_* letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrz_Touchmark(String);
+zrbtdrz_string_fact!(rbtdrz_Touchmark);
+
+impl rbtdrz_Touchmark {
+    /// The kind prefix, decoded the way rbldk_kind.sh does (`${t%%[0-9]*}`).
+    pub fn kind(&self) -> &str {
+        let digits = self.0.find(|c: char| c.is_ascii_digit()).unwrap_or(self.0.len());
+        &self.0[..digits]
+    }
+}
+
+impl rbtdrz_Fact for rbtdrz_Touchmark {
+    const NOUN: &'static str = "touchmark";
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let digits = text.find(|c: char| c.is_ascii_digit()).unwrap_or(text.len());
+        let (kind, stamp) = text.split_at(digits);
+        if !RBTDRZ_LODE_KINDS.contains(&kind) {
+            return Err(format!(
+                "'{}' has kind '{}', not one of {}",
+                text,
+                kind,
+                RBTDRZ_LODE_KINDS.join(", ")
+            ));
+        }
+        if !zrbtdrz_is_stamp(stamp) {
+            return Err(format!("'{}' is not {}<{}-digit stamp>", text, kind, RBTDRZ_STAMP_LEN));
+        }
+        Ok(Self(text.to_string()))
+    }
+}
+
+// ── Digest-pinned image ref ──────────────────────────────────
+
+/// An image ref pinned by content: `<name>@sha256:<64 lowercase hex>` — the
+/// shape rbrv_regime.sh demands of RBRV_BIND_IMAGE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct rbtdrz_DigestRef(String);
+zrbtdrz_string_fact!(rbtdrz_DigestRef);
+
+impl rbtdrz_DigestRef {
+    /// The ref portion before `@sha256:`.
+    pub fn name(&self) -> &str {
+        &self.0[..self.0.len() - RBTDRZ_DIGEST_SEPARATOR.len() - RBTDRZ_DIGEST_HEX_LEN]
+    }
+
+    /// The 64-hex digest, without the `sha256:` algorithm prefix.
+    pub fn digest(&self) -> &str {
+        &self.0[self.0.len() - RBTDRZ_DIGEST_HEX_LEN..]
+    }
+}
+
+impl rbtdrz_Fact for rbtdrz_DigestRef {
+    const NOUN: &'static str = "digest-pinned image ref";
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let (name, digest) = text
+            .rsplit_once(RBTDRZ_DIGEST_SEPARATOR)
+            .ok_or_else(|| {
+                format!("'{}' is not {}<hex>-pinned (bare tag?)", text, RBTDRZ_DIGEST_SEPARATOR)
+            })?;
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!(
+                "'{}' has an empty or whitespace-bearing name before the digest",
+                text
+            ));
+        }
+        if digest.len() != RBTDRZ_DIGEST_HEX_LEN
+            || !digest.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            return Err(format!(
+                "digest '{}' is not {} lowercase hex characters",
+                digest, RBTDRZ_DIGEST_HEX_LEN
+            ));
+        }
+        Ok(Self(text.to_string()))
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTB — suite checkpoint tests: the file round-trips, the newest sibling
+// checkpoint for the suite wins, and a resume point is only granted for a
+// green list that is still a prefix of the suite.
+
+use super::rbtdrb_checkpoint::*;
+use super::rbtdre_engine::*;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+
+static RBTDTB_FIXTURE_A: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdtb-a",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: None,
+    cases: &[],
+    credless: true,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+static RBTDTB_FIXTURE_B: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdtb-b",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: None,
+    cases: &[],
+    credless: true,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+static RBTDTB_SUITE: rbtdre_Suite = rbtdre_Suite {
+    name: "zrbtdtb-suite",
+    fixtures: &[&RBTDTB_FIXTURE_A, &RBTDTB_FIXTURE_B],
+    case_timeout_secs: None,
+};
+
+fn rbtdtb_checkpoint(suite: &str, passed: &[&str]) -> rbtdrb_Checkpoint {
+    rbtdrb_Checkpoint {
+        suite: suite.to_string(),
+        commit: "0123abcd".to_string(),
+        invoke_count: 42,
+        passed: passed.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn rbtdtb_render_parse_round_trip() {
+    let checkpoint = rbtdtb_checkpoint("gauntlet", &["zrbtdtb-a", "zrbtdtb-b"]);
+    let text = checkpoint.render();
+    assert!(text.contains("invoke_count=42\n"));
+    assert_eq!(rbtdrb_Checkpoint::parse(&text).unwrap(), checkpoint);
+}
+
+#[test]
+fn rbtdtb_parse_rejects_missing_and_unknown_keys() {
+    let err = rbtdrb_Checkpoint::parse("suite=x\ninvoke_count=1\n").unwrap_err();
+    assert!(err.contains("commit"), "{}", err);
+    let err = rbtdrb_Checkpoint::parse("suite=x\ncommit=c\ninvoke_count=1\nbogus=1\n").unwrap_err();
+    assert!(err.contains("bogus"), "{}", err);
+}
+
+#[test]
+fn rbtdtb_find_latest_picks_newest_sibling_for_suite() {
+    // Layout mirrors BURC_TEMP_ROOT_DIR/temp-<stamp>/rbtd.
+    let temp_root = rbtdth_make_scratch("checkpoint-find");
+    let older = temp_root.join("temp-20260101-000000-1-1").join("rbtd");
+    let newer = temp_root.join("temp-20260102-000000-1-1").join("rbtd");
+    let other_suite = temp_root.join("temp-20260103-000000-1-1").join("rbtd");
+    let current = temp_root.join("temp-20260104-000000-1-1").join("rbtd");
+    for dir in [&older, &newer, &other_suite, &current] {
+        std::fs::create_dir_all(dir).unwrap();
+    }
+    rbtdtb_checkpoint("gauntlet", &["zrbtdtb-a"]).write(&older).unwrap();
+    rbtdtb_checkpoint("gauntlet", &["zrbtdtb-a", "zrbtdtb-b"]).write(&newer).unwrap();
+    rbtdtb_checkpoint("skirmish", &[]).write(&other_suite).unwrap();
+    // The current run's own checkpoint is never a resume source.
+    rbtdtb_checkpoint("gauntlet", &[]).write(&current).unwrap();
+
+    let (path, found) = rbtdrb_find_latest(&current, "gauntlet").unwrap();
+    assert_eq!(path, newer.join(RBTDRB_CHECKPOINT_FILE));
+    assert_eq!(found.passed.len(), 2);
+
+    let err = rbtdrb_find_latest(&current, "siege").unwrap_err();
+    assert!(err.contains("nothing to resume"), "{}", err);
+    let _ = std::fs::remove_dir_all(&temp_root);
+}
+
+#[test]
+fn rbtdtb_find_latest_skips_malformed_newer_checkpoint() {
+    let temp_root = rbtdth_make_scratch("checkpoint-malformed");
+    let valid = temp_root.join("temp-20260101-000000-1-1").join("rbtd");
+    let torn = temp_root.join("temp-20260102-000000-1-1").join("rbtd");
+    let current = temp_root.join("temp-20260103-000000-1-1").join("rbtd");
+    for dir in [&valid, &torn, &current] {
+        std::fs::create_dir_all(dir).unwrap();
+    }
+    rbtdtb_checkpoint("gauntlet", &["zrbtdtb-a"]).write(&valid).unwrap();
+    std::fs::write(torn.join(RBTDRB_CHECKPOINT_FILE), "suite=gauntlet\ncomm").unwrap();
+
+    let (path, found) = rbtdrb_find_latest(&current, "gauntlet").unwrap();
+    assert_eq!(path, valid.join(RBTDRB_CHECKPOINT_FILE));
+    assert_eq!(found.passed.len(), 1);
+    let _ = std::fs::remove_dir_all(&temp_root);
+}
+
+#[test]
+fn rbtdtb_resume_index_is_length_of_green_prefix() {
+    let checkpoint = rbtdtb_checkpoint(RBTDTB_SUITE.name, &["zrbtdtb-a"]);
+    assert_eq!(rbtdrb_resume_index(&checkpoint, &RBTDTB_SUITE).unwrap(), 1);
+    let checkpoint = rbtdtb_checkpoint(RBTDTB_SUITE.name, &[]);
+    assert_eq!(rbtdrb_resume_index(&checkpoint, &RBTDTB_SUITE).unwrap(), 0);
+}
+
+#[test]
+fn rbtdtb_resume_index_refuses_changed_composition() {
+    // Green list no longer a prefix of the suite order.
+    let checkpoint = rbtdtb_checkpoint(RBTDTB_SUITE.name, &["zrbtdtb-b"]);
+    let err = rbtdrb_resume_index(&checkpoint, &RBTDTB_SUITE).unwrap_err();
+    assert!(err.contains("suite changed"), "{}", err);
+    // Checkpoint for a different suite.
+    let checkpoint = rbtdtb_checkpoint("gauntlet", &[]);
+    assert!(rbtdrb_resume_index(&checkpoint, &RBTDTB_SUITE).is_err());
+}
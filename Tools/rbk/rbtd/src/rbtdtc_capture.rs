@@ -0,0 +1,278 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTC — tests for rbtdrc_capture pcap reading and egress assertions
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use super::rbtdrc_capture::*;
+use super::rbtdrc_policy::rbtdrc_Cidr;
+
+/// IPv4 header (20 bytes, no options) plus TCP/UDP ports.
+fn rbtdtc_ipv4(src: [u8; 4], dst: [u8; 4], protocol: u8, sport: u16, dport: u16) -> Vec<u8> {
+    let mut ip = vec![0x45, 0, 0, 40, 0, 0, 0x40, 0, 64, protocol, 0, 0];
+    ip.extend_from_slice(&src);
+    ip.extend_from_slice(&dst);
+    ip.extend_from_slice(&sport.to_be_bytes());
+    ip.extend_from_slice(&dport.to_be_bytes());
+    ip.extend_from_slice(&[0; 16]);
+    ip
+}
+
+fn rbtdtc_ethernet(ethertypes: &[u16], payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0xaa; 12];
+    for (i, t) in ethertypes.iter().enumerate() {
+        frame.extend_from_slice(&t.to_be_bytes());
+        if i + 1 < ethertypes.len() {
+            frame.extend_from_slice(&[0x00, 0x05]);
+        }
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// A classic pcap file in the given byte order and stamp unit.
+fn rbtdtc_pcap(big: bool, nanos: bool, linktype: u32, frames: &[Vec<u8>]) -> Vec<u8> {
+    let word = |v: u32| if big { v.to_be_bytes() } else { v.to_le_bytes() };
+    let half = |v: u16| if big { v.to_be_bytes() } else { v.to_le_bytes() };
+    let mut out = Vec::new();
+    out.extend_from_slice(&word(if nanos { 0xa1b2_3c4d } else { 0xa1b2_c3d4 }));
+    out.extend_from_slice(&half(2));
+    out.extend_from_slice(&half(4));
+    out.extend_from_slice(&word(0));
+    out.extend_from_slice(&word(0));
+    out.extend_from_slice(&word(256));
+    out.extend_from_slice(&word(linktype));
+    for (i, frame) in frames.iter().enumerate() {
+        out.extend_from_slice(&word(1_700_000_000 + i as u32));
+        out.extend_from_slice(&word(7));
+        out.extend_from_slice(&word(frame.len() as u32));
+        out.extend_from_slice(&word(frame.len() as u32 + 100));
+        out.extend_from_slice(frame);
+    }
+    out
+}
+
+#[test]
+fn rbtdtc_pcap_reads_either_byte_order_and_stamp_unit() {
+    let tcp = rbtdtc_ipv4([172, 17, 0, 2], [1, 1, 1, 1], RBTDRC_PROTO_TCP, 40000, 443);
+    let tagged = rbtdtc_ethernet(&[0x8100, 0x0800], &tcp);
+    let arp = rbtdtc_ethernet(&[0x0806], &[0; 28]);
+    for (big, nanos) in [(false, false), (true, false), (false, true), (true, true)] {
+        let frames = [tagged.clone(), arp.clone()];
+        let bytes = rbtdtc_pcap(big, nanos, RBTDRC_LINKTYPE_ETHERNET, &frames);
+        let pcap = rbtdrc_Pcap::parse(&bytes).unwrap();
+        assert_eq!(pcap.nanos, nanos);
+        assert_eq!((pcap.linktype, pcap.snaplen), (RBTDRC_LINKTYPE_ETHERNET, 256));
+        assert_eq!(pcap.records.len(), 2);
+        assert_eq!(pcap.records[1].ts_secs, 1_700_000_001);
+        assert_eq!(pcap.records[0].orig_len, tagged.len() as u32 + 100);
+        // The ARP frame is not IP; the VLAN-tagged one decodes through its tag.
+        let packets = pcap.packets();
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].to_string(), "tcp 172.17.0.2:40000 → 1.1.1.1:443");
+    }
+
+    // A record cut short by a capture killed mid-write ends the file.
+    let bytes = rbtdtc_pcap(false, false, RBTDRC_LINKTYPE_ETHERNET, &[tagged.clone(), tagged]);
+    let pcap = rbtdrc_Pcap::parse(&bytes[..bytes.len() - 5]).unwrap();
+    assert_eq!(pcap.records.len(), 1);
+
+    let err = rbtdrc_Pcap::parse(&[0x0a, 0x0d, 0x0d, 0x0a].repeat(8)).unwrap_err();
+    assert!(err.contains("not a classic pcap"), "{}", err);
+    assert!(rbtdrc_Pcap::parse(&[0xd4, 0xc3]).is_err());
+}
+
+#[test]
+fn rbtdtc_ip_decode_covers_cooked_raw_and_ipv6() {
+    let udp = rbtdtc_ipv4([10, 242, 0, 2], [8, 8, 8, 8], RBTDRC_PROTO_UDP, 5353, 53);
+
+    let mut sll = vec![0; 14];
+    sll.extend_from_slice(&0x0800u16.to_be_bytes());
+    sll.extend_from_slice(&udp);
+    let packet = rbtdrc_ip_packet(RBTDRC_LINKTYPE_LINUX_SLL, &sll).unwrap();
+    assert_eq!(packet.dst, IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)));
+    assert_eq!((packet.src_port, packet.dst_port), (Some(5353), Some(53)));
+
+    let mut sll2 = 0x0800u16.to_be_bytes().to_vec();
+    sll2.extend_from_slice(&[0; 18]);
+    sll2.extend_from_slice(&udp);
+    assert_eq!(rbtdrc_ip_packet(RBTDRC_LINKTYPE_LINUX_SLL2, &sll2), Some(packet));
+    assert_eq!(rbtdrc_ip_packet(RBTDRC_LINKTYPE_RAW, &udp), Some(packet));
+
+    // IPv6 UDP from fe80::1 to 2001:db8::1, ports after the fixed header.
+    let mut v6 = vec![0x60, 0, 0, 0, 0, 8, RBTDRC_PROTO_UDP, 64];
+    v6.extend_from_slice(&[0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    v6.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    v6.extend_from_slice(&[0x02, 0x22, 0x02, 0x23, 0, 8, 0, 0]);
+    let packet = rbtdrc_ip_packet(RBTDRC_LINKTYPE_RAW, &v6).unwrap();
+    assert_eq!(packet.to_string(), "udp fe80::1:546 → 2001:db8::1:547");
+
+    // A non-first fragment carries no ports; a frame cut inside the header is dropped.
+    let mut fragment = udp.clone();
+    fragment[7] = 0x10;
+    let packet = rbtdrc_ip_packet(RBTDRC_LINKTYPE_RAW, &fragment).unwrap();
+    assert_eq!((packet.src_port, packet.dst_port), (None, None));
+    assert_eq!(rbtdrc_ip_packet(RBTDRC_LINKTYPE_RAW, &udp[..15]), None);
+    assert_eq!(rbtdrc_ip_packet(147, &udp), None);
+}
+
+/// An untagged IPv4/IPv6 Ethernet frame sent from `src_mac`.
+fn rbtdtc_sent(src_mac: [u8; 6], payload: &[u8]) -> Vec<u8> {
+    let ethertype: u16 = if payload[0] >> 4 == 6 { 0x86dd } else { 0x0800 };
+    let mut frame = vec![0xbb; 6];
+    frame.extend_from_slice(&src_mac);
+    frame.extend_from_slice(&ethertype.to_be_bytes());
+    frame.extend_from_slice(payload);
+    frame
+}
+
+const RBTDTC_UPLINK_MAC: [u8; 6] = [0x02, 0x42, 0xac, 0x11, 0x00, 0x03];
+const RBTDTC_PEER_MAC: [u8; 6] = [0x02, 0x42, 0xac, 0x11, 0x00, 0x01];
+
+#[test]
+fn rbtdtc_egress_violations_name_what_left_the_uplink() {
+    let uplink = [172, 17, 0, 3];
+    let out = |payload: Vec<u8>| rbtdtc_sent(RBTDTC_UPLINK_MAC, &payload);
+    let inbound = |payload: Vec<u8>| rbtdtc_sent(RBTDTC_PEER_MAC, &payload);
+    let frames: Vec<Vec<u8>> = vec![
+        // Allowed CIDR, resolver on 53, and inbound replies: all fine.
+        out(rbtdtc_ipv4(uplink, [192, 0, 40, 1], RBTDRC_PROTO_TCP, 40000, 443)),
+        out(rbtdtc_ipv4(uplink, [8, 8, 8, 8], RBTDRC_PROTO_UDP, 40001, 53)),
+        inbound(rbtdtc_ipv4([1, 1, 1, 1], uplink, RBTDRC_PROTO_TCP, 443, 40002)),
+        // Received, though it claims the uplink's address: not egress.
+        inbound(rbtdtc_ipv4(uplink, [1, 1, 1, 1], RBTDRC_PROTO_TCP, 40003, 443)),
+        // Resolver on another port, and an address outside the allowlist.
+        out(rbtdtc_ipv4(uplink, [8, 8, 8, 8], RBTDRC_PROTO_TCP, 40004, 443)),
+        out(rbtdtc_ipv4(uplink, [1, 1, 1, 1], RBTDRC_PROTO_TCP, 40005, 443)),
+        // A bottle packet that left un-NATed is egress whatever its source.
+        out(rbtdtc_ipv4([10, 242, 0, 3], [1, 1, 1, 1], RBTDRC_PROTO_TCP, 40006, 443)),
+    ];
+    let bytes = rbtdtc_pcap(false, false, RBTDRC_LINKTYPE_ETHERNET, &frames);
+    let packets = rbtdrc_Pcap::parse(&bytes).unwrap().outbound_packets(&RBTDTC_UPLINK_MAC);
+    let packets = packets.unwrap();
+    assert_eq!(packets.len(), 5);
+    let allowed = vec![rbtdrc_Cidr::parse("192.0.32.0/20").unwrap()];
+    let violations =
+        rbtdrc_egress_violations(&packets, &allowed, Some(Ipv4Addr::new(8, 8, 8, 8)));
+    assert_eq!(
+        violations,
+        vec![
+            "tcp 172.17.0.3:40004 → 8.8.8.8:443",
+            "tcp 172.17.0.3:40005 → 1.1.1.1:443",
+            "tcp 10.242.0.3:40006 → 1.1.1.1:443",
+        ]
+    );
+
+    // Without a known resolver, DNS to it is just another address.
+    let violations = rbtdrc_egress_violations(&packets, &allowed, None);
+    assert_eq!(violations.len(), 4);
+}
+
+#[test]
+fn rbtdtc_outbound_ipv6_is_always_a_violation() {
+    // ICMPv6 from the uplink's link-local address to a documentation address.
+    let mut v6 = vec![0x60, 0, 0, 0, 0, 8, 58, 64];
+    v6.extend_from_slice(&[0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]);
+    v6.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    v6.extend_from_slice(&[0x80, 0, 0, 0, 0, 0, 0, 0]);
+    let frames = vec![rbtdtc_sent(RBTDTC_UPLINK_MAC, &v6), rbtdtc_sent(RBTDTC_PEER_MAC, &v6)];
+    let bytes = rbtdtc_pcap(false, false, RBTDRC_LINKTYPE_ETHERNET, &frames);
+    let packets = rbtdrc_Pcap::parse(&bytes).unwrap().outbound_packets(&RBTDTC_UPLINK_MAC);
+    // Even a global allowlist of IPv4 space does not cover it.
+    let everything = vec![rbtdrc_Cidr::parse("0.0.0.0/0").unwrap()];
+    assert_eq!(
+        rbtdrc_egress_violations(&packets.unwrap(), &everything, None),
+        vec!["icmp6 fe80::3 → 2001:db8::1"]
+    );
+}
+
+#[test]
+fn rbtdtc_frame_direction_by_link_type() {
+    let udp = rbtdtc_ipv4([10, 242, 0, 2], [8, 8, 8, 8], RBTDRC_PROTO_UDP, 5353, 53);
+    let sent = rbtdtc_sent(RBTDTC_UPLINK_MAC, &udp);
+    let mac = &RBTDTC_UPLINK_MAC;
+    assert_eq!(rbtdrc_frame_outbound(RBTDRC_LINKTYPE_ETHERNET, &sent, mac), Some(true));
+    assert_eq!(rbtdrc_frame_outbound(RBTDRC_LINKTYPE_ETHERNET, &sent, &[0; 6]), Some(false));
+    assert_eq!(rbtdrc_frame_outbound(RBTDRC_LINKTYPE_ETHERNET, &sent[..9], mac), None);
+
+    // Cooked captures carry the packet type: 4 is outgoing, 0 is to us.
+    let mut sll = vec![0, 4];
+    sll.extend_from_slice(&[0; 14]);
+    assert_eq!(rbtdrc_frame_outbound(RBTDRC_LINKTYPE_LINUX_SLL, &sll, mac), Some(true));
+    sll[1] = 0;
+    assert_eq!(rbtdrc_frame_outbound(RBTDRC_LINKTYPE_LINUX_SLL, &sll, mac), Some(false));
+    let mut sll2 = vec![0; 20];
+    sll2[10] = 4;
+    assert_eq!(rbtdrc_frame_outbound(RBTDRC_LINKTYPE_LINUX_SLL2, &sll2, mac), Some(true));
+
+    // Raw IP records no direction, so egress cannot be read from it.
+    let bytes = rbtdtc_pcap(false, false, RBTDRC_LINKTYPE_RAW, &[udp]);
+    let err = rbtdrc_Pcap::parse(&bytes).unwrap().outbound_packets(mac).unwrap_err();
+    assert!(err.contains("records no direction"), "{}", err);
+}
+
+#[test]
+fn rbtdtc_capture_legs_are_found_by_role_not_name() {
+    let output = "\
+1: lo    inet 127.0.0.1/8 scope host lo\\       valid_lft forever preferred_lft forever
+41: eth0    inet 10.242.0.2/24 brd 10.242.0.255 scope global eth0\\       valid_lft forever
+43: eth1    inet 172.17.0.3/16 brd 172.17.255.255 scope global eth1\\       valid_lft forever
+";
+    let links = "\
+1: lo: <LOOPBACK,UP,LOWER_UP> mtu 65536\\    link/loopback 00:00:00:00:00:00
+41: eth0@if42: <BROADCAST,UP> mtu 1500\\    link/ether 02:42:0a:f2:00:02 brd ff:ff:ff:ff:ff:ff
+43: eth1@if44: <BROADCAST,UP> mtu 1500\\    link/ether 02:42:ac:11:00:03 brd ff:ff:ff:ff:ff:ff
+";
+    let legs = rbtdrc_capture_legs(output, links, Ipv4Addr::new(10, 242, 0, 2)).unwrap();
+    assert_eq!(legs.len(), 2);
+    assert_eq!((legs[0].role, legs[0].iface.as_str()), (RBTDRC_LEG_ENCLAVE, "eth0"));
+    assert_eq!((legs[1].role, legs[1].iface.as_str()), (RBTDRC_LEG_UPLINK, "eth1"));
+    assert_eq!(legs[1].address, Ipv4Addr::new(172, 17, 0, 3));
+    assert_eq!(legs[1].mac, RBTDTC_UPLINK_MAC);
+
+    // Docker may number them the other way round.
+    let swapped = output.replace("eth0", "ethX").replace("eth1", "eth0").replace("ethX", "eth1");
+    let links_swapped =
+        links.replace("eth0", "ethX").replace("eth1", "eth0").replace("ethX", "eth1");
+    let legs = rbtdrc_capture_legs(&swapped, &links_swapped, Ipv4Addr::new(10, 242, 0, 2));
+    let legs = legs.unwrap();
+    assert_eq!((legs[0].iface.as_str(), legs[1].iface.as_str()), ("eth1", "eth0"));
+    assert_eq!(legs[1].mac, RBTDTC_UPLINK_MAC);
+
+    let err = rbtdrc_capture_legs(output, links, Ipv4Addr::new(10, 9, 0, 2)).unwrap_err();
+    assert!(err.contains("enclave IP 10.9.0.2"), "{}", err);
+    let lone = output.lines().take(2).collect::<Vec<_>>().join("\n");
+    let err = rbtdrc_capture_legs(&lone, links, Ipv4Addr::new(10, 242, 0, 2)).unwrap_err();
+    assert!(err.contains("no sentry uplink interface"), "{}", err);
+    let unlinked = links.lines().take(2).collect::<Vec<_>>().join("\n");
+    let err = rbtdrc_capture_legs(output, &unlinked, Ipv4Addr::new(10, 242, 0, 2)).unwrap_err();
+    assert!(err.contains("no link line for sentry interface eth1"), "{}", err);
+    let err = rbtdrc_link_mac(links, "lo").unwrap_err();
+    assert!(err.contains("no link/ether"), "{}", err);
+}
+
+#[test]
+fn rbtdtc_base64_round_trips_pcap_bytes() {
+    assert_eq!(rbtdrc_base64_decode("").unwrap(), b"");
+    assert_eq!(rbtdrc_base64_decode("Zg==").unwrap(), b"f");
+    assert_eq!(rbtdrc_base64_decode("Zm8=").unwrap(), b"fo");
+    assert_eq!(rbtdrc_base64_decode("Zm9v\n").unwrap(), b"foo");
+    assert_eq!(rbtdrc_base64_decode("1MOyoQIABAA=").unwrap(), [0xd4, 0xc3, 0xb2, 0xa1, 2, 0, 4, 0]);
+    assert_eq!(rbtdrc_base64_decode("+/8=").unwrap(), [0xfb, 0xff]);
+    assert!(rbtdrc_base64_decode("Zm9v!").unwrap_err().contains("0x21"));
+    assert!(rbtdrc_base64_decode("Zg==Zg==").is_err());
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTC — tests for the rbtdrc_chaos degradation catalog
+
+use super::rbtdrc_chaos::*;
+
+const RBTDTC_SAVE: &str = "\
+# Generated by iptables-save v1.8.9 (legacy) on Sun Oct 18 10:01:02 2026
+*filter
+:INPUT DROP [0:0]
+:FORWARD DROP [0:0]
+:OUTPUT DROP [0:0]
+-A FORWARD -d 160.79.104.0/23 -i eth0 -j ACCEPT
+COMMIT
+";
+
+#[test]
+fn rbtdtc_chaos_every_degradation_is_undone() {
+    for chaos in rbtdrc_Chaos::ALL {
+        let captured = match chaos.capture() {
+            Some(_) if chaos == rbtdrc_Chaos::IptablesFlushed => RBTDTC_SAVE,
+            Some(_) => "default via 172.17.0.1 dev eth1 \n",
+            None => "",
+        };
+        let revert = chaos.revert(captured).unwrap();
+        // Only a restart undoes itself; everything else needs a revert.
+        assert_eq!(revert.is_empty(), chaos == rbtdrc_Chaos::SentryRestarted, "{}", chaos);
+        // Only a restart goes unchecked; it races the startup by design.
+        assert_eq!(chaos.holds().is_none(), chaos.recycles() && revert.is_empty());
+        assert!(!chaos.inflict().is_empty(), "{}", chaos);
+    }
+}
+
+#[test]
+fn rbtdtc_chaos_container_steps_use_the_runtime() {
+    for chaos in rbtdrc_Chaos::ALL {
+        let steps = chaos.inflict();
+        let on_runtime = steps.iter().all(|c| c.site == rbtdrc_ChaosSite::Runtime);
+        let on_sentry = steps.iter().all(|c| c.site == rbtdrc_ChaosSite::Sentry);
+        // A killed or restarting sentry cannot be reached through writ.
+        assert_eq!(on_runtime, chaos.recycles(), "{}", chaos);
+        assert_eq!(on_sentry, !chaos.recycles(), "{}", chaos);
+    }
+    // Smite names the sentry container itself; the catalog never does.
+    assert_eq!(rbtdrc_Chaos::SentryKilled.inflict()[0].to_string(), "smite: kill");
+    let (check, expect) = rbtdrc_Chaos::SentryKilled.holds().unwrap();
+    assert_eq!(check.to_string(), "smite: inspect -f {{.State.Running}}");
+    assert_eq!(expect, "false");
+}
+
+#[test]
+fn rbtdtc_chaos_smite_carries_only_allowlisted_verbs() {
+    for chaos in rbtdrc_Chaos::ALL {
+        let captured = match chaos {
+            rbtdrc_Chaos::IptablesFlushed => RBTDTC_SAVE,
+            _ => "default via 172.17.0.1 dev eth1 \n",
+        };
+        let mut steps = chaos.inflict();
+        steps.extend(chaos.holds().map(|(check, _)| check));
+        steps.extend(chaos.revert(captured).unwrap());
+        for step in steps.iter().filter(|c| c.site == rbtdrc_ChaosSite::Runtime) {
+            assert!(RBTDRC_CHAOS_SMITE_VERBS.contains(&step.args[0].as_str()), "{}", step);
+        }
+    }
+}
+
+#[test]
+fn rbtdtc_chaos_iptables_revert_restores_the_capture() {
+    let revert = rbtdrc_Chaos::IptablesFlushed.revert(RBTDTC_SAVE).unwrap();
+    assert_eq!(revert.len(), 1);
+    assert_eq!(revert[0].site, rbtdrc_ChaosSite::Sentry);
+    // The ruleset rides as one argument, never through the shell's parser.
+    assert_eq!(revert[0].args.last().map(String::as_str), Some(RBTDTC_SAVE.trim_end()));
+    assert!(revert[0].args[2].contains("iptables-restore"));
+}
+
+#[test]
+fn rbtdtc_chaos_revert_refuses_an_empty_capture() {
+    let err = rbtdrc_Chaos::IptablesFlushed.revert("*filter\nCOMMIT\n");
+    assert!(err.unwrap_err().contains("no rules"));
+    let err = rbtdrc_Chaos::UplinkRouteRemoved.revert("");
+    assert!(err.unwrap_err().contains("no default route"));
+}
+
+#[test]
+fn rbtdtc_chaos_route_revert_replays_the_default_route() {
+    let captured = "default via 172.17.0.1 dev eth1 proto static\n";
+    let revert = rbtdrc_Chaos::UplinkRouteRemoved.revert(captured).unwrap();
+    assert_eq!(
+        revert[0].to_string(),
+        "writ: ip -4 route add default via 172.17.0.1 dev eth1 proto static"
+    );
+}
+
+#[test]
+fn rbtdtc_chaos_dark_probes_follow_the_cut() {
+    assert_eq!(rbtdrc_Chaos::DnsmasqKilled.dark(), &[rbtdrc_Dark::AllowedDomain]);
+    assert_eq!(rbtdrc_Chaos::UplinkRouteRemoved.dark(), &[rbtdrc_Dark::AllowedAddress]);
+    assert_eq!(rbtdrc_Chaos::SentryKilled.dark().len(), 2);
+    assert!(rbtdrc_Chaos::SentryRestarted.dark().is_empty());
+    assert_eq!(rbtdrc_Dark::AllowedAddress.dark_attack(), "tcp443-block");
+    // The battery only ever expects denial; an allow attack there would pass
+    // exactly when the sentry leaks.
+    for dark in rbtdrc_Dark::ALL {
+        assert!(!RBTDRC_CHAOS_BATTERY.contains(&dark.lit_attack()));
+    }
+}
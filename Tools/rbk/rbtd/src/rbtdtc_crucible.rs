@@ -36,6 +36,7 @@ use std::collections::BTreeSet;
 use crate::rbtdra_almanac::{
     RBTDRA_SUITES,
     RBTDRA_SUITE_NAME_CALIBRANT,
+    rbtdra_fixture_suite_timeout,
     rbtdra_lookup_suite,
 };
 
@@ -182,3 +183,20 @@ fn rbtdtc_lifecycle_builds_on_reuse() {
         }
     }
 }
+
+#[test]
+fn rbtdtc_fixture_suite_timeout_is_tightest_carrier() {
+    // The fixture and single runners borrow a suite budget; the tightest one is
+    // the only budget every carrying suite agrees a healthy case fits inside.
+    for suite in RBTDRA_SUITES {
+        for fixture in suite.fixtures {
+            let borrowed = rbtdra_fixture_suite_timeout(fixture);
+            assert!(borrowed.is_some(), "suite member '{}' borrows no budget", fixture.name);
+            assert!(
+                borrowed <= suite.case_timeout_secs,
+                "fixture '{}' borrows a budget looser than suite '{}'",
+                fixture.name, suite.name
+            );
+        }
+    }
+}
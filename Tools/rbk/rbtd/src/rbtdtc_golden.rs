@@ -0,0 +1,220 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTC — tests for rbtdrc_golden sentry snapshots and golden diffs
+
+use std::net::Ipv4Addr;
+use std::path::Path;
+
+use super::rbtdrc_golden::*;
+use super::rbtdrc_iptables::rbtdrc_SentryFacts;
+
+const RBTDTC_SAVE: &str = "\
+# Generated by iptables-save v1.8.9 (legacy) on Sat Oct 17 09:12:44 2026
+*nat
+:PREROUTING ACCEPT [0:0]
+:POSTROUTING ACCEPT [12:840]
+-A POSTROUTING -s 10.242.0.0/24 ! -d 10.242.0.0/24 -o eth1 -j MASQUERADE
+COMMIT
+# Completed on Sat Oct 17 09:12:44 2026
+*filter
+:INPUT DROP [3:180]
+:FORWARD DROP [0:0]
+:OUTPUT DROP [0:0]
+-A INPUT -d 172.17.0.2/32 -i eth1 -j DROP
+-A OUTPUT -d 8.8.8.8/32 -o eth1 -p udp -m udp --dport 53 -j ACCEPT
+-A FORWARD -d 10.242.0.25/32 -i eth0 -j ACCEPT
+COMMIT
+";
+
+const RBTDTC_DNSMASQ: &str = "\
+# dnsmasq for the enclave
+bind-interfaces
+interface=eth0
+
+listen-address=10.242.0.2
+address=/anthropic.com/160.79.104.10
+address=/anthropic.com/160.79.104.11
+address=/#/
+except-interface=eth1
+";
+
+const RBTDTC_SYSCTL: &str = "\
+/proc/sys/net/ipv4/ip_local_port_range = 32768\t65535
+/proc/sys/net/ipv4/ip_forward = 1
+/proc/sys/net/ipv4/conf/eth1/route_localnet = 1
+/proc/sys/net/ipv4/conf/all/rp_filter = 2
+";
+
+const RBTDTC_ROUTES: &str = "\
+default via 172.17.0.1 dev eth1
+172.17.0.0/16 dev eth1 proto kernel scope link src 172.17.0.2
+10.242.0.0/24 dev eth0 proto kernel scope link src 10.242.0.2
+";
+
+fn rbtdtc_raw() -> rbtdrc_SentryRaw {
+    rbtdrc_SentryRaw {
+        iptables_save: RBTDTC_SAVE.to_string(),
+        dnsmasq_conf: RBTDTC_DNSMASQ.to_string(),
+        sysctl: RBTDTC_SYSCTL.to_string(),
+        routes: RBTDTC_ROUTES.to_string(),
+    }
+}
+
+fn rbtdtc_facts(enclave_if: &str, uplink_if: &str, uplink_ip: Ipv4Addr) -> rbtdrc_SentryFacts {
+    rbtdrc_SentryFacts {
+        enclave_if: enclave_if.to_string(),
+        uplink_if: uplink_if.to_string(),
+        uplink_ip,
+        dns_server: Ipv4Addr::new(8, 8, 8, 8),
+    }
+}
+
+fn rbtdtc_snapshot() -> String {
+    let facts = rbtdtc_facts("eth0", "eth1", Ipv4Addr::new(172, 17, 0, 2));
+    rbtdrc_sentry_snapshot("tadmor", &rbtdtc_raw(), &facts).unwrap()
+}
+
+#[test]
+fn rbtdtc_golden_path_per_nameplate() {
+    assert_eq!(
+        rbtdrc_golden_path(Path::new("/repo"), "srjcl"),
+        Path::new("/repo/Tools/rbk/rbtd/golden/srjcl.sentry-config.txt")
+    );
+}
+
+#[test]
+fn rbtdtc_golden_snapshot_redacts_runtime_values() {
+    let snap = rbtdtc_snapshot();
+    for volatile in ["eth0", "eth1", "172.17.0.2", "172.17.0.0/16", "172.17.0.1", "160.79"] {
+        assert!(!snap.contains(volatile), "{} survived:\n{}", volatile, snap);
+    }
+    for line in [
+        "filter: -A INPUT -d <uplink-ip>/32 -i <uplink-if> -j DROP",
+        "nat: -A POSTROUTING -s 10.242.0.0/24 ! -d 10.242.0.0/24 -o <uplink-if> -j MASQUERADE",
+        "address=/anthropic.com/<resolved>",
+        "net.ipv4.conf.<uplink-if>.route_localnet = 1",
+        "net.ipv4.ip_local_port_range = 32768 65535",
+        "default via <uplink-gw> dev <uplink-if>",
+        "<uplink-net> dev <uplink-if> proto kernel scope link src <uplink-ip>",
+    ] {
+        assert!(snap.lines().any(|l| l == line), "missing '{}':\n{}", line, snap);
+    }
+    // Nameplate and regime addresses are policy and stay, down to the one
+    // that merely shares a prefix with the sentry.
+    assert!(snap.contains("listen-address=10.242.0.2\n"));
+    assert!(snap.contains("-d 10.242.0.25/32"));
+    assert!(snap.contains("-d 8.8.8.8/32"));
+    // Counters and save-time comments do not reach the golden.
+    assert!(!snap.contains("[3:180]") && !snap.contains("Sat Oct"));
+    assert_eq!(snap.matches("address=/anthropic.com/").count(), 1);
+    assert!(!snap.contains("# dnsmasq"));
+}
+
+#[test]
+fn rbtdtc_golden_snapshot_is_stable_across_charges() {
+    // A second charge: other interface order, addresses, pins and line order.
+    let mut raw = rbtdtc_raw();
+    raw.iptables_save = RBTDTC_SAVE
+        .replace("eth0", "ethX")
+        .replace("eth1", "eth0")
+        .replace("ethX", "eth1")
+        .replace("172.17.0.2", "192.168.5.7")
+        .replace("[3:180]", "[0:0]")
+        .replace("Sat Oct 17", "Sun Oct 18");
+    raw.dnsmasq_conf = RBTDTC_DNSMASQ
+        .replace("eth0", "ethX")
+        .replace("eth1", "eth0")
+        .replace("ethX", "eth1")
+        .replace("160.79.104.10", "160.79.104.99");
+    raw.sysctl = RBTDTC_SYSCTL.replace("eth1", "eth0").lines().rev().collect::<Vec<_>>().join("\n");
+    raw.routes = "\
+10.242.0.0/24 dev eth1 proto kernel scope link src 10.242.0.2
+192.168.5.0/24 dev eth0 proto kernel scope link src 192.168.5.7
+default via 192.168.5.1 dev eth0
+"
+    .to_string();
+    let facts = rbtdtc_facts("eth1", "eth0", Ipv4Addr::new(192, 168, 5, 7));
+    let again = rbtdrc_sentry_snapshot("tadmor", &raw, &facts).unwrap();
+    assert_eq!(rbtdrc_golden_diff(&rbtdtc_snapshot(), &again), Vec::<String>::new());
+}
+
+#[test]
+fn rbtdtc_golden_snapshot_marks_empty_sections() {
+    let mut raw = rbtdtc_raw();
+    raw.dnsmasq_conf = String::new();
+    let facts = rbtdtc_facts("eth0", "eth1", Ipv4Addr::new(172, 17, 0, 2));
+    let snap = rbtdrc_sentry_snapshot("pluml", &raw, &facts).unwrap();
+    assert!(snap.contains("[dnsmasq]\n(none)\n"), "{}", snap);
+    assert!(snap.contains("nameplate pluml"));
+}
+
+#[test]
+fn rbtdtc_golden_snapshot_rejects_malformed_sysctl() {
+    let mut raw = rbtdtc_raw();
+    raw.sysctl = "net/ipv4/ip_forward 1".to_string();
+    let facts = rbtdtc_facts("eth0", "eth1", Ipv4Addr::new(172, 17, 0, 2));
+    let err = rbtdrc_sentry_snapshot("tadmor", &raw, &facts).unwrap_err();
+    assert!(err.contains("without '='"), "{}", err);
+}
+
+#[test]
+fn rbtdtc_golden_diff_names_section_and_side() {
+    let golden = rbtdtc_snapshot();
+    let pinned = "-A OUTPUT -d 8.8.8.8/32 -o <uplink-if> -p udp --dport 53 -j ACCEPT";
+    let open = "-A OUTPUT -o <uplink-if> -p udp --dport 53 -j ACCEPT";
+    let widened = golden.replace(pinned, open);
+    assert_ne!(golden, widened, "fixture line not found in:\n{}", golden);
+    let widened = widened.replace("net.ipv4.ip_forward = 1", "net.ipv4.ip_forward = 0");
+    assert_eq!(
+        rbtdrc_golden_diff(&golden, &widened),
+        vec![
+            format!("[iptables] - filter: {}", pinned),
+            format!("[iptables] + filter: {}", open),
+            "[sysctl] - net.ipv4.ip_forward = 1".to_string(),
+            "[sysctl] + net.ipv4.ip_forward = 0".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn rbtdtc_golden_diff_added_section_lines() {
+    let golden = "[a]\nx\n\n[b]\ny\n";
+    let actual = "[a]\nx\nz\n\n[b]\n";
+    assert_eq!(rbtdrc_golden_diff(golden, actual), vec!["[a] + z", "[b] - y"]);
+    assert!(rbtdrc_golden_diff(golden, golden).is_empty());
+}
+
+#[test]
+fn rbtdtc_golden_committed_for_every_crucible_nameplate() {
+    use crate::rbtdrm_manifest::{
+        RBTDRM_FIXTURE_MORIAH, RBTDRM_FIXTURE_PLUML, RBTDRM_FIXTURE_SRJCL, RBTDRM_FIXTURE_TADMOR,
+    };
+    // A missing golden fails the case on a live charge; catch it here first.
+    let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../..");
+    for nameplate in [
+        RBTDRM_FIXTURE_TADMOR,
+        RBTDRM_FIXTURE_MORIAH,
+        RBTDRM_FIXTURE_SRJCL,
+        RBTDRM_FIXTURE_PLUML,
+    ] {
+        let path = rbtdrc_golden_path(&root, nameplate);
+        let golden = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("no golden at {}: {}", path.display(), e));
+        let header = format!("nameplate {}\n", nameplate);
+        assert!(golden.contains(&header), "{} is not {}'s golden", path.display(), nameplate);
+    }
+}
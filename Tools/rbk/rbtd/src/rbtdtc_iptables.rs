@@ -0,0 +1,351 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTC — tests for rbtdrc_iptables save parsing and the sentry policy model
+
+use std::net::Ipv4Addr;
+
+use super::rbtdrc_iptables::*;
+use super::rbtdrc_policy::rbtdrc_Policy;
+
+const RBTDTC_NAMEPLATE: &str = "\
+RBRN_ENTRY_MODE=rbnne_enabled
+RBRN_ENTRY_PORT_WORKSTATION=8890
+RBRN_ENTRY_PORT_ENCLAVE=8888
+RBRN_UPLINK_DNS_MODE=rbnne_allowlist
+RBRN_UPLINK_ACCESS_MODE=rbnne_allowlist
+RBRN_UPLINK_ALLOWED_CIDRS=\"192.0.32.0/20\"
+RBRN_ENCLAVE_BASE_IP=10.242.0.0
+RBRN_ENCLAVE_NETMASK=24
+RBRN_ENCLAVE_SENTRY_IP=10.242.0.2
+RBRN_ENCLAVE_BOTTLE_IP=10.242.0.3
+";
+
+/// What rbjs_sentry.sh leaves behind for RBTDTC_NAMEPLATE, as legacy
+/// iptables-save prints it (enclave eth0, uplink eth1, resolver 8.8.8.8).
+const RBTDTC_ALLOWLIST_SAVE: &str = "\
+# Generated by iptables-save v1.8.9 (legacy) on Sat Oct 17 09:12:44 2026
+*nat
+:PREROUTING ACCEPT [0:0]
+:INPUT ACCEPT [0:0]
+:OUTPUT ACCEPT [0:0]
+:POSTROUTING ACCEPT [0:0]
+-A PREROUTING -s 10.242.0.2/32 -p tcp -m tcp --dport 8890 -j RETURN
+-A PREROUTING -s 10.242.0.3/32 -p tcp -m tcp --dport 8890 -j RETURN
+-A PREROUTING -p tcp -m tcp --dport 8890 -j DNAT --to-destination 10.242.0.3:8888
+-A POSTROUTING -d 10.242.0.3/32 -o eth0 -p tcp -m tcp --dport 8888 -j MASQUERADE
+-A POSTROUTING -s 10.242.0.0/24 ! -d 10.242.0.0/24 -o eth1 -j MASQUERADE
+COMMIT
+# Completed on Sat Oct 17 09:12:44 2026
+# Generated by iptables-save v1.8.9 (legacy) on Sat Oct 17 09:12:44 2026
+*filter
+:INPUT DROP [0:0]
+:FORWARD DROP [0:0]
+:OUTPUT DROP [0:0]
+:RBM-EGRESS - [0:0]
+:RBM-FORWARD - [0:0]
+:RBM-INGRESS - [0:0]
+-A INPUT -i lo -j ACCEPT
+-A INPUT -m state --state RELATED,ESTABLISHED -j ACCEPT
+-A INPUT -j RBM-INGRESS
+-A FORWARD -m state --state RELATED,ESTABLISHED -j ACCEPT
+-A FORWARD -j RBM-FORWARD
+-A OUTPUT -o lo -j ACCEPT
+-A OUTPUT -m state --state RELATED,ESTABLISHED -j ACCEPT
+-A OUTPUT -j RBM-EGRESS
+-A RBM-EGRESS -o eth0 -p icmp -j ACCEPT
+-A RBM-EGRESS -o eth1 -p icmp -j DROP
+-A RBM-EGRESS -d 8.8.8.8/32 -o eth1 -p udp -m udp --dport 53 -j ACCEPT
+-A RBM-EGRESS -d 8.8.8.8/32 -o eth1 -p tcp -m tcp --dport 53 -j ACCEPT
+-A RBM-EGRESS -d 192.0.32.0/20 -o eth1 -j ACCEPT
+-A RBM-FORWARD -d 10.242.0.3/32 -p tcp -m tcp --dport 8888 -m conntrack --ctstate DNAT -j ACCEPT
+-A RBM-FORWARD -p icmp -j DROP
+-A RBM-FORWARD -d 10.242.0.2/32 -i eth0 -p udp -m udp --dport 53 -j ACCEPT
+-A RBM-FORWARD -d 10.242.0.2/32 -i eth0 -p tcp -m tcp --dport 53 -j ACCEPT
+-A RBM-FORWARD -i eth0 -p udp -m udp --dport 53 -j DROP
+-A RBM-FORWARD -i eth0 -p tcp -m tcp --dport 53 -j DROP
+-A RBM-FORWARD -d 192.0.32.0/20 -i eth0 -j ACCEPT
+-A RBM-INGRESS -i eth0 -p icmp -j ACCEPT
+-A RBM-INGRESS -i eth0 -p udp -m udp --dport 53 -j ACCEPT
+-A RBM-INGRESS -i eth0 -p tcp -m tcp --dport 53 -j ACCEPT
+COMMIT
+";
+
+fn rbtdtc_facts() -> rbtdrc_SentryFacts {
+    rbtdrc_SentryFacts {
+        enclave_if: "eth0".to_string(),
+        uplink_if: "eth1".to_string(),
+        uplink_ip: Ipv4Addr::new(172, 17, 0, 2),
+        dns_server: Ipv4Addr::new(8, 8, 8, 8),
+    }
+}
+
+/// Every failed claim of the model for `nameplate` against `save`.
+fn rbtdtc_failures(nameplate: &str, save: &str) -> Vec<String> {
+    let ruleset = rbtdrc_Ruleset::parse(save).unwrap();
+    let policy = rbtdrc_Policy::parse(nameplate).unwrap();
+    let model = rbtdrc_policy_model(&policy, &rbtdtc_facts()).unwrap();
+    model.iter().filter_map(|e| e.check(&ruleset).err()).collect()
+}
+
+fn rbtdtc_packet(protocol: &'static str, dst: [u8; 4], dport: Option<u16>) -> rbtdrc_Packet {
+    rbtdrc_Packet {
+        protocol,
+        src: Ipv4Addr::new(10, 242, 0, 3),
+        dst: Ipv4Addr::from(dst),
+        dport,
+        in_iface: Some("eth0".to_string()),
+        out_iface: Some("eth1".to_string()),
+        ctstate: &["NEW"],
+    }
+}
+
+#[test]
+fn rbtdtc_legacy_save_parses_into_tables_chains_and_rules() {
+    let ruleset = rbtdrc_Ruleset::parse(RBTDTC_ALLOWLIST_SAVE).unwrap();
+    assert_eq!(ruleset.backend, rbtdrc_Backend::Legacy);
+    let names: Vec<&str> = ruleset.tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["nat", "filter"]);
+
+    let forward = ruleset.chain("filter", "FORWARD").unwrap();
+    assert_eq!(forward.policy.as_deref(), Some("DROP"));
+    assert_eq!(forward.rules.len(), 2);
+    assert_eq!(ruleset.chain("filter", "RBM-FORWARD").unwrap().policy, None);
+
+    let dnat = &ruleset.chain("nat", "PREROUTING").unwrap().rules[2];
+    assert_eq!(dnat.target.as_deref(), Some("DNAT"));
+    assert_eq!(dnat.target_args, vec!["--to-destination", "10.242.0.3:8888"]);
+    let masq = &ruleset.chain("nat", "POSTROUTING").unwrap().rules[1];
+    assert!(masq.destination.as_ref().unwrap().negated);
+    assert_eq!(
+        masq.to_string(),
+        "-A POSTROUTING -s 10.242.0.0/24 ! -d 10.242.0.0/24 -o eth1 -j MASQUERADE"
+    );
+    // Legacy `-m state --state` renders as conntrack, states sorted.
+    assert_eq!(
+        forward.rules[0].to_string(),
+        "-A FORWARD -m conntrack --ctstate ESTABLISHED,RELATED -j ACCEPT"
+    );
+}
+
+#[test]
+fn rbtdtc_nft_save_reads_the_same_as_legacy() {
+    let nft = RBTDTC_ALLOWLIST_SAVE
+        .replace("iptables-save v1.8.9 (legacy)", "iptables-nft-save v1.8.9 (nf_tables)")
+        .replace("-m state --state", "-m conntrack --ctstate")
+        .replace(
+            "-A POSTROUTING -s 10.242.0.0/24 ! -d 10.242.0.0/24 -o eth1 -j MASQUERADE",
+            "[3:180] -A POSTROUTING -o eth1 -s 10.242.0.0/24 -d ! 10.242.0.0/24 -j MASQUERADE",
+        )
+        .replace(
+            "-A RBM-EGRESS -o eth1 -p icmp -j DROP",
+            "-A RBM-EGRESS -o eth1 -p 1 -m comment --comment \"uplink \\\"icmp\\\"\" -j DROP",
+        );
+    let legacy = rbtdrc_Ruleset::parse(RBTDTC_ALLOWLIST_SAVE).unwrap();
+    let ruleset = rbtdrc_Ruleset::parse(&nft).unwrap();
+    assert_eq!(ruleset.backend, rbtdrc_Backend::Nft);
+    assert_eq!(rbtdrc_ruleset_diff(&legacy, &ruleset), Vec::<String>::new());
+    assert_eq!(rbtdtc_failures(RBTDTC_NAMEPLATE, &nft), Vec::<String>::new());
+}
+
+#[test]
+fn rbtdtc_sentry_ruleset_meets_its_policy_model() {
+    assert_eq!(rbtdtc_failures(RBTDTC_NAMEPLATE, RBTDTC_ALLOWLIST_SAVE), Vec::<String>::new());
+
+    // Every rule group carries claims, so each sentry_config case has work.
+    let policy = rbtdrc_Policy::parse(RBTDTC_NAMEPLATE).unwrap();
+    let model = rbtdrc_policy_model(&policy, &rbtdtc_facts()).unwrap();
+    for group in [
+        rbtdrc_RuleGroup::Policies,
+        rbtdrc_RuleGroup::Input,
+        rbtdrc_RuleGroup::Forward,
+        rbtdrc_RuleGroup::Output,
+        rbtdrc_RuleGroup::Prerouting,
+        rbtdrc_RuleGroup::Postrouting,
+    ] {
+        assert!(model.iter().any(|e| e.group == group), "{:?}", group);
+    }
+}
+
+#[test]
+fn rbtdtc_widened_forward_names_the_missing_drop() {
+    let cidr_rule = "-A RBM-FORWARD -d 192.0.32.0/20";
+    let widened = RBTDTC_ALLOWLIST_SAVE.replace(cidr_rule, "-A RBM-FORWARD");
+    let failures = rbtdtc_failures(RBTDTC_NAMEPLATE, &widened);
+    assert_eq!(failures.len(), 1, "{:?}", failures);
+    assert_eq!(
+        failures[0],
+        "FORWARD lacks DROP for enclave→0.0.0.0/0 except 192.0.32.0/20: \
+         tcp 10.242.0.3→192.0.31.255:443 NEW (eth0→eth1) \
+         meets ACCEPT by `-A RBM-FORWARD -i eth0 -j ACCEPT`"
+    );
+}
+
+#[test]
+fn rbtdtc_model_catches_order_and_omission_regressions() {
+    // DNAT ahead of the enclave-source RETURNs reflects the bottle's own traffic.
+    let dnat = "-A PREROUTING -p tcp -m tcp --dport 8890 -j DNAT \
+                --to-destination 10.242.0.3:8888\n";
+    let swapped = RBTDTC_ALLOWLIST_SAVE.replace(dnat, "").replace(
+        "-A PREROUTING -s 10.242.0.2/32",
+        &format!("{}-A PREROUTING -s 10.242.0.2/32", dnat),
+    );
+    let failures = rbtdtc_failures(RBTDTC_NAMEPLATE, &swapped);
+    assert_eq!(failures.len(), 1, "{:?}", failures);
+    let lacks = "PREROUTING lacks RETURN for enclave-sourced port 8890: tcp 10.242.0.3→";
+    assert!(failures[0].starts_with(lacks), "{}", failures[0]);
+
+    // Without the conntrack shortcut, replies to allowed egress die at FORWARD.
+    let no_estab = RBTDTC_ALLOWLIST_SAVE
+        .replace("-A FORWARD -m state --state RELATED,ESTABLISHED -j ACCEPT\n", "");
+    let failures = rbtdtc_failures(RBTDTC_NAMEPLATE, &no_estab);
+    assert_eq!(failures.len(), 1, "{:?}", failures);
+    assert!(failures[0].contains("meets DROP by FORWARD policy"), "{}", failures[0]);
+
+    let open = RBTDTC_ALLOWLIST_SAVE.replace(":OUTPUT DROP", ":OUTPUT ACCEPT");
+    let failures = rbtdtc_failures(RBTDTC_NAMEPLATE, &open);
+    let lacks = "OUTPUT policy is ACCEPT, expected DROP".to_string();
+    assert!(failures.contains(&lacks), "{:?}", failures);
+}
+
+#[test]
+fn rbtdtc_disabled_nameplate_model_holds_for_a_closed_sentry() {
+    let nameplate = RBTDTC_NAMEPLATE
+        .replace("rbnne_allowlist", "rbnne_disabled")
+        .replace("RBRN_ENTRY_MODE=rbnne_enabled", "RBRN_ENTRY_MODE=rbnne_disabled")
+        .replace("192.0.32.0/20", "0.0.0.0/0");
+    let save = "\
+*nat
+:PREROUTING ACCEPT [0:0]
+:POSTROUTING ACCEPT [0:0]
+COMMIT
+*filter
+:INPUT DROP [0:0]
+:FORWARD DROP [0:0]
+:OUTPUT DROP [0:0]
+:RBM-EGRESS - [0:0]
+:RBM-FORWARD - [0:0]
+:RBM-INGRESS - [0:0]
+-A INPUT -i lo -j ACCEPT
+-A INPUT -m state --state RELATED,ESTABLISHED -j ACCEPT
+-A INPUT -j RBM-INGRESS
+-A FORWARD -m state --state RELATED,ESTABLISHED -j ACCEPT
+-A FORWARD -j RBM-FORWARD
+-A OUTPUT -o lo -j ACCEPT
+-A OUTPUT -m state --state RELATED,ESTABLISHED -j ACCEPT
+-A OUTPUT -j RBM-EGRESS
+-A RBM-EGRESS -o eth0 -p icmp -j ACCEPT
+-A RBM-EGRESS -o eth1 -p icmp -j DROP
+-A RBM-EGRESS -o eth1 -j DROP
+-A RBM-FORWARD -p icmp -j DROP
+-A RBM-FORWARD -i eth0 -j DROP
+-A RBM-INGRESS -i eth0 -p icmp -j ACCEPT
+COMMIT
+";
+    assert_eq!(rbtdtc_failures(&nameplate, save), Vec::<String>::new());
+
+    // The allowlist sentry's ruleset is far too open for this nameplate.
+    let failures = rbtdtc_failures(&nameplate, RBTDTC_ALLOWLIST_SAVE);
+    assert!(failures.iter().any(|f| f.starts_with("INPUT lacks DROP for enclave DNS")));
+    assert!(failures.iter().any(|f| f.starts_with("OUTPUT lacks DROP for sentry→0.0.0.0/0:")));
+    assert!(failures.iter().any(|f| f.starts_with("POSTROUTING lacks RETURN for enclave→uplink ")));
+}
+
+#[test]
+fn rbtdtc_evaluator_follows_jumps_gotos_and_refuses_unmodeled() {
+    let save = "\
+*filter
+:FORWARD DROP [0:0]
+:A - [0:0]
+:B - [0:0]
+-A FORWARD -i eth+ -j LOG --log-prefix \"fw \"
+-A FORWARD -d 9.9.9.9/32 -j A
+-A FORWARD -d 7.7.7.7/32 -m limit --limit 5/min -j ACCEPT
+-A FORWARD -d ! 10.0.0.0/8 -p tcp --dport 1000:2000 -j ACCEPT
+-A A -g B
+-A A -j ACCEPT
+-A B -p tcp -j RETURN
+-A B -j REJECT --reject-with icmp-port-unreachable
+COMMIT
+";
+    let ruleset = rbtdrc_Ruleset::parse(save).unwrap();
+    assert_eq!(ruleset.backend, rbtdrc_Backend::Unknown);
+    let decide = |p: &rbtdrc_Packet| ruleset.decide("filter", "FORWARD", p).unwrap().to_string();
+
+    // LOG does not terminate; a RETURN under goto returns past the jumping chain.
+    assert_eq!(decide(&rbtdtc_packet("tcp", [9, 9, 9, 9], Some(80))), "DROP by FORWARD policy");
+    assert_eq!(
+        decide(&rbtdtc_packet("udp", [9, 9, 9, 9], Some(80))),
+        "REJECT by `-A B -j REJECT --reject-with icmp-port-unreachable`"
+    );
+    assert_eq!(
+        decide(&rbtdtc_packet("tcp", [1, 1, 1, 1], Some(1500))),
+        "ACCEPT by `-A FORWARD ! -d 10.0.0.0/8 -p tcp --dport 1000:2000 -j ACCEPT`"
+    );
+    assert_eq!(decide(&rbtdtc_packet("tcp", [10, 1, 1, 1], Some(1500))), "DROP by FORWARD policy");
+
+    // An unmodeled match is only an error when the packet could meet it.
+    let err = ruleset.decide("filter", "FORWARD", &rbtdtc_packet("tcp", [7, 7, 7, 7], None));
+    assert!(err.unwrap_err().contains("unmodeled match -m limit --limit 5/min"));
+
+    let err = rbtdrc_Ruleset::parse("*filter\n:INPUT DROP [0:0]\n-A OUTPUT -j DROP\nCOMMIT\n");
+    assert!(err.unwrap_err().contains("line 3: rule for undeclared chain OUTPUT"));
+    let err = rbtdrc_Ruleset::parse("*filter\n:INPUT DROP [0:0]\n-I INPUT -j DROP\nCOMMIT\n");
+    assert!(err.unwrap_err().contains("line 3: unrecognized line"));
+    assert!(rbtdrc_Ruleset::parse("*filter\n:INPUT DROP [0:0]\n").is_err());
+}
+
+#[test]
+fn rbtdtc_ruleset_diff_reports_changes_and_reorders() {
+    let before = rbtdrc_Ruleset::parse(RBTDTC_ALLOWLIST_SAVE).unwrap();
+    let tampered = RBTDTC_ALLOWLIST_SAVE
+        .replace(":FORWARD DROP", ":FORWARD ACCEPT")
+        .replace("-A RBM-INGRESS -i eth0 -p icmp -j ACCEPT\n", "");
+    let after = rbtdrc_Ruleset::parse(&tampered).unwrap();
+    assert_eq!(
+        rbtdrc_ruleset_diff(&before, &after),
+        vec![
+            "- filter: :FORWARD DROP",
+            "- filter: -A RBM-INGRESS -i eth0 -p icmp -j ACCEPT",
+            "+ filter: :FORWARD ACCEPT",
+        ]
+    );
+
+    let udp = "-A RBM-INGRESS -i eth0 -p udp -m udp --dport 53 -j ACCEPT\n";
+    let icmp = "-A RBM-INGRESS -i eth0 -p icmp";
+    let swapped = format!("{}{}", udp, icmp);
+    let reordered = RBTDTC_ALLOWLIST_SAVE.replace(udp, "").replace(icmp, &swapped);
+    let after = rbtdrc_Ruleset::parse(&reordered).unwrap();
+    let diff = rbtdrc_ruleset_diff(&before, &after);
+    assert_eq!(diff, vec!["~ filter: rules of RBM-INGRESS reordered"]);
+}
+
+#[test]
+fn rbtdtc_every_nameplate_yields_a_policy_model() {
+    // Each real nameplate, whatever its modes, derives a model with claims
+    // for every chain policy.
+    let moorings = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../..")
+        .join(crate::rbtdgc_consts::RBTDGC_MOORINGS_DIR);
+    for nameplate in ["ccyolo", "fdkyclk", "moriah", "nineveh", "pluml", "srjcl", "tadmor"] {
+        let path = moorings.join(nameplate).join(crate::rbtdrc_policy::RBTDRC_POLICY_FILE);
+        let policy = rbtdrc_Policy::parse(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let model = rbtdrc_policy_model(&policy, &rbtdtc_facts())
+            .unwrap_or_else(|e| panic!("{}: {}", nameplate, e));
+        let policies = model.iter().filter(|e| e.group == rbtdrc_RuleGroup::Policies).count();
+        assert_eq!(policies, 3, "{}", nameplate);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTC — tests for rbtdrc_policy nameplate-derived containment probes
+
+use std::net::Ipv4Addr;
+
+use super::rbtdrc_policy::*;
+use super::rbtdre_engine::rbtdre_Verdict;
+
+const RBTDTC_NAMEPLATE: &str = "\
+#!/bin/bash
+# Port Service Configuration
+RBRN_ENTRY_MODE=rbnne_enabled
+RBRN_ENTRY_PORT_WORKSTATION=8890
+
+# Network Uplink Configuration
+RBRN_UPLINK_DNS_MODE=rbnne_allowlist
+RBRN_UPLINK_ACCESS_MODE=rbnne_allowlist
+RBRN_UPLINK_ALLOWED_CIDRS=\"192.0.32.0/20\"
+RBRN_UPLINK_ALLOWED_DOMAINS=\"www.internic.net example.org\"
+";
+
+fn rbtdtc_probes(body: &str) -> Vec<rbtdrc_Probe> {
+    rbtdrc_policy_probes(&rbtdrc_Policy::parse(body).unwrap()).unwrap()
+}
+
+/// (expect, target) pairs, for compact whole-list assertions.
+fn rbtdtc_shape(probes: &[rbtdrc_Probe]) -> Vec<(rbtdrc_Expect, String)> {
+    probes.iter().map(|p| (p.expect, p.target.to_string())).collect()
+}
+
+#[test]
+fn rbtdtc_allowlist_nameplate_yields_entries_and_near_misses() {
+    use rbtdrc_Expect::{Allow, Deny};
+    let probes = rbtdtc_probes(RBTDTC_NAMEPLATE);
+    let shape = rbtdtc_shape(&probes);
+    let expected: Vec<(rbtdrc_Expect, &str)> = vec![
+        (Allow, "resolve www.internic.net"),
+        (Deny, "resolve internic.net"),
+        (Deny, "resolve www.internic.org"),
+        (Deny, "resolve notwww.internic.net"),
+        (Allow, "resolve example.org"),
+        (Deny, "resolve example.com"),
+        (Deny, "resolve notexample.org"),
+        (Allow, "tcp/443 host within 192.0.32.0/20"),
+        (Deny, "tcp/443 192.0.31.255"),
+        (Deny, "tcp/443 192.0.48.0"),
+        (Allow, "host port 8890"),
+        (Deny, "host port 8889"),
+        (Deny, "host port 8891"),
+    ];
+    let expected: Vec<(rbtdrc_Expect, String)> =
+        expected.into_iter().map(|(e, t)| (e, t.to_string())).collect();
+    assert_eq!(shape, expected);
+
+    // Every probe maps to the line it tests, by number and verbatim text.
+    let near = &probes[1];
+    assert_eq!(near.line.number, 10);
+    assert_eq!(near.line.key, RBTDRC_KEY_ALLOWED_DOMAINS);
+    assert_eq!(near.rationale, "near-miss: parent of www.internic.net");
+    let entry = probes.last().unwrap();
+    assert_eq!(entry.line.to_string(), "rbrn.env:4: RBRN_ENTRY_PORT_WORKSTATION=8890");
+    assert_eq!(entry.family(), rbtdrc_PolicyFamily::Entry);
+}
+
+#[test]
+fn rbtdtc_near_misses_never_fall_inside_the_allowlist() {
+    // The sibling TLD of example.org is allowed, and the parent of
+    // api.example.org is allowed — neither may be offered as a deny probe.
+    let body = RBTDTC_NAMEPLATE
+        .replace("www.internic.net example.org", "example.org example.com api.example.org")
+        .replace("192.0.32.0/20", "10.0.0.0/24 10.0.1.0/24");
+    let probes = rbtdtc_probes(&body);
+    let denied: Vec<String> = probes
+        .iter()
+        .filter(|p| p.expect == rbtdrc_Expect::Deny)
+        .map(|p| p.target.to_string())
+        .collect();
+    assert!(!denied.contains(&"resolve example.com".to_string()), "{:?}", denied);
+    assert!(!denied.contains(&"resolve example.org".to_string()), "{:?}", denied);
+    assert!(denied.contains(&"resolve example.net".to_string()), "{:?}", denied);
+    // Adjacent blocks cover each other's inner edge; only the outer edges remain.
+    assert!(denied.contains(&"tcp/443 9.255.255.255".to_string()), "{:?}", denied);
+    assert!(denied.contains(&"tcp/443 10.0.2.0".to_string()), "{:?}", denied);
+    assert!(!denied.contains(&"tcp/443 10.0.1.0".to_string()), "{:?}", denied);
+    assert!(!denied.contains(&"tcp/443 10.0.0.255".to_string()), "{:?}", denied);
+    // No candidate is offered twice.
+    let mut unique = denied.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), denied.len());
+
+    let allowed = vec!["example.org".to_string()];
+    assert!(rbtdrc_domain_covered("api.Example.org", &allowed));
+    assert!(!rbtdrc_domain_covered("notexample.org", &allowed));
+}
+
+#[test]
+fn rbtdtc_modes_shape_the_probe_set() {
+    // Global allows everything: allow probes only, no near-misses.
+    let global = RBTDTC_NAMEPLATE.replace("rbnne_allowlist", "rbnne_global");
+    assert!(rbtdtc_probes(&global)
+        .iter()
+        .filter(|p| p.family() != rbtdrc_PolicyFamily::Entry)
+        .all(|p| p.expect == rbtdrc_Expect::Allow));
+
+    // Disabled turns every listed entry into a deny probe tied to the mode line.
+    let disabled = RBTDTC_NAMEPLATE
+        .replace("rbnne_allowlist", "rbnne_disabled")
+        .replace("RBRN_ENTRY_MODE=rbnne_enabled", "RBRN_ENTRY_MODE=rbnne_disabled");
+    let probes = rbtdtc_probes(&disabled);
+    assert_eq!(
+        rbtdtc_shape(&probes),
+        vec![
+            (rbtdrc_Expect::Deny, "resolve www.internic.net".to_string()),
+            (rbtdrc_Expect::Deny, "resolve example.org".to_string()),
+            (rbtdrc_Expect::Deny, "tcp/443 192.0.32.1".to_string()),
+            (rbtdrc_Expect::Deny, "host port 8890".to_string()),
+        ]
+    );
+    assert_eq!(probes[0].line.key, RBTDRC_KEY_DNS_MODE);
+    assert_eq!(probes[2].line.key, RBTDRC_KEY_ACCESS_MODE);
+    assert_eq!(probes[3].line.key, RBTDRC_KEY_ENTRY_MODE);
+}
+
+#[test]
+fn rbtdtc_malformed_policy_is_refused() {
+    let unknown = RBTDTC_NAMEPLATE.replace("DNS_MODE=rbnne_allowlist", "DNS_MODE=rbnne_open");
+    let err = rbtdrc_policy_probes(&rbtdrc_Policy::parse(&unknown).unwrap()).unwrap_err();
+    assert!(err.contains("unknown mode 'rbnne_open'") && err.contains("rbrn.env:7:"), "{}", err);
+
+    let bad_cidr = RBTDTC_NAMEPLATE.replace("192.0.32.0/20", "192.0.32.0/33");
+    let err = rbtdrc_policy_probes(&rbtdrc_Policy::parse(&bad_cidr).unwrap()).unwrap_err();
+    assert!(err.contains("invalid prefix length"), "{}", err);
+
+    let no_port = RBTDTC_NAMEPLATE.replace("RBRN_ENTRY_PORT_WORKSTATION=8890\n", "");
+    let err = rbtdrc_policy_probes(&rbtdrc_Policy::parse(&no_port).unwrap()).unwrap_err();
+    assert!(err.contains("RBRN_ENTRY_PORT_WORKSTATION is not set"), "{}", err);
+
+    let twice = format!("{}RBRN_UPLINK_DNS_MODE=rbnne_global\n", RBTDTC_NAMEPLATE);
+    let err = rbtdrc_Policy::parse(&twice).unwrap_err();
+    assert!(err.contains("lines 7 and 11"), "{}", err);
+}
+
+#[test]
+fn rbtdtc_cidr_arithmetic_and_report_rows() {
+    let cidr = rbtdrc_Cidr::parse("192.0.40.7/20").unwrap();
+    assert_eq!(cidr.to_string(), "192.0.32.0/20");
+    assert!(cidr.contains(Ipv4Addr::new(192, 0, 47, 255)));
+    assert!(!cidr.contains(Ipv4Addr::new(192, 0, 48, 0)));
+    assert_eq!(rbtdrc_Cidr::parse("0.0.0.0/0").unwrap().last(), u32::MAX);
+    assert_eq!(rbtdrc_Cidr::parse("8.8.8.8").unwrap().prefix, 32);
+    assert!(rbtdrc_Cidr::parse("8.8.8/24").is_err());
+
+    let probe = &rbtdtc_probes(RBTDTC_NAMEPLATE)[1];
+    let failed = rbtdre_Verdict::Fail("BREACH: resolved\nsecond line".to_string());
+    assert_eq!(
+        rbtdrc_policy_report_line(probe, &failed),
+        "FAIL deny resolve internic.net (near-miss: parent of www.internic.net) — BREACH: \
+         resolved ← rbrn.env:10: RBRN_UPLINK_ALLOWED_DOMAINS=\"www.internic.net example.org\""
+    );
+    assert!(rbtdrc_policy_report_line(probe, &rbtdre_Verdict::Pass).starts_with("PASS deny "));
+}
+
+#[test]
+fn rbtdtc_every_ifrit_nameplate_generates_probes() {
+    // The real nameplates of the ifrit fixtures parse and yield each family.
+    let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../..");
+    for nameplate in ["tadmor", "moriah"] {
+        let path = root
+            .join(crate::rbtdgc_consts::RBTDGC_MOORINGS_DIR)
+            .join(nameplate)
+            .join(RBTDRC_POLICY_FILE);
+        let body = std::fs::read_to_string(&path).unwrap();
+        let probes = rbtdtc_probes(&body);
+        for family in [
+            rbtdrc_PolicyFamily::Domains,
+            rbtdrc_PolicyFamily::Cidrs,
+            rbtdrc_PolicyFamily::Entry,
+        ] {
+            assert!(probes.iter().any(|p| p.family() == family), "{} {:?}", nameplate, family);
+        }
+    }
+}
+
+#[test]
+fn rbtdtc_listening_ports_read_only_listeners() {
+    let table = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000:22BA 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1
+   1: 0100007F:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 2
+   2: 0A0B0C02:22BB 0A0B0C03:9C40 01 00000000:00000000 00:00000000 00000000     0        0 3
+   3: 00000000000000000000000000000000:22BA 00000000000000000000000000000000:0000 0A 0 0 0 0 4
+";
+    // 8890 twice (tcp and tcp6) and 53; 8891 is an established flow, not a listener.
+    assert_eq!(rbtdrc_listening_ports(table), vec![53, 8890]);
+    assert!(rbtdrc_listening_ports("").is_empty());
+}
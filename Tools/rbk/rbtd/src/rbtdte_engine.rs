@@ -17,8 +17,10 @@
 // RBTDTE — tests for case execution engine
 
 use std::path::Path;
+use std::time::Duration;
 
 use super::rbtdre_engine::*;
+use super::rbtdrt_interrupt::{rbtdrt_current, rbtdrt_watch, rbtdrt_Interrupt};
 use super::rbtdth_helpers::rbtdth_make_scratch;
 
 fn rbtdte_pass(_dir: &Path) -> rbtdre_Verdict {
@@ -40,17 +42,25 @@ const RBTDTE_COLORS: rbtdre_Colors = rbtdre_Colors {
     reset: "",
 };
 
+const RBTDTE_OPTIONS: rbtdre_RunOptions = rbtdre_RunOptions {
+    keep_going: false,
+    case_timeout_secs: None,
+    selection: rbtdre_Selection::ALL,
+    tree_root: None,
+};
+
 #[test]
 fn rbtdte_counts_all_verdict_types() {
     static CASES: &[rbtdre_Case] = &[
-        rbtdre_Case { name: "p1", func: rbtdte_pass },
-        rbtdre_Case { name: "p2", func: rbtdte_pass },
-        rbtdre_Case { name: "s1", func: rbtdte_skip },
-        rbtdre_Case { name: "f1", func: rbtdte_fail },
+        rbtdre_Case { name: "p1", func: rbtdte_pass, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
+        rbtdre_Case { name: "p2", func: rbtdte_pass, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
+        rbtdre_Case { name: "s1", func: rbtdte_skip, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
+        rbtdre_Case { name: "f1", func: rbtdte_fail, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
     ];
 
     let tmp = rbtdth_make_scratch("counts");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 2);
     assert_eq!(result.failed, 1);
     assert_eq!(result.skipped, 1);
@@ -60,12 +70,23 @@ fn rbtdte_counts_all_verdict_types() {
 #[test]
 fn rbtdte_fail_fast_stops_after_first_failure() {
     static CASES: &[rbtdre_Case] = &[
-        rbtdre_Case { name: "ff-f1", func: rbtdte_fail },
-        rbtdre_Case { name: "ff-p1", func: rbtdte_pass },
+        rbtdre_Case {
+            name: "ff-f1",
+            func: rbtdte_fail,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "ff-p1",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
     ];
 
     let tmp = rbtdth_make_scratch("failfast");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, true, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, true, None, &tmp).unwrap();
     assert_eq!(result.failed, 1);
     assert_eq!(result.passed, 0);
     let _ = std::fs::remove_dir_all(&tmp);
@@ -77,15 +98,20 @@ fn rbtdte_trace_files_written() {
         rbtdre_Case {
             name: "traced-pass",
             func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
         },
         rbtdre_Case {
             name: "traced-fail",
             func: rbtdte_fail,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
         },
     ];
 
     let tmp = rbtdth_make_scratch("trace");
-    let _ = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let _ =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
 
     let pass_trace =
         std::fs::read_to_string(tmp.join("traced-pass").join("trace.txt")).unwrap();
@@ -102,13 +128,29 @@ fn rbtdte_trace_files_written() {
 #[test]
 fn rbtdte_cases_run_in_declaration_order() {
     static CASES: &[rbtdre_Case] = &[
-        rbtdre_Case { name: "ord-a", func: rbtdte_pass },
-        rbtdre_Case { name: "ord-b", func: rbtdte_pass },
-        rbtdre_Case { name: "ord-c", func: rbtdte_skip },
+        rbtdre_Case {
+            name: "ord-a",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "ord-b",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "ord-c",
+            func: rbtdte_skip,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
     ];
 
     let tmp = rbtdth_make_scratch("order");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 2);
     assert_eq!(result.skipped, 1);
     assert_eq!(result.failed, 0);
@@ -121,7 +163,8 @@ fn rbtdte_cases_run_in_declaration_order() {
 fn rbtdte_zero_cases() {
     static CASES: &[rbtdre_Case] = &[];
     let tmp = rbtdth_make_scratch("zerocases");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 0);
     assert_eq!(result.failed, 0);
     assert_eq!(result.skipped, 0);
@@ -131,13 +174,14 @@ fn rbtdte_zero_cases() {
 #[test]
 fn rbtdte_all_skip() {
     static CASES: &[rbtdre_Case] = &[
-        rbtdre_Case { name: "sk1", func: rbtdte_skip },
-        rbtdre_Case { name: "sk2", func: rbtdte_skip },
-        rbtdre_Case { name: "sk3", func: rbtdte_skip },
+        rbtdre_Case { name: "sk1", func: rbtdte_skip, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
+        rbtdre_Case { name: "sk2", func: rbtdte_skip, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
+        rbtdre_Case { name: "sk3", func: rbtdte_skip, tags: &[], tariff: rbtdre_Tariff::UNCHECKED },
     ];
 
     let tmp = rbtdth_make_scratch("allskip");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 0);
     assert_eq!(result.failed, 0);
     assert_eq!(result.skipped, 3);
@@ -149,10 +193,13 @@ fn rbtdte_single_case_pass() {
     static CASES: &[rbtdre_Case] = &[rbtdre_Case {
         name: "solo-pass",
         func: rbtdte_pass,
+        tags: &[],
+        tariff: rbtdre_Tariff::UNCHECKED,
     }];
 
     let tmp = rbtdth_make_scratch("solopass");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 1);
     assert_eq!(result.failed, 0);
     assert_eq!(result.skipped, 0);
@@ -164,10 +211,13 @@ fn rbtdte_single_case_fail() {
     static CASES: &[rbtdre_Case] = &[rbtdre_Case {
         name: "solo-fail",
         func: rbtdte_fail,
+        tags: &[],
+        tariff: rbtdre_Tariff::UNCHECKED,
     }];
 
     let tmp = rbtdth_make_scratch("solofail");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 0);
     assert_eq!(result.failed, 1);
     assert_eq!(result.skipped, 0);
@@ -179,14 +229,35 @@ fn rbtdte_single_case_fail() {
 #[test]
 fn rbtdte_run_all_executes_every_case_despite_failures() {
     static CASES: &[rbtdre_Case] = &[
-        rbtdre_Case { name: "ra-f1", func: rbtdte_fail },
-        rbtdre_Case { name: "ra-p1", func: rbtdte_pass },
-        rbtdre_Case { name: "ra-f2", func: rbtdte_fail },
-        rbtdre_Case { name: "ra-p2", func: rbtdte_pass },
+        rbtdre_Case {
+            name: "ra-f1",
+            func: rbtdte_fail,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "ra-p1",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "ra-f2",
+            func: rbtdte_fail,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "ra-p2",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
     ];
 
     let tmp = rbtdth_make_scratch("runall");
-    let result = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
     assert_eq!(result.passed, 2);
     assert_eq!(result.failed, 2);
     // All four case dirs were created — every case ran
@@ -207,12 +278,23 @@ fn rbtdte_write_marker(dir: &Path) -> rbtdre_Verdict {
 #[test]
 fn rbtdte_temp_dirs_are_distinct_and_isolated() {
     static CASES: &[rbtdre_Case] = &[
-        rbtdre_Case { name: "iso-a", func: rbtdte_write_marker },
-        rbtdre_Case { name: "iso-b", func: rbtdte_write_marker },
+        rbtdre_Case {
+            name: "iso-a",
+            func: rbtdte_write_marker,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "iso-b",
+            func: rbtdte_write_marker,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
     ];
 
     let tmp = rbtdth_make_scratch("isolation");
-    let _ = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let _ =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
 
     let dir_a = tmp.join("iso-a");
     let dir_b = tmp.join("iso-b");
@@ -276,36 +358,258 @@ fn rbtdte_args(raw: &[&str]) -> Vec<String> {
     raw.iter().map(|s| s.to_string()).collect()
 }
 
+fn rbtdte_parse_fixture(raw: &[&str]) -> Result<rbtdre_RunnerArgs, String> {
+    rbtdre_parse_runner_args(&rbtdte_args(raw), RBTDRE_FIXTURE_FLAGS)
+}
+
 #[test]
 fn rbtdte_parse_keep_going_absent() {
-    let (pos, kg) = rbtdre_parse_keep_going(&rbtdte_args(&["tadmor"])).unwrap();
-    assert_eq!(pos, vec!["tadmor".to_string()]);
-    assert!(!kg);
+    let parsed = rbtdte_parse_fixture(&["tadmor"]).unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert!(!parsed.keep_going);
 }
 
 #[test]
 fn rbtdte_parse_keep_going_trailing() {
-    let (pos, kg) =
-        rbtdre_parse_keep_going(&rbtdte_args(&["tadmor", RBTDRE_FLAG_KEEP_GOING])).unwrap();
-    assert_eq!(pos, vec!["tadmor".to_string()]);
-    assert!(kg);
+    let parsed = rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_KEEP_GOING]).unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert!(parsed.keep_going);
 }
 
 #[test]
 fn rbtdte_parse_keep_going_position_independent() {
-    let (pos, kg) =
-        rbtdre_parse_keep_going(&rbtdte_args(&[RBTDRE_FLAG_KEEP_GOING, "tadmor"])).unwrap();
-    assert_eq!(pos, vec!["tadmor".to_string()]);
-    assert!(kg);
+    let parsed = rbtdte_parse_fixture(&[RBTDRE_FLAG_KEEP_GOING, "tadmor"]).unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert!(parsed.keep_going);
 }
 
 #[test]
 fn rbtdte_parse_keep_going_unknown_flag_rejected() {
-    let err = rbtdre_parse_keep_going(&rbtdte_args(&["tadmor", "--keep-goign"])).unwrap_err();
+    let err = rbtdte_parse_fixture(&["tadmor", "--keep-goign"]).unwrap_err();
     assert!(err.contains("--keep-goign"));
     assert!(err.contains(RBTDRE_FLAG_KEEP_GOING));
 }
 
+#[test]
+fn rbtdte_parse_runner_args_suite_flags() {
+    let args = rbtdte_args(&[RBTDRE_FLAG_RESUME, "gauntlet"]);
+    let parsed = rbtdre_parse_runner_args(&args, RBTDRE_SUITE_FLAGS).unwrap();
+    assert_eq!(parsed.positionals, vec!["gauntlet".to_string()]);
+    assert!(parsed.resume);
+    assert!(!parsed.keep_going);
+}
+
+#[test]
+fn rbtdte_parse_verbose_accepted_by_both_runners() {
+    let parsed = rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_VERBOSE]).unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert!(parsed.verbose);
+    let args = rbtdte_args(&[RBTDRE_FLAG_VERBOSE, "gauntlet"]);
+    assert!(rbtdre_parse_runner_args(&args, RBTDRE_SUITE_FLAGS).unwrap().verbose);
+    assert!(!rbtdte_parse_fixture(&["tadmor"]).unwrap().verbose);
+}
+
+#[test]
+fn rbtdte_parse_hermetic_keep_is_repeatable() {
+    let parsed = rbtdte_parse_fixture(&[
+        "tadmor",
+        RBTDRE_FLAG_HERMETIC,
+        RBTDRE_FLAG_HERMETIC_KEEP,
+        "LANG",
+        RBTDRE_FLAG_HERMETIC_KEEP,
+        "CLOUDSDK_CONFIG",
+    ])
+    .unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert!(parsed.hermetic);
+    assert_eq!(parsed.hermetic_keep, vec!["LANG".to_string(), "CLOUDSDK_CONFIG".to_string()]);
+    let args = rbtdte_args(&["gauntlet", RBTDRE_FLAG_HERMETIC]);
+    assert!(rbtdre_parse_runner_args(&args, RBTDRE_SUITE_FLAGS).unwrap().hermetic);
+    assert!(rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_HERMETIC_KEEP]).is_err());
+}
+
+#[test]
+fn rbtdte_parse_netns_accepted_by_both_runners() {
+    assert!(rbtdte_parse_fixture(&["pyx", RBTDRE_FLAG_NETNS]).unwrap().netns);
+    let args = rbtdte_args(&[RBTDRE_FLAG_NETNS, "reveille"]);
+    assert!(rbtdre_parse_runner_args(&args, RBTDRE_SUITE_FLAGS).unwrap().netns);
+    assert!(!rbtdte_parse_fixture(&["pyx"]).unwrap().netns);
+}
+
+#[test]
+fn rbtdte_parse_worktree_is_suite_only() {
+    let args = rbtdte_args(&["gauntlet", RBTDRE_FLAG_WORKTREE]);
+    assert!(rbtdre_parse_runner_args(&args, RBTDRE_SUITE_FLAGS).unwrap().worktree);
+    let err = rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_WORKTREE]).unwrap_err();
+    assert!(err.contains(RBTDRE_FLAG_WORKTREE));
+}
+
+#[test]
+fn rbtdte_parse_cassette_flags_take_a_directory() {
+    let parsed = rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_REPLAY, "tapes/tadmor"]).unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert_eq!(parsed.replay.as_deref(), Some("tapes/tadmor"));
+    assert_eq!(parsed.record, None);
+    assert!(rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_RECORD]).is_err());
+    let args = rbtdte_args(&["gauntlet", RBTDRE_FLAG_RECORD, "tapes"]);
+    assert!(rbtdre_parse_runner_args(&args, RBTDRE_SUITE_FLAGS).is_err());
+}
+
+#[test]
+fn rbtdte_parse_keep_going_rejects_resume() {
+    // --resume is a suite-runner flag; the fixture runner must not swallow it.
+    let err = rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_RESUME]).unwrap_err();
+    assert!(err.contains(RBTDRE_FLAG_RESUME));
+}
+
+#[test]
+fn rbtdte_parse_selection_flags_take_values() {
+    let parsed = rbtdte_parse_fixture(&[
+        "tadmor", RBTDRE_FLAG_ONLY, "sortie_*", RBTDRE_FLAG_TAG, "dns", RBTDRE_FLAG_SKIP, "*_ipv6*",
+        RBTDRE_FLAG_TAG, "sentry",
+    ])
+    .unwrap();
+    assert_eq!(parsed.positionals, vec!["tadmor".to_string()]);
+    assert_eq!(parsed.selection.only, vec!["sortie_*".to_string()]);
+    assert_eq!(parsed.selection.skip, vec!["*_ipv6*".to_string()]);
+    assert_eq!(parsed.selection.tags, vec!["dns".to_string(), "sentry".to_string()]);
+
+    let err = rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_ONLY]).unwrap_err();
+    assert!(err.contains("needs a value"), "{}", err);
+    let err =
+        rbtdte_parse_fixture(&["tadmor", RBTDRE_FLAG_TAG, RBTDRE_FLAG_KEEP_GOING]).unwrap_err();
+    assert!(err.contains("needs a value"), "{}", err);
+}
+
+// ── Case selection ───────────────────────────────────────────
+
+#[test]
+fn rbtdte_glob_match() {
+    assert!(rbtdre_glob_match("sortie_dns_*", "sortie_dns_rebinding"));
+    assert!(rbtdre_glob_match("*dns*", "ifrit_dns_tcp"));
+    assert!(rbtdre_glob_match("a?c", "abc"));
+    assert!(rbtdre_glob_match("*", ""));
+    assert!(rbtdre_glob_match("a*b*c", "axxbyyc"));
+    assert!(!rbtdre_glob_match("a*b*c", "axxbyy"));
+    assert!(!rbtdre_glob_match("sortie_dns_*", "xsortie_dns_a"));
+    assert!(!rbtdre_glob_match("abc", "abcd"));
+}
+
+static RBTDTE_SELECT_CASES: &[rbtdre_Case] = &[
+    rbtdre_Case {
+        name: "zsel_ifrit_dns_tcp",
+        func: rbtdte_pass,
+        tags: &["dns"],
+        tariff: rbtdre_Tariff::UNCHECKED,
+    },
+    rbtdre_Case {
+        name: "zsel_sortie_dns_rebinding",
+        func: rbtdte_pass,
+        tags: &["sortie", "dns"],
+        tariff: rbtdre_Tariff::UNCHECKED,
+    },
+    rbtdre_Case {
+        name: "zsel_sortie_net_ipv6",
+        func: rbtdte_pass,
+        tags: &["sortie"],
+        tariff: rbtdre_Tariff::UNCHECKED,
+    },
+    rbtdre_Case {
+        name: "zsel_sentry_rp_filter",
+        func: rbtdte_pass,
+        tags: &[],
+        tariff: rbtdre_Tariff::UNCHECKED,
+    },
+];
+
+fn rbtdte_selected(
+    selection: &rbtdre_Selection,
+    disposition: rbtdre_Disposition,
+) -> Vec<&'static str> {
+    rbtdre_select_cases(RBTDTE_SELECT_CASES, disposition, selection)
+        .unwrap()
+        .iter()
+        .map(|c| c.name)
+        .collect()
+}
+
+#[test]
+fn rbtdte_selection_only_skip_and_tag_compose() {
+    let independent = rbtdre_Disposition::Independent;
+    // Bare pattern matches without the module prefix; prefixed matches too.
+    let only = rbtdre_Selection { only: vec!["sortie_dns_*".into()], ..Default::default() };
+    assert_eq!(rbtdte_selected(&only, independent), vec!["zsel_sortie_dns_rebinding"]);
+    let only = rbtdre_Selection { only: vec!["zsel_sortie_*".into()], ..Default::default() };
+    assert_eq!(rbtdte_selected(&only, independent).len(), 2);
+
+    let tag = rbtdre_Selection { tags: vec!["dns".into()], ..Default::default() };
+    assert_eq!(
+        rbtdte_selected(&tag, independent),
+        vec!["zsel_ifrit_dns_tcp", "zsel_sortie_dns_rebinding"]
+    );
+
+    let tag_skip = rbtdre_Selection {
+        tags: vec!["sortie".into()],
+        skip: vec!["*ipv6*".into()],
+        ..Default::default()
+    };
+    assert_eq!(rbtdte_selected(&tag_skip, independent), vec!["zsel_sortie_dns_rebinding"]);
+
+    assert_eq!(rbtdte_selected(&rbtdre_Selection::ALL, independent).len(), 4);
+
+    let none = rbtdre_Selection { tags: vec!["nope".into()], ..Default::default() };
+    let err = rbtdre_select_cases(RBTDTE_SELECT_CASES, independent, &none)
+        .err()
+        .expect("empty selection refused");
+    assert!(err.contains("matches no cases"), "{}", err);
+}
+
+#[test]
+fn rbtdte_selection_state_progressing_accepts_only_a_leading_run() {
+    let progressing = rbtdre_Disposition::StateProgressing;
+    let prefix = rbtdre_Selection { skip: vec!["*sentry*".into()], ..Default::default() };
+    assert_eq!(rbtdte_selected(&prefix, progressing).len(), 3);
+
+    // Dropping the first case while running later ones breaks the chain.
+    let gap = rbtdre_Selection { only: vec!["sortie_*".into()], ..Default::default() };
+    let err = rbtdre_select_cases(RBTDTE_SELECT_CASES, progressing, &gap)
+        .err()
+        .expect("gapped selection refused");
+    assert!(err.contains("refused for StateProgressing"), "{}", err);
+    assert!(err.contains("'zsel_ifrit_dns_tcp'"), "{}", err);
+    assert!(err.contains("'zsel_sortie_dns_rebinding'"), "{}", err);
+}
+
+static ZRBTDTE_SELECT_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-select-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: None,
+    cases: RBTDTE_SELECT_CASES,
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff { min_secs: Some(60), max_secs: None, invocations: Some(9) },
+    case_timeout_secs: None,
+};
+
+#[test]
+fn rbtdte_selected_run_is_held_to_no_tariff() {
+    let tmp = rbtdth_make_scratch("select-fixture");
+    let options = rbtdre_RunOptions {
+        selection: rbtdre_Selection { tags: vec!["dns".into()], ..Default::default() },
+        ..Default::default()
+    };
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_SELECT_FIXTURE, &RBTDTE_COLORS, &tmp, &options).unwrap();
+    assert_eq!(result.passed, 2);
+    // An instant run against a 60s floor would be too-fast — but a partial run
+    // is evaluated UNCHECKED, and its census is not demanded.
+    assert_eq!(result.failed, 0);
+    assert!(result.tariff.as_ref().is_some_and(|t| !t.too_fast));
+    assert!(result.census.is_none());
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
 // ── Trace file content detail ────────────────────────────────
 
 #[test]
@@ -313,10 +617,13 @@ fn rbtdte_trace_file_skip_contains_reason() {
     static CASES: &[rbtdre_Case] = &[rbtdre_Case {
         name: "traced-skip",
         func: rbtdte_skip,
+        tags: &[],
+        tariff: rbtdre_Tariff::UNCHECKED,
     }];
 
     let tmp = rbtdth_make_scratch("skiptrace");
-    let _ = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let _ =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
 
     let trace = std::fs::read_to_string(tmp.join("traced-skip").join("trace.txt")).unwrap();
     assert!(trace.contains("SKIPPED"));
@@ -336,10 +643,13 @@ fn rbtdte_case_output_files_survive_in_trace_dir() {
     static CASES: &[rbtdre_Case] = &[rbtdre_Case {
         name: "output-case",
         func: write_output,
+        tags: &[],
+        tariff: rbtdre_Tariff::UNCHECKED,
     }];
 
     let tmp = rbtdth_make_scratch("caseoutput");
-    let _ = rbtdre_run_cases(CASES, &RBTDTE_COLORS, false, &tmp).unwrap();
+    let _ =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
 
     let output = std::fs::read_to_string(tmp.join("output-case").join("output.txt")).unwrap();
     assert!(output.contains("custom output data"));
@@ -449,6 +759,148 @@ fn rbtdte_commit_nameplates_scopes_to_named_class_only() {
     let _ = std::fs::remove_dir_all(&tmp);
 }
 
+#[test]
+fn rbtdte_commit_verbs_stand_down_under_replay() {
+    let tmp = rbtdth_make_scratch("commit-replay");
+    assert!(rbtdte_git(&["init", "-q"], &tmp).status.success());
+    rbtdte_git(&["config", "user.email", "theurge@test"], &tmp);
+    rbtdte_git(&["config", "user.name", "theurge test"], &tmp);
+    let np_dir = tmp.join(crate::rbtdgc_consts::RBTDGC_MOORINGS_DIR).join("testnp");
+    std::fs::create_dir_all(&np_dir).unwrap();
+    let rbrn = np_dir.join(crate::rbtdgc_consts::RBTDGC_RBRN_FILE);
+    std::fs::write(&rbrn, "RBRN_SENTRY_HALLMARK=\n").unwrap();
+    rbtdte_git(&["add", "-A"], &tmp);
+    assert!(rbtdte_git(&["commit", "-q", "-m", "baseline"], &tmp).status.success());
+    std::fs::write(&rbrn, "RBRN_SENTRY_HALLMARK=kabc123\n").unwrap();
+
+    let tape = tmp.join("tape");
+    std::fs::create_dir_all(&tape).unwrap();
+    crate::rbtdri_invocation::rbtdri_arm_cassette(Some(
+        crate::rbtdry_cassette::rbtdry_Cassette::replay(&tape).unwrap(),
+    ));
+    let committed = rbtdre_commit_nameplates(&tmp, &["testnp"], "test: nameplate hallmark");
+    crate::rbtdri_invocation::rbtdri_arm_cassette(None);
+    committed.unwrap();
+
+    // The edit is still the operator's, uncommitted.
+    let status = rbtdte_git(&["status", "--porcelain"], &tmp);
+    assert!(String::from_utf8_lossy(&status.stdout).contains("rbrn.env"));
+    let log = rbtdte_git(&["rev-list", "--count", "HEAD"], &tmp);
+    assert_eq!(String::from_utf8_lossy(&log.stdout).trim(), "1");
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+// ── Read-only guard ──────────────────────────────────────────
+
+/// A committed repo with a tracked kit dir, an ignore rule for lockfiles, an
+/// already-dirty tracked file, and an ignored root scratch file.
+fn zrbtdte_readonly_repo(label: &str) -> std::path::PathBuf {
+    let tmp = rbtdth_make_scratch(label);
+    assert!(rbtdte_git(&["init", "-q"], &tmp).status.success());
+    rbtdte_git(&["config", "user.email", "theurge@test"], &tmp);
+    rbtdte_git(&["config", "user.name", "theurge test"], &tmp);
+    std::fs::create_dir_all(tmp.join("kit")).unwrap();
+    std::fs::write(tmp.join("kit/tool.sh"), "echo tool\n").unwrap();
+    std::fs::write(tmp.join("kit/dirty.env"), "A=1\n").unwrap();
+    std::fs::write(tmp.join(".gitignore"), "*.lock\n/scratch.txt\n").unwrap();
+    rbtdte_git(&["add", "-A"], &tmp);
+    assert!(rbtdte_git(&["commit", "-q", "-m", "baseline"], &tmp).status.success());
+    std::fs::write(tmp.join("kit/dirty.env"), "A=2\n").unwrap();
+    std::fs::write(tmp.join("scratch.txt"), "operator notes\n").unwrap();
+    tmp
+}
+
+#[test]
+fn rbtdte_tree_mutations_name_every_changed_path() {
+    let tmp = zrbtdte_readonly_repo("readonly-snapshot");
+    let before = rbtdre_tree_snapshot(&tmp).unwrap();
+    assert_eq!(before, rbtdre_tree_snapshot(&tmp).unwrap(), "snapshot is stable");
+
+    // Edit the already-dirty file (its status code does not change), delete a
+    // tracked file, and drop an ignored lockfile and an untracked file into the
+    // kit dir. The ignored root scratch file is rewritten too — it is not tree.
+    std::fs::write(tmp.join("kit/dirty.env"), "A=3\n").unwrap();
+    std::fs::remove_file(tmp.join("kit/tool.sh")).unwrap();
+    std::fs::write(tmp.join("kit/Cargo.lock"), "lock\n").unwrap();
+    std::fs::write(tmp.join("kit/notes.txt"), "new\n").unwrap();
+    std::fs::write(tmp.join("scratch.txt"), "more notes\n").unwrap();
+
+    let after = rbtdre_tree_snapshot(&tmp).unwrap();
+    assert_eq!(
+        rbtdre_tree_mutations(&before, &after),
+        vec![
+            "created: kit/Cargo.lock",
+            "modified: kit/dirty.env",
+            "created: kit/notes.txt",
+            "deleted: kit/tool.sh",
+        ]
+    );
+
+    rbtdte_git(&["add", "-A"], &tmp);
+    assert!(rbtdte_git(&["commit", "-q", "-m", "sweep"], &tmp).status.success());
+    let committed = rbtdre_tree_snapshot(&tmp).unwrap();
+    let mutations = rbtdre_tree_mutations(&after, &committed);
+    assert!(mutations[0].starts_with("HEAD moved: "), "{:?}", mutations);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+static ZRBTDTE_READONLY_ROOT: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+
+fn zrbtdte_readonly_writer(_dir: &Path) -> rbtdre_Verdict {
+    let root = ZRBTDTE_READONLY_ROOT.get().expect("read-only root set");
+    std::fs::write(root.join("kit/tool.sh"), "echo rewritten\n").unwrap();
+    rbtdre_Verdict::Pass
+}
+
+static ZRBTDTE_READONLY_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-readonly-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: None,
+    cases: &[crate::case!(rbtdte_pass), crate::case!(zrbtdte_readonly_writer)],
+    credless: false,
+    read_only: true,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+#[test]
+fn rbtdte_read_only_fixture_fails_when_a_case_writes_the_tree() {
+    let repo = zrbtdte_readonly_repo("readonly-fixture");
+    ZRBTDTE_READONLY_ROOT.set(repo.clone()).unwrap();
+    let trace = rbtdth_make_scratch("readonly-trace");
+
+    // Unenforced without a tree root: every case passes and nothing is counted.
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_READONLY_FIXTURE, &RBTDTE_COLORS, &trace, &RBTDTE_OPTIONS)
+            .unwrap();
+    assert_eq!((result.passed, result.failed), (2, 0));
+
+    let options = rbtdre_RunOptions { tree_root: Some(repo.clone()), ..Default::default() };
+    std::fs::write(repo.join("kit/tool.sh"), "echo tool\n").unwrap();
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_READONLY_FIXTURE, &RBTDTE_COLORS, &trace, &options).unwrap();
+    assert_eq!(result.passed, 2, "the cases' own verdicts stand");
+    assert_eq!(result.failed, 1, "the mutation fails the fixture once");
+    let guard = result.cases.last().unwrap();
+    assert_eq!(guard.name, RBTDRE_GUARD_READ_ONLY);
+    assert!(
+        matches!(&guard.verdict, rbtdre_Verdict::Fail(d) if d.contains("kit/tool.sh")),
+        "the report carries the mutation"
+    );
+
+    let outside = rbtdth_make_scratch("readonly-not-a-repo");
+    let options = rbtdre_RunOptions { tree_root: Some(outside.clone()), ..Default::default() };
+    let err = rbtdre_run_fixture(&ZRBTDTE_READONLY_FIXTURE, &RBTDTE_COLORS, &trace, &options)
+        .err()
+        .unwrap();
+    assert!(err.contains("read-only baseline snapshot failed"), "{}", err);
+
+    let _ = std::fs::remove_dir_all(&repo);
+    let _ = std::fs::remove_dir_all(&trace);
+    let _ = std::fs::remove_dir_all(&outside);
+}
+
 // ── Tariff evaluation seam ─────────────────────────────────────
 //
 // The pure seam is the tariff feature's testable heart: given a declared tariff
@@ -512,6 +964,63 @@ fn rbtdte_tariff_bounds_are_independent() {
     assert_eq!(mixed.invocations, 4);
 }
 
+#[test]
+fn rbtdte_case_tariff_too_fast_fails_the_case() {
+    // A case declaring a floor it cannot meet turns its Pass into a Fail,
+    // and fail-fast stops on it like any other red.
+    static CASES: &[rbtdre_Case] = &[
+        rbtdre_Case {
+            name: "ct-vacuous",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff { min_secs: Some(30), max_secs: None, invocations: None },
+        },
+        rbtdre_Case {
+            name: "ct-never",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+    ];
+    let tmp = rbtdth_make_scratch("case-tariff");
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, true, None, &tmp).unwrap();
+    assert_eq!((result.passed, result.failed), (0, 1));
+    assert_eq!(result.cases.len(), 1);
+    match &result.cases[0].verdict {
+        rbtdre_Verdict::Fail(msg) => assert!(msg.contains("tariff too-fast"), "{}", msg),
+        _ => panic!("expected the too-fast case to fail"),
+    }
+    assert_eq!(result.cases[0].invocations, 0);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdte_case_tariff_leaves_drift_and_non_pass_verdicts_alone() {
+    let case = rbtdre_Case {
+        name: "ct-drift",
+        func: rbtdte_pass,
+        tags: &[],
+        tariff: rbtdre_Tariff { min_secs: Some(5), max_secs: Some(10), invocations: Some(2) },
+    };
+    // Too-slow and count-drift are advisories — the Pass stands.
+    let verdict = rbtdre_case_tariff(
+        "zrbtdte-fixture", &case, rbtdre_Verdict::Pass, Duration::from_secs(20), 7, &RBTDTE_COLORS,
+    );
+    assert!(matches!(verdict, rbtdre_Verdict::Pass));
+    // A skipped or failed case is not a green, so its footprint is never held
+    // to the floor.
+    let verdict = rbtdre_case_tariff(
+        "zrbtdte-fixture",
+        &case,
+        rbtdre_Verdict::Skip("no creds".to_string()),
+        Duration::ZERO,
+        0,
+        &RBTDTE_COLORS,
+    );
+    assert!(matches!(verdict, rbtdre_Verdict::Skip(_)));
+}
+
 // ── Colophon census wired into rbtdre_run_fixture ────────────────
 //
 // Mirrors the real Context::new -> rbtdrc_set_context -> rbtdre_run_fixture
@@ -549,7 +1058,9 @@ static ZRBTDTE_CENSUS_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: ZRBTDTE_CENSUS_CASES,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 
 /// Scratch project root with a tt/ script satisfying ZRBTDTE_CENSUS_COL_USED
@@ -579,7 +1090,8 @@ fn rbtdte_run_fixture_passes_when_declared_colophons_all_used() {
     crate::rbtdrc_crucible::rbtdrc_set_context(ctx);
     crate::rbtdri_invocation::rbtdri_census_arm(Some(&[ZRBTDTE_CENSUS_COL_USED]), &[]);
 
-    let result = rbtdre_run_fixture(&ZRBTDTE_CENSUS_FIXTURE, &RBTDTE_COLORS, &tmp, false).unwrap();
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_CENSUS_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS).unwrap();
 
     let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
 
@@ -603,7 +1115,8 @@ fn rbtdte_run_fixture_fails_on_declared_but_unused_colophon() {
         ZRBTDTE_CENSUS_COL_UNUSED,
     ]), &[]);
 
-    let result = rbtdre_run_fixture(&ZRBTDTE_CENSUS_FIXTURE, &RBTDTE_COLORS, &tmp, false).unwrap();
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_CENSUS_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS).unwrap();
 
     let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
 
@@ -630,7 +1143,8 @@ fn rbtdte_run_single_case_ignores_unused_declared_colophons() {
         ZRBTDTE_CENSUS_COL_UNUSED,
     ]), &[]);
 
-    let result = rbtdre_run_single_case(&ZRBTDTE_CENSUS_CASES[0], &RBTDTE_COLORS, &tmp).unwrap();
+    let result = rbtdre_run_single_case(&ZRBTDTE_CENSUS_CASES[0], &RBTDTE_COLORS, &tmp, None)
+        .unwrap();
 
     let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
 
@@ -655,7 +1169,8 @@ fn rbtdte_run_fixture_permitted_colophon_invocation_not_refused() {
     crate::rbtdrc_crucible::rbtdrc_set_context(ctx);
     crate::rbtdri_invocation::rbtdri_census_arm(Some(&[]), &[ZRBTDTE_CENSUS_COL_USED]);
 
-    let result = rbtdre_run_fixture(&ZRBTDTE_CENSUS_FIXTURE, &RBTDTE_COLORS, &tmp, false).unwrap();
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_CENSUS_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS).unwrap();
 
     let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
 
@@ -674,7 +1189,9 @@ static ZRBTDTE_CENSUS_NOINVOKE_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: ZRBTDTE_CENSUS_NOINVOKE_CASES,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 
 #[test]
@@ -694,7 +1211,8 @@ fn rbtdte_run_fixture_permitted_colophon_unused_does_not_fail() {
     crate::rbtdri_invocation::rbtdri_census_arm(Some(&[]), &[ZRBTDTE_CENSUS_COL_UNUSED]);
 
     let result =
-        rbtdre_run_fixture(&ZRBTDTE_CENSUS_NOINVOKE_FIXTURE, &RBTDTE_COLORS, &tmp, false).unwrap();
+        rbtdre_run_fixture(&ZRBTDTE_CENSUS_NOINVOKE_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS)
+            .unwrap();
 
     let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
 
@@ -717,7 +1235,9 @@ static ZRBTDTE_CENSUS_SKIP_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
     teardown: None,
     cases: ZRBTDTE_CENSUS_SKIP_CASES,
     credless: false,
+    read_only: false,
     tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
 };
 
 #[test]
@@ -736,7 +1256,8 @@ fn rbtdte_run_fixture_skip_suppresses_negative_census() {
     crate::rbtdri_invocation::rbtdri_census_arm(Some(&[ZRBTDTE_CENSUS_COL_UNUSED]), &[]);
 
     let result =
-        rbtdre_run_fixture(&ZRBTDTE_CENSUS_SKIP_FIXTURE, &RBTDTE_COLORS, &tmp, false).unwrap();
+        rbtdre_run_fixture(&ZRBTDTE_CENSUS_SKIP_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS)
+            .unwrap();
 
     let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
 
@@ -745,3 +1266,333 @@ fn rbtdte_run_fixture_skip_suppresses_negative_census() {
 
     let _ = std::fs::remove_dir_all(&tmp);
 }
+
+// ── Panic boundary ───────────────────────────────────────────
+//
+// A panicking case must come back as a Fail verdict carrying the panic message
+// and location, never unwind past the fixture's teardown. Each teardown probe
+// has its own flag so parallel test threads cannot observe one another.
+
+fn zrbtdte_panicking_case(_dir: &Path) -> rbtdre_Verdict {
+    let facts: Vec<&str> = Vec::new();
+    rbtdre_Verdict::Fail(facts.first().expect("zrbtdte missing fact").to_string())
+}
+
+#[test]
+fn rbtdte_catch_panic_reports_message_and_location() {
+    let err = rbtdre_catch_panic("case", || -> u32 { panic!("zrbtdte boom {}", 7) }).unwrap_err();
+    assert!(err.starts_with("case panicked at "), "{}", err);
+    assert!(err.contains("rbtdte_engine.rs:"), "{}", err);
+    assert!(err.ends_with("zrbtdte boom 7"), "{}", err);
+    assert_eq!(rbtdre_catch_panic("case", || 41 + 1).unwrap(), 42);
+}
+
+#[test]
+fn rbtdte_panicking_case_is_a_fail_and_run_continues() {
+    static CASES: &[rbtdre_Case] = &[
+        rbtdre_Case {
+            name: "pn-panic",
+            func: zrbtdte_panicking_case,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "pn-pass",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+    ];
+
+    let tmp = rbtdth_make_scratch("panic-case");
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
+    assert_eq!(result.failed, 1);
+    assert_eq!(result.passed, 1, "keep-going must reach the case after a panic");
+
+    let trace = std::fs::read_to_string(tmp.join("pn-panic").join("trace.txt")).unwrap();
+    assert!(trace.contains("FAILED"));
+    assert!(trace.contains("case panicked at"));
+    assert!(trace.contains("zrbtdte missing fact"));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+static ZRBTDTE_PANIC_TEARDOWN_RAN: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+fn zrbtdte_panic_teardown() {
+    ZRBTDTE_PANIC_TEARDOWN_RAN.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+static ZRBTDTE_PANIC_CASES: &[rbtdre_Case] = &[
+    crate::case!(zrbtdte_panicking_case),
+    crate::case!(rbtdte_pass),
+];
+
+static ZRBTDTE_PANIC_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-panic-fixture",
+    disposition: rbtdre_Disposition::StateProgressing,
+    setup: None,
+    teardown: Some(zrbtdte_panic_teardown),
+    cases: ZRBTDTE_PANIC_CASES,
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+#[test]
+fn rbtdte_panicking_case_still_runs_fixture_teardown() {
+    let tmp = rbtdth_make_scratch("panic-teardown");
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_PANIC_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS).unwrap();
+    assert!(ZRBTDTE_PANIC_TEARDOWN_RAN.load(std::sync::atomic::Ordering::SeqCst));
+    // StateProgressing forces fail-fast: the panic stops the case loop.
+    assert_eq!(result.failed, 1);
+    assert_eq!(result.passed, 0);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+static ZRBTDTE_SETUP_PANIC_TEARDOWN_RAN: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+fn zrbtdte_panicking_setup() -> Result<(), String> {
+    panic!("zrbtdte setup exploded");
+}
+
+fn zrbtdte_setup_panic_teardown() {
+    ZRBTDTE_SETUP_PANIC_TEARDOWN_RAN.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+fn zrbtdte_panicking_teardown() {
+    panic!("zrbtdte teardown exploded");
+}
+
+static ZRBTDTE_SETUP_PANIC_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-setup-panic-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: Some(zrbtdte_panicking_setup),
+    teardown: Some(zrbtdte_setup_panic_teardown),
+    cases: ZRBTDTE_PANIC_CASES,
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+static ZRBTDTE_TEARDOWN_PANIC_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-teardown-panic-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: Some(zrbtdte_panicking_teardown),
+    cases: ZRBTDTE_CENSUS_NOINVOKE_CASES,
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+#[test]
+fn rbtdte_panicking_setup_is_a_setup_failure_and_teardown_runs() {
+    let tmp = rbtdth_make_scratch("panic-setup");
+    let err =
+        rbtdre_run_fixture(&ZRBTDTE_SETUP_PANIC_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS)
+            .err()
+            .expect("a panicking setup must fail the fixture");
+    assert!(err.contains("setup failed"), "{}", err);
+    assert!(err.contains("zrbtdte setup exploded"), "{}", err);
+    assert!(ZRBTDTE_SETUP_PANIC_TEARDOWN_RAN.load(std::sync::atomic::Ordering::SeqCst));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdte_panicking_teardown_does_not_change_the_verdict() {
+    let tmp = rbtdth_make_scratch("panic-teardown-hook");
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_TEARDOWN_PANIC_FIXTURE, &RBTDTE_COLORS, &tmp, &RBTDTE_OPTIONS)
+            .unwrap();
+    assert_eq!(result.passed, 1);
+    assert_eq!(result.failed, 0);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+// ── Case timeout ─────────────────────────────────────────────────
+
+fn zrbtdte_slow_case(_dir: &Path) -> rbtdre_Verdict {
+    std::thread::sleep(std::time::Duration::from_secs(2));
+    rbtdre_Verdict::Pass
+}
+
+#[test]
+fn rbtdte_case_over_budget_is_a_timeout_failure() {
+    static CASES: &[rbtdre_Case] = &[
+        rbtdre_Case {
+            name: "to-slow",
+            func: zrbtdte_slow_case,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "to-fast",
+            func: rbtdte_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+    ];
+
+    let tmp = rbtdth_make_scratch("timeout-case");
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, Some(1), &tmp).unwrap();
+    assert_eq!(result.failed, 1);
+    assert_eq!(result.passed, 1, "a case inside its budget is unaffected");
+
+    match &result.cases[0].verdict {
+        rbtdre_Verdict::Fail(detail) => {
+            assert!(detail.starts_with(RBTDRE_WORD_TIMEOUT), "{}", detail);
+            assert!(detail.contains("1s budget"), "{}", detail);
+            // The case's own verdict after the kill is kept for diagnosis.
+            assert!(detail.ends_with("pass"), "{}", detail);
+        }
+        _ => panic!("expected a timeout Fail"),
+    }
+    let trace = std::fs::read_to_string(tmp.join("to-slow").join(RBTDRE_TRACE_FILE)).unwrap();
+    assert!(trace.starts_with(RBTDRE_WORD_FAILED));
+    assert!(trace.contains(RBTDRE_WORD_TIMEOUT));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+static ZRBTDTE_TIMEOUT_TEARDOWN_RAN: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+fn zrbtdte_timeout_teardown() {
+    ZRBTDTE_TIMEOUT_TEARDOWN_RAN.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+static ZRBTDTE_TIMEOUT_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-timeout-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: Some(zrbtdte_timeout_teardown),
+    cases: &[crate::case!(zrbtdte_slow_case)],
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: Some(1),
+};
+
+#[test]
+fn rbtdte_fixture_budget_wins_over_suite_and_teardown_runs() {
+    let tmp = rbtdth_make_scratch("timeout-fixture");
+    // A generous suite default must not rescue a fixture that declared its own.
+    let options = rbtdre_RunOptions { case_timeout_secs: Some(600), ..Default::default() };
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_TIMEOUT_FIXTURE, &RBTDTE_COLORS, &tmp, &options).unwrap();
+    assert_eq!(result.failed, 1);
+    assert!(ZRBTDTE_TIMEOUT_TEARDOWN_RAN.load(std::sync::atomic::Ordering::SeqCst));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdte_suite_budget_applies_to_undeclared_fixture() {
+    static FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+        name: "zrbtdte-timeout-undeclared",
+        disposition: rbtdre_Disposition::Independent,
+        setup: None,
+        teardown: None,
+        cases: &[crate::case!(zrbtdte_slow_case)],
+        credless: false,
+        read_only: false,
+        tariff: rbtdre_Tariff::UNCHECKED,
+        case_timeout_secs: None,
+    };
+
+    let tmp = rbtdth_make_scratch("timeout-suite");
+    let options = rbtdre_RunOptions { case_timeout_secs: Some(1), ..Default::default() };
+    let result = rbtdre_run_fixture(&FIXTURE, &RBTDTE_COLORS, &tmp, &options).unwrap();
+    assert_eq!(result.failed, 1);
+    assert!(matches!(&result.cases[0].verdict,
+        rbtdre_Verdict::Fail(d) if d.starts_with(RBTDRE_WORD_TIMEOUT)));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+// ── Interrupt ────────────────────────────────────────────────────
+//
+// Each test installs a private interrupt source on its own thread, so the
+// simulated signal never reaches cases other tests run in parallel.
+
+fn zrbtdte_private_interrupt() {
+    rbtdrt_watch(Box::leak(Box::new(rbtdrt_Interrupt::new())));
+}
+
+/// Simulates the operator's Ctrl-C arriving while the case blocks.
+fn zrbtdte_interrupted_case(_dir: &Path) -> rbtdre_Verdict {
+    rbtdrt_current().raise(libc::SIGINT);
+    std::thread::sleep(Duration::from_secs(1));
+    rbtdre_Verdict::Pass
+}
+
+/// Simulates the interrupt landing just as the case concludes.
+fn zrbtdte_interrupt_then_pass(_dir: &Path) -> rbtdre_Verdict {
+    rbtdrt_current().raise(libc::SIGTERM);
+    rbtdre_Verdict::Pass
+}
+
+static ZRBTDTE_INTERRUPT_TEARDOWN_RAN: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+fn zrbtdte_interrupt_teardown() {
+    ZRBTDTE_INTERRUPT_TEARDOWN_RAN.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+static ZRBTDTE_INTERRUPT_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdte-interrupt-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: Some(zrbtdte_interrupt_teardown),
+    cases: &[crate::case!(zrbtdte_interrupted_case), crate::case!(rbtdte_pass)],
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff { min_secs: Some(60), max_secs: None, invocations: None },
+    case_timeout_secs: None,
+};
+
+#[test]
+fn rbtdte_interrupt_fails_the_case_in_flight_and_tears_down() {
+    zrbtdte_private_interrupt();
+    let tmp = rbtdth_make_scratch("interrupt-fixture");
+    let options = rbtdre_RunOptions { keep_going: true, ..Default::default() };
+    let result =
+        rbtdre_run_fixture(&ZRBTDTE_INTERRUPT_FIXTURE, &RBTDTE_COLORS, &tmp, &options).unwrap();
+
+    assert!(result.interrupted);
+    assert_eq!(result.cases.len(), 1, "keep-going must not launch past an interrupt");
+    match &result.cases[0].verdict {
+        rbtdre_Verdict::Fail(detail) => {
+            assert!(detail.starts_with(RBTDRE_WORD_INTERRUPTED), "{}", detail);
+            assert!(detail.contains("SIGINT"), "{}", detail);
+        }
+        _ => panic!("expected an interrupted Fail"),
+    }
+    // The partial footprint is held to no tariff: no too-fast failure on top.
+    assert_eq!(result.failed, 1);
+    assert!(ZRBTDTE_INTERRUPT_TEARDOWN_RAN.load(std::sync::atomic::Ordering::SeqCst));
+    rbtdre_print_summary(&result, &RBTDTE_COLORS);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdte_interrupt_between_cases_launches_nothing_more() {
+    static CASES: &[rbtdre_Case] = &[
+        crate::case!(zrbtdte_interrupt_then_pass),
+        crate::case!(rbtdte_pass),
+    ];
+
+    zrbtdte_private_interrupt();
+    let tmp = rbtdth_make_scratch("interrupt-between");
+    let result =
+        rbtdre_run_cases("zrbtdte-fixture", CASES, &RBTDTE_COLORS, false, None, &tmp).unwrap();
+    assert!(result.interrupted);
+    assert_eq!((result.passed, result.failed, result.cases.len()), (1, 0, 1));
+    assert_eq!(rbtdrt_current().signal_name(), "SIGTERM");
+    let _ = std::fs::remove_dir_all(&tmp);
+}
@@ -282,6 +282,78 @@ fn rbtdti_chain_next_without_prior_invoke_errs() {
     let _ = std::fs::remove_dir_all(&tmp);
 }
 
+#[test]
+fn rbtdti_chain_session_spans_interleaved_invokes_and_checks_handoffs() {
+    let tmp = rbtdth_make_scratch("invoke-chain-session");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    // Writes its first arg as a fact into current/, as a producing link would.
+    rbtdti_write_script(
+        &tt,
+        &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK),
+        "test -z \"$1\" && exit 0\nmkdir -p \"$BURV_OUTPUT_ROOT_DIR/current\"\n\
+         echo v > \"$BURV_OUTPUT_ROOT_DIR/current/$1\"\n",
+    );
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+
+    assert!(ctx.chain_into("zrbtdti_lineage", &[]).unwrap_err().contains("not open"));
+    ctx.chain_open("zrbtdti_lineage");
+    assert!(ctx.chain_into("zrbtdti_lineage", &["zrbtdti_fact"]).unwrap_err().contains("no link"));
+
+    ctx.chain_into("zrbtdti_lineage", &[]).unwrap();
+    let producer = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["zrbtdti_fact"]).unwrap();
+    // A helper between links takes a fresh root and breaks nothing.
+    let helper = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    assert_ne!(helper.burv_output, producer.burv_output);
+
+    ctx.chain_into("zrbtdti_lineage", &["zrbtdti_fact"]).unwrap();
+    let consumer = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    assert_eq!(consumer.burv_output, producer.burv_output);
+    assert_eq!(ctx.invoke_count, 2, "session links share one root");
+
+    ctx.chain_into("zrbtdti_lineage", &["zrbtdti_absent"]).unwrap();
+    let broken = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap_err();
+    assert!(broken.contains("hand-off") && broken.contains("zrbtdti_absent"), "{}", broken);
+
+    let links = ctx.chain_close("zrbtdti_lineage").unwrap();
+    assert_eq!(links, vec![RBTDGC_CRUCIBLE_BARK.to_string(); 2]);
+    assert!(ctx.chain_close("zrbtdti_lineage").unwrap_err().contains("not open"));
+    ctx.chain_open("zrbtdti_idle");
+    assert!(ctx.chain_close("zrbtdti_idle").unwrap_err().contains("without launching"));
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdti_chain_join_is_spent_by_an_invoke_that_never_launches() {
+    let tmp = rbtdth_make_scratch("invoke-chain-spent");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    rbtdti_write_script(&tt, &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK), "exit 0\n");
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+
+    // A join aimed at a tabtarget that does not resolve is spent with it, so
+    // the next invoke runs unchained rather than inheriting the join.
+    ctx.chain_open("zrbtdti_lineage");
+    ctx.chain_into("zrbtdti_lineage", &[]).unwrap();
+    assert!(rbtdri_invoke(&mut ctx, "zrbtdti-absent", &[]).is_err());
+    rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    assert!(ctx.chain_close("zrbtdti_lineage").unwrap_err().contains("without launching"));
+
+    // Between cases every mark goes: the flag, the join, and the session.
+    ctx.chain_open("zrbtdti_lineage");
+    ctx.chain_into("zrbtdti_lineage", &[]).unwrap();
+    ctx.chain_reset();
+    assert!(!ctx.chain_next && ctx.chain_pending.is_none());
+    assert!(ctx.chain_into("zrbtdti_lineage", &[]).unwrap_err().contains("not open"));
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
 #[test]
 fn rbtdti_invoke_captures_stdout() {
     let tmp = rbtdth_make_scratch("invoke-stdout");
@@ -759,3 +831,319 @@ fn rbtdti_invoke_fails_no_tabtarget() {
 
     let _ = std::fs::remove_dir_all(&tmp);
 }
+
+// ── Case kill switch ─────────────────────────────────────────
+
+#[test]
+fn rbtdti_kill_switch_kills_in_flight_group_and_refuses_later_launches() {
+    let tmp = rbtdth_make_scratch("invoke-killswitch");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    // A backgrounded grandchild proves the whole group dies, not just bash.
+    rbtdti_write_script(
+        &tt,
+        &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK),
+        "sleep 60 &\nwait\n",
+    );
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+
+    let switch = rbtdri_KillSwitch::new();
+    rbtdri_arm_kill_switch(Some(switch.clone()));
+    let watchdog = switch.clone();
+    let tripper = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        watchdog.trip()
+    });
+
+    let started = std::time::Instant::now();
+    let result = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    assert_eq!(tripper.join().unwrap(), 1, "exactly the one launch was in flight");
+    assert!(started.elapsed() < std::time::Duration::from_secs(30));
+    assert_ne!(result.exit_code, 0);
+
+    let refused = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]);
+    rbtdri_arm_kill_switch(None);
+    assert!(refused.unwrap_err().contains("budget expired"));
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+// ── Invocation transcript ────────────────────────────────────
+
+#[test]
+fn rbtdti_transcript_records_each_launch_and_spills_output() {
+    let tmp = rbtdth_make_scratch("invoke-transcript");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    rbtdti_write_script(
+        &tt,
+        &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK),
+        "echo \"out $1\"\necho \"err $1\" >&2\nexit 3\n",
+    );
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let transcript_dir = tmp.join("trace").join("testplate");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+    ctx.set_transcript_dir(&transcript_dir);
+
+    rbtdri_set_phase("zrbtdti_case");
+    rbtdri_invoke_env(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["alpha"], &[("ZRBTDTI_KEY", "v\"1")])
+        .unwrap();
+    ctx.chain_next_invoke();
+    rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["beta"]).unwrap();
+    rbtdri_set_phase("");
+
+    let transcript =
+        std::fs::read_to_string(transcript_dir.join(RBTDRI_TRANSCRIPT_FILE)).unwrap();
+    let lines: Vec<&str> = transcript.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", transcript);
+    let first = lines[0];
+    assert!(first.starts_with("{\"seq\":0,\"phase\":\"zrbtdti_case\",\"fixture\":\"testplate\""));
+    assert!(first.contains("\"args\":[\"alpha\"]"), "{}", first);
+    assert!(first.contains("\"env\":{\"ZRBTDTI_KEY\":\"v\\\"1\"}"), "{}", first);
+    assert!(first.contains("\"chained\":false"), "{}", first);
+    assert!(first.contains("\"exit_code\":3,\"killed\":false"), "{}", first);
+    assert!(first.ends_with("\"error\":null}"), "{}", first);
+    assert!(lines[1].contains("\"seq\":1"), "{}", lines[1]);
+    assert!(lines[1].contains("\"chained\":true"), "{}", lines[1]);
+
+    let stem = rbtdri_LaunchRecord::spill_stem(1, RBTDGC_CRUCIBLE_BARK);
+    let out = std::fs::read_to_string(transcript_dir.join(format!("{}.stdout", stem))).unwrap();
+    let err = std::fs::read_to_string(transcript_dir.join(format!("{}.stderr", stem))).unwrap();
+    assert_eq!((out.as_str(), err.as_str()), ("out beta\n", "err beta\n"));
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdti_transcript_absent_without_dir() {
+    let tmp = rbtdth_make_scratch("invoke-no-transcript");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    rbtdti_write_script(&tt, &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK), "exit 0\n");
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+    rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    assert_eq!(ctx.transcript_seq, 1);
+    assert!(!tmp.join(RBTDRI_TRANSCRIPT_FILE).exists());
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdti_transcript_streams_output_while_launch_runs() {
+    let tmp = rbtdth_make_scratch("invoke-stream");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    rbtdti_write_script(
+        &tt,
+        &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK),
+        "echo warned >&2\nsleep 0.5\necho first\necho second\nsleep 60 &\nwait\n",
+    );
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let transcript_dir = tmp.join("trace").join("testplate");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+    ctx.set_transcript_dir(&transcript_dir);
+
+    // The watcher sees the lines in the trace file and on the switch while the
+    // launch still blocks, and only then kills it. The drain writes a line to
+    // the trace before noting it, so the switch is polled on its own until it
+    // catches up. The stderr line leads by a margin so the two readers note
+    // their lines in a known order.
+    let switch = rbtdri_KillSwitch::new();
+    rbtdri_arm_kill_switch(Some(switch.clone()));
+    let watchdog = switch.clone();
+    let trace = transcript_dir
+        .join(format!("{}.stdout", rbtdri_LaunchRecord::spill_stem(0, RBTDGC_CRUCIBLE_BARK)));
+    let expected = (RBTDGC_CRUCIBLE_BARK.to_string(), "second".to_string());
+    let watcher = std::thread::spawn(move || {
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(20);
+        let (mut streamed, mut noted) = (false, false);
+        while std::time::Instant::now() < deadline {
+            streamed = std::fs::read_to_string(&trace).unwrap_or_default() == "first\nsecond\n";
+            noted = watchdog.in_flight() == Some(expected.clone());
+            if streamed && noted {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        watchdog.trip();
+        (streamed, noted)
+    });
+
+    let result = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    rbtdri_arm_kill_switch(None);
+    assert_eq!(watcher.join().unwrap(), (true, true));
+    assert_eq!(result.stdout, "first\nsecond\n");
+    assert_eq!(result.stderr, "warned\n");
+    assert_eq!(switch.in_flight(), None);
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+// ── Hermetic child environment ──────────────────────────────
+
+#[test]
+fn rbtdti_hermetic_partitions_inherited_env() {
+    let hermetic = rbtdri_Hermetic::new(&["LANG".to_string(), "ZRBTDTI_ABSENT".to_string()]);
+    let inherited = ["PATH", "BURE_CONFIRM", "LANG", "AWS_PROFILE", "BURD_NO_LOG"]
+        .iter()
+        .map(|k| (k.to_string(), "v"));
+    let (kept, dropped) = hermetic.partition(inherited.clone());
+    let kept: Vec<&str> = kept.iter().map(|(k, _)| k.as_str()).collect();
+    assert_eq!(kept, vec!["BURE_CONFIRM", "LANG", "PATH"]);
+    assert_eq!(dropped, vec!["AWS_PROFILE".to_string(), "BURD_NO_LOG".to_string()]);
+
+    let report = hermetic.report(inherited);
+    assert_eq!(report[0], "hermetic: 3 inherited variable(s) kept, 2 dropped");
+    assert!(report.contains(&"  dropped  AWS_PROFILE".to_string()), "{:?}", report);
+    assert!(report.iter().any(|l| l.starts_with("  absent   ZRBTDTI_ABSENT")), "{:?}", report);
+    assert!(!report.iter().any(|l| l.contains("LANG") && l.contains("absent")), "{:?}", report);
+}
+
+#[test]
+fn rbtdti_hermetic_launch_drops_stray_inheritance() {
+    // cargo exports CARGO_MANIFEST_DIR to the test process: a stray variable a
+    // hermetic child must not see unless it is declared.
+    assert!(std::env::var("CARGO_MANIFEST_DIR").is_ok());
+    let tmp = rbtdth_make_scratch("invoke-hermetic");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    rbtdti_write_script(
+        &tt,
+        &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK),
+        "echo \"stray:${CARGO_MANIFEST_DIR:+set} path:${PATH:+set} \
+         burv:${BURV_OUTPUT_ROOT_DIR:+set} extra:${ZRBTDTI_EXTRA:-unset}\"\n",
+    );
+
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let transcript_dir = tmp.join("trace").join("testplate");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+    ctx.set_transcript_dir(&transcript_dir);
+    let extra = &[("ZRBTDTI_EXTRA", "v")];
+
+    rbtdri_set_hermetic(Some(rbtdri_Hermetic::default()));
+    let sealed = rbtdri_invoke_env(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[], extra).unwrap();
+    rbtdri_set_hermetic(Some(rbtdri_Hermetic::new(&["CARGO_MANIFEST_DIR".to_string()])));
+    let declared = rbtdri_invoke_env(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[], extra).unwrap();
+    rbtdri_set_hermetic(None);
+    let inherited = rbtdri_invoke_env(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[], extra).unwrap();
+
+    assert_eq!(sealed.stdout, "stray: path:set burv:set extra:v\n");
+    assert_eq!(declared.stdout, "stray:set path:set burv:set extra:v\n");
+    assert_eq!(inherited.stdout, "stray:set path:set burv:set extra:v\n");
+    let transcript =
+        std::fs::read_to_string(transcript_dir.join(RBTDRI_TRANSCRIPT_FILE)).unwrap();
+    let flags: Vec<bool> =
+        transcript.lines().map(|l| l.contains("\"hermetic\":true")).collect();
+    assert_eq!(flags, vec![true, true, false]);
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+// ── Network isolation ───────────────────────────────────────
+
+#[test]
+fn rbtdti_netns_isolates_credless_launches_and_records_reaches() {
+    // Hosts without unprivileged user namespaces are refused by the runners
+    // up front; there is nothing to isolate here either.
+    if rbtdri_netns_probe().is_err() {
+        return;
+    }
+    let tmp = rbtdth_make_scratch("invoke-netns");
+    let tt = rbtdti_make_tt_dir(&tmp);
+    // 192.0.2.1 is TEST-NET-1. Every address routes to loopback inside the
+    // namespace, so both connects are refused at once; only the second
+    // reaches off loopback. The resolver-shaped echo reaches for nothing and
+    // must not count.
+    rbtdti_write_script(
+        &tt,
+        &format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK),
+        "(exec 3<>/dev/tcp/127.0.0.1/9) 2>&1 | sed 's/^/lo: /'\n\
+         (exec 3<>/dev/tcp/192.0.2.1/80) 2>/dev/null\n\
+         echo 'curl: (6) Could not resolve host: example.com' >&2\n",
+    );
+    // A bypass launch, run outside the funnel, is watched all the same.
+    let bypass = format!("{}.Bark.bypass.sh", RBTDGC_CRUCIBLE_BARK);
+    rbtdti_write_script(&tt, &bypass, "(exec 3<>/dev/tcp/192.0.2.7/443) 2>/dev/null\nexit 0\n");
+    let burv_temp_root = tmp.join("burv-temp");
+    let burv_output_root = tmp.join("burv-output");
+    let mut ctx = rbtdri_Context::new(&tmp, "testplate", &burv_temp_root, &burv_output_root);
+
+    rbtdri_set_netns(true);
+    assert!(!rbtdri_netns_active(), "isolation rides the credless guard");
+    rbtdri_arm_credless(true);
+    assert!(rbtdri_netns_active());
+    let isolated = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &[]).unwrap();
+    let bypassed = rbtdri_tabtarget_command(&tt.join(&bypass)).output().unwrap();
+    rbtdri_arm_credless(false);
+    rbtdri_set_netns(false);
+
+    let violations = rbtdri_netns_take_violations();
+    assert_eq!(violations.len(), 2, "{:?}", violations);
+    assert!(violations[0].contains(RBTDGC_CRUCIBLE_BARK), "{}", violations[0]);
+    assert!(violations[0].ends_with("tcp 192.0.2.1:80"), "{}", violations[0]);
+    assert!(violations[1].contains(&bypass), "{}", violations[1]);
+    assert!(violations[1].ends_with("tcp 192.0.2.7:443"), "{}", violations[1]);
+    assert!(isolated.stdout.contains("Connection refused"), "{}", isolated.stdout);
+    assert!(bypassed.status.success());
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+/// One IPv4 packet from 127.0.0.1 to `dst`, protocol `proto`, over `l4`.
+fn rbtdti_ipv4(dst: [u8; 4], proto: u8, l4: &[u8]) -> Vec<u8> {
+    let mut packet = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64, proto, 0, 0, 127, 0, 0, 1];
+    packet.extend_from_slice(&dst);
+    packet.extend_from_slice(l4);
+    packet
+}
+
+/// A TCP header to `port` with `flags`.
+fn rbtdti_tcp(port: u16, flags: u8) -> Vec<u8> {
+    let mut tcp = vec![0u8; 20];
+    tcp[2..4].copy_from_slice(&port.to_be_bytes());
+    tcp[13] = flags;
+    tcp
+}
+
+#[test]
+fn rbtdti_netns_reach_records_opening_sends_off_loopback() {
+    let syn = rbtdti_ipv4([192, 0, 2, 1], 6, &rbtdti_tcp(80, 0x02));
+    let record = rbtdri_netns_reach(0x0800, &syn).expect("an off-loopback SYN reaches");
+    assert_eq!(rbtdri_netns_describe(&record), "tcp 192.0.2.1:80");
+
+    // An answer, and anything between loopback addresses, reaches for nothing.
+    let ack = rbtdti_ipv4([192, 0, 2, 1], 6, &rbtdti_tcp(80, 0x12));
+    assert!(rbtdri_netns_reach(0x0800, &ack).is_none());
+    let local = rbtdti_ipv4([127, 0, 0, 1], 6, &rbtdti_tcp(9, 0x02));
+    assert!(rbtdri_netns_reach(0x0800, &local).is_none());
+    let icmp = rbtdti_ipv4([192, 0, 2, 1], 1, &[3, 3, 0, 0, 0, 0, 0, 0]);
+    assert!(rbtdri_netns_reach(0x0800, &icmp).is_none());
+    assert!(rbtdri_netns_reach(0x0806, &syn).is_none());
+}
+
+#[test]
+fn rbtdti_netns_reach_records_dns_queries_with_their_name() {
+    // A query to a loopback resolver is still a reach: it names what it wants.
+    let mut udp = vec![0, 0, 0, 53, 0, 0, 0, 0];
+    udp.extend_from_slice(&[0x12, 0x34, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+    udp.extend_from_slice(b"\x07example\x03com\x00\x00\x01\x00\x01");
+    let query = rbtdti_ipv4([127, 0, 0, 53], 17, &udp);
+    let record = rbtdri_netns_reach(0x0800, &query).expect("a DNS query reaches");
+    assert_eq!(rbtdri_netns_describe(&record), "udp 127.0.0.53:53 query example.com");
+
+    let mut v6 = vec![0x60, 0, 0, 0, 0, 8, 17, 64];
+    v6.extend_from_slice(&[0; 15]);
+    v6.push(1);
+    v6.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8]);
+    v6.extend_from_slice(&[0; 11]);
+    v6.push(1);
+    v6.extend_from_slice(&[0, 0, 0x01, 0xbb, 0, 8, 0, 0]);
+    let record = rbtdri_netns_reach(0x86dd, &v6).expect("an off-loopback datagram reaches");
+    assert_eq!(rbtdri_netns_describe(&record), "udp [2001:db8::1]:443");
+}
@@ -18,7 +18,10 @@
 
 use super::rbtdgc_consts::*;
 use crate::rbtdra_almanac::RBTDRA_FIXTURES;
-use crate::rbtdrm_manifest::{rbtdrm_permitted_colophons, rbtdrm_required_colophons};
+use crate::rbtdrm_manifest::{
+    rbtdrm_permitted_colophons, rbtdrm_replayable, rbtdrm_required_colophons,
+    RBTDRM_FIXTURE_CHAOS_TADMOR,
+};
 
 /// Pin the one surviving compile-time path literal (the `rbtd_vessels_dir!`
 /// macro in lib.rs) to the generated source of truth. The macro must equal
@@ -53,3 +56,12 @@ fn rbtdtm_permitted_declaration_requires_a_required_entry() {
         );
     }
 }
+
+/// Replay refuses only the chaos crucible, whose cases act on a live sentry.
+#[test]
+fn rbtdtm_replay_refuses_only_chaos() {
+    for fixture in RBTDRA_FIXTURES {
+        let chaos = fixture.name == RBTDRM_FIXTURE_CHAOS_TADMOR;
+        assert_eq!(rbtdrm_replayable(fixture.name), !chaos, "fixture '{}'", fixture.name);
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTP — plan tests: envelopes sum only what is declared or observed, an
+// UNCHECKED tariff is flagged, selection narrows the planned cases, and the
+// JSON rendering carries the manifest colophons.
+
+use std::path::Path;
+
+use super::rbtdre_engine::*;
+use super::rbtdrm_manifest::RBTDRM_FIXTURE_FOEDUS_REUSE;
+use super::rbtdrp_plan::*;
+use super::rbtdrw_dowse::rbtdrw_History;
+
+fn rbtdtp_pass(_dir: &Path) -> rbtdre_Verdict {
+    rbtdre_Verdict::Pass
+}
+
+static RBTDTP_DECLARED: rbtdre_Fixture = rbtdre_Fixture {
+    name: RBTDRM_FIXTURE_FOEDUS_REUSE,
+    disposition: rbtdre_Disposition::StateProgressing,
+    setup: None,
+    teardown: None,
+    cases: &[
+        rbtdre_Case {
+            name: "zplan_mint",
+            func: rbtdtp_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff { min_secs: Some(3), max_secs: None, invocations: Some(1) },
+        },
+        rbtdre_Case {
+            name: "zplan_reuse",
+            func: rbtdtp_pass,
+            tags: &["reuse"],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+    ],
+    credless: false,
+    read_only: false,
+    tariff: rbtdre_Tariff { min_secs: Some(10), max_secs: Some(60), invocations: Some(4) },
+    case_timeout_secs: Some(120),
+};
+
+static RBTDTP_UNCHECKED: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdtp-unchecked",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: None,
+    cases: &[
+        rbtdre_Case {
+            name: "zplan_alpha",
+            func: rbtdtp_pass,
+            tags: &["dns"],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+        rbtdre_Case {
+            name: "zplan_beta",
+            func: rbtdtp_pass,
+            tags: &[],
+            tariff: rbtdre_Tariff::UNCHECKED,
+        },
+    ],
+    credless: true,
+    read_only: false,
+    tariff: rbtdre_Tariff::UNCHECKED,
+    case_timeout_secs: None,
+};
+
+fn rbtdtp_history() -> rbtdrw_History {
+    let mut history = rbtdrw_History::default();
+    history.fixtures.insert(
+        RBTDRM_FIXTURE_FOEDUS_REUSE.to_string(),
+        vec![
+            ("20260102-000000".to_string(), 40, 4, "suite x".to_string()),
+            ("20260101-000000".to_string(), 25, 4, "suite x".to_string()),
+        ],
+    );
+    history
+}
+
+fn rbtdtp_plan(history: Result<rbtdrw_History, String>) -> rbtdrp_Plan {
+    rbtdrp_build(
+        "suite",
+        "zrbtdtp-suite",
+        &[&RBTDTP_DECLARED, &RBTDTP_UNCHECKED],
+        Some(300),
+        &rbtdre_Selection::ALL,
+        history,
+    )
+    .unwrap()
+}
+
+#[test]
+fn rbtdtp_envelopes_sum_declared_and_observed() {
+    let plan = rbtdtp_plan(Ok(rbtdtp_history()));
+    assert_eq!(
+        plan.declared_envelope(),
+        rbtdrp_Envelope { min_secs: 10, max_secs: 60, missing: 1 }
+    );
+    assert_eq!(
+        plan.observed_envelope(),
+        rbtdrp_Envelope { min_secs: 25, max_secs: 40, missing: 1 }
+    );
+    let observed = plan.fixtures[0].observed.as_ref().unwrap();
+    assert_eq!((observed.runs, observed.last_secs), (2, 40));
+    // Fixture budget wins; the undeclared fixture takes the suite default.
+    assert_eq!(plan.fixtures[0].case_timeout_secs, Some(120));
+    assert_eq!(plan.fixtures[1].case_timeout_secs, Some(300));
+}
+
+#[test]
+fn rbtdtp_text_flags_unchecked_and_missing_history() {
+    let plan = rbtdtp_plan(Err("BURD_LOG_DIR not set".to_string()));
+    let text = plan.render_text().join("\n");
+    assert!(text.contains("plan UNCHECKED tariff: zrbtdtp-unchecked"), "{}", text);
+    assert!(text.contains("ETA observed: unavailable — BURD_LOG_DIR not set"), "{}", text);
+    assert!(text.contains("required colophons: no manifest entry"), "{}", text);
+    assert!(text.contains("zplan_alpha  [dns]"), "{}", text);
+    assert!(text.contains("zplan_mint  tariff min=3s max=— inv=1"), "{}", text);
+}
+
+#[test]
+fn rbtdtp_selection_narrows_planned_cases() {
+    let selection = rbtdre_Selection { tags: vec!["dns".into()], ..Default::default() };
+    let plan = rbtdrp_build(
+        "fixture",
+        RBTDTP_UNCHECKED.name,
+        &[&RBTDTP_UNCHECKED],
+        None,
+        &selection,
+        Ok(rbtdrw_History::default()),
+    )
+    .unwrap();
+    assert_eq!(plan.fixtures[0].cases.len(), 1);
+    assert_eq!(plan.fixtures[0].declared_cases, 2);
+    assert!(plan.render_text().iter().any(|l| l.contains("1 of 2 case(s)")));
+
+    // A selection the runner would refuse is refused by the plan too.
+    let gap = rbtdre_Selection { only: vec!["zplan_reuse".into()], ..Default::default() };
+    let err = rbtdrp_build(
+        "fixture",
+        RBTDTP_DECLARED.name,
+        &[&RBTDTP_DECLARED],
+        None,
+        &gap,
+        Ok(rbtdrw_History::default()),
+    )
+    .unwrap_err();
+    assert!(err.contains("StateProgressing"), "{}", err);
+}
+
+#[test]
+fn rbtdtp_json_carries_colophons_and_envelopes() {
+    let json = rbtdtp_plan(Ok(rbtdtp_history())).render_json();
+    assert!(json.starts_with("{\"mode\":\"suite\",\"name\":\"zrbtdtp-suite\""), "{}", json);
+    assert!(json.contains("\"permitted_colophons\":[\""), "{}", json);
+    assert!(json.contains("\"required_colophons\":null"), "{}", json);
+    assert!(json.contains("\"unchecked\":true"), "{}", json);
+    assert!(
+        json.contains("\"eta\":{\"declared\":{\"min_secs\":10,\"max_secs\":60,\"missing\":1},\
+                       \"observed\":{\"min_secs\":25,\"max_secs\":40,\"missing\":1}}"),
+        "{}",
+        json
+    );
+    assert!(json.ends_with("\"history_note\":null}\n"), "{}", json);
+}
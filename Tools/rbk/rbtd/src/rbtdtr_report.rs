@@ -0,0 +1,229 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTR — run report tests: the escapers keep both formats well-formed, the
+// JUnit totals match the engine's fold semantics (too-fast and census failures
+// as synthetic testcases), and the event stream carries one line per event.
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::rbtdre_engine::*;
+use super::rbtdrr_report::*;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+
+fn rbtdtr_noop(_dir: &Path) -> rbtdre_Verdict {
+    rbtdre_Verdict::Pass
+}
+
+static RBTDTR_CASES: &[rbtdre_Case] = &[crate::case!(rbtdtr_noop)];
+
+static RBTDTR_FIXTURE: rbtdre_Fixture = rbtdre_Fixture {
+    name: "zrbtdtr-fixture",
+    disposition: rbtdre_Disposition::Independent,
+    setup: None,
+    teardown: None,
+    cases: RBTDTR_CASES,
+    credless: true,
+    read_only: false,
+    tariff: rbtdre_Tariff { min_secs: Some(5), max_secs: None, invocations: Some(2) },
+    case_timeout_secs: None,
+};
+
+/// A result shaped like a too-fast run: one pass, one fail, one skip, plus the
+/// too-fast fold the engine adds to `failed`.
+fn rbtdtr_result(dir: &Path) -> rbtdre_RunResult {
+    rbtdre_RunResult {
+        passed: 1,
+        failed: 2,
+        skipped: 1,
+        temp_dir: dir.to_path_buf(),
+        elapsed_secs: 3,
+        invocations: 2,
+        cases: vec![
+            rbtdre_CaseRecord {
+                name: "case_ok",
+                verdict: rbtdre_Verdict::Pass,
+                elapsed: Duration::from_millis(1500),
+                invocations: 3,
+            },
+            rbtdre_CaseRecord {
+                name: "case_bad",
+                verdict: rbtdre_Verdict::Fail("exit 1\n\x1b[31mred\x1b[0m <stderr> & \"more\"".to_string()),
+                elapsed: Duration::from_millis(250),
+                invocations: 1,
+            },
+            rbtdre_CaseRecord {
+                name: "case_skip",
+                verdict: rbtdre_Verdict::Skip("no creds".to_string()),
+                elapsed: Duration::from_millis(0),
+                invocations: 0,
+            },
+        ],
+        tariff: Some(rbtdre_evaluate_tariff(&RBTDTR_FIXTURE.tariff, 3, 2)),
+        census: None,
+        interrupted: false,
+    }
+}
+
+#[test]
+fn rbtdtr_json_str_escapes_quotes_backslashes_and_controls() {
+    assert_eq!(rbtdrr_json_str("plain"), "\"plain\"");
+    assert_eq!(rbtdrr_json_str("a\"b\\c"), "\"a\\\"b\\\\c\"");
+    assert_eq!(rbtdrr_json_str("l1\nl2\t"), "\"l1\\nl2\\t\"");
+    assert_eq!(rbtdrr_json_str("\x1b[0m"), "\"\\u001b[0m\"");
+}
+
+#[test]
+fn rbtdtr_xml_escape_handles_markup_and_control_chars() {
+    assert_eq!(rbtdrr_xml_escape("<a & 'b'>"), "&lt;a &amp; &apos;b&apos;&gt;");
+    assert_eq!(rbtdrr_xml_escape("q\"q"), "q&quot;q");
+    // Tab/newline survive; ESC (never legal in XML 1.0) is replaced.
+    assert_eq!(rbtdrr_xml_escape("x\ty\n\x1bz"), "x\ty\n\u{FFFD}z");
+}
+
+#[test]
+fn rbtdtr_junit_totals_include_synthetic_tariff_failure() {
+    let tmp = rbtdth_make_scratch("report-junit");
+    let mut report = rbtdrr_Report::new(&tmp, "fixture", RBTDTR_FIXTURE.name);
+    report.record_fixture(&RBTDTR_FIXTURE, &rbtdtr_result(&tmp));
+
+    let xml = report.render_junit();
+    // Three real cases plus the too-fast synthetic testcase; the two failures
+    // equal the engine's folded `failed` count.
+    assert!(xml.contains("tests=\"4\" failures=\"2\" errors=\"0\" skipped=\"1\""), "{}", xml);
+    assert!(xml.contains("name=\"case_ok\" time=\"1.500\"/>"));
+    assert!(xml.contains("<skipped message=\"no creds\"/>"));
+    assert!(xml.contains("name=\"tariff\""));
+    assert!(xml.contains("tariff too-fast"));
+    assert!(xml.contains("<property name=\"credless\" value=\"true\"/>"));
+    assert!(xml.contains("<property name=\"census\" value=\"unchecked\"/>"));
+    // Failure detail is escaped — no raw markup or ESC reaches the document.
+    assert!(xml.contains("&lt;stderr&gt; &amp; &quot;more&quot;"));
+    assert!(!xml.contains('\x1b'));
+
+    // The file on disk is refreshed on every record.
+    let on_disk = std::fs::read_to_string(tmp.join(RBTDRR_JUNIT_FILE)).unwrap();
+    assert_eq!(on_disk, xml);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdtr_census_failure_renders_as_synthetic_testcase() {
+    let tmp = rbtdth_make_scratch("report-census");
+    let mut result = rbtdtr_result(&tmp);
+    result.tariff = Some(rbtdre_evaluate_tariff(&RBTDTR_FIXTURE.tariff, 9, 2));
+    result.census = Some(vec!["rbw-xx", "rbw-yy"]);
+
+    let mut report = rbtdrr_Report::new(&tmp, "fixture", RBTDTR_FIXTURE.name);
+    report.record_fixture(&RBTDTR_FIXTURE, &result);
+
+    let xml = report.render_junit();
+    assert!(xml.contains("name=\"census\""));
+    assert!(xml.contains("declared but never invoked: rbw-xx, rbw-yy"));
+    assert!(xml.contains("value=\"unused: rbw-xx, rbw-yy\""));
+    // Tariff held this time — no synthetic tariff testcase.
+    assert!(!xml.contains("name=\"tariff\""));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdtr_fixture_error_renders_as_junit_error() {
+    let tmp = rbtdth_make_scratch("report-error");
+    let mut report = rbtdrr_Report::new(&tmp, "suite", "zrbtdtr-suite");
+    report.record_fixture_error(&RBTDTR_FIXTURE, "setup failed: charge exit 1\ndetail");
+
+    let xml = report.render_junit();
+    assert!(xml.contains("<testsuites name=\"zrbtdtr-suite\" tests=\"1\" failures=\"0\" errors=\"1\""));
+    assert!(xml.contains("<error message=\"setup failed: charge exit 1\">"));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdtr_event_stream_has_one_line_per_event() {
+    let tmp = rbtdth_make_scratch("report-events");
+    let mut report = rbtdrr_Report::new(&tmp, "suite", "zrbtdtr-suite");
+    report.record_fixture(&RBTDTR_FIXTURE, &rbtdtr_result(&tmp));
+    report.record_fixture_error(&RBTDTR_FIXTURE, "boom");
+    report.finish(1, 3, 1);
+
+    let events = std::fs::read_to_string(tmp.join(RBTDRR_EVENTS_FILE)).unwrap();
+    let lines: Vec<&str> = events.lines().collect();
+    // run_start, 3 case events, fixture_end, fixture_error, run_end.
+    assert_eq!(lines.len(), 7, "{}", events);
+    assert!(lines[0].starts_with("{\"event\":\"run_start\",\"mode\":\"suite\",\"name\":\"zrbtdtr-suite\""));
+    assert!(lines[1].contains("\"case\":\"case_ok\",\"verdict\":\"pass\""));
+    assert!(lines[1].contains("\"elapsed_ms\":1500"));
+    assert!(lines[1].contains("\"invocations\":3"));
+    assert!(lines[2].contains("\"verdict\":\"fail\",\"detail\":\"exit 1\\n\\u001b[31mred"));
+    assert!(lines[4].contains("\"event\":\"fixture_end\""));
+    assert!(lines[4].contains("\"tariff\":{\"declared\":{\"min_secs\":5,\"max_secs\":null,\"invocations\":2},\"too_fast\":true"));
+    assert!(lines[4].contains("\"census\":null"));
+    assert!(lines[5].contains("\"event\":\"fixture_error\""));
+    assert!(lines[6].contains("\"event\":\"run_end\""));
+    assert!(lines[6].contains("\"fixtures\":2,\"passed\":1,\"failed\":3,\"skipped\":1"));
+    // Every line is a single JSON object — no raw newline leaked from detail.
+    assert!(lines.iter().all(|l| l.starts_with('{') && l.ends_with('}')));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdtr_environment_event_summarizes_the_fingerprint() {
+    use super::rbtdrx_fingerprint::{rbtdrx_Fingerprint, RBTDRX_KEY_KERNEL, RBTDRX_KEY_PODMAN};
+    let tmp = rbtdth_make_scratch("report-environment");
+    let mut report = rbtdrr_Report::new(&tmp, "fixture", "zrbtdtr-fixture");
+    let fingerprint = rbtdrx_Fingerprint {
+        entries: vec![
+            (RBTDRX_KEY_KERNEL.to_string(), "Linux 6.8.0".to_string()),
+            (RBTDRX_KEY_PODMAN.to_string(), "podman version 4.9.3".to_string()),
+        ],
+    };
+    report.record_environment(&fingerprint, &tmp.join("environment.json"));
+
+    let events = std::fs::read_to_string(tmp.join(RBTDRR_EVENTS_FILE)).unwrap();
+    let line = events.lines().nth(1).unwrap();
+    assert!(line.starts_with("{\"event\":\"environment\",\"file\":"), "{}", line);
+    assert!(line.contains("\"kernel\":\"Linux 6.8.0\""), "{}", line);
+    // Summary keys absent from the fingerprint read as unavailable; keys outside
+    // the summary stay in the file only.
+    assert!(line.contains("\"docker\":\"unavailable\""), "{}", line);
+    assert!(!line.contains("podman"), "{}", line);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdtr_run_fixture_populates_case_records_and_tariff() {
+    // The report reads only what rbtdre_run_fixture returns — prove the engine
+    // fills the per-case records, the tariff report, and the census outcome.
+    let tmp = rbtdth_make_scratch("report-engine");
+    let ctx = crate::rbtdri_invocation::rbtdri_Context::new(
+        &tmp, RBTDTR_FIXTURE.name, &tmp.join("burv-temp"), &tmp.join("burv-output"),
+    );
+    crate::rbtdrc_crucible::rbtdrc_set_context(ctx);
+    let colors = rbtdre_Colors { green: "", red: "", yellow: "", reset: "" };
+    let options = rbtdre_RunOptions::default();
+    let result = rbtdre_run_fixture(&RBTDTR_FIXTURE, &colors, &tmp, &options).unwrap();
+    let _ = crate::rbtdrc_crucible::rbtdrc_take_context();
+
+    assert_eq!(result.cases.len(), 1);
+    assert_eq!(result.cases[0].name, "rbtdtr_noop");
+    assert!(matches!(result.cases[0].verdict, rbtdre_Verdict::Pass));
+    // Instant green against a 5s floor — the too-fast fold is recorded.
+    assert!(result.tariff.as_ref().is_some_and(|t| t.too_fast));
+    // The too-fast failure gates the census check off.
+    assert!(result.census.is_none());
+    let _ = std::fs::remove_dir_all(&tmp);
+}
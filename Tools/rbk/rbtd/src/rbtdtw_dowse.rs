@@ -18,10 +18,16 @@
 // matching shape; every sibling emission under the same grep token (drift
 // table, advisories, declared listing) must be a non-match, and the hist
 // filename classifier must reject everything but theurge suite/fixture logs.
+// The gather withholds every fixture its FAILED lines show red.
+// The proposal seam is pure over sightings: percentiles, margins, the
+// insufficient cut-off, the literal rendering, and the drift report. The
+// verdict seam parses only bare verdict lines and the suite summary, keys each
+// case by the fixture section it falls in, and classifies chronological
+// readings steady/failing/regressed/recovered/flaky.
 
-use super::rbtdrw_dowse::{
-    rbtdrw_parse_log_name, rbtdrw_parse_tariff_line, rbtdrw_LogKind, rbtdrw_Observation,
-};
+use super::rbtdre_engine::rbtdre_Tariff;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+use super::rbtdrw_dowse::*;
 
 #[test]
 fn rbtdtw_parses_bare_per_fixture_line() {
@@ -80,6 +86,31 @@ fn rbtdtw_rejects_advisory_lines() {
     assert_eq!(rbtdrw_parse_tariff_line(drift), None);
 }
 
+#[test]
+fn rbtdtw_case_line_parses_only_as_a_case() {
+    let line = "[INFO] [src/rbtdre_engine.rs:814] tariff case tadmor/rbtdrc_ifrit_dns_tcp: elapsed=3s invocations=2 [declared min=— max=— invocations=—]";
+    assert_eq!(
+        rbtdrw_parse_case_tariff_line(line),
+        Some(rbtdrw_CaseObservation {
+            fixture: "tadmor".to_string(),
+            case: "rbtdrc_ifrit_dns_tcp".to_string(),
+            elapsed_secs: 3,
+            invocations: 2,
+        })
+    );
+    // The `case` word keeps it out of the per-fixture census.
+    assert_eq!(rbtdrw_parse_tariff_line(line), None);
+}
+
+#[test]
+fn rbtdtw_case_parse_rejects_fixture_line_and_case_advisory() {
+    let fixture = "[INFO] tariff regime-smoke: elapsed=7s invocations=47 [declared min=2s max=60s invocations=47]";
+    let too_fast = "[INFO] FAILED: case tadmor/rbtdrc_x tariff too-fast — elapsed 0s below declared min 2s (vacuous green)";
+    assert_eq!(rbtdrw_parse_case_tariff_line(fixture), None);
+    assert_eq!(rbtdrw_parse_case_tariff_line(too_fast), None);
+    assert_eq!(rbtdrw_parse_tariff_line(too_fast), None);
+}
+
 #[test]
 fn rbtdtw_rejects_tokenless_line() {
     assert_eq!(rbtdrw_parse_tariff_line("Suite 'reveille': 12 fixture(s) run"), None);
@@ -114,3 +145,336 @@ fn rbtdtw_rejects_foreign_log_names() {
     assert_eq!(rbtdrw_parse_log_name("last.txt"), None);
     assert_eq!(rbtdrw_parse_log_name("hist-rbw-tc-sh-20260709-212119-1-1.txt"), None);
 }
+
+#[test]
+fn rbtdtw_gather_withholds_red_fixture_runs() {
+    let dir = rbtdth_make_scratch("dowse-red");
+    let decl = "[declared min=- max=- invocations=-]";
+    std::fs::write(
+        dir.join("hist-rbw-ts-fast-20260101-090000-11-1.txt"),
+        format!(
+            "PASSED: zcase_a\ntariff zfix-green: elapsed=10s invocations=2 {decl}\n\
+             FAILED: zcase_b\nboom\ntariff zfix-red: elapsed=3s invocations=1 {decl}\n\
+             \x1b[31mFAILED:\x1b[0m zcase_c\ntariff zfix-ansi: elapsed=4s invocations=1 {decl}\n\
+             tariff zfix-fast: elapsed=1s invocations=1 {decl}\n\
+             FAILED: zfix-fast tariff too-fast — elapsed 1s below declared min 60s\n"
+        ),
+    )
+    .unwrap();
+
+    let history = rbtdrw_gather(&dir).unwrap();
+    // Only the green fixture is observed; each red one is counted, not folded.
+    assert_eq!(history.fixtures.keys().collect::<Vec<_>>(), vec!["zfix-green"]);
+    assert_eq!(history.withheld, 3);
+    assert_eq!(history.suites["fast"], vec![("20260101-090000".to_string(), 1, 10)]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ── Proposals ─────────────────────────────────────────────────
+
+fn rbtdtw_sightings(obs: &[(u64, u32)]) -> Vec<rbtdrw_Sighting> {
+    obs.iter()
+        .enumerate()
+        .map(|(i, (e, n))| (format!("2026010{}-000000", i), *e, *n, "suite x".to_string()))
+        .collect()
+}
+
+fn rbtdtw_args(args: &[&str]) -> Result<rbtdrw_DowseArgs, String> {
+    rbtdrw_parse_dowse_args(&args.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+}
+
+#[test]
+fn rbtdtw_percentile_is_nearest_rank() {
+    let v = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+    assert_eq!(rbtdrw_percentile(&v, 0), 10);
+    assert_eq!(rbtdrw_percentile(&v, 10), 10);
+    assert_eq!(rbtdrw_percentile(&v, 11), 20);
+    assert_eq!(rbtdrw_percentile(&v, 90), 90);
+    assert_eq!(rbtdrw_percentile(&v, 100), 100);
+    assert_eq!(rbtdrw_percentile(&[7], 50), 7);
+}
+
+#[test]
+fn rbtdtw_propose_applies_percentiles_and_margin() {
+    let params = rbtdrw_ProposeParams::default();
+    let sightings = rbtdtw_sightings(&[(40, 12), (44, 12), (50, 12), (61, 12)]);
+    let rbtdrw_Proposal::Proposed { tariff, floor_secs, ceiling_secs, .. } =
+        rbtdrw_propose(&sightings, &params)
+    else {
+        panic!("four observations are sufficient");
+    };
+    assert_eq!((floor_secs, ceiling_secs), (40, 61));
+    // p10 less 50% rounds down; p90 plus 50% rounds up; a steady count is kept.
+    assert_eq!(
+        tariff,
+        rbtdre_Tariff { min_secs: Some(20), max_secs: Some(92), invocations: Some(12) }
+    );
+    assert_eq!(
+        rbtdrw_tariff_literal(&tariff),
+        "tariff: rbtdre_Tariff { min_secs: Some(20), max_secs: Some(92), invocations: Some(12) },"
+    );
+}
+
+#[test]
+fn rbtdtw_propose_leaves_varying_count_and_zero_floor_unchecked() {
+    let params = rbtdrw_ProposeParams::default();
+    let sightings = rbtdtw_sightings(&[(0, 2), (1, 3), (1, 2)]);
+    let rbtdrw_Proposal::Proposed { tariff, invocations_lo, invocations_hi, .. } =
+        rbtdrw_propose(&sightings, &params)
+    else {
+        panic!("three observations are sufficient");
+    };
+    assert_eq!((invocations_lo, invocations_hi), (2, 3));
+    assert_eq!(tariff, rbtdre_Tariff { min_secs: None, max_secs: Some(2), invocations: None });
+}
+
+#[test]
+fn rbtdtw_propose_flags_too_few_observations() {
+    let params = rbtdrw_ProposeParams::default();
+    let sightings = rbtdtw_sightings(&[(40, 12), (44, 12)]);
+    assert_eq!(rbtdrw_propose(&sightings, &params), rbtdrw_Proposal::Insufficient { runs: 2 });
+    assert_eq!(rbtdrw_propose(&[], &params), rbtdrw_Proposal::Insufficient { runs: 0 });
+}
+
+#[test]
+fn rbtdtw_drift_names_each_bound_outside_the_envelope() {
+    let sightings = rbtdtw_sightings(&[(30, 5), (80, 6)]);
+    let covering = rbtdre_Tariff { min_secs: Some(30), max_secs: Some(80), invocations: Some(6) };
+    assert!(rbtdrw_drift(&covering, &sightings).is_empty());
+    assert!(rbtdrw_drift(&rbtdre_Tariff::UNCHECKED, &sightings).is_empty());
+
+    let outside = rbtdre_Tariff { min_secs: Some(31), max_secs: Some(79), invocations: Some(7) };
+    let drift = rbtdrw_drift(&outside, &sightings);
+    assert_eq!(drift.len(), 3, "{:?}", drift);
+    assert!(drift[0].contains("too-fast"));
+    assert!(drift[1].contains("slowest 80s"));
+    assert!(drift[2].contains("(5..6)"));
+    // No observations, no envelope to drift from.
+    assert!(rbtdrw_drift(&outside, &[]).is_empty());
+}
+
+#[test]
+fn rbtdtw_dowse_args_gate_tuning_on_propose() {
+    assert_eq!(
+        rbtdtw_args(&["/logs"]).unwrap(),
+        rbtdrw_DowseArgs {
+            log_dir: "/logs".to_string(),
+            propose: None,
+            verdicts: false,
+            since: None,
+        }
+    );
+    let parsed = rbtdtw_args(&["/logs", RBTDRW_FLAG_PROPOSE, RBTDRW_FLAG_MIN_RUNS, "5"]).unwrap();
+    assert_eq!(parsed.propose.map(|p| p.min_runs), Some(5));
+
+    let err = rbtdtw_args(&["/logs", RBTDRW_FLAG_MARGIN_PCT, "20"]).unwrap_err();
+    assert!(err.contains("only with"), "{}", err);
+    let err = rbtdtw_args(&["/logs", RBTDRW_FLAG_PROPOSE, RBTDRW_FLAG_FLOOR_PCT, "x"]).unwrap_err();
+    assert!(err.contains("needs a number"), "{}", err);
+    let err = rbtdtw_args(&[
+        "/logs", RBTDRW_FLAG_PROPOSE, RBTDRW_FLAG_FLOOR_PCT, "95", RBTDRW_FLAG_CEILING_PCT, "90",
+    ])
+    .unwrap_err();
+    assert!(err.contains("percentiles"), "{}", err);
+    assert!(rbtdtw_args(&[]).is_err());
+}
+
+// ── Verdict history ───────────────────────────────────────────
+
+fn rbtdtw_readings(verdicts: &[(&str, rbtdrw_Verdict)]) -> Vec<rbtdrw_Reading> {
+    verdicts
+        .iter()
+        .map(|(stamp, v)| (stamp.to_string(), *v, "suite x".to_string()))
+        .collect()
+}
+
+#[test]
+fn rbtdtw_verdict_line_matches_bare_verdicts_only() {
+    assert_eq!(
+        rbtdrw_parse_verdict_line("[INFO] [src/rbtdre_engine.rs:1191] PASSED: rbtdrc_dns_allowed"),
+        Some((rbtdrw_Verdict::Pass, "rbtdrc_dns_allowed".to_string()))
+    );
+    assert_eq!(
+        rbtdrw_parse_verdict_line("\x1b[1;31mFAILED:\x1b[0m rbtdrc_icmp_blocked"),
+        Some((rbtdrw_Verdict::Fail, "rbtdrc_icmp_blocked".to_string()))
+    );
+    assert_eq!(
+        rbtdrw_parse_verdict_line("SKIPPED: rbtdrc_parley"),
+        Some((rbtdrw_Verdict::Skip, "rbtdrc_parley".to_string()))
+    );
+    // Reason-bearing FAILED lines (tariff, census) must not count twice.
+    assert_eq!(
+        rbtdrw_parse_verdict_line(
+            "FAILED: case fx/ct tariff too-fast — elapsed 0s below declared min 30s"
+        ),
+        None
+    );
+    assert_eq!(
+        rbtdrw_parse_verdict_line(
+            "FAILED: fx census — colophon 'rbw-x' declared but never invoked"
+        ),
+        None
+    );
+    assert_eq!(rbtdrw_parse_verdict_line("FAILED:"), None);
+    assert_eq!(rbtdrw_parse_verdict_line("tariff fx: elapsed=3s invocations=2"), None);
+}
+
+#[test]
+fn rbtdtw_suite_line_reads_green_and_red() {
+    assert_eq!(
+        rbtdrw_parse_suite_line("Suite 'fast': 4 fixture(s) run, 30 passed, 0 failed, 1 skipped"),
+        Some(("fast".to_string(), true))
+    );
+    assert_eq!(
+        rbtdrw_parse_suite_line(
+            "[INFO] [src/main.rs:485] Suite 'crucible': 2 fixture(s) run, 9 passed, 2 failed, 0 skipped"
+        ),
+        Some(("crucible".to_string(), false))
+    );
+    assert_eq!(
+        rbtdrw_parse_suite_line("Suite 'fast' is already green in /tmp/x — nothing to resume"),
+        None
+    );
+    assert_eq!(rbtdrw_parse_suite_line("Resuming suite 'fast' at fixture 'x'"), None);
+    // An interrupted suite prints this in place of its summary: incomplete.
+    assert_eq!(
+        rbtdrw_parse_suite_line("Suite 'fast' interrupted by SIGINT — continue with --resume"),
+        None
+    );
+}
+
+#[test]
+fn rbtdtw_analyze_case_classifies_history() {
+    use rbtdrw_Verdict::{Fail, Pass, Skip};
+
+    let steady = rbtdrw_analyze_case(&rbtdtw_readings(&[("20260101", Pass), ("20260102", Skip)]));
+    assert_eq!(steady.class, rbtdrw_CaseClass::Steady);
+    assert_eq!((steady.pass_rate_pct(), steady.first_failing), (Some(100), None));
+
+    // Out-of-order input is taken in stamp order.
+    let regressed = rbtdrw_analyze_case(&rbtdtw_readings(&[
+        ("20260104", Fail),
+        ("20260101", Pass),
+        ("20260103", Skip),
+        ("20260102", Fail),
+    ]));
+    assert_eq!(regressed.class, rbtdrw_CaseClass::Regressed);
+    assert_eq!(regressed.failing_since.as_deref(), Some("20260102"));
+    assert_eq!(regressed.last_failure.as_deref(), Some("20260104"));
+    assert_eq!((regressed.passed, regressed.failed, regressed.skipped), (1, 2, 1));
+    assert_eq!(regressed.pass_rate_pct(), Some(33));
+
+    let recovered =
+        rbtdrw_analyze_case(&rbtdtw_readings(&[("20260101", Fail), ("20260102", Pass)]));
+    assert_eq!(recovered.class, rbtdrw_CaseClass::Recovered);
+    assert_eq!(recovered.failing_since, None);
+
+    let flaky = rbtdrw_analyze_case(&rbtdtw_readings(&[
+        ("20260101", Pass),
+        ("20260102", Fail),
+        ("20260103", Pass),
+    ]));
+    assert_eq!((flaky.class, flaky.flips), (rbtdrw_CaseClass::Flaky, 2));
+    assert_eq!(flaky.first_failing.as_deref(), Some("20260102"));
+
+    let failing = rbtdrw_analyze_case(&rbtdtw_readings(&[("20260101", Fail), ("20260102", Fail)]));
+    assert_eq!(failing.class, rbtdrw_CaseClass::Failing);
+    assert_eq!(failing.failing_since.as_deref(), Some("20260101"));
+
+    let skipped = rbtdrw_analyze_case(&rbtdtw_readings(&[("20260101", Skip)]));
+    assert_eq!(skipped.pass_rate_pct(), None);
+}
+
+#[test]
+fn rbtdtw_suite_streaks_skip_incomplete_runs() {
+    let runs: Vec<(String, Option<bool>)> = [
+        ("20260105", Some(true)),
+        ("20260101", Some(true)),
+        ("20260102", Some(true)),
+        ("20260103", Some(false)),
+        ("20260104", None),
+        ("20260106", Some(true)),
+    ]
+    .iter()
+    .map(|(s, o)| (s.to_string(), *o))
+    .collect();
+    assert_eq!(
+        rbtdrw_suite_streaks(&runs),
+        rbtdrw_Streaks {
+            green: 4,
+            red: 1,
+            incomplete: 1,
+            current: 2,
+            longest: 2,
+            last_red: Some("20260103".to_string()),
+        }
+    );
+}
+
+#[test]
+fn rbtdtw_gather_verdicts_reads_hist_logs_since_bound() {
+    let dir = rbtdth_make_scratch("dowse-verdicts");
+    let write = |name: &str, body: &str| std::fs::write(dir.join(name), body).unwrap();
+    write(
+        "hist-rbw-ts-fast-20260101-090000-11-1.txt",
+        "PASSED: zcase_a\nFAILED: zcase_b\nboom\n\
+         Suite 'fast': 1 fixture(s) run, 1 passed, 1 failed, 0 skipped\n",
+    );
+    write("hist-rbw-ts-fast-20260102-090000-12-1.txt", "PASSED: zcase_a\n");
+    write("hist-rbw-tf-sh-20251231-090000-10-1.txt", "FAILED: zcase_a\n");
+    write("last.txt", "FAILED: zcase_a\n");
+
+    let history = rbtdrw_gather_verdicts(&dir, Some("20260101")).unwrap();
+    assert_eq!((history.scanned, history.carrying), (2, 2));
+    let mut runs = history.suites["fast"].clone();
+    runs.sort();
+    assert_eq!(
+        runs,
+        vec![("20260101-090000".to_string(), Some(false)), ("20260102-090000".to_string(), None)]
+    );
+    assert_eq!(history.cases["zcase_a"].len(), 2);
+    assert_eq!(history.cases["zcase_b"][0].1, rbtdrw_Verdict::Fail);
+
+    let all = rbtdrw_gather_verdicts(&dir, None).unwrap();
+    assert_eq!(all.cases["zcase_a"].len(), 3);
+}
+
+#[test]
+fn rbtdtw_gather_verdicts_keys_cases_by_fixture_section() {
+    let dir = rbtdth_make_scratch("dowse-sections");
+    // A case tadmor and moriah share: with no section line it stays bare.
+    let shared = crate::rbtdrc_crucible::RBTDRC_FIXTURE_TADMOR.cases[0].name;
+    std::fs::write(
+        dir.join("hist-rbw-ts-fast-20260101-090000-11-1.txt"),
+        format!(
+            "PASSED: {shared}\nFIXTURE: zfix-a\nPASSED: zcase\n\
+             \x1b[1mFIXTURE:\x1b[0m zfix-b\nFAILED: zcase\nboom\n"
+        ),
+    )
+    .unwrap();
+
+    let history = rbtdrw_gather_verdicts(&dir, None).unwrap();
+    assert_eq!(history.cases["zfix-a/zcase"][0].1, rbtdrw_Verdict::Pass);
+    assert_eq!(history.cases["zfix-b/zcase"][0].1, rbtdrw_Verdict::Fail);
+    assert_eq!(history.cases[shared].len(), 1);
+    assert_eq!(history.cases.len(), 3);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn rbtdtw_dowse_args_gate_since_on_verdicts() {
+    let since =
+        |stamp: &str| rbtdtw_args(&["/logs", RBTDRW_FLAG_VERDICTS, RBTDRW_FLAG_SINCE, stamp]);
+    let parsed = since("20260101").unwrap();
+    assert!(parsed.verdicts && parsed.propose.is_none());
+    assert_eq!(parsed.since.as_deref(), Some("20260101"));
+    assert!(since("20260101-090000").is_ok());
+    assert!(since("20260101-0900").is_err());
+
+    let err = rbtdtw_args(&["/logs", RBTDRW_FLAG_SINCE, "20260101"]).unwrap_err();
+    assert!(err.contains("only with"), "{}", err);
+    let err = rbtdtw_args(&["/logs", RBTDRW_FLAG_VERDICTS, RBTDRW_FLAG_PROPOSE]).unwrap_err();
+    assert!(err.contains("separate reports"), "{}", err);
+    let err = rbtdtw_args(&["/logs", RBTDRW_FLAG_VERDICTS, RBTDRW_FLAG_MIN_RUNS, "2"]).unwrap_err();
+    assert!(err.contains("only with"), "{}", err);
+}
@@ -0,0 +1,99 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTW — tests for rbtdrw_worktree disposable suite worktrees
+
+use std::path::{Path, PathBuf};
+
+use super::rbtdrw_worktree::*;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+
+const RBTDTW_STAMP: &str = "20260806-142233-4242-7";
+
+fn rbtdtw_git(args: &[&str], dir: &Path) -> std::process::Output {
+    std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git invocation")
+}
+
+/// A scratch parent holding one committed checkout, returned as (parent, checkout).
+fn rbtdtw_checkout(label: &str) -> (PathBuf, PathBuf) {
+    let parent = rbtdth_make_scratch(label);
+    let checkout = parent.join("crate");
+    std::fs::create_dir_all(&checkout).unwrap();
+    assert!(rbtdtw_git(&["init", "-q"], &checkout).status.success());
+    rbtdtw_git(&["config", "user.email", "theurge@test"], &checkout);
+    rbtdtw_git(&["config", "user.name", "theurge test"], &checkout);
+    std::fs::write(checkout.join("rbrn.env"), "RBRN_SENTRY_HALLMARK=\n").unwrap();
+    rbtdtw_git(&["add", "-A"], &checkout);
+    assert!(rbtdtw_git(&["commit", "-q", "-m", "baseline"], &checkout).status.success());
+    (parent, checkout)
+}
+
+#[test]
+fn rbtdtw_commits_are_offered_as_a_branch_and_checkout_untouched() {
+    let (parent, checkout) = rbtdtw_checkout("worktree-offer");
+    // The operator's own edit in progress — the run must neither see nor touch it.
+    std::fs::write(checkout.join("rbrn.env"), "RBRN_SENTRY_HALLMARK=operator\n").unwrap();
+
+    let worktree = rbtdrw_Worktree::create(&checkout, "gauntlet", RBTDTW_STAMP).unwrap();
+    assert_eq!(worktree.path, parent.join(format!("crate-rbtd-gauntlet-{}", RBTDTW_STAMP)));
+    let body = std::fs::read_to_string(worktree.path.join("rbrn.env")).unwrap();
+    assert_eq!(body, "RBRN_SENTRY_HALLMARK=\n", "the worktree runs HEAD");
+
+    std::fs::write(worktree.path.join("rbrn.env"), "RBRN_SENTRY_HALLMARK=k1\n").unwrap();
+    rbtdtw_git(&["commit", "-q", "-am", "hallmark k1"], &worktree.path);
+    std::fs::write(worktree.path.join("stray.txt"), "half-done\n").unwrap();
+    let path = worktree.path.clone();
+    let base = worktree.base.clone();
+
+    let outcome = worktree.finish().unwrap();
+    let branch = format!("{}gauntlet/{}", RBTDRW_BRANCH_PREFIX, RBTDTW_STAMP);
+    assert_eq!(outcome.branch.as_deref(), Some(branch.as_str()));
+    assert_eq!(outcome.commits.len(), 1);
+    assert!(outcome.commits[0].ends_with(" hallmark k1"), "{:?}", outcome.commits);
+    assert_eq!(outcome.leftovers, vec!["?? stray.txt".to_string()]);
+    assert!(!path.exists(), "the worktree is removed");
+
+    // The branch holds the commit; the checkout's HEAD and edit are as they were.
+    let shown = rbtdtw_git(&["show", &format!("{}:rbrn.env", branch)], &checkout);
+    assert_eq!(String::from_utf8_lossy(&shown.stdout), "RBRN_SENTRY_HALLMARK=k1\n");
+    let head = rbtdtw_git(&["rev-parse", "HEAD"], &checkout);
+    assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), base);
+    let body = std::fs::read_to_string(checkout.join("rbrn.env")).unwrap();
+    assert_eq!(body, "RBRN_SENTRY_HALLMARK=operator\n");
+
+    // A second run with the same stamp is refused, not merged into the first.
+    let err = rbtdrw_Worktree::create(&checkout, "gauntlet", RBTDTW_STAMP).unwrap_err();
+    assert!(err.contains("already exists"), "{}", err);
+    let _ = std::fs::remove_dir_all(&parent);
+}
+
+#[test]
+fn rbtdtw_run_without_commits_leaves_no_branch() {
+    let (parent, checkout) = rbtdtw_checkout("worktree-discard");
+    let worktree = rbtdrw_Worktree::create(&checkout, "reveille", RBTDTW_STAMP).unwrap();
+    let path = worktree.path.clone();
+
+    let outcome = worktree.finish().unwrap();
+    assert_eq!(outcome, rbtdrw_Outcome::default());
+    assert!(!path.exists());
+    let branches = rbtdtw_git(&["branch", "--list", "rbtd/*"], &checkout);
+    assert!(String::from_utf8_lossy(&branches.stdout).trim().is_empty());
+    let _ = std::fs::remove_dir_all(&parent);
+}
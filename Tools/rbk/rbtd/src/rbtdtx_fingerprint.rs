@@ -0,0 +1,96 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTX — tests for rbtdrx_fingerprint host fingerprints
+
+use super::rbtdrx_fingerprint::*;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+
+fn rbtdtx_fingerprint(pairs: &[(&str, &str)]) -> rbtdrx_Fingerprint {
+    rbtdrx_Fingerprint {
+        entries: pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+    }
+}
+
+#[test]
+fn rbtdtx_collect_covers_every_key_and_round_trips() {
+    let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let fingerprint = rbtdrx_Fingerprint::collect(&root);
+    for key in [
+        RBTDRX_KEY_OS, RBTDRX_KEY_ARCH, RBTDRX_KEY_OS_RELEASE, RBTDRX_KEY_KERNEL,
+        RBTDRX_KEY_CGROUP, RBTDRX_KEY_DOCKER, RBTDRX_KEY_PODMAN, RBTDRX_KEY_IPTABLES,
+        RBTDRX_KEY_BASH, RBTDRX_KEY_GIT_HEAD, RBTDRX_KEY_RUSTC, RBTDRX_KEY_CARGO,
+        RBTDRX_KEY_CYGWIN,
+    ] {
+        assert!(fingerprint.get(key).is_some_and(|v| !v.is_empty()), "{}", key);
+    }
+    assert_eq!(fingerprint.get(RBTDRX_KEY_OS), Some(std::env::consts::OS));
+    assert_eq!(fingerprint.get(RBTDRX_KEY_CYGWIN), Some("false"));
+
+    let tmp = rbtdth_make_scratch("fingerprint");
+    let path = fingerprint.write(&tmp).unwrap();
+    assert_eq!(path, tmp.join(RBTDRX_FINGERPRINT_FILE));
+    assert_eq!(rbtdrx_Fingerprint::read(&path).unwrap(), fingerprint);
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdtx_parse_reads_escapes_and_refuses_other_shapes() {
+    let tricky = rbtdtx_fingerprint(&[("bash", "GNU bash \"5.2\"\tx\\y"), ("kernel", "Linux é")]);
+    assert_eq!(rbtdrx_Fingerprint::parse(&tricky.render()).unwrap(), tricky);
+    let compact = rbtdrx_Fingerprint::parse("{\"os\":\"linux\",\"a\":\"\\u0041\\/\"}").unwrap();
+    assert_eq!(compact, rbtdtx_fingerprint(&[("os", "linux"), ("a", "A/")]));
+    assert_eq!(rbtdrx_Fingerprint::parse("{ }").unwrap(), rbtdrx_Fingerprint::default());
+
+    assert!(rbtdrx_Fingerprint::parse("[]").is_err());
+    assert!(rbtdrx_Fingerprint::parse("{\"os\":1}").unwrap_err().contains("'os'"));
+    let repeated = rbtdrx_Fingerprint::parse("{\"os\":\"a\",\"os\":\"b\"}").unwrap_err();
+    assert!(repeated.contains("repeats"), "{}", repeated);
+    assert!(rbtdrx_Fingerprint::parse("{\"os\":\"a\"} x").unwrap_err().contains("trailing"));
+    assert!(rbtdrx_Fingerprint::parse("{\"os\":\"a").is_err());
+}
+
+#[test]
+fn rbtdtx_diff_names_changed_and_one_sided_keys() {
+    let a = rbtdtx_fingerprint(&[("kernel", "Linux 6.1"), ("cgroup", "v2"), ("podman", "4.9")]);
+    let b = rbtdtx_fingerprint(&[("kernel", "Linux 6.8"), ("cgroup", "v2"), ("docker", "27.1")]);
+    assert_eq!(
+        rbtdrx_fingerprint_diff(&a, &b),
+        vec![
+            "kernel: Linux 6.1 → Linux 6.8",
+            "podman: 4.9 → (absent)",
+            "docker: (absent) → 27.1",
+        ]
+    );
+    assert!(rbtdrx_fingerprint_diff(&a, &a).is_empty());
+}
+
+#[test]
+fn rbtdtx_cgroup_and_os_release_readers() {
+    let tmp = rbtdth_make_scratch("fingerprint-cgroup");
+    assert_eq!(zrbtdrx_cgroup_version(&tmp.join("absent")), "none");
+    assert_eq!(zrbtdrx_cgroup_version(&tmp), "v1");
+    std::fs::create_dir_all(tmp.join("unified")).unwrap();
+    std::fs::write(tmp.join("unified/cgroup.controllers"), "").unwrap();
+    assert_eq!(zrbtdrx_cgroup_version(&tmp), "hybrid");
+    std::fs::write(tmp.join("cgroup.controllers"), "cpu memory\n").unwrap();
+    assert_eq!(zrbtdrx_cgroup_version(&tmp), "v2");
+    let _ = std::fs::remove_dir_all(&tmp);
+
+    let body = "NAME=\"Debian\"\nPRETTY_NAME=\"Debian GNU/Linux 12 (bookworm)\"\n";
+    assert_eq!(zrbtdrx_os_release_name(body).as_deref(), Some("Debian GNU/Linux 12 (bookworm)"));
+    assert_eq!(zrbtdrx_os_release_name("NAME=x\n"), None);
+}
@@ -0,0 +1,145 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTY — tests for rbtdry_cassette record/replay
+
+use std::path::{Path, PathBuf};
+
+use super::rbtdgc_consts::RBTDGC_CRUCIBLE_BARK;
+use super::rbtdri_invocation::*;
+use super::rbtdry_cassette::*;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+
+fn rbtdty_write_script(root: &Path, name: &str, body: &str) {
+    let tt = root.join("tt");
+    std::fs::create_dir_all(&tt).unwrap();
+    let path = tt.join(name);
+    std::fs::write(&path, format!("#!/bin/bash\n{}", body)).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+}
+
+fn rbtdty_context(root: &Path, label: &str) -> rbtdri_Context {
+    let burv: PathBuf = root.join(label);
+    rbtdri_Context::new(root, "testplate", &burv.join("temp"), &burv.join("output"))
+}
+
+#[test]
+fn rbtdty_entry_round_trips_escaped_args() {
+    let entry = rbtdry_Entry {
+        key: rbtdry_Key {
+            colophon: "rbw-cC".to_string(),
+            imprint: "tadmor".to_string(),
+            args: vec!["two\nlines".to_string(), "back\\slash".to_string(), String::new()],
+        },
+        exit_code: -1,
+    };
+    let text = entry.render();
+    assert_eq!(text.lines().count(), 6, "{}", text);
+    assert_eq!(rbtdry_Entry::parse(&text).unwrap(), entry);
+    assert!(rbtdry_Entry::parse("colophon=x\nimprint=\n").unwrap_err().contains("exit_code"));
+}
+
+#[test]
+fn rbtdty_key_reads_imprint_off_the_tabtarget() {
+    let imprinted = rbtdry_Key::new("rbw-cC", Path::new("tt/rbw-cC.Charge.tadmor.sh"), &["a"]);
+    assert_eq!(imprinted.imprint, "tadmor");
+    assert_eq!(imprinted.args, vec!["a".to_string()]);
+    let global = rbtdry_Key::new("rbw-cA", Path::new("tt/rbw-cA.Active.sh"), &[]);
+    assert_eq!(global.imprint, "");
+}
+
+#[test]
+fn rbtdty_replay_serves_recorded_launches_without_spawning() {
+    let tmp = rbtdth_make_scratch("cassette-replay");
+    let script = format!("{}.Bark.testplate.sh", RBTDGC_CRUCIBLE_BARK);
+    rbtdty_write_script(
+        &tmp,
+        &script,
+        "mkdir -p \"$BURV_OUTPUT_ROOT_DIR/current\"\n\
+         echo \"fact $1\" > \"$BURV_OUTPUT_ROOT_DIR/current/zrbtdty_fact\"\n\
+         echo \"out $1\"\necho \"err $1\" >&2\nexit 4\n",
+    );
+    let tape = tmp.join("tape");
+
+    let mut ctx = rbtdty_context(&tmp, "recorded");
+    rbtdri_arm_cassette(Some(rbtdry_Cassette::record(&tape).unwrap()));
+    rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["alpha"]).unwrap();
+    rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["beta"]).unwrap();
+    rbtdri_arm_cassette(None);
+
+    // The tabtarget now fails loud if spawned; replay must not reach it.
+    rbtdty_write_script(&tmp, &script, "echo spawned\nexit 99\n");
+    let mut ctx = rbtdty_context(&tmp, "replayed");
+    rbtdri_arm_cassette(Some(rbtdry_Cassette::replay(&tape).unwrap()));
+    assert!(rbtdri_replaying());
+    let beta = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["beta"]).unwrap();
+    let unmatched = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["beta"]);
+    let alpha = rbtdri_invoke(&mut ctx, RBTDGC_CRUCIBLE_BARK, &["alpha"]).unwrap();
+    let cassette = rbtdri_arm_cassette(None).unwrap();
+    assert!(!rbtdri_replaying());
+
+    assert_eq!((beta.stdout.as_str(), beta.stderr.as_str()), ("out beta\n", "err beta\n"));
+    assert_eq!(beta.exit_code, 4);
+    assert_eq!(rbtdri_read_burv_fact(&beta, "zrbtdty_fact").unwrap(), "fact beta");
+    assert_eq!(rbtdri_read_burv_fact(&alpha, "zrbtdty_fact").unwrap(), "fact alpha");
+    assert!(unmatched.unwrap_err().contains("no unserved entry"));
+    assert_eq!(cassette.served(), (2, 2));
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdty_replay_promotes_current_on_a_chained_launch() {
+    let tmp = rbtdth_make_scratch("cassette-chain");
+    let tape = tmp.join("tape");
+    let mut cassette = rbtdry_Cassette::record(&tape).unwrap();
+    let key = rbtdry_Key::new(RBTDGC_CRUCIBLE_BARK, Path::new("x.Bark.testplate.sh"), &[]);
+    for value in ["first", "second"] {
+        let burv_output = tmp.join(value);
+        let current = burv_output.join(RBTDRI_BURV_OUTPUT_SUBDIR);
+        std::fs::create_dir_all(&current).unwrap();
+        std::fs::write(current.join("zrbtdty_fact"), value).unwrap();
+        let result = rbtdri_InvokeResult {
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: 0,
+            burv_output,
+        };
+        cassette.capture(&key, &result).unwrap();
+    }
+
+    let mut cassette = rbtdry_Cassette::replay(&tape).unwrap();
+    let burv_output = tmp.join("replayed");
+    cassette.serve(&key, &burv_output).unwrap();
+    cassette.serve(&key, &burv_output).unwrap();
+    let read = |sub: &str| std::fs::read_to_string(burv_output.join(sub).join("zrbtdty_fact"));
+    assert_eq!(read(RBTDRI_BURV_OUTPUT_SUBDIR).unwrap(), "second");
+    assert_eq!(read("previous").unwrap(), "first");
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[test]
+fn rbtdty_record_refuses_an_occupied_directory() {
+    let tmp = rbtdth_make_scratch("cassette-occupied");
+    std::fs::write(tmp.join("stray"), "x").unwrap();
+    assert!(rbtdry_Cassette::record(&tmp).unwrap_err().contains("not empty"));
+    let _ = std::fs::remove_dir_all(&tmp);
+}
@@ -0,0 +1,111 @@
+// Copyright 2026 Scale Invariant, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Author: Brad Hyslop <bhyslop@scaleinvariant.org>
+//
+// RBTDTZ — tests for rbtdrz_facts typed BURV fact parsing
+
+use super::rbtdri_invocation::{rbtdri_InvokeResult, RBTDRI_BURV_OUTPUT_SUBDIR};
+use super::rbtdrz_facts::*;
+use super::rbtdth_helpers::rbtdth_make_scratch;
+
+const RBTDTZ_DIGEST: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+#[test]
+fn rbtdtz_hallmark_accepts_every_bash_prefix() {
+    for good in [
+        "c260327172456-r260327172501",
+        "b260327172456-r260327172501",
+        "g260327172456-r260327172501",
+        "k260327172456-git-unavailable",
+        "k260327172456-v1.2-3-gabc1234-dirty",
+    ] {
+        assert_eq!(rbtdrz_Hallmark::parse(good).unwrap().as_str(), good);
+    }
+    for (bad, rule) in [
+        ("", "empty"),
+        ("x260327172456-r260327172501", "no known prefix"),
+        ("c26032717245-r260327172501", "12-digit"),
+        ("c260327172456", "-r<12-digit"),
+        ("k260327172456-", "git context"),
+        ("k260327172456-has space", "git context"),
+    ] {
+        let e = rbtdrz_Hallmark::parse(bad).unwrap_err();
+        assert!(e.contains(rule), "{:?}: {}", bad, e);
+    }
+}
+
+#[test]
+fn rbtdtz_gar_root_and_ark_stem_shapes() {
+    let root = rbtdrz_GarRoot::parse("us-central1-docker.pkg.dev/proj-1/rbw-depot").unwrap();
+    assert_eq!(root.to_string(), "us-central1-docker.pkg.dev/proj-1/rbw-depot");
+    assert!(rbtdrz_GarRoot::parse("us-central1-docker.pkg.dev/proj-1").is_err());
+    assert!(rbtdrz_GarRoot::parse("gcr.io/proj-1/repo").unwrap_err().contains("host"));
+    assert!(rbtdrz_GarRoot::parse("us-docker.pkg.dev/p/repo:tag").unwrap_err().contains("tag"));
+
+    let stem = rbtdrz_ArkStem::parse("rbi_hm/c260327172456-r260327172501").unwrap();
+    assert_eq!(stem.hallmark(), "c260327172456-r260327172501");
+    assert!(rbtdrz_ArkStem::parse("rbi_other/c260327172456-r260327172501").is_err());
+    assert!(rbtdrz_ArkStem::parse("rbi_hm/nope").unwrap_err().contains("hallmark segment"));
+}
+
+#[test]
+fn rbtdtz_touchmark_decodes_kind_like_bash() {
+    let cases = [("b260327172456", "b"), ("vw260327172456", "vw"), ("vn260327172456", "vn")];
+    for (good, kind) in cases {
+        assert_eq!(rbtdrz_Touchmark::parse(good).unwrap().kind(), kind);
+    }
+    assert!(rbtdrz_Touchmark::parse("zz260327172456").unwrap_err().contains("kind 'zz'"));
+    assert!(rbtdrz_Touchmark::parse("b2603271724").unwrap_err().contains("12-digit"));
+}
+
+#[test]
+fn rbtdtz_digest_ref_splits_name_and_digest() {
+    let text = format!("docker.io/library/busybox@sha256:{}", RBTDTZ_DIGEST);
+    let pinned = rbtdrz_DigestRef::parse(&text).unwrap();
+    assert_eq!(pinned.name(), "docker.io/library/busybox");
+    assert_eq!(pinned.digest(), RBTDTZ_DIGEST);
+    let tagged = rbtdrz_DigestRef::parse("docker.io/library/busybox:1.36").unwrap_err();
+    assert!(tagged.contains("bare tag"), "{}", tagged);
+    let upper = format!("busybox@sha256:{}", RBTDTZ_DIGEST.to_uppercase());
+    assert!(rbtdrz_DigestRef::parse(&upper).unwrap_err().contains("lowercase hex"));
+    assert!(rbtdrz_DigestRef::parse(&format!("@sha256:{}", RBTDTZ_DIGEST)).is_err());
+}
+
+#[test]
+fn rbtdtz_read_fact_names_the_file_and_rule() {
+    let tmp = rbtdth_make_scratch("facts-read");
+    let current = tmp.join(RBTDRI_BURV_OUTPUT_SUBDIR);
+    std::fs::create_dir_all(&current).unwrap();
+    std::fs::write(current.join("rbf_fact_hallmark"), "c260327172456-r260327172501\n").unwrap();
+    let stem = "rbi_hm/c260327172456-r260327172501\n";
+    std::fs::write(current.join("rbf_fact_gar_root"), stem).unwrap();
+    let result = rbtdri_InvokeResult {
+        stdout: String::new(),
+        stderr: String::new(),
+        exit_code: 0,
+        burv_output: tmp.clone(),
+    };
+
+    let hallmark: rbtdrz_Hallmark = rbtdrz_read_fact(&result, "rbf_fact_hallmark").unwrap();
+    assert_eq!(hallmark.as_str(), "c260327172456-r260327172501");
+    let swapped = rbtdrz_read_fact::<rbtdrz_GarRoot>(&result, "rbf_fact_gar_root").unwrap_err();
+    assert!(swapped.contains("'rbf_fact_gar_root'"), "{}", swapped);
+    assert!(swapped.contains("well-formed GAR root"), "{}", swapped);
+    let path = current.join("rbf_fact_gar_root").display().to_string();
+    assert!(swapped.contains(&path), "{}", swapped);
+    assert!(rbtdrz_read_fact::<rbtdrz_Touchmark>(&result, "rbf_fact_absent").is_err());
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
@@ -62,6 +62,7 @@ zrbz_kindle() {
   buz_enroll RBZ_CRUCIBLE_WRIT    "rbw-cw"  "${z_mod}" "rbob_writ"         "imprint"  "Non-interactive exec in Sentry container"
   buz_enroll RBZ_CRUCIBLE_FIAT    "rbw-cf"  "${z_mod}" "rbob_fiat"         "imprint"  "Non-interactive exec in Pentacle container"
   buz_enroll RBZ_CRUCIBLE_BARK    "rbw-cb"  "${z_mod}" "rbob_bark"         "imprint"  "Non-interactive exec in Bottle container"
+  buz_enroll RBZ_CRUCIBLE_SMITE   "rbw-cm"  "${z_mod}" "rbob_smite"        "imprint"  "Container-runtime verb against the Sentry container"
   buz_enroll RBZ_CRUCIBLE_ACTIVE  "rbw-cic" "${z_mod}" "rbob_charged"      "param1"   "Check whether the Crucible is charged (compose project has running containers)"
   buz_enroll RBZ_CRUCIBLE_KLUDGE_BOTTLE "rbw-cKB" "${z_mod}" "rbob_kludge_bottle" "param1" "Kludge Bottle vessel and drive hallmark into nameplate"
   buz_enroll RBZ_CRUCIBLE_KLUDGE_SENTRY "rbw-cKS" "${z_mod}" "rbob_kludge_sentry" "param1" "Kludge Sentry vessel and drive hallmark into nameplate"
@@ -49,6 +49,8 @@ const RBIDA_SEL_DNS_BLOCK_SPOOFING: &str = "dns-block-spoofing";
 const RBIDA_SEL_DNS_BLOCK_TUNNELING: &str = "dns-block-tunneling";
 const RBIDA_SEL_TCP443_CONNECT: &str = "tcp443-connect";
 const RBIDA_SEL_TCP443_BLOCK: &str = "tcp443-block";
+const RBIDA_SEL_DNS_RESOLVE: &str = "dns-resolve";
+const RBIDA_SEL_DNS_RESOLVE_BLOCK: &str = "dns-resolve-block";
 const RBIDA_SEL_ICMP_FIRST_HOP: &str = "icmp-first-hop";
 const RBIDA_SEL_ICMP_SECOND_HOP_BLOCKED: &str = "icmp-second-hop-blocked";
 const RBIDA_SEL_DNS_EXFIL_SUBDOMAIN: &str = "dns-exfil-subdomain";
@@ -122,6 +124,10 @@ pub enum rbida_Attack {
     Tcp443Connect,
     /// TCP 443 connection to IP should fail (pass IP in extra_args[0])
     Tcp443Block,
+    /// DNS resolution of a domain should succeed (pass domain in extra_args[0])
+    DnsResolve,
+    /// DNS resolution of a domain should fail (pass domain in extra_args[0])
+    DnsResolveBlock,
     /// First traceroute hop should be sentry IP or blocked (* * *)
     IcmpFirstHop,
     /// Second traceroute hop should be blocked (* * *)
@@ -232,6 +238,8 @@ impl rbida_Attack {
             RBIDA_SEL_DNS_BLOCK_TUNNELING => Some(Self::DnsBlockTunneling),
             RBIDA_SEL_TCP443_CONNECT => Some(Self::Tcp443Connect),
             RBIDA_SEL_TCP443_BLOCK => Some(Self::Tcp443Block),
+            RBIDA_SEL_DNS_RESOLVE => Some(Self::DnsResolve),
+            RBIDA_SEL_DNS_RESOLVE_BLOCK => Some(Self::DnsResolveBlock),
             RBIDA_SEL_ICMP_FIRST_HOP => Some(Self::IcmpFirstHop),
             RBIDA_SEL_ICMP_SECOND_HOP_BLOCKED => Some(Self::IcmpSecondHopBlocked),
             RBIDA_SEL_DNS_EXFIL_SUBDOMAIN => Some(Self::DnsExfilSubdomain),
@@ -288,6 +296,8 @@ impl rbida_Attack {
             Self::DnsBlockTunneling => RBIDA_SEL_DNS_BLOCK_TUNNELING,
             Self::Tcp443Connect => RBIDA_SEL_TCP443_CONNECT,
             Self::Tcp443Block => RBIDA_SEL_TCP443_BLOCK,
+            Self::DnsResolve => RBIDA_SEL_DNS_RESOLVE,
+            Self::DnsResolveBlock => RBIDA_SEL_DNS_RESOLVE_BLOCK,
             Self::IcmpFirstHop => RBIDA_SEL_ICMP_FIRST_HOP,
             Self::IcmpSecondHopBlocked => RBIDA_SEL_ICMP_SECOND_HOP_BLOCKED,
             Self::DnsExfilSubdomain => RBIDA_SEL_DNS_EXFIL_SUBDOMAIN,
@@ -343,6 +353,8 @@ impl rbida_Attack {
             RBIDA_SEL_DNS_BLOCK_TUNNELING,
             RBIDA_SEL_TCP443_CONNECT,
             RBIDA_SEL_TCP443_BLOCK,
+            RBIDA_SEL_DNS_RESOLVE,
+            RBIDA_SEL_DNS_RESOLVE_BLOCK,
             RBIDA_SEL_ICMP_FIRST_HOP,
             RBIDA_SEL_ICMP_SECOND_HOP_BLOCKED,
             RBIDA_SEL_DNS_EXFIL_SUBDOMAIN,
@@ -495,6 +507,34 @@ pub fn rbida_run(attack: &rbida_Attack, extra_args: &[&str]) -> rbida_Verdict {
                 &format!("TCP 443 connection to {} (should be blocked)", ip),
             )
         }
+        rbida_Attack::DnsResolve => {
+            let domain = extra_args.first().copied().unwrap_or("");
+            if domain.is_empty() {
+                return rbida_Verdict {
+                    passed: false,
+                    detail: "ERROR: dns-resolve requires domain as extra arg".to_string(),
+                };
+            }
+            rbida_expect_command_succeeds(
+                "getent",
+                &["hosts", domain],
+                &format!("DNS resolution of {} (should be allowed)", domain),
+            )
+        }
+        rbida_Attack::DnsResolveBlock => {
+            let domain = extra_args.first().copied().unwrap_or("");
+            if domain.is_empty() {
+                return rbida_Verdict {
+                    passed: false,
+                    detail: "ERROR: dns-resolve-block requires domain as extra arg".to_string(),
+                };
+            }
+            rbida_expect_command_fails(
+                "getent",
+                &["hosts", domain],
+                &format!("DNS resolution of {} (should be blocked)", domain),
+            )
+        }
         rbida_Attack::IcmpFirstHop => rbida_check_icmp_first_hop(),
         rbida_Attack::IcmpSecondHopBlocked => rbida_check_icmp_second_hop_blocked(),
         // Ported python sorties